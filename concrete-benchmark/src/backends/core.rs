@@ -54,6 +54,8 @@ pub fn bench() {
     lwe_ciphertext_discarding_addition::bench::<CoreEngine, LweCiphertext64, LweCiphertext64>(&mut criterion);
     lwe_ciphertext_vector_discarding_affine_transformation::bench::<CoreEngine, LweCiphertextVector32, CleartextVector32, Plaintext32, LweCiphertext32>(&mut criterion);
     lwe_ciphertext_vector_discarding_affine_transformation::bench::<CoreEngine, LweCiphertextVector64, CleartextVector64, Plaintext64, LweCiphertext64>(&mut criterion);
+    lwe_ciphertext_vector_discarding_bootstrap::bench::<CoreEngine, FourierLweBootstrapKey32, GlweCiphertextVector32, LweCiphertextVector32, LweCiphertextVector32>(&mut criterion);
+    lwe_ciphertext_vector_discarding_bootstrap::bench::<CoreEngine, FourierLweBootstrapKey64, GlweCiphertextVector64, LweCiphertextVector64, LweCiphertextVector64>(&mut criterion);
     lwe_ciphertext_discarding_decryption::bench::<CoreEngine, LweSecretKey32, LweCiphertext32, Plaintext32>(&mut criterion);
     lwe_ciphertext_discarding_decryption::bench::<CoreEngine, LweSecretKey64, LweCiphertext64, Plaintext64>(&mut criterion);
     lwe_ciphertext_discarding_encryption::bench::<CoreEngine, LweSecretKey32, Plaintext32, LweCiphertext32>(&mut criterion);
@@ -62,6 +64,8 @@ pub fn bench() {
     lwe_ciphertext_discarding_extraction::bench::<CoreEngine, GlweCiphertext64, LweCiphertext64>(&mut criterion);
     lwe_ciphertext_discarding_keyswitch::bench::<CoreEngine, LweKeyswitchKey32, LweCiphertext32, LweCiphertext32>(&mut criterion);
     lwe_ciphertext_discarding_keyswitch::bench::<CoreEngine, LweKeyswitchKey64, LweCiphertext64, LweCiphertext64>(&mut criterion);
+    lwe_ciphertext_vector_discarding_keyswitch::bench::<CoreEngine, LweKeyswitchKey32, LweCiphertextVector32, LweCiphertextVector32>(&mut criterion);
+    lwe_ciphertext_vector_discarding_keyswitch::bench::<CoreEngine, LweKeyswitchKey64, LweCiphertextVector64, LweCiphertextVector64>(&mut criterion);
     lwe_ciphertext_discarding_negation::bench::<CoreEngine, LweCiphertext32, LweCiphertext32>(&mut criterion);
     lwe_ciphertext_discarding_negation::bench::<CoreEngine, LweCiphertext64, LweCiphertext64>(&mut criterion);
     lwe_ciphertext_plaintext_fusing_addition::bench::<CoreEngine, LweCiphertext32, Plaintext32>(&mut criterion);
@@ -76,6 +80,8 @@ pub fn bench() {
     lwe_ciphertext_vector_discarding_decryption::bench::<CoreEngine, LweSecretKey64, LweCiphertextVector64, PlaintextVector64>(&mut criterion);
     lwe_ciphertext_vector_discarding_encryption::bench::<CoreEngine, LweSecretKey32, PlaintextVector32, LweCiphertextVector32>(&mut criterion);
     lwe_ciphertext_vector_discarding_encryption::bench::<CoreEngine, LweSecretKey64, PlaintextVector64, LweCiphertextVector64>(&mut criterion);
+    lwe_ciphertext_vector_discarding_extraction::bench::<CoreEngine, GlweCiphertext32, LweCiphertextVector32>(&mut criterion);
+    lwe_ciphertext_vector_discarding_extraction::bench::<CoreEngine, GlweCiphertext64, LweCiphertextVector64>(&mut criterion);
     lwe_ciphertext_vector_zero_encryption::bench::<CoreEngine, LweSecretKey32, LweCiphertextVector32>(&mut criterion);
     lwe_ciphertext_vector_zero_encryption::bench::<CoreEngine, LweSecretKey64, LweCiphertextVector64>(&mut criterion);
     lwe_ciphertext_zero_encryption::bench::<CoreEngine, LweSecretKey32, LweCiphertext32>(&mut criterion);
@@ -0,0 +1,135 @@
+use crate::synthesizer::{
+    SynthesizableGlweCiphertextVectorEntity, SynthesizableLweBootstrapKeyEntity,
+    SynthesizableLweCiphertextVectorEntity, Synthesizer,
+};
+use crate::utils::benchmark_name;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweCiphertextCount, GlweDimension,
+    LweCiphertextCount, LweDimension, PolynomialSize,
+};
+use concrete_core::specification::engines::LweCiphertextVectorDiscardingBootstrapEngine;
+use criterion::{black_box, BenchmarkId, Criterion};
+
+/// A generic function benchmarking the discarding lwe vector bootstrap operation.
+pub fn bench<Engine, BootstrapKey, AccumulatorVector, InputCiphertextVector, OutputCiphertextVector>(
+    c: &mut Criterion,
+) where
+    Engine: LweCiphertextVectorDiscardingBootstrapEngine<
+        BootstrapKey,
+        AccumulatorVector,
+        InputCiphertextVector,
+        OutputCiphertextVector,
+    >,
+    BootstrapKey: SynthesizableLweBootstrapKeyEntity,
+    AccumulatorVector: SynthesizableGlweCiphertextVectorEntity<
+        KeyDistribution = BootstrapKey::OutputKeyDistribution,
+    >,
+    InputCiphertextVector: SynthesizableLweCiphertextVectorEntity<
+        KeyDistribution = BootstrapKey::InputKeyDistribution,
+    >,
+    OutputCiphertextVector: SynthesizableLweCiphertextVectorEntity<
+        KeyDistribution = BootstrapKey::OutputKeyDistribution,
+    >,
+{
+    let mut group = c.benchmark_group(
+        benchmark_name!(impl LweCiphertextVectorDiscardingBootstrapEngine<
+            BootstrapKey,
+            AccumulatorVector,
+            InputCiphertextVector,
+            OutputCiphertextVector
+            > for Engine),
+    );
+
+    let mut engine = Engine::new().unwrap();
+    let mut synthesizer = Synthesizer::default();
+
+    for param in PARAMETERS {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("{:?}", param)),
+            &param,
+            |b, param| {
+                let (input_lwe_dim, glwe_dim, poly_size, base_log, level, ciphertext_count) =
+                    param.to_owned();
+                let output_lwe_dim = LweDimension(glwe_dim.0 * poly_size.0);
+                let bsk = BootstrapKey::synthesize(
+                    &mut synthesizer,
+                    input_lwe_dim,
+                    poly_size,
+                    glwe_dim,
+                    base_log,
+                    level,
+                    VARIANCE,
+                );
+                let accumulators = AccumulatorVector::synthesize(
+                    &mut synthesizer,
+                    poly_size,
+                    glwe_dim,
+                    GlweCiphertextCount(ciphertext_count.0),
+                    VARIANCE,
+                );
+                let mut output_lwe_vector = OutputCiphertextVector::synthesize(
+                    &mut synthesizer,
+                    output_lwe_dim,
+                    ciphertext_count,
+                    VARIANCE,
+                );
+                let input_lwe_vector = InputCiphertextVector::synthesize(
+                    &mut synthesizer,
+                    input_lwe_dim,
+                    ciphertext_count,
+                    VARIANCE,
+                );
+                b.iter(|| {
+                    engine
+                        .discard_bootstrap_lwe_ciphertext_vector(
+                            black_box(&mut output_lwe_vector),
+                            black_box(&input_lwe_vector),
+                            black_box(&accumulators),
+                            black_box(&bsk),
+                        )
+                        .unwrap();
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+/// The variance used to encrypt everything in the benchmark.
+const VARIANCE: Variance = Variance(0.00000001);
+
+/// The parameters the benchmark is executed against.
+const PARAMETERS: [(
+    LweDimension,
+    GlweDimension,
+    PolynomialSize,
+    DecompositionBaseLog,
+    DecompositionLevelCount,
+    LweCiphertextCount,
+); 3] = [
+    (
+        LweDimension(100),
+        GlweDimension(1),
+        PolynomialSize(512),
+        DecompositionBaseLog(2),
+        DecompositionLevelCount(3),
+        LweCiphertextCount(10),
+    ),
+    (
+        LweDimension(100),
+        GlweDimension(1),
+        PolynomialSize(1024),
+        DecompositionBaseLog(2),
+        DecompositionLevelCount(3),
+        LweCiphertextCount(10),
+    ),
+    (
+        LweDimension(100),
+        GlweDimension(1),
+        PolynomialSize(2048),
+        DecompositionBaseLog(2),
+        DecompositionLevelCount(3),
+        LweCiphertextCount(10),
+    ),
+];
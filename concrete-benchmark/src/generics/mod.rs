@@ -35,8 +35,11 @@ pub mod lwe_ciphertext_plaintext_discarding_addition;
 pub mod lwe_ciphertext_plaintext_fusing_addition;
 pub mod lwe_ciphertext_vector_decryption;
 pub mod lwe_ciphertext_vector_discarding_affine_transformation;
+pub mod lwe_ciphertext_vector_discarding_bootstrap;
 pub mod lwe_ciphertext_vector_discarding_decryption;
 pub mod lwe_ciphertext_vector_discarding_encryption;
+pub mod lwe_ciphertext_vector_discarding_extraction;
+pub mod lwe_ciphertext_vector_discarding_keyswitch;
 pub mod lwe_ciphertext_vector_encryption;
 pub mod lwe_ciphertext_vector_zero_encryption;
 pub mod lwe_ciphertext_zero_encryption;
@@ -1,4 +1,5 @@
 use concrete_boolean::client_key::ClientKey;
+use concrete_boolean::evaluator::Evaluator;
 use concrete_boolean::parameters::{BooleanParameters, DEFAULT_PARAMETERS, TFHE_LIB_PARAMETERS};
 use concrete_boolean::server_key::ServerKey;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
@@ -26,9 +27,16 @@ criterion_group!(
     xor_gate_tfhelib
 );
 
+criterion_group!(
+    evaluator_default_params,
+    evaluator_and_gate_default,
+    evaluator_mux_gate_default
+);
+
 criterion_main!(
     gate_bootstrapping_default_params,
-    gate_bootstrapping_tfhelib_params
+    gate_bootstrapping_tfhelib_params,
+    evaluator_default_params
 );
 
 fn and_gate(c: &mut Criterion, params: &BooleanParameters, option: &str) {
@@ -174,3 +182,28 @@ fn xor_gate_default(c: &mut Criterion) {
 fn xor_gate_tfhelib(c: &mut Criterion) {
     xor_gate(c, &TFHE_LIB_PARAMETERS, "(TFHE-lib parameters)");
 }
+
+fn evaluator_and_gate_default(c: &mut Criterion) {
+    let cks = ClientKey::new(&DEFAULT_PARAMETERS);
+    let sks = ServerKey::new(&cks);
+    let mut evaluator = Evaluator::new(&sks);
+    let ct1 = cks.encrypt(true);
+    let ct2 = cks.encrypt(false);
+    let mut ct_res = cks.encrypt(false);
+    c.bench_function("Evaluator AND gate (default parameters)", |b| {
+        b.iter(|| evaluator.discard_and(black_box(&mut ct_res), &ct1, &ct2))
+    });
+}
+
+fn evaluator_mux_gate_default(c: &mut Criterion) {
+    let cks = ClientKey::new(&DEFAULT_PARAMETERS);
+    let sks = ServerKey::new(&cks);
+    let mut evaluator = Evaluator::new(&sks);
+    let ct1 = cks.encrypt(true);
+    let ct2 = cks.encrypt(false);
+    let ct3 = cks.encrypt(true);
+    let mut ct_res = cks.encrypt(false);
+    c.bench_function("Evaluator MUX gate (default parameters)", |b| {
+        b.iter(|| evaluator.discard_mux(black_box(&mut ct_res), &ct1, &ct2, &ct3))
+    });
+}
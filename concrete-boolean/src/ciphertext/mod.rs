@@ -1,12 +1,50 @@
 //! An encryption of a boolean message.
 //!
-//! This module implements the ciphertext structure containing an encryption of a Boolean message.
+//! This module implements the ciphertext structure containing an encryption of a Boolean message,
+//! together with its compressed form.
 
-use concrete_core::backends::core::private::crypto::lwe::LweCiphertext;
+use crate::engine::BooleanEngine;
+use concrete_core::prelude::{
+    CoreEngine, LweCiphertext32, LweSeededCiphertext32, LweSeededCiphertextExpansionEngine,
+};
 use serde::{Deserialize, Serialize};
 
 /// A structure containing a ciphertext, meant to encrypt a Boolean message.
 ///
 /// It is used to evaluate a Boolean circuits homomorphically.
 #[derive(Serialize, Clone, Deserialize)]
-pub struct Ciphertext(pub(crate) LweCiphertext<Vec<u32>>);
+pub struct Ciphertext(pub(crate) LweCiphertext32);
+
+/// A structure containing a compressed ciphertext, meant to encrypt a Boolean message.
+///
+/// The mask of a ciphertext is uniformly random, and makes up nearly all of its size. A compressed
+/// ciphertext only stores the body, together with the seed of the generator the mask was sampled
+/// from. It must be decompressed before being used in a Boolean circuit.
+#[derive(Serialize, Clone, Deserialize)]
+pub struct CompressedCiphertext(pub(crate) LweSeededCiphertext32);
+
+impl CompressedCiphertext {
+    /// Decompresses the ciphertext, by sampling its mask again from the seed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Compressed encryption of one message:
+    /// let compressed = cks.encrypt_compressed(true);
+    ///
+    /// // Decompression and decryption:
+    /// let ct = compressed.decompress();
+    /// let dec = cks.decrypt(&ct);
+    /// assert_eq!(true, dec);
+    /// ```
+    pub fn decompress(&self) -> Ciphertext {
+        CoreEngine::with_thread_local_mut(|engine| {
+            Ciphertext(engine.expand_lwe_seeded_ciphertext(&self.0).unwrap())
+        })
+    }
+}
@@ -3,14 +3,19 @@
 //! This module implements the generation of the client' secret keys, together with the
 //! encryption and decryption methods.
 
-use crate::ciphertext::Ciphertext;
+use crate::ciphertext::{Ciphertext, CompressedCiphertext};
+use crate::encode_boolean;
+use crate::engine::BooleanEngine;
+use crate::integer::FheUint;
 use crate::parameters::BooleanParameters;
-use crate::{PLAINTEXT_FALSE, PLAINTEXT_TRUE};
-use concrete_commons::key_kinds::BinaryKeyKind;
-use concrete_core::backends::core::private::crypto::encoding::Plaintext;
-use concrete_core::backends::core::private::crypto::lwe::LweCiphertext;
-use concrete_core::backends::core::private::crypto::secret::generators::{EncryptionRandomGenerator, SecretRandomGenerator};
-use concrete_core::backends::core::private::crypto::secret::{GlweSecretKey, LweSecretKey};
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+use concrete_commons::numeric::UnsignedInteger;
+use concrete_core::prelude::{
+    CoreEngine, GlweSecretKey32, GlweSecretKeyCreationEngine, LweCiphertextDecryptionEngine,
+    LweCiphertextEncryptionEngine, LweSecretKey32, LweSecretKeyCreationEngine,
+    LweSeededCiphertextEncryptionEngine, Plaintext32, PlaintextCreationEngine,
+    PlaintextRetrievalEngine,
+};
 use serde::{Deserialize, Serialize};
 
 /// A structure containing the client key, which must be kept secret.
@@ -23,8 +28,8 @@ use serde::{Deserialize, Serialize};
 /// * `parameters` - the cryptographic parameter set.
 #[derive(Serialize, Clone, Deserialize, PartialEq, Debug)]
 pub struct ClientKey {
-    pub(crate) lwe_secret_key: LweSecretKey<BinaryKeyKind, Vec<u32>>,
-    pub(crate) glwe_secret_key: GlweSecretKey<BinaryKeyKind, Vec<u32>>,
+    pub(crate) lwe_secret_key: LweSecretKey32,
+    pub(crate) glwe_secret_key: GlweSecretKey32,
     pub(crate) parameters: BooleanParameters,
 }
 
@@ -48,27 +53,64 @@ impl ClientKey {
     /// ```
     pub fn encrypt(&self, message: bool) -> Ciphertext {
         // encode the boolean message
-        let plain: Plaintext<u32> = if message {
-            Plaintext(PLAINTEXT_TRUE)
-        } else {
-            Plaintext(PLAINTEXT_FALSE)
-        };
+        let encoded = encode_boolean(message, self.parameters.plaintext_log_scaling_factor);
 
-        // instantiate an encryption random generator
-        let mut encryption_generator = EncryptionRandomGenerator::new(None);
+        CoreEngine::with_thread_local_mut(|engine| {
+            // create the plaintext
+            let plain: Plaintext32 = engine.create_plaintext(&encoded).unwrap();
 
-        // allocate the ciphertext
-        let mut ct = LweCiphertext::allocate(0_u32, self.parameters.lwe_dimension.to_lwe_size());
+            // encrypt the encoded boolean
+            let ct = engine
+                .encrypt_lwe_ciphertext(
+                    &self.lwe_secret_key,
+                    &plain,
+                    Variance(self.parameters.lwe_modular_std_dev.get_variance()),
+                )
+                .unwrap();
 
-        // encrypt the encoded boolean
-        self.lwe_secret_key.encrypt_lwe(
-            &mut ct,
-            &plain,
-            self.parameters.lwe_modular_std_dev,
-            &mut encryption_generator,
-        );
+            Ciphertext(ct)
+        })
+    }
 
-        Ciphertext(ct)
+    /// Encrypts a Boolean message into a compressed ciphertext using the client key.
+    ///
+    /// The compressed ciphertext only stores the body of the encryption and the seed of its mask,
+    /// and must be decompressed with [`CompressedCiphertext::decompress`] before being used.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Compressed encryption of one message:
+    /// let compressed = cks.encrypt_compressed(true);
+    ///
+    /// // Decompression and decryption:
+    /// let dec = cks.decrypt(&compressed.decompress());
+    /// assert_eq!(true, dec);
+    /// ```
+    pub fn encrypt_compressed(&self, message: bool) -> CompressedCiphertext {
+        // encode the boolean message
+        let encoded = encode_boolean(message, self.parameters.plaintext_log_scaling_factor);
+
+        CoreEngine::with_thread_local_mut(|engine| {
+            // create the plaintext
+            let plain: Plaintext32 = engine.create_plaintext(&encoded).unwrap();
+
+            // encrypt the encoded boolean
+            let ct = engine
+                .encrypt_lwe_seeded_ciphertext(
+                    &self.lwe_secret_key,
+                    &plain,
+                    Variance(self.parameters.lwe_modular_std_dev.get_variance()),
+                )
+                .unwrap();
+
+            CompressedCiphertext(ct)
+        })
     }
 
     /// Decrypts a ciphertext encrypting a Boolean message using the client key.
@@ -89,14 +131,67 @@ impl ClientKey {
     /// assert_eq!(true, dec);
     /// ```
     pub fn decrypt(&self, ct: &Ciphertext) -> bool {
-        // allocation for the decryption
-        let mut decrypted = Plaintext(0_u32);
-
-        // decryption
-        self.lwe_secret_key.decrypt_lwe(&mut decrypted, &ct.0);
+        let decrypted: u32 = CoreEngine::with_thread_local_mut(|engine| {
+            // decryption
+            let plain: Plaintext32 = engine
+                .decrypt_lwe_ciphertext(&self.lwe_secret_key, &ct.0)
+                .unwrap();
+            engine.retrieve_plaintext(&plain).unwrap()
+        });
 
         // return
-        decrypted.0 < (1 << 31)
+        decrypted < (1 << 31)
+    }
+
+    /// Encrypts an unsigned integer bit by bit using the client key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    /// use concrete_boolean::integer::FheUint16;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encryption of one integer:
+    /// let ct: FheUint16 = cks.encrypt_integer(1234_u16);
+    ///
+    /// // Decryption:
+    /// let dec = cks.decrypt_integer(&ct);
+    /// assert_eq!(1234_u16, dec);
+    /// ```
+    pub fn encrypt_integer<T: UnsignedInteger>(&self, message: T) -> FheUint<T> {
+        FheUint::from_bits(
+            (0..T::BITS)
+                .map(|i| self.encrypt((message >> i) & T::ONE == T::ONE))
+                .collect(),
+        )
+    }
+
+    /// Decrypts an encrypted unsigned integer using the client key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encryption of one integer:
+    /// let ct = cks.encrypt_integer(42_u8);
+    ///
+    /// // Decryption:
+    /// let dec = cks.decrypt_integer(&ct);
+    /// assert_eq!(42_u8, dec);
+    /// ```
+    pub fn decrypt_integer<T: UnsignedInteger>(&self, ct: &FheUint<T>) -> T {
+        ct.bits()
+            .iter()
+            .enumerate()
+            .filter(|(_, bit)| self.decrypt(bit))
+            .fold(T::ZERO, |message, (i, _)| message | (T::ONE << i))
     }
 
     /// Allocates and generates a client key.
@@ -111,20 +206,19 @@ impl ClientKey {
     /// let cks = ClientKey::new(&DEFAULT_PARAMETERS);
     /// ```
     pub fn new(parameter_set: &BooleanParameters) -> ClientKey {
-        // instantiate a secret random generator
-        let mut secret_generator = SecretRandomGenerator::new(None);
-
-        // generate the lwe secret key
-        let lwe_secret_key: LweSecretKey<BinaryKeyKind, Vec<u32>> =
-            LweSecretKey::generate_binary(parameter_set.lwe_dimension, &mut secret_generator);
-
-        // generate the rlwe secret key
-        let glwe_secret_key: GlweSecretKey<BinaryKeyKind, Vec<u32>> =
-            GlweSecretKey::generate_binary(
-                parameter_set.glwe_dimension,
-                parameter_set.polynomial_size,
-                &mut secret_generator,
-            );
+        let (lwe_secret_key, glwe_secret_key) = CoreEngine::with_thread_local_mut(|engine| {
+            // generate the lwe secret key
+            let lwe_secret_key: LweSecretKey32 = engine
+                .create_lwe_secret_key(parameter_set.lwe_dimension)
+                .unwrap();
+
+            // generate the rlwe secret key
+            let glwe_secret_key: GlweSecretKey32 = engine
+                .create_glwe_secret_key(parameter_set.glwe_dimension, parameter_set.polynomial_size)
+                .unwrap();
+
+            (lwe_secret_key, glwe_secret_key)
+        });
 
         // pack the keys in the client key set
         let cks: ClientKey = ClientKey {
@@ -0,0 +1,132 @@
+//! The engines used to evaluate Boolean circuits.
+//!
+//! This module defines the traits a `concrete-core` engine must implement to be used by the
+//! Boolean server key. The bootstraps of the gates of [`ServerKey`](crate::server_key::ServerKey)
+//! only rely on the operations exposed by these traits, such that any backend implementing the
+//! bootstrap engine traits of `concrete-core` can be used to evaluate Boolean circuits. The linear
+//! operations and the key switches of the gates are computed with the [`CoreEngine`].
+//!
+//! The bootstraps can be computed with the `CoreEngine`, or with the `OptalysysEngine` when the
+//! `backend_optalysys` feature is enabled.
+
+use concrete_core::prelude::*;
+use concrete_core::specification::entities::markers::BinaryKeyDistribution;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::cell::RefCell;
+use std::fmt::Debug;
+
+/// An engine which can be used to evaluate the bootstraps of Boolean circuits.
+///
+/// Implementors define the type of the bootstrap key contained in the server key, and give access
+/// to an engine instance owned by the current thread. The engine is created the first time it is
+/// used on a thread, which allows the gates to be evaluated from several threads at once.
+pub trait BooleanEngine: AbstractEngine + 'static {
+    /// The type of the bootstrap key used by the engine.
+    type BootstrapKey: LweBootstrapKeyEntity<
+            InputKeyDistribution = BinaryKeyDistribution,
+            OutputKeyDistribution = BinaryKeyDistribution,
+        > + Clone
+        + PartialEq
+        + Debug
+        + Serialize
+        + DeserializeOwned;
+
+    /// Executes `f` with the engine owned by the current thread.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if it is called from within `f`.
+    fn with_thread_local_mut<R, F>(f: F) -> R
+    where
+        F: FnOnce(&mut Self) -> R;
+}
+
+/// An engine implementing the operations needed to generate the bootstrap key of a server key and
+/// to bootstrap the ciphertexts of the Boolean gates.
+///
+/// This trait is automatically implemented for every [`BooleanEngine`] implementing the
+/// corresponding `concrete-core` engine traits.
+pub trait BooleanServerEngine:
+    BooleanEngine
+    + LweBootstrapKeyCreationEngine<
+        LweSecretKey32,
+        GlweSecretKey32,
+        <Self as BooleanEngine>::BootstrapKey,
+    > + LweCiphertextDiscardingBootstrapEngine<
+        <Self as BooleanEngine>::BootstrapKey,
+        GlweCiphertext32,
+        LweCiphertext32,
+        LweCiphertext32,
+    >
+{
+}
+
+impl<Engine> BooleanServerEngine for Engine where
+    Engine: BooleanEngine
+        + LweBootstrapKeyCreationEngine<
+            LweSecretKey32,
+            GlweSecretKey32,
+            <Engine as BooleanEngine>::BootstrapKey,
+        > + LweCiphertextDiscardingBootstrapEngine<
+            <Engine as BooleanEngine>::BootstrapKey,
+            GlweCiphertext32,
+            LweCiphertext32,
+            LweCiphertext32,
+        >
+{
+}
+
+thread_local! {
+    static CORE_ENGINE: RefCell<CoreEngine> = RefCell::new(CoreEngine::new().unwrap());
+    // The engine computing the linear operations of the gates, kept apart from `CORE_ENGINE` so
+    // that it can be borrowed while the core engine bootstraps.
+    static GATE_ENGINE: RefCell<CoreEngine> = RefCell::new(CoreEngine::new().unwrap());
+}
+
+impl BooleanEngine for CoreEngine {
+    type BootstrapKey = FourierLweBootstrapKey32;
+
+    fn with_thread_local_mut<R, F>(f: F) -> R
+    where
+        F: FnOnce(&mut Self) -> R,
+    {
+        CORE_ENGINE.with(|engine| f(&mut engine.borrow_mut()))
+    }
+}
+
+#[cfg(feature = "backend_optalysys")]
+thread_local! {
+    static OPTALYSYS_ENGINE: RefCell<OptalysysEngine> =
+        RefCell::new(OptalysysEngine::new().unwrap());
+}
+
+#[cfg(feature = "backend_optalysys")]
+impl BooleanEngine for OptalysysEngine {
+    type BootstrapKey = OptalysysFourierLweBootstrapKey32;
+
+    fn with_thread_local_mut<R, F>(f: F) -> R
+    where
+        F: FnOnce(&mut Self) -> R,
+    {
+        OPTALYSYS_ENGINE.with(|engine| f(&mut engine.borrow_mut()))
+    }
+}
+
+/// Executes `f` with the core engine computing the linear operations and the key switches of the
+/// gates, and with the `Engine` computing their bootstraps, both owned by the current thread.
+///
+/// # Panics
+///
+/// This function panics if it is called from within `f`.
+pub(crate) fn with_gate_engines_mut<Engine, R, F>(f: F) -> R
+where
+    Engine: BooleanEngine,
+    F: FnOnce(&mut CoreEngine, &mut Engine) -> R,
+{
+    GATE_ENGINE.with(|engine| {
+        Engine::with_thread_local_mut(|bootstrap_engine| {
+            f(&mut engine.borrow_mut(), bootstrap_engine)
+        })
+    })
+}
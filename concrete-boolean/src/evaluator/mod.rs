@@ -0,0 +1,664 @@
+//! A stateful evaluator for homomorphic Boolean circuits.
+//!
+//! The gates of the [`ServerKey`] allocate their scratch objects (accumulator, temporary
+//! ciphertexts, ...) every time they are evaluated. This module implements the [`Evaluator`]
+//! type, which owns these scratch objects, such that they are allocated once and reused by every
+//! gate. The evaluator also exposes _discarding_ variants of the gates, which write their result
+//! in an existing ciphertext instead of allocating a new one.
+
+#[cfg(test)]
+mod tests;
+
+use crate::ciphertext::Ciphertext;
+use crate::engine::{BooleanEngine, BooleanServerEngine};
+use crate::server_key::{GateBuffers, ServerKey};
+use concrete_core::prelude::{AbstractEngine, CoreEngine};
+
+/// A structure evaluating Boolean gates with a server key, while reusing its scratch objects.
+///
+/// The evaluator owns a core engine computing the linear operations and the key switches, an
+/// `Engine` computing the bootstraps, and the buffers needed to evaluate the gates. The
+/// accumulator used in the bootstrap is computed once at creation.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_boolean::evaluator::Evaluator;
+/// use concrete_boolean::gen_keys;
+///
+/// // Generate the client key and the server key:
+/// let (cks, sks) = gen_keys();
+///
+/// // Create the evaluator:
+/// let mut evaluator = Evaluator::new(&sks);
+///
+/// // Encrypt two messages:
+/// let ct1 = cks.encrypt(true);
+/// let ct2 = cks.encrypt(false);
+///
+/// // Compute homomorphically an AND gate, then a NOT gate in place:
+/// let mut ct_res = evaluator.and(&ct1, &ct2);
+/// evaluator.discard_not(&mut ct_res, &ct1);
+///
+/// // Decrypt:
+/// let dec = cks.decrypt(&ct_res);
+/// assert_eq!(false, dec);
+/// ```
+pub struct Evaluator<'sks, Engine: BooleanEngine = CoreEngine> {
+    server_key: &'sks ServerKey<Engine>,
+    engine: CoreEngine,
+    bootstrap_engine: Engine,
+    buffers: GateBuffers,
+}
+
+impl<'sks, Engine> Evaluator<'sks, Engine>
+where
+    Engine: BooleanServerEngine,
+{
+    /// Creates an evaluator for the given server key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::evaluator::Evaluator;
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Create the evaluator:
+    /// let evaluator = Evaluator::new(&sks);
+    /// ```
+    pub fn new(server_key: &'sks ServerKey<Engine>) -> Evaluator<'sks, Engine> {
+        let mut engine = CoreEngine::new().unwrap();
+        let bootstrap_engine = Engine::new().unwrap();
+        let buffers = server_key.create_buffers(&mut engine);
+        Evaluator {
+            server_key,
+            engine,
+            bootstrap_engine,
+            buffers,
+        }
+    }
+
+    /// Computes homomorphically an AND gate between two ciphertexts encrypting Boolean values:
+    /// $$ ct_{out} = ct_{left}~\mathrm{AND}~ct_{right} $$
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::evaluator::Evaluator;
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    /// let mut evaluator = Evaluator::new(&sks);
+    ///
+    /// // Encrypt two messages:
+    /// let ct1 = cks.encrypt(true);
+    /// let ct2 = cks.encrypt(false);
+    ///
+    /// // Compute homomorphically an AND gate:
+    /// let ct_res = evaluator.and(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_and = cks.decrypt(&ct_res);
+    /// assert_eq!(false, dec_and);
+    /// ```
+    pub fn and(&mut self, ct_left: &Ciphertext, ct_right: &Ciphertext) -> Ciphertext {
+        let mut ct_out = self.server_key.allocate_ciphertext(&mut self.engine);
+        self.discard_and(&mut ct_out, ct_left, ct_right);
+        ct_out
+    }
+
+    /// Computes homomorphically an AND gate between two ciphertexts encrypting Boolean values:
+    /// $$ ct_{out} = ct_{left}~\mathrm{AND}~ct_{right} $$
+    ///
+    /// The result is written in `ct_out`, which avoids allocating a new ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::evaluator::Evaluator;
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    /// let mut evaluator = Evaluator::new(&sks);
+    ///
+    /// // Encrypt two messages:
+    /// let ct1 = cks.encrypt(true);
+    /// let ct2 = cks.encrypt(false);
+    ///
+    /// // Compute homomorphically an AND gate:
+    /// let mut ct_res = cks.encrypt(true);
+    /// evaluator.discard_and(&mut ct_res, &ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_and = cks.decrypt(&ct_res);
+    /// assert_eq!(false, dec_and);
+    /// ```
+    pub fn discard_and(
+        &mut self,
+        ct_out: &mut Ciphertext,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+    ) {
+        self.server_key.discard_and(
+            &mut self.engine,
+            &mut self.bootstrap_engine,
+            &mut self.buffers,
+            ct_out,
+            ct_left,
+            ct_right,
+        );
+    }
+
+    /// Computes an homomorphic MUX gate between three ciphertexts encrypting Boolean values:
+    /// $$ct_{out} = (ct_{condition}?~ct_{then}:~ct_{else}) $$
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::evaluator::Evaluator;
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    /// let mut evaluator = Evaluator::new(&sks);
+    ///
+    /// // Encrypt three messages:
+    /// let ct1 = cks.encrypt(true);
+    /// let ct2 = cks.encrypt(false);
+    /// let ct3 = cks.encrypt(true);
+    ///
+    /// // Compute homomorphically a MUX gate:
+    /// let ct_res = evaluator.mux(&ct1, &ct2, &ct3);
+    ///
+    /// // Decrypt:
+    /// let dec_mux = cks.decrypt(&ct_res);
+    /// assert_eq!(false, dec_mux);
+    /// ```
+    pub fn mux(
+        &mut self,
+        ct_condition: &Ciphertext,
+        ct_then: &Ciphertext,
+        ct_else: &Ciphertext,
+    ) -> Ciphertext {
+        let mut ct_out = self.server_key.allocate_ciphertext(&mut self.engine);
+        self.discard_mux(&mut ct_out, ct_condition, ct_then, ct_else);
+        ct_out
+    }
+
+    /// Computes an homomorphic MUX gate between three ciphertexts encrypting Boolean values:
+    /// $$ct_{out} = (ct_{condition}?~ct_{then}:~ct_{else}) $$
+    ///
+    /// The result is written in `ct_out`, which avoids allocating a new ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::evaluator::Evaluator;
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    /// let mut evaluator = Evaluator::new(&sks);
+    ///
+    /// // Encrypt three messages:
+    /// let ct1 = cks.encrypt(true);
+    /// let ct2 = cks.encrypt(false);
+    /// let ct3 = cks.encrypt(true);
+    ///
+    /// // Compute homomorphically a MUX gate:
+    /// let mut ct_res = cks.encrypt(true);
+    /// evaluator.discard_mux(&mut ct_res, &ct1, &ct2, &ct3);
+    ///
+    /// // Decrypt:
+    /// let dec_mux = cks.decrypt(&ct_res);
+    /// assert_eq!(false, dec_mux);
+    /// ```
+    pub fn discard_mux(
+        &mut self,
+        ct_out: &mut Ciphertext,
+        ct_condition: &Ciphertext,
+        ct_then: &Ciphertext,
+        ct_else: &Ciphertext,
+    ) {
+        self.server_key.discard_mux(
+            &mut self.engine,
+            &mut self.bootstrap_engine,
+            &mut self.buffers,
+            ct_out,
+            ct_condition,
+            ct_then,
+            ct_else,
+        );
+    }
+
+    /// Computes homomorphically a NAND gate between two ciphertexts encrypting Boolean values:
+    /// $$ct_{out} = \mathrm{NOT} (ct_{left}~\mathrm{AND}~ct_{right})$$
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::evaluator::Evaluator;
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    /// let mut evaluator = Evaluator::new(&sks);
+    ///
+    /// // Encrypt two messages:
+    /// let ct1 = cks.encrypt(true);
+    /// let ct2 = cks.encrypt(false);
+    ///
+    /// // Compute homomorphically a NAND gate:
+    /// let ct_res = evaluator.nand(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_nand = cks.decrypt(&ct_res);
+    /// assert_eq!(true, dec_nand);
+    /// ```
+    pub fn nand(&mut self, ct_left: &Ciphertext, ct_right: &Ciphertext) -> Ciphertext {
+        let mut ct_out = self.server_key.allocate_ciphertext(&mut self.engine);
+        self.discard_nand(&mut ct_out, ct_left, ct_right);
+        ct_out
+    }
+
+    /// Computes homomorphically a NAND gate between two ciphertexts encrypting Boolean values:
+    /// $$ct_{out} = \mathrm{NOT} (ct_{left}~\mathrm{AND}~ct_{right})$$
+    ///
+    /// The result is written in `ct_out`, which avoids allocating a new ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::evaluator::Evaluator;
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    /// let mut evaluator = Evaluator::new(&sks);
+    ///
+    /// // Encrypt two messages:
+    /// let ct1 = cks.encrypt(true);
+    /// let ct2 = cks.encrypt(false);
+    ///
+    /// // Compute homomorphically a NAND gate:
+    /// let mut ct_res = cks.encrypt(true);
+    /// evaluator.discard_nand(&mut ct_res, &ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_nand = cks.decrypt(&ct_res);
+    /// assert_eq!(true, dec_nand);
+    /// ```
+    pub fn discard_nand(
+        &mut self,
+        ct_out: &mut Ciphertext,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+    ) {
+        self.server_key.discard_nand(
+            &mut self.engine,
+            &mut self.bootstrap_engine,
+            &mut self.buffers,
+            ct_out,
+            ct_left,
+            ct_right,
+        );
+    }
+
+    /// Computes homomorphically a NOR gate between two ciphertexts encrypting Boolean values:
+    /// $$ ct_{out} = \mathrm{NOT}(ct_{left}~\mathrm{OR}~ct_{right}) $$
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::evaluator::Evaluator;
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    /// let mut evaluator = Evaluator::new(&sks);
+    ///
+    /// // Encrypt two messages:
+    /// let ct1 = cks.encrypt(true);
+    /// let ct2 = cks.encrypt(false);
+    ///
+    /// // Compute homomorphically the NOR gate:
+    /// let ct_res = evaluator.nor(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_nor = cks.decrypt(&ct_res);
+    /// assert_eq!(false, dec_nor);
+    /// ```
+    pub fn nor(&mut self, ct_left: &Ciphertext, ct_right: &Ciphertext) -> Ciphertext {
+        let mut ct_out = self.server_key.allocate_ciphertext(&mut self.engine);
+        self.discard_nor(&mut ct_out, ct_left, ct_right);
+        ct_out
+    }
+
+    /// Computes homomorphically a NOR gate between two ciphertexts encrypting Boolean values:
+    /// $$ ct_{out} = \mathrm{NOT}(ct_{left}~\mathrm{OR}~ct_{right}) $$
+    ///
+    /// The result is written in `ct_out`, which avoids allocating a new ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::evaluator::Evaluator;
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    /// let mut evaluator = Evaluator::new(&sks);
+    ///
+    /// // Encrypt two messages:
+    /// let ct1 = cks.encrypt(true);
+    /// let ct2 = cks.encrypt(false);
+    ///
+    /// // Compute homomorphically the NOR gate:
+    /// let mut ct_res = cks.encrypt(true);
+    /// evaluator.discard_nor(&mut ct_res, &ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_nor = cks.decrypt(&ct_res);
+    /// assert_eq!(false, dec_nor);
+    /// ```
+    pub fn discard_nor(
+        &mut self,
+        ct_out: &mut Ciphertext,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+    ) {
+        self.server_key.discard_nor(
+            &mut self.engine,
+            &mut self.bootstrap_engine,
+            &mut self.buffers,
+            ct_out,
+            ct_left,
+            ct_right,
+        );
+    }
+
+    /// Computes homomorphically a NOT gate of a ciphertexts encrypting a Boolean value:
+    /// $$ct_{out} = \mathrm{NOT}(ct_{in})$$
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::evaluator::Evaluator;
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    /// let mut evaluator = Evaluator::new(&sks);
+    ///
+    /// // Encrypt a message:
+    /// let ct = cks.encrypt(true);
+    ///
+    /// // Compute homomorphically a NOT gate:
+    /// let ct_res = evaluator.not(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec_not = cks.decrypt(&ct_res);
+    /// assert_eq!(false, dec_not);
+    /// ```
+    pub fn not(&mut self, ct: &Ciphertext) -> Ciphertext {
+        let mut ct_out = self.server_key.allocate_ciphertext(&mut self.engine);
+        self.discard_not(&mut ct_out, ct);
+        ct_out
+    }
+
+    /// Computes homomorphically a NOT gate of a ciphertexts encrypting a Boolean value:
+    /// $$ct_{out} = \mathrm{NOT}(ct_{in})$$
+    ///
+    /// The result is written in `ct_out`, which avoids allocating a new ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::evaluator::Evaluator;
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    /// let mut evaluator = Evaluator::new(&sks);
+    ///
+    /// // Encrypt a message:
+    /// let ct = cks.encrypt(true);
+    ///
+    /// // Compute homomorphically a NOT gate:
+    /// let mut ct_res = cks.encrypt(true);
+    /// evaluator.discard_not(&mut ct_res, &ct);
+    ///
+    /// // Decrypt:
+    /// let dec_not = cks.decrypt(&ct_res);
+    /// assert_eq!(false, dec_not);
+    /// ```
+    pub fn discard_not(&mut self, ct_out: &mut Ciphertext, ct: &Ciphertext) {
+        self.server_key.discard_not(&mut self.engine, ct_out, ct);
+    }
+
+    /// Computes homomorphically an OR gate between two ciphertexts encrypting Boolean values:
+    /// $$ct_{out} = ct_{left}~\mathrm{OR}~ct_{right}$$
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::evaluator::Evaluator;
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    /// let mut evaluator = Evaluator::new(&sks);
+    ///
+    /// // Encrypt two messages:
+    /// let ct1 = cks.encrypt(true);
+    /// let ct2 = cks.encrypt(false);
+    ///
+    /// // Compute homomorphically the OR gate:
+    /// let ct_res = evaluator.or(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_or = cks.decrypt(&ct_res);
+    /// assert_eq!(true, dec_or);
+    /// ```
+    pub fn or(&mut self, ct_left: &Ciphertext, ct_right: &Ciphertext) -> Ciphertext {
+        let mut ct_out = self.server_key.allocate_ciphertext(&mut self.engine);
+        self.discard_or(&mut ct_out, ct_left, ct_right);
+        ct_out
+    }
+
+    /// Computes homomorphically an OR gate between two ciphertexts encrypting Boolean values:
+    /// $$ct_{out} = ct_{left}~\mathrm{OR}~ct_{right}$$
+    ///
+    /// The result is written in `ct_out`, which avoids allocating a new ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::evaluator::Evaluator;
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    /// let mut evaluator = Evaluator::new(&sks);
+    ///
+    /// // Encrypt two messages:
+    /// let ct1 = cks.encrypt(true);
+    /// let ct2 = cks.encrypt(false);
+    ///
+    /// // Compute homomorphically the OR gate:
+    /// let mut ct_res = cks.encrypt(true);
+    /// evaluator.discard_or(&mut ct_res, &ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_or = cks.decrypt(&ct_res);
+    /// assert_eq!(true, dec_or);
+    /// ```
+    pub fn discard_or(
+        &mut self,
+        ct_out: &mut Ciphertext,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+    ) {
+        self.server_key.discard_or(
+            &mut self.engine,
+            &mut self.bootstrap_engine,
+            &mut self.buffers,
+            ct_out,
+            ct_left,
+            ct_right,
+        );
+    }
+
+    /// Computes homomorphically an XNOR gate (or equality test) between two ciphertexts encrypting
+    /// Boolean values:
+    /// $$ct_{out} = (ct_{left}~==~ct_{right}) $$
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::evaluator::Evaluator;
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    /// let mut evaluator = Evaluator::new(&sks);
+    ///
+    /// // Encrypt two messages:
+    /// let ct1 = cks.encrypt(true);
+    /// let ct2 = cks.encrypt(false);
+    ///
+    /// // Compute the XNOR gate:
+    /// let ct_res = evaluator.xnor(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_xnor = cks.decrypt(&ct_res);
+    /// assert_eq!(false, dec_xnor);
+    /// ```
+    pub fn xnor(&mut self, ct_left: &Ciphertext, ct_right: &Ciphertext) -> Ciphertext {
+        let mut ct_out = self.server_key.allocate_ciphertext(&mut self.engine);
+        self.discard_xnor(&mut ct_out, ct_left, ct_right);
+        ct_out
+    }
+
+    /// Computes homomorphically an XNOR gate (or equality test) between two ciphertexts encrypting
+    /// Boolean values:
+    /// $$ct_{out} = (ct_{left}~==~ct_{right}) $$
+    ///
+    /// The result is written in `ct_out`, which avoids allocating a new ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::evaluator::Evaluator;
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    /// let mut evaluator = Evaluator::new(&sks);
+    ///
+    /// // Encrypt two messages:
+    /// let ct1 = cks.encrypt(true);
+    /// let ct2 = cks.encrypt(false);
+    ///
+    /// // Compute the XNOR gate:
+    /// let mut ct_res = cks.encrypt(true);
+    /// evaluator.discard_xnor(&mut ct_res, &ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_xnor = cks.decrypt(&ct_res);
+    /// assert_eq!(false, dec_xnor);
+    /// ```
+    pub fn discard_xnor(
+        &mut self,
+        ct_out: &mut Ciphertext,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+    ) {
+        self.server_key.discard_xnor(
+            &mut self.engine,
+            &mut self.bootstrap_engine,
+            &mut self.buffers,
+            ct_out,
+            ct_left,
+            ct_right,
+        );
+    }
+
+    /// Computes homomorphically an XOR gate between two ciphertexts encrypting Boolean values:
+    /// $$ct_{out}= ct_{left}~\mathrm{XOR}~ct_{right}$$
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::evaluator::Evaluator;
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    /// let mut evaluator = Evaluator::new(&sks);
+    ///
+    /// // Encryption of two messages:
+    /// let ct1 = cks.encrypt(true);
+    /// let ct2 = cks.encrypt(false);
+    ///
+    /// // Compute the XOR gate:
+    /// let ct_res = evaluator.xor(&ct1, &ct2);
+    ///
+    /// // Decryption:
+    /// let dec_xor = cks.decrypt(&ct_res);
+    /// assert_eq!(true, dec_xor);
+    /// ```
+    pub fn xor(&mut self, ct_left: &Ciphertext, ct_right: &Ciphertext) -> Ciphertext {
+        let mut ct_out = self.server_key.allocate_ciphertext(&mut self.engine);
+        self.discard_xor(&mut ct_out, ct_left, ct_right);
+        ct_out
+    }
+
+    /// Computes homomorphically an XOR gate between two ciphertexts encrypting Boolean values:
+    /// $$ct_{out}= ct_{left}~\mathrm{XOR}~ct_{right}$$
+    ///
+    /// The result is written in `ct_out`, which avoids allocating a new ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::evaluator::Evaluator;
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    /// let mut evaluator = Evaluator::new(&sks);
+    ///
+    /// // Encryption of two messages:
+    /// let ct1 = cks.encrypt(true);
+    /// let ct2 = cks.encrypt(false);
+    ///
+    /// // Compute the XOR gate:
+    /// let mut ct_res = cks.encrypt(true);
+    /// evaluator.discard_xor(&mut ct_res, &ct1, &ct2);
+    ///
+    /// // Decryption:
+    /// let dec_xor = cks.decrypt(&ct_res);
+    /// assert_eq!(true, dec_xor);
+    /// ```
+    pub fn discard_xor(
+        &mut self,
+        ct_out: &mut Ciphertext,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+    ) {
+        self.server_key.discard_xor(
+            &mut self.engine,
+            &mut self.bootstrap_engine,
+            &mut self.buffers,
+            ct_out,
+            ct_left,
+            ct_right,
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use crate::client_key::ClientKey;
+use crate::evaluator::Evaluator;
+use crate::parameters::DEFAULT_PARAMETERS;
+use crate::random_boolean;
+use crate::server_key::ServerKey;
+
+/// Number of assert in randomized tests
+const NB_TEST: usize = 128;
+
+#[test]
+fn test_evaluator_gates() {
+    // generate the client key set
+    let cks = ClientKey::new(&DEFAULT_PARAMETERS);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    // create the evaluator
+    let mut evaluator = Evaluator::new(&sks);
+
+    for _ in 0..NB_TEST {
+        // generation of three random booleans
+        let b1 = random_boolean();
+        let b2 = random_boolean();
+        let b3 = random_boolean();
+
+        // encryption of the booleans
+        let ct1 = cks.encrypt(b1);
+        let ct2 = cks.encrypt(b2);
+        let ct3 = cks.encrypt(b3);
+
+        // gates evaluation, decryption and asserts
+        assert_eq!(b1 && b2, cks.decrypt(&evaluator.and(&ct1, &ct2)));
+        assert_eq!(
+            if b1 { b2 } else { b3 },
+            cks.decrypt(&evaluator.mux(&ct1, &ct2, &ct3))
+        );
+        assert_eq!(!(b1 && b2), cks.decrypt(&evaluator.nand(&ct1, &ct2)));
+        assert_eq!(!(b1 || b2), cks.decrypt(&evaluator.nor(&ct1, &ct2)));
+        assert_eq!(!b1, cks.decrypt(&evaluator.not(&ct1)));
+        assert_eq!(b1 || b2, cks.decrypt(&evaluator.or(&ct1, &ct2)));
+        assert_eq!(b1 == b2, cks.decrypt(&evaluator.xnor(&ct1, &ct2)));
+        assert_eq!(b1 ^ b2, cks.decrypt(&evaluator.xor(&ct1, &ct2)));
+    }
+}
+
+#[test]
+fn test_evaluator_discarding_gates() {
+    // generate the client key set
+    let cks = ClientKey::new(&DEFAULT_PARAMETERS);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    // create the evaluator
+    let mut evaluator = Evaluator::new(&sks);
+
+    // the output ciphertext, overwritten by every gate
+    let mut ct_res = cks.encrypt(false);
+
+    for _ in 0..NB_TEST {
+        // generation of three random booleans
+        let b1 = random_boolean();
+        let b2 = random_boolean();
+        let b3 = random_boolean();
+
+        // encryption of the booleans
+        let ct1 = cks.encrypt(b1);
+        let ct2 = cks.encrypt(b2);
+        let ct3 = cks.encrypt(b3);
+
+        // gates evaluation, decryption and asserts
+        evaluator.discard_and(&mut ct_res, &ct1, &ct2);
+        assert_eq!(b1 && b2, cks.decrypt(&ct_res));
+        evaluator.discard_mux(&mut ct_res, &ct1, &ct2, &ct3);
+        assert_eq!(if b1 { b2 } else { b3 }, cks.decrypt(&ct_res));
+        evaluator.discard_nand(&mut ct_res, &ct1, &ct2);
+        assert_eq!(!(b1 && b2), cks.decrypt(&ct_res));
+        evaluator.discard_nor(&mut ct_res, &ct1, &ct2);
+        assert_eq!(!(b1 || b2), cks.decrypt(&ct_res));
+        evaluator.discard_not(&mut ct_res, &ct1);
+        assert_eq!(!b1, cks.decrypt(&ct_res));
+        evaluator.discard_or(&mut ct_res, &ct1, &ct2);
+        assert_eq!(b1 || b2, cks.decrypt(&ct_res));
+        evaluator.discard_xnor(&mut ct_res, &ct1, &ct2);
+        assert_eq!(b1 == b2, cks.decrypt(&ct_res));
+        evaluator.discard_xor(&mut ct_res, &ct1, &ct2);
+        assert_eq!(b1 ^ b2, cks.decrypt(&ct_res));
+    }
+}
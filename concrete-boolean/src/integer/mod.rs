@@ -0,0 +1,875 @@
+//! Encrypted unsigned integers of fixed width.
+//!
+//! This module implements the [`FheUint`] type, which encrypts an unsigned integer bit by bit,
+//! together with the integer operations of the [`ServerKey`]: bitwise operations, additions,
+//! subtraction, comparisons, shifts by clear amounts and selection. The integers are encrypted
+//! and decrypted with
+//! [`ClientKey::encrypt_integer`](crate::client_key::ClientKey::encrypt_integer) and
+//! [`ClientKey::decrypt_integer`](crate::client_key::ClientKey::decrypt_integer).
+//!
+//! All the operations wrap around, as the `wrapping_*` methods of the standard integer types.
+//!
+//! # Example
+//!
+//! ```rust
+//! use concrete_boolean::gen_keys;
+//! use concrete_boolean::integer::FheUint8;
+//!
+//! // Generate the client key and the server key:
+//! let (cks, sks) = gen_keys();
+//!
+//! // Encrypt two integers:
+//! let ct1: FheUint8 = cks.encrypt_integer(200_u8);
+//! let ct2: FheUint8 = cks.encrypt_integer(100_u8);
+//!
+//! // Compute homomorphically their sum, and compare it with the first integer:
+//! let ct_sum = sks.add_ripple_carry(&ct1, &ct2);
+//! let ct_overflow = sks.less_than(&ct_sum, &ct1);
+//!
+//! // Decrypt:
+//! assert_eq!(cks.decrypt_integer(&ct_sum), 44_u8);
+//! assert_eq!(cks.decrypt(&ct_overflow), true);
+//! ```
+
+#[cfg(test)]
+mod tests;
+
+use crate::ciphertext::Ciphertext;
+use crate::engine::{with_gate_engines_mut, BooleanServerEngine};
+use crate::server_key::{GateBuffers, ServerKey};
+use concrete_commons::numeric::UnsignedInteger;
+use concrete_core::prelude::CoreEngine;
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+/// An encrypted unsigned integer, whose clear type is `T`.
+///
+/// The integer is encrypted bit by bit, with one [`Ciphertext`] per bit.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(bound = "", try_from = "UncheckedFheUint<T>")]
+pub struct FheUint<T: UnsignedInteger> {
+    bits: Vec<Ciphertext>,
+    integer: PhantomData<T>,
+}
+
+// The serialized form of an integer, whose number of bits is checked when it is converted back to
+// an integer.
+#[derive(Deserialize)]
+#[serde(bound = "")]
+struct UncheckedFheUint<T: UnsignedInteger> {
+    bits: Vec<Ciphertext>,
+    integer: PhantomData<T>,
+}
+
+impl<T: UnsignedInteger> TryFrom<UncheckedFheUint<T>> for FheUint<T> {
+    type Error = String;
+
+    fn try_from(unchecked: UncheckedFheUint<T>) -> Result<Self, Self::Error> {
+        if unchecked.bits.len() != T::BITS {
+            return Err(format!(
+                "An integer of {} bits cannot be created from {} bits.",
+                T::BITS,
+                unchecked.bits.len()
+            ));
+        }
+        Ok(FheUint {
+            bits: unchecked.bits,
+            integer: unchecked.integer,
+        })
+    }
+}
+
+/// An encrypted 8 bits unsigned integer.
+pub type FheUint8 = FheUint<u8>;
+
+/// An encrypted 16 bits unsigned integer.
+pub type FheUint16 = FheUint<u16>;
+
+/// An encrypted 32 bits unsigned integer.
+pub type FheUint32 = FheUint<u32>;
+
+impl<T: UnsignedInteger> FheUint<T> {
+    /// Creates an integer from the encryptions of its bits, starting from the least significant
+    /// one.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the number of bits does not match the width of the integer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    /// use concrete_boolean::integer::FheUint8;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encrypt the bits of an integer:
+    /// let bits = (0..8).map(|i| cks.encrypt(i == 1)).collect();
+    /// let ct = FheUint8::from_bits(bits);
+    ///
+    /// // Decrypt:
+    /// assert_eq!(cks.decrypt_integer(&ct), 2_u8);
+    /// ```
+    pub fn from_bits(bits: Vec<Ciphertext>) -> FheUint<T> {
+        assert_eq!(
+            bits.len(),
+            T::BITS,
+            "An integer of {} bits cannot be created from {} bits.",
+            T::BITS,
+            bits.len()
+        );
+        FheUint {
+            bits,
+            integer: PhantomData,
+        }
+    }
+
+    /// Returns the encryptions of the bits of the integer, starting from the least significant
+    /// one.
+    pub fn bits(&self) -> &[Ciphertext] {
+        &self.bits
+    }
+
+    /// Returns the encryptions of the bits of the integer, starting from the least significant
+    /// one.
+    pub fn into_bits(self) -> Vec<Ciphertext> {
+        self.bits
+    }
+}
+
+impl<Engine> ServerKey<Engine>
+where
+    Engine: BooleanServerEngine,
+{
+    /// Computes homomorphically the bitwise AND of two encrypted integers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encrypt two integers:
+    /// let ct1 = cks.encrypt_integer(0b1100_u8);
+    /// let ct2 = cks.encrypt_integer(0b1010_u8);
+    ///
+    /// // Compute homomorphically the bitwise AND:
+    /// let ct_res = sks.bitand(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// assert_eq!(cks.decrypt_integer(&ct_res), 0b1000_u8);
+    /// ```
+    pub fn bitand<T: UnsignedInteger>(
+        &self,
+        ct_left: &FheUint<T>,
+        ct_right: &FheUint<T>,
+    ) -> FheUint<T> {
+        self.with_gates(|gates| zip_bits(ct_left, ct_right, |left, right| gates.and(left, right)))
+    }
+
+    /// Computes homomorphically the bitwise OR of two encrypted integers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encrypt two integers:
+    /// let ct1 = cks.encrypt_integer(0b1100_u8);
+    /// let ct2 = cks.encrypt_integer(0b1010_u8);
+    ///
+    /// // Compute homomorphically the bitwise OR:
+    /// let ct_res = sks.bitor(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// assert_eq!(cks.decrypt_integer(&ct_res), 0b1110_u8);
+    /// ```
+    pub fn bitor<T: UnsignedInteger>(
+        &self,
+        ct_left: &FheUint<T>,
+        ct_right: &FheUint<T>,
+    ) -> FheUint<T> {
+        self.with_gates(|gates| zip_bits(ct_left, ct_right, |left, right| gates.or(left, right)))
+    }
+
+    /// Computes homomorphically the bitwise XOR of two encrypted integers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encrypt two integers:
+    /// let ct1 = cks.encrypt_integer(0b1100_u8);
+    /// let ct2 = cks.encrypt_integer(0b1010_u8);
+    ///
+    /// // Compute homomorphically the bitwise XOR:
+    /// let ct_res = sks.bitxor(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// assert_eq!(cks.decrypt_integer(&ct_res), 0b0110_u8);
+    /// ```
+    pub fn bitxor<T: UnsignedInteger>(
+        &self,
+        ct_left: &FheUint<T>,
+        ct_right: &FheUint<T>,
+    ) -> FheUint<T> {
+        self.with_gates(|gates| zip_bits(ct_left, ct_right, |left, right| gates.xor(left, right)))
+    }
+
+    /// Computes homomorphically the bitwise NOT of an encrypted integer.
+    ///
+    /// This operation does not need any bootstrap.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encrypt an integer:
+    /// let ct = cks.encrypt_integer(0b1100_u8);
+    ///
+    /// // Compute homomorphically the bitwise NOT:
+    /// let ct_res = sks.bitnot(&ct);
+    ///
+    /// // Decrypt:
+    /// assert_eq!(cks.decrypt_integer(&ct_res), 0b1111_0011_u8);
+    /// ```
+    pub fn bitnot<T: UnsignedInteger>(&self, ct: &FheUint<T>) -> FheUint<T> {
+        self.with_gates(|gates| {
+            FheUint::from_bits(ct.bits.iter().map(|bit| gates.not(bit)).collect())
+        })
+    }
+
+    /// Computes homomorphically the sum of two encrypted integers, with a ripple-carry adder.
+    ///
+    /// The carry is propagated from the least significant bit to the most significant one, which
+    /// makes this adder the one with the fewest gates, but with a depth linear in the width of
+    /// the integers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encrypt two integers:
+    /// let ct1 = cks.encrypt_integer(200_u8);
+    /// let ct2 = cks.encrypt_integer(100_u8);
+    ///
+    /// // Compute homomorphically the sum:
+    /// let ct_res = sks.add_ripple_carry(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// assert_eq!(cks.decrypt_integer(&ct_res), 200_u8.wrapping_add(100));
+    /// ```
+    pub fn add_ripple_carry<T: UnsignedInteger>(
+        &self,
+        ct_left: &FheUint<T>,
+        ct_right: &FheUint<T>,
+    ) -> FheUint<T> {
+        self.with_gates(|gates| gates.ripple_carry(&ct_left.bits, &ct_right.bits, None))
+    }
+
+    /// Computes homomorphically the sum of two encrypted integers, with a carry-lookahead adder.
+    ///
+    /// The carries are computed with a parallel prefix (Kogge-Stone) network, whose depth is
+    /// logarithmic in the width of the integers, at the cost of more gates than
+    /// [`add_ripple_carry`](ServerKey::add_ripple_carry).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encrypt two integers:
+    /// let ct1 = cks.encrypt_integer(200_u8);
+    /// let ct2 = cks.encrypt_integer(100_u8);
+    ///
+    /// // Compute homomorphically the sum:
+    /// let ct_res = sks.add_carry_lookahead(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// assert_eq!(cks.decrypt_integer(&ct_res), 200_u8.wrapping_add(100));
+    /// ```
+    pub fn add_carry_lookahead<T: UnsignedInteger>(
+        &self,
+        ct_left: &FheUint<T>,
+        ct_right: &FheUint<T>,
+    ) -> FheUint<T> {
+        self.with_gates(|gates| {
+            let n = T::BITS;
+            let (left, right) = (&ct_left.bits, &ct_right.bits);
+
+            // The propagate and generate signals of every bit.
+            let propagate: Vec<Ciphertext> =
+                (0..n).map(|i| gates.xor(&left[i], &right[i])).collect();
+            let mut generate: Vec<Ciphertext> =
+                (0..n).map(|i| gates.and(&left[i], &right[i])).collect();
+
+            // Combine the signals of the blocks of bits of width 1, 2, 4, ... After the last
+            // stage, generate[i] is the carry out of the bits 0 to i. The carry out of the most
+            // significant bit is not needed.
+            let mut block_propagate = propagate.clone();
+            let mut width = 1;
+            while width < n - 1 {
+                for i in (width..n - 1).rev() {
+                    // The block propagates the carry of the lower block, or generates its own.
+                    generate[i] =
+                        gates.mux(&block_propagate[i], &generate[i - width], &generate[i]);
+                    if 2 * width < n - 1 {
+                        block_propagate[i] =
+                            gates.and(&block_propagate[i], &block_propagate[i - width]);
+                    }
+                }
+                width *= 2;
+            }
+
+            let mut bits = Vec::with_capacity(n);
+            bits.push(propagate[0].clone());
+            bits.extend((1..n).map(|i| gates.xor(&propagate[i], &generate[i - 1])));
+            FheUint::from_bits(bits)
+        })
+    }
+
+    /// Computes homomorphically the difference of two encrypted integers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encrypt two integers:
+    /// let ct1 = cks.encrypt_integer(100_u8);
+    /// let ct2 = cks.encrypt_integer(200_u8);
+    ///
+    /// // Compute homomorphically the difference:
+    /// let ct_res = sks.sub(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// assert_eq!(cks.decrypt_integer(&ct_res), 100_u8.wrapping_sub(200));
+    /// ```
+    pub fn sub<T: UnsignedInteger>(
+        &self,
+        ct_left: &FheUint<T>,
+        ct_right: &FheUint<T>,
+    ) -> FheUint<T> {
+        self.with_gates(|gates| {
+            // left - right = left + NOT(right) + 1
+            let not_right: Vec<Ciphertext> =
+                ct_right.bits.iter().map(|bit| gates.not(bit)).collect();
+            let one = gates.constant(true);
+            gates.ripple_carry(&ct_left.bits, &not_right, Some(one))
+        })
+    }
+
+    /// Compares homomorphically two encrypted integers, and returns an encryption of `true` if
+    /// they are equal.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encrypt two integers:
+    /// let ct1 = cks.encrypt_integer(42_u8);
+    /// let ct2 = cks.encrypt_integer(42_u8);
+    ///
+    /// // Compare homomorphically the integers:
+    /// let ct_res = sks.equal(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// assert_eq!(cks.decrypt(&ct_res), true);
+    /// ```
+    pub fn equal<T: UnsignedInteger>(
+        &self,
+        ct_left: &FheUint<T>,
+        ct_right: &FheUint<T>,
+    ) -> Ciphertext {
+        self.with_gates(|gates| gates.equal(ct_left, ct_right))
+    }
+
+    /// Compares homomorphically two encrypted integers, and returns an encryption of `true` if
+    /// they are different.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encrypt two integers:
+    /// let ct1 = cks.encrypt_integer(42_u8);
+    /// let ct2 = cks.encrypt_integer(43_u8);
+    ///
+    /// // Compare homomorphically the integers:
+    /// let ct_res = sks.not_equal(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// assert_eq!(cks.decrypt(&ct_res), true);
+    /// ```
+    pub fn not_equal<T: UnsignedInteger>(
+        &self,
+        ct_left: &FheUint<T>,
+        ct_right: &FheUint<T>,
+    ) -> Ciphertext {
+        self.with_gates(|gates| {
+            let equal = gates.equal(ct_left, ct_right);
+            gates.not(&equal)
+        })
+    }
+
+    /// Compares homomorphically two encrypted integers, and returns an encryption of `true` if
+    /// the left one is strictly smaller than the right one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encrypt two integers:
+    /// let ct1 = cks.encrypt_integer(42_u8);
+    /// let ct2 = cks.encrypt_integer(43_u8);
+    ///
+    /// // Compare homomorphically the integers:
+    /// let ct_res = sks.less_than(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// assert_eq!(cks.decrypt(&ct_res), true);
+    /// ```
+    pub fn less_than<T: UnsignedInteger>(
+        &self,
+        ct_left: &FheUint<T>,
+        ct_right: &FheUint<T>,
+    ) -> Ciphertext {
+        self.with_gates(|gates| gates.less_than(ct_left, ct_right))
+    }
+
+    /// Compares homomorphically two encrypted integers, and returns an encryption of `true` if
+    /// the left one is smaller than or equal to the right one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encrypt two integers:
+    /// let ct1 = cks.encrypt_integer(42_u8);
+    /// let ct2 = cks.encrypt_integer(42_u8);
+    ///
+    /// // Compare homomorphically the integers:
+    /// let ct_res = sks.less_or_equal(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// assert_eq!(cks.decrypt(&ct_res), true);
+    /// ```
+    pub fn less_or_equal<T: UnsignedInteger>(
+        &self,
+        ct_left: &FheUint<T>,
+        ct_right: &FheUint<T>,
+    ) -> Ciphertext {
+        self.with_gates(|gates| {
+            let greater = gates.less_than(ct_right, ct_left);
+            gates.not(&greater)
+        })
+    }
+
+    /// Compares homomorphically two encrypted integers, and returns an encryption of `true` if
+    /// the left one is strictly greater than the right one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encrypt two integers:
+    /// let ct1 = cks.encrypt_integer(43_u8);
+    /// let ct2 = cks.encrypt_integer(42_u8);
+    ///
+    /// // Compare homomorphically the integers:
+    /// let ct_res = sks.greater_than(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// assert_eq!(cks.decrypt(&ct_res), true);
+    /// ```
+    pub fn greater_than<T: UnsignedInteger>(
+        &self,
+        ct_left: &FheUint<T>,
+        ct_right: &FheUint<T>,
+    ) -> Ciphertext {
+        self.with_gates(|gates| gates.less_than(ct_right, ct_left))
+    }
+
+    /// Compares homomorphically two encrypted integers, and returns an encryption of `true` if
+    /// the left one is greater than or equal to the right one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encrypt two integers:
+    /// let ct1 = cks.encrypt_integer(42_u8);
+    /// let ct2 = cks.encrypt_integer(42_u8);
+    ///
+    /// // Compare homomorphically the integers:
+    /// let ct_res = sks.greater_or_equal(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// assert_eq!(cks.decrypt(&ct_res), true);
+    /// ```
+    pub fn greater_or_equal<T: UnsignedInteger>(
+        &self,
+        ct_left: &FheUint<T>,
+        ct_right: &FheUint<T>,
+    ) -> Ciphertext {
+        self.with_gates(|gates| {
+            let less = gates.less_than(ct_left, ct_right);
+            gates.not(&less)
+        })
+    }
+
+    /// Shifts homomorphically an encrypted integer to the left, by a clear amount.
+    ///
+    /// The bits shifted in are zeros, and shifting by the width of the integer or more gives an
+    /// encryption of zero. This operation does not need any bootstrap.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encrypt an integer:
+    /// let ct = cks.encrypt_integer(0b1100_0011_u8);
+    ///
+    /// // Shift homomorphically the integer:
+    /// let ct_res = sks.shift_left(&ct, 2);
+    ///
+    /// // Decrypt:
+    /// assert_eq!(cks.decrypt_integer(&ct_res), 0b0000_1100_u8);
+    /// ```
+    pub fn shift_left<T: UnsignedInteger>(&self, ct: &FheUint<T>, amount: usize) -> FheUint<T> {
+        self.with_gates(|gates| {
+            let amount = amount.min(T::BITS);
+            let mut bits: Vec<Ciphertext> = (0..amount).map(|_| gates.constant(false)).collect();
+            bits.extend_from_slice(&ct.bits[..T::BITS - amount]);
+            FheUint::from_bits(bits)
+        })
+    }
+
+    /// Shifts homomorphically an encrypted integer to the right, by a clear amount.
+    ///
+    /// The bits shifted in are zeros, and shifting by the width of the integer or more gives an
+    /// encryption of zero. This operation does not need any bootstrap.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encrypt an integer:
+    /// let ct = cks.encrypt_integer(0b1100_0011_u8);
+    ///
+    /// // Shift homomorphically the integer:
+    /// let ct_res = sks.shift_right(&ct, 2);
+    ///
+    /// // Decrypt:
+    /// assert_eq!(cks.decrypt_integer(&ct_res), 0b0011_0000_u8);
+    /// ```
+    pub fn shift_right<T: UnsignedInteger>(&self, ct: &FheUint<T>, amount: usize) -> FheUint<T> {
+        self.with_gates(|gates| {
+            let amount = amount.min(T::BITS);
+            let mut bits = ct.bits[amount..].to_vec();
+            bits.extend((0..amount).map(|_| gates.constant(false)));
+            FheUint::from_bits(bits)
+        })
+    }
+
+    /// Selects homomorphically one of two encrypted integers, depending on an encrypted
+    /// condition: $$ct_{out} = (ct_{condition}?~ct_{then}:~ct_{else}) $$
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encrypt a condition and two integers:
+    /// let ct_condition = cks.encrypt(false);
+    /// let ct_then = cks.encrypt_integer(42_u8);
+    /// let ct_else = cks.encrypt_integer(24_u8);
+    ///
+    /// // Select homomorphically one of the integers:
+    /// let ct_res = sks.select(&ct_condition, &ct_then, &ct_else);
+    ///
+    /// // Decrypt:
+    /// assert_eq!(cks.decrypt_integer(&ct_res), 24_u8);
+    /// ```
+    pub fn select<T: UnsignedInteger>(
+        &self,
+        ct_condition: &Ciphertext,
+        ct_then: &FheUint<T>,
+        ct_else: &FheUint<T>,
+    ) -> FheUint<T> {
+        self.with_gates(|gates| {
+            zip_bits(ct_then, ct_else, |bit_then, bit_else| {
+                gates.mux(ct_condition, bit_then, bit_else)
+            })
+        })
+    }
+
+    // Evaluates the gates of an integer operation with the engines of the current thread, and a
+    // single set of buffers.
+    fn with_gates<R, F>(&self, operation: F) -> R
+    where
+        F: FnOnce(&mut Gates<Engine>) -> R,
+    {
+        with_gate_engines_mut(|engine, bootstrap_engine| {
+            let buffers = self.create_buffers(engine);
+            operation(&mut Gates {
+                server_key: self,
+                engine,
+                bootstrap_engine,
+                buffers,
+            })
+        })
+    }
+}
+
+/// The gates of the server key, evaluated with borrowed engines and buffers.
+struct Gates<'a, Engine: BooleanServerEngine> {
+    server_key: &'a ServerKey<Engine>,
+    engine: &'a mut CoreEngine,
+    bootstrap_engine: &'a mut Engine,
+    buffers: GateBuffers,
+}
+
+impl<'a, Engine> Gates<'a, Engine>
+where
+    Engine: BooleanServerEngine,
+{
+    fn constant(&mut self, value: bool) -> Ciphertext {
+        self.server_key.create_trivial(self.engine, value)
+    }
+
+    fn and(&mut self, ct_left: &Ciphertext, ct_right: &Ciphertext) -> Ciphertext {
+        let mut ct_out = self.server_key.allocate_ciphertext(self.engine);
+        self.server_key.discard_and(
+            self.engine,
+            self.bootstrap_engine,
+            &mut self.buffers,
+            &mut ct_out,
+            ct_left,
+            ct_right,
+        );
+        ct_out
+    }
+
+    fn or(&mut self, ct_left: &Ciphertext, ct_right: &Ciphertext) -> Ciphertext {
+        let mut ct_out = self.server_key.allocate_ciphertext(self.engine);
+        self.server_key.discard_or(
+            self.engine,
+            self.bootstrap_engine,
+            &mut self.buffers,
+            &mut ct_out,
+            ct_left,
+            ct_right,
+        );
+        ct_out
+    }
+
+    fn xor(&mut self, ct_left: &Ciphertext, ct_right: &Ciphertext) -> Ciphertext {
+        let mut ct_out = self.server_key.allocate_ciphertext(self.engine);
+        self.server_key.discard_xor(
+            self.engine,
+            self.bootstrap_engine,
+            &mut self.buffers,
+            &mut ct_out,
+            ct_left,
+            ct_right,
+        );
+        ct_out
+    }
+
+    fn xnor(&mut self, ct_left: &Ciphertext, ct_right: &Ciphertext) -> Ciphertext {
+        let mut ct_out = self.server_key.allocate_ciphertext(self.engine);
+        self.server_key.discard_xnor(
+            self.engine,
+            self.bootstrap_engine,
+            &mut self.buffers,
+            &mut ct_out,
+            ct_left,
+            ct_right,
+        );
+        ct_out
+    }
+
+    fn not(&mut self, ct: &Ciphertext) -> Ciphertext {
+        let mut ct_out = self.server_key.allocate_ciphertext(self.engine);
+        self.server_key.discard_not(self.engine, &mut ct_out, ct);
+        ct_out
+    }
+
+    fn mux(
+        &mut self,
+        ct_condition: &Ciphertext,
+        ct_then: &Ciphertext,
+        ct_else: &Ciphertext,
+    ) -> Ciphertext {
+        let mut ct_out = self.server_key.allocate_ciphertext(self.engine);
+        self.server_key.discard_mux(
+            self.engine,
+            self.bootstrap_engine,
+            &mut self.buffers,
+            &mut ct_out,
+            ct_condition,
+            ct_then,
+            ct_else,
+        );
+        ct_out
+    }
+
+    // Adds two integers given by their bits, with an optional carry in.
+    fn ripple_carry<T: UnsignedInteger>(
+        &mut self,
+        left: &[Ciphertext],
+        right: &[Ciphertext],
+        carry_in: Option<Ciphertext>,
+    ) -> FheUint<T> {
+        let n = left.len();
+        let mut carry = carry_in;
+        let mut bits = Vec::with_capacity(n);
+        for i in 0..n {
+            let half_sum = self.xor(&left[i], &right[i]);
+            let is_last = i == n - 1;
+            carry = match carry {
+                None => {
+                    bits.push(half_sum);
+                    if is_last {
+                        None
+                    } else {
+                        Some(self.and(&left[i], &right[i]))
+                    }
+                }
+                Some(carry) => {
+                    bits.push(self.xor(&half_sum, &carry));
+                    if is_last {
+                        None
+                    } else {
+                        // The carry is propagated if the bits differ, and is their value otherwise.
+                        Some(self.mux(&half_sum, &carry, &left[i]))
+                    }
+                }
+            };
+        }
+        FheUint::from_bits(bits)
+    }
+
+    // Returns an encryption of `true` if the two integers are equal.
+    fn equal<T: UnsignedInteger>(
+        &mut self,
+        ct_left: &FheUint<T>,
+        ct_right: &FheUint<T>,
+    ) -> Ciphertext {
+        let mut bits: Vec<Ciphertext> = ct_left
+            .bits
+            .iter()
+            .zip(ct_right.bits.iter())
+            .map(|(left, right)| self.xnor(left, right))
+            .collect();
+
+        // AND the equalities of the bits, as a balanced tree.
+        while bits.len() > 1 {
+            bits = bits
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => self.and(left, right),
+                    _ => pair[0].clone(),
+                })
+                .collect();
+        }
+        bits.pop().unwrap()
+    }
+
+    // Returns an encryption of `true` if the left integer is strictly smaller than the right one.
+    fn less_than<T: UnsignedInteger>(
+        &mut self,
+        ct_left: &FheUint<T>,
+        ct_right: &FheUint<T>,
+    ) -> Ciphertext {
+        let (left, right) = (&ct_left.bits, &ct_right.bits);
+
+        // Compare the bits from the least significant one: the most significant bit where the
+        // integers differ decides the comparison.
+        let not_left = self.not(&left[0]);
+        let mut less = self.and(&not_left, &right[0]);
+        for i in 1..left.len() {
+            let differ = self.xor(&left[i], &right[i]);
+            less = self.mux(&differ, &right[i], &less);
+        }
+        less
+    }
+}
+
+// Applies a gate on the bits of two integers.
+fn zip_bits<T, F>(ct_left: &FheUint<T>, ct_right: &FheUint<T>, mut gate: F) -> FheUint<T>
+where
+    T: UnsignedInteger,
+    F: FnMut(&Ciphertext, &Ciphertext) -> Ciphertext,
+{
+    FheUint::from_bits(
+        ct_left
+            .bits
+            .iter()
+            .zip(ct_right.bits.iter())
+            .map(|(left, right)| gate(left, right))
+            .collect(),
+    )
+}
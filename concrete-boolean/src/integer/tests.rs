@@ -0,0 +1,179 @@
+use crate::client_key::ClientKey;
+use crate::integer::{FheUint16, FheUint8};
+use crate::parameters::DEFAULT_PARAMETERS;
+use crate::server_key::ServerKey;
+use crate::{random_boolean, random_integer};
+
+/// Number of assert in randomized tests
+const NB_TEST: usize = 4;
+
+#[test]
+fn test_encrypt_decrypt_integer() {
+    // generate the client key set
+    let cks = ClientKey::new(&DEFAULT_PARAMETERS);
+
+    for _ in 0..NB_TEST {
+        // generation of random integers
+        let m8 = random_integer() as u8;
+        let m16 = random_integer() as u16;
+
+        // encryption, decryption and asserts
+        let ct8: FheUint8 = cks.encrypt_integer(m8);
+        let ct16: FheUint16 = cks.encrypt_integer(m16);
+        assert_eq!(m8, cks.decrypt_integer(&ct8));
+        assert_eq!(m16, cks.decrypt_integer(&ct16));
+    }
+}
+
+#[test]
+fn test_bitwise_operations() {
+    // generate the client key set
+    let cks = ClientKey::new(&DEFAULT_PARAMETERS);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    for _ in 0..NB_TEST {
+        // generation of two random integers
+        let m1 = random_integer() as u8;
+        let m2 = random_integer() as u8;
+
+        // encryption of the integers
+        let ct1 = cks.encrypt_integer(m1);
+        let ct2 = cks.encrypt_integer(m2);
+
+        // operations, decryption and asserts
+        assert_eq!(m1 & m2, cks.decrypt_integer(&sks.bitand(&ct1, &ct2)));
+        assert_eq!(m1 | m2, cks.decrypt_integer(&sks.bitor(&ct1, &ct2)));
+        assert_eq!(m1 ^ m2, cks.decrypt_integer(&sks.bitxor(&ct1, &ct2)));
+        assert_eq!(!m1, cks.decrypt_integer(&sks.bitnot(&ct1)));
+    }
+}
+
+#[test]
+fn test_arithmetic_operations() {
+    // generate the client key set
+    let cks = ClientKey::new(&DEFAULT_PARAMETERS);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    // the extreme values check the propagation of the carry through every bit
+    let mut pairs = vec![(u8::MAX, 1), (0, 1), (u8::MAX, u8::MAX)];
+    pairs.extend((0..NB_TEST).map(|_| (random_integer() as u8, random_integer() as u8)));
+    for (m1, m2) in pairs {
+        // encryption of the integers
+        let ct1 = cks.encrypt_integer(m1);
+        let ct2 = cks.encrypt_integer(m2);
+
+        // operations, decryption and asserts
+        let sum = m1.wrapping_add(m2);
+        assert_eq!(sum, cks.decrypt_integer(&sks.add_ripple_carry(&ct1, &ct2)));
+        assert_eq!(
+            sum,
+            cks.decrypt_integer(&sks.add_carry_lookahead(&ct1, &ct2))
+        );
+        assert_eq!(
+            m1.wrapping_sub(m2),
+            cks.decrypt_integer(&sks.sub(&ct1, &ct2))
+        );
+    }
+}
+
+#[test]
+fn test_carry_lookahead_16_bits() {
+    // generate the client key set
+    let cks = ClientKey::new(&DEFAULT_PARAMETERS);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    let mut pairs = vec![(u16::MAX, 1)];
+    pairs.extend((0..NB_TEST).map(|_| (random_integer() as u16, random_integer() as u16)));
+    for (m1, m2) in pairs {
+        // encryption of the integers
+        let ct1: FheUint16 = cks.encrypt_integer(m1);
+        let ct2: FheUint16 = cks.encrypt_integer(m2);
+
+        // addition, decryption and assert
+        let ct_res = sks.add_carry_lookahead(&ct1, &ct2);
+        assert_eq!(m1.wrapping_add(m2), cks.decrypt_integer(&ct_res));
+    }
+}
+
+#[test]
+fn test_comparisons() {
+    // generate the client key set
+    let cks = ClientKey::new(&DEFAULT_PARAMETERS);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    // equal integers, and integers differing only in their least or most significant bit
+    let mut pairs = vec![(42, 42), (42, 43), (0b1000_0000, 0)];
+    pairs.extend((0..NB_TEST).map(|_| (random_integer() as u8, random_integer() as u8)));
+    for (m1, m2) in pairs {
+        // encryption of the integers
+        let ct1 = cks.encrypt_integer(m1);
+        let ct2 = cks.encrypt_integer(m2);
+
+        // comparisons, decryption and asserts
+        assert_eq!(m1 == m2, cks.decrypt(&sks.equal(&ct1, &ct2)));
+        assert_eq!(m1 != m2, cks.decrypt(&sks.not_equal(&ct1, &ct2)));
+        assert_eq!(m1 < m2, cks.decrypt(&sks.less_than(&ct1, &ct2)));
+        assert_eq!(m1 <= m2, cks.decrypt(&sks.less_or_equal(&ct1, &ct2)));
+        assert_eq!(m1 > m2, cks.decrypt(&sks.greater_than(&ct1, &ct2)));
+        assert_eq!(m1 >= m2, cks.decrypt(&sks.greater_or_equal(&ct1, &ct2)));
+    }
+}
+
+#[test]
+fn test_shifts_and_select() {
+    // generate the client key set
+    let cks = ClientKey::new(&DEFAULT_PARAMETERS);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    for _ in 0..NB_TEST {
+        // generation of a random condition and two random integers
+        let b = random_boolean();
+        let m1 = random_integer() as u8;
+        let m2 = random_integer() as u8;
+
+        // encryption of the condition and the integers
+        let ct_condition = cks.encrypt(b);
+        let ct1 = cks.encrypt_integer(m1);
+        let ct2 = cks.encrypt_integer(m2);
+
+        // shifts, decryption and asserts
+        for amount in [0, 3, 8, 9].iter() {
+            let shift_left = cks.decrypt_integer(&sks.shift_left(&ct1, *amount));
+            let shift_right = cks.decrypt_integer(&sks.shift_right(&ct1, *amount));
+            assert_eq!(m1.checked_shl(*amount as u32).unwrap_or(0), shift_left);
+            assert_eq!(m1.checked_shr(*amount as u32).unwrap_or(0), shift_right);
+        }
+
+        // selection, decryption and assert
+        let ct_res = sks.select(&ct_condition, &ct1, &ct2);
+        assert_eq!(if b { m1 } else { m2 }, cks.decrypt_integer(&ct_res));
+    }
+}
+
+#[test]
+fn test_deserialize_checks_the_number_of_bits() {
+    // generate the client key set
+    let cks = ClientKey::new(&DEFAULT_PARAMETERS);
+
+    // an integer is deserialized into an integer of the same width
+    let ct16: FheUint16 = cks.encrypt_integer(random_integer() as u16);
+    let serialized = bincode::serialize(&ct16).unwrap();
+    let deserialized: FheUint16 = bincode::deserialize(&serialized).unwrap();
+    assert_eq!(
+        cks.decrypt_integer(&ct16),
+        cks.decrypt_integer(&deserialized)
+    );
+
+    // but not into an integer of another width
+    assert!(bincode::deserialize::<FheUint8>(&serialized).is_err());
+}
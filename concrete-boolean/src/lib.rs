@@ -46,17 +46,33 @@ use crate::server_key::ServerKey;
 
 pub mod ciphertext;
 pub mod client_key;
+pub mod engine;
+pub mod evaluator;
+pub mod integer;
+pub mod netlist;
 pub mod parameters;
+pub mod public_key;
 pub mod server_key;
 
-/// The scaling factor used for the plaintext
+/// The scaling factor used for the plaintext by the provided two-input parameter sets
 pub(crate) const PLAINTEXT_LOG_SCALING_FACTOR: usize = 3;
 
-/// The plaintext associated with true: 1/8
-pub(crate) const PLAINTEXT_TRUE: u32 = 1 << (32 - PLAINTEXT_LOG_SCALING_FACTOR);
+/// The plaintext log scaling factor of the keys and parameters serialized before it was made
+/// configurable
+pub(crate) fn default_plaintext_log_scaling_factor() -> usize {
+    PLAINTEXT_LOG_SCALING_FACTOR
+}
 
-/// The plaintext associated with false: -1/8
-pub(crate) const PLAINTEXT_FALSE: u32 = 7 << (32 - PLAINTEXT_LOG_SCALING_FACTOR);
+/// Encodes a Boolean message: true is associated with $2^{-p}$ and false with $-2^{-p}$, where
+/// $p$ is the log of the scaling factor
+pub(crate) fn encode_boolean(message: bool, plaintext_log_scaling_factor: usize) -> u32 {
+    let plaintext_true = 1_u32 << (32 - plaintext_log_scaling_factor);
+    if message {
+        plaintext_true
+    } else {
+        plaintext_true.wrapping_neg()
+    }
+}
 
 /// tool to generate random booleans
 #[cfg(test)]
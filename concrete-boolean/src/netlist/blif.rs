@@ -0,0 +1,447 @@
+//! The parser of the BLIF format.
+//!
+//! Only the combinational subset of the format is supported: a single `.model`, described with
+//! `.inputs`, `.outputs` and `.names` constructs. Each `.names` construct defines a logic
+//! function by a cover, that is a list of cubes whose output is either the ON-set or the OFF-set
+//! of the function.
+
+use super::{Circuit, CircuitBuilder, Driver, Gate, NetlistError};
+use std::collections::HashMap;
+
+/// A cube of a cover: the value of each input, `None` standing for a don't care.
+type Cube = Vec<Option<bool>>;
+
+/// A function creating a gate from the wires of its two inputs.
+type BinaryGate = fn(usize, usize) -> Gate;
+
+pub(super) fn parse(netlist: &str) -> Result<Circuit, NetlistError> {
+    let mut builder = CircuitBuilder::new(0);
+    let mut signals = Signals::default();
+    let mut cover: Option<Cover> = None;
+    let mut model_seen = false;
+
+    for (line, text) in logical_lines(netlist) {
+        let mut tokens = text.split_whitespace();
+        let first = tokens.next().unwrap();
+        if !first.starts_with('.') {
+            // A row of the cover of the last `.names` construct.
+            match cover.as_mut() {
+                Some(cover) => cover.add_row(line, &text)?,
+                None => return Err(syntax(line, "a cover row must follow a .names construct")),
+            }
+            continue;
+        }
+
+        // Any construct ends the cover being parsed.
+        if let Some(cover) = cover.take() {
+            cover.synthesize(&mut builder)?;
+        }
+        match first {
+            ".model" => {
+                if model_seen {
+                    return Err(NetlistError::Unsupported {
+                        line,
+                        construct: "multiple models".to_string(),
+                    });
+                }
+                model_seen = true;
+            }
+            ".inputs" => {
+                for name in tokens {
+                    let wire = signals.get(&mut builder, name);
+                    if !builder.drive(wire, Driver::Input) {
+                        return Err(NetlistError::MultipleDrivers(name.to_string()));
+                    }
+                }
+            }
+            ".outputs" => {
+                for name in tokens {
+                    let wire = signals.get(&mut builder, name);
+                    builder.add_output(wire);
+                }
+            }
+            ".names" => {
+                let names: Vec<&str> = tokens.collect();
+                let (output_name, input_names) = names
+                    .split_last()
+                    .ok_or_else(|| syntax(line, "a .names construct needs an output"))?;
+                cover = Some(Cover {
+                    inputs: input_names
+                        .iter()
+                        .map(|name| signals.get(&mut builder, name))
+                        .collect(),
+                    output: signals.get(&mut builder, output_name),
+                    output_name: output_name.to_string(),
+                    cubes: Vec::new(),
+                    polarity: None,
+                });
+            }
+            ".end" => break,
+            _ => {
+                return Err(NetlistError::Unsupported {
+                    line,
+                    construct: format!("{} construct", first),
+                })
+            }
+        }
+    }
+    if let Some(cover) = cover.take() {
+        cover.synthesize(&mut builder)?;
+    }
+
+    builder.build(|wire| signals.name(wire))
+}
+
+/// The names of the signals of the netlist, and the wires they are mapped to.
+#[derive(Default)]
+struct Signals {
+    wires: HashMap<String, usize>,
+    names: HashMap<usize, String>,
+}
+
+impl Signals {
+    // Returns the wire of a signal, which is created on first use.
+    fn get(&mut self, builder: &mut CircuitBuilder, name: &str) -> usize {
+        if let Some(wire) = self.wires.get(name) {
+            return *wire;
+        }
+        let wire = builder.add_wire();
+        self.wires.insert(name.to_string(), wire);
+        self.names.insert(wire, name.to_string());
+        wire
+    }
+
+    fn name(&self, wire: usize) -> String {
+        match self.names.get(&wire) {
+            Some(name) => name.clone(),
+            None => format!("<internal {}>", wire),
+        }
+    }
+}
+
+/// The cover of a `.names` construct.
+struct Cover {
+    inputs: Vec<usize>,
+    output: usize,
+    output_name: String,
+    cubes: Vec<Cube>,
+    // Whether the cubes give the ON-set (`true`) or the OFF-set (`false`) of the function.
+    polarity: Option<bool>,
+}
+
+impl Cover {
+    fn add_row(&mut self, line: usize, text: &str) -> Result<(), NetlistError> {
+        let tokens: Vec<&str> = text.split_whitespace().collect();
+        let (pattern, output) = match (self.inputs.len(), tokens.as_slice()) {
+            (0, [output]) => ("", *output),
+            (_, [pattern, output]) => (*pattern, *output),
+            _ => return Err(syntax(line, "malformed cover row")),
+        };
+        if pattern.len() != self.inputs.len() {
+            return Err(syntax(
+                line,
+                &format!("the cover row must have {} inputs", self.inputs.len()),
+            ));
+        }
+        let cube = pattern
+            .chars()
+            .map(|c| match c {
+                '0' => Ok(Some(false)),
+                '1' => Ok(Some(true)),
+                '-' => Ok(None),
+                _ => Err(syntax(line, &format!("`{}` is not a valid input value", c))),
+            })
+            .collect::<Result<Cube, NetlistError>>()?;
+        let polarity = match output {
+            "0" => false,
+            "1" => true,
+            _ => return Err(syntax(line, &format!("`{}` is not a valid output", output))),
+        };
+        if *self.polarity.get_or_insert(polarity) != polarity {
+            return Err(syntax(line, "a cover cannot mix ON-set and OFF-set rows"));
+        }
+        self.cubes.push(cube);
+        Ok(())
+    }
+
+    // Adds the gates computing the function of the cover, and drives its output with them.
+    fn synthesize(self, builder: &mut CircuitBuilder) -> Result<(), NetlistError> {
+        // A cover without rows is the constant false.
+        let polarity = self.polarity.unwrap_or(true);
+        let driver = if self.inputs.len() <= 3 {
+            map_table(builder, &self.inputs, self.truth_table(polarity))
+        } else {
+            sum_of_products(builder, &self.inputs, &self.cubes, polarity)
+        };
+        if !builder.drive(self.output, driver) {
+            return Err(NetlistError::MultipleDrivers(self.output_name));
+        }
+        Ok(())
+    }
+
+    // Computes the truth table of the function, whose bit `m` is the value of the function when
+    // the value of the input `j` is the bit `j` of `m`.
+    fn truth_table(&self, polarity: bool) -> u8 {
+        let mut table = 0;
+        for m in 0..(1 << self.inputs.len()) {
+            let covered = self.cubes.iter().any(|cube| {
+                cube.iter().enumerate().all(|(j, value)| match value {
+                    Some(value) => ((m >> j) & 1 == 1) == *value,
+                    None => true,
+                })
+            });
+            if covered == polarity {
+                table |= 1 << m;
+            }
+        }
+        table
+    }
+}
+
+// Maps a function with up to three inputs onto the gates, with negated inputs if needed.
+fn map_table(builder: &mut CircuitBuilder, inputs: &[usize], table: u8) -> Driver {
+    let (inputs, table) = remove_unused_inputs(inputs, table);
+    match inputs.len() {
+        0 => Driver::Constant(table & 1 == 1),
+        1 if table == 0b10 => Driver::Alias(inputs[0]),
+        1 => Driver::Gate(Gate::Not(inputs[0])),
+        2 => Driver::Gate(map_binary(builder, inputs[0], inputs[1], table)),
+        _ => map_ternary(builder, &inputs, table),
+    }
+}
+
+// Maps a function depending on both of its inputs onto a single gate.
+fn map_binary(builder: &mut CircuitBuilder, left: usize, right: usize, table: u8) -> Gate {
+    // Every binary function depending on both inputs is one of these gates, with possibly
+    // negated inputs.
+    let gates: [(u8, BinaryGate); 6] = [
+        (0b1000, Gate::And),
+        (0b0111, Gate::Nand),
+        (0b1110, Gate::Or),
+        (0b0001, Gate::Nor),
+        (0b0110, Gate::Xor),
+        (0b1001, Gate::Xnor),
+    ];
+    for negation in 0..4 {
+        for (gate_table, gate) in gates.iter() {
+            if permute_table(*gate_table, 2, |m| m ^ negation) == table {
+                let left = negate_if(builder, left, negation & 1 == 1);
+                let right = negate_if(builder, right, negation & 2 == 2);
+                return gate(left, right);
+            }
+        }
+    }
+    unreachable!()
+}
+
+// Maps a function depending on its three inputs onto a MUX gate if possible, and onto two
+// gates otherwise.
+fn map_ternary(builder: &mut CircuitBuilder, inputs: &[usize], table: u8) -> Driver {
+    // Try every choice of the condition of a MUX gate, and every negation of its branches.
+    let orders = [
+        (0, 1, 2),
+        (0, 2, 1),
+        (1, 0, 2),
+        (1, 2, 0),
+        (2, 0, 1),
+        (2, 1, 0),
+    ];
+    for (c, t, e) in orders.iter() {
+        for negation in 0..4 {
+            let mux_table = (0..8).fold(0, |mux_table, m| {
+                let bit = |j: usize| (m >> j) & 1;
+                let value = if bit(*c) == 1 {
+                    bit(*t) ^ (negation & 1)
+                } else {
+                    bit(*e) ^ (negation >> 1)
+                };
+                mux_table | ((value as u8) << m)
+            });
+            if mux_table == table {
+                let then = negate_if(builder, inputs[*t], negation & 1 == 1);
+                let otherwise = negate_if(builder, inputs[*e], negation & 2 == 2);
+                return Driver::Gate(Gate::Mux(inputs[*c], then, otherwise));
+            }
+        }
+    }
+
+    // Try to write the function as h(g(x_i, x_j), x_k): its cofactors with respect to x_k must
+    // then be constants, g or the negation of g.
+    let cofactors = |k: usize| {
+        let (i, j) = match k {
+            0 => (1, 2),
+            1 => (0, 2),
+            _ => (0, 1),
+        };
+        let cofactor = |value: usize| {
+            permute_table(table, 2, |m| {
+                ((m & 1) << i) | ((m >> 1) << j) | (value << k)
+            })
+        };
+        ((i, j), cofactor(0), cofactor(1))
+    };
+    for k in 0..3 {
+        let ((i, j), low, high) = cofactors(k);
+        let g = if low == 0b0000 || low == 0b1111 {
+            high
+        } else {
+            low
+        };
+        let in_terms_of_g = |cofactor: u8| match cofactor {
+            0b0000 => Some((0, 0)),
+            0b1111 => Some((1, 1)),
+            _ if cofactor == g => Some((0, 1)),
+            _ if cofactor == g ^ 0b1111 => Some((1, 0)),
+            _ => None,
+        };
+        if let (Some(h_low), Some(h_high)) = (in_terms_of_g(low), in_terms_of_g(high)) {
+            // The entry m of the table of h is its value for g = bit 0 and x_k = bit 1 of m.
+            let h_table = h_low.0 | (h_low.1 << 1) | (h_high.0 << 2) | (h_high.1 << 3);
+            let g_wire = table_wire(builder, &[inputs[i], inputs[j]], g);
+            return map_table(builder, &[g_wire, inputs[k]], h_table);
+        }
+    }
+
+    // Otherwise, use the Shannon expansion with respect to the first input.
+    let ((i, j), low, high) = cofactors(0);
+    let low_wire = table_wire(builder, &[inputs[i], inputs[j]], low);
+    let high_wire = table_wire(builder, &[inputs[i], inputs[j]], high);
+    Driver::Gate(Gate::Mux(inputs[0], high_wire, low_wire))
+}
+
+// Maps a function with up to three inputs onto the gates, and returns the wire of its output.
+fn table_wire(builder: &mut CircuitBuilder, inputs: &[usize], table: u8) -> usize {
+    match map_table(builder, inputs, table) {
+        Driver::Alias(wire) => wire,
+        Driver::Gate(gate) => builder.add_gate(gate),
+        driver => {
+            let wire = builder.add_wire();
+            builder.drive(wire, driver);
+            wire
+        }
+    }
+}
+
+// Removes the inputs the function does not depend on, and returns the remaining inputs together
+// with the reduced truth table.
+fn remove_unused_inputs(inputs: &[usize], table: u8) -> (Vec<usize>, u8) {
+    let mut inputs = inputs.to_vec();
+    let mut table = table;
+    for j in (0..inputs.len()).rev() {
+        let size = inputs.len();
+        let depends = (0..(1 << size))
+            .filter(|m| (m >> j) & 1 == 0)
+            .any(|m| (table >> m) & 1 != (table >> (m | (1 << j))) & 1);
+        if !depends {
+            // Keep the entries for which the input is zero, with the bit `j` removed.
+            table = permute_table(table, size - 1, |m| {
+                let low = m & ((1 << j) - 1);
+                let high = (m >> j) << (j + 1);
+                high | low
+            });
+            inputs.remove(j);
+        }
+    }
+    (inputs, table)
+}
+
+// Returns the table whose entry `m` is the entry `index(m)` of `table`, for a function of
+// `size` inputs.
+fn permute_table<F: Fn(usize) -> usize>(table: u8, size: usize, index: F) -> u8 {
+    (0..(1 << size)).fold(0, |result, m| result | (((table >> index(m)) & 1) << m))
+}
+
+fn negate_if(builder: &mut CircuitBuilder, wire: usize, negate: bool) -> usize {
+    if negate {
+        builder.add_gate(Gate::Not(wire))
+    } else {
+        wire
+    }
+}
+
+// Decomposes a function as an OR of ANDs of its (possibly negated) inputs. The AND and OR gates
+// are arranged as balanced trees, to keep the depth of the circuit low.
+fn sum_of_products(
+    builder: &mut CircuitBuilder,
+    inputs: &[usize],
+    cubes: &[Cube],
+    polarity: bool,
+) -> Driver {
+    let mut negated_inputs: Vec<Option<usize>> = vec![None; inputs.len()];
+    let mut products = Vec::with_capacity(cubes.len());
+    for cube in cubes.iter() {
+        let mut literals = Vec::new();
+        for (j, value) in cube.iter().enumerate() {
+            match value {
+                Some(true) => literals.push(inputs[j]),
+                Some(false) => {
+                    let negated = *negated_inputs[j]
+                        .get_or_insert_with(|| builder.add_gate(Gate::Not(inputs[j])));
+                    literals.push(negated);
+                }
+                None => (),
+            }
+        }
+        if literals.is_empty() {
+            // The cube covers every input value.
+            return Driver::Constant(polarity);
+        }
+        products.push(balanced_tree(builder, literals, Gate::And));
+    }
+    if products.is_empty() {
+        return Driver::Constant(!polarity);
+    }
+    let sum = balanced_tree(builder, products, Gate::Or);
+    if polarity {
+        Driver::Alias(sum)
+    } else {
+        Driver::Gate(Gate::Not(sum))
+    }
+}
+
+fn balanced_tree(builder: &mut CircuitBuilder, mut wires: Vec<usize>, gate: BinaryGate) -> usize {
+    while wires.len() > 1 {
+        wires = wires
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => builder.add_gate(gate(*left, *right)),
+                _ => pair[0],
+            })
+            .collect();
+    }
+    wires[0]
+}
+
+// Splits the netlist in logical lines, without comments and with the continued lines joined,
+// and returns them with the number of their first line.
+fn logical_lines(netlist: &str) -> Vec<(usize, String)> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut start = 0;
+    for (index, text) in netlist.lines().enumerate() {
+        if current.is_empty() {
+            start = index + 1;
+        }
+        let text = text.split('#').next().unwrap().trim_end();
+        let continued = text.ends_with('\\');
+        current.push_str(text.trim_end_matches('\\'));
+        current.push(' ');
+        if !continued {
+            if !current.trim().is_empty() {
+                lines.push((start, current.trim().to_string()));
+            }
+            current.clear();
+        }
+    }
+    if !current.trim().is_empty() {
+        lines.push((start, current.trim().to_string()));
+    }
+    lines
+}
+
+fn syntax(line: usize, message: &str) -> NetlistError {
+    NetlistError::Syntax {
+        line,
+        message: message.to_string(),
+    }
+}
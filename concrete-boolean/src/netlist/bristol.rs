@@ -0,0 +1,213 @@
+//! The parser of the Bristol Fashion format.
+//!
+//! A netlist in this format starts with a header giving the number of gates and of wires, then
+//! the number and the sizes of the input values, and the number and the sizes of the output
+//! values. Each of the following lines describes a gate as `nin nout in_1 .. in_nin out_1 ..
+//! out_nout OP`.
+
+use super::{Circuit, CircuitBuilder, Driver, Gate, NetlistError};
+
+pub(super) fn parse(netlist: &str) -> Result<Circuit, NetlistError> {
+    let mut lines = netlist
+        .lines()
+        .enumerate()
+        .map(|(index, text)| (index + 1, text))
+        .filter(|(_, text)| !text.trim().is_empty());
+    let mut next_header = |what: &str| {
+        lines.next().ok_or_else(|| NetlistError::Syntax {
+            line: 1,
+            message: format!("the netlist ends before the {} of the header", what),
+        })
+    };
+
+    // Parse the header.
+    let (line, text) = next_header("gate and wire counts")?;
+    let counts = parse_numbers(line, text.split_whitespace())?;
+    if counts.len() != 2 {
+        return Err(syntax(
+            line,
+            "expected the number of gates and the number of wires",
+        ));
+    }
+    let (num_gates, num_wires) = (counts[0], counts[1]);
+    let (line, text) = next_header("input sizes")?;
+    let num_inputs = parse_sizes(line, text)?;
+    let (line, text) = next_header("output sizes")?;
+    let num_outputs = parse_sizes(line, text)?;
+    if num_inputs > num_wires || num_outputs > num_wires {
+        return Err(syntax(
+            line,
+            "the circuit has more inputs or outputs than wires",
+        ));
+    }
+
+    // Parse the gates. Every wire is an input or is connected to a gate, which bounds the number
+    // of wires before they are allocated.
+    let gates = lines
+        .map(|(line, text)| parse_gate(line, text).map(|(op, numbers)| (line, op, numbers)))
+        .collect::<Result<Vec<_>, _>>()?;
+    if gates.len() != num_gates {
+        return Err(syntax(
+            1,
+            &format!(
+                "the header announces {} gates, but {} were found",
+                num_gates,
+                gates.len()
+            ),
+        ));
+    }
+    let num_connected = gates.iter().try_fold(num_inputs, |sum, (_, _, numbers)| {
+        sum.checked_add(numbers.len() - 2)
+    });
+    if num_connected.map_or(true, |num_connected| num_wires > num_connected) {
+        return Err(syntax(
+            1,
+            &format!(
+                "the header announces {} wires, more than the inputs and the wires of the gates",
+                num_wires
+            ),
+        ));
+    }
+
+    // The inputs are the first wires of the circuit.
+    let mut builder = CircuitBuilder::new(num_wires);
+    for wire in 0..num_inputs {
+        builder.drive(wire, Driver::Input);
+    }
+    for (line, op, numbers) in gates.iter() {
+        add_gate(&mut builder, num_wires, *line, op, numbers)?;
+    }
+
+    // The outputs are the last wires of the circuit.
+    for wire in (num_wires - num_outputs)..num_wires {
+        builder.add_output(wire);
+    }
+
+    builder.build(|wire| wire.to_string())
+}
+
+// Parses a line `nin nout in_1 .. in_nin out_1 .. out_nout OP`, and returns the operation and the
+// numbers of the line.
+fn parse_gate(line: usize, text: &str) -> Result<(&str, Vec<usize>), NetlistError> {
+    let mut tokens: Vec<&str> = text.split_whitespace().collect();
+    let op = tokens
+        .pop()
+        .ok_or_else(|| syntax(line, "expected a gate"))?;
+    let numbers = parse_numbers(line, tokens.into_iter())?;
+    let num_numbers = match numbers.get(..2) {
+        Some([num_in, num_out]) => num_in.checked_add(*num_out).and_then(|n| n.checked_add(2)),
+        _ => None,
+    };
+    if num_numbers != Some(numbers.len()) {
+        return Err(syntax(
+            line,
+            "the number of wires does not match the gate arity",
+        ));
+    }
+    Ok((op, numbers))
+}
+
+// Adds a gate parsed by `parse_gate` to the circuit.
+fn add_gate(
+    builder: &mut CircuitBuilder,
+    num_wires: usize,
+    line: usize,
+    op: &str,
+    numbers: &[usize],
+) -> Result<(), NetlistError> {
+    let (num_in, num_out) = (numbers[0], numbers[1]);
+    let (ins, outs) = numbers[2..].split_at(num_in);
+
+    // Check the arity of the gate.
+    let expected = match op {
+        "XOR" | "AND" => Some((2, 1)),
+        "INV" | "EQ" | "EQW" => Some((1, 1)),
+        "MAND" => Some((2 * num_out, num_out)),
+        _ => None,
+    };
+    match expected {
+        None => {
+            return Err(NetlistError::Unsupported {
+                line,
+                construct: format!("{} gate", op),
+            })
+        }
+        Some((expected_in, expected_out)) if (expected_in, expected_out) != (num_in, num_out) => {
+            return Err(syntax(
+                line,
+                &format!(
+                    "the {} gate expects {} inputs and {} outputs",
+                    op, expected_in, expected_out
+                ),
+            ))
+        }
+        _ => (),
+    }
+
+    // The input of an EQ gate is a constant, every other index is a wire.
+    let wires = if op == "EQ" { outs } else { &numbers[2..] };
+    if let Some(wire) = wires.iter().find(|wire| **wire >= num_wires) {
+        return Err(syntax(line, &format!("the wire {} does not exist", wire)));
+    }
+
+    let drivers: Vec<Driver> = match op {
+        "XOR" => vec![Driver::Gate(Gate::Xor(ins[0], ins[1]))],
+        "AND" => vec![Driver::Gate(Gate::And(ins[0], ins[1]))],
+        "INV" => vec![Driver::Gate(Gate::Not(ins[0]))],
+        "EQW" => vec![Driver::Alias(ins[0])],
+        "EQ" => match ins[0] {
+            0 => vec![Driver::Constant(false)],
+            1 => vec![Driver::Constant(true)],
+            _ => return Err(syntax(line, "the input of an EQ gate must be 0 or 1")),
+        },
+        "MAND" => {
+            let (left, right) = ins.split_at(num_out);
+            left.iter()
+                .zip(right.iter())
+                .map(|(l, r)| Driver::Gate(Gate::And(*l, *r)))
+                .collect()
+        }
+        _ => unreachable!(),
+    };
+    for (wire, driver) in outs.iter().zip(drivers) {
+        if !builder.drive(*wire, driver) {
+            return Err(NetlistError::MultipleDrivers(wire.to_string()));
+        }
+    }
+    Ok(())
+}
+
+// Parses a line `n size_1 .. size_n`, and returns the sum of the sizes.
+fn parse_sizes(line: usize, text: &str) -> Result<usize, NetlistError> {
+    let numbers = parse_numbers(line, text.split_whitespace())?;
+    if numbers.first().and_then(|n| n.checked_add(1)) != Some(numbers.len()) {
+        return Err(syntax(
+            line,
+            "the number of sizes does not match the number of values",
+        ));
+    }
+    numbers[1..]
+        .iter()
+        .try_fold(0_usize, |sum, size| sum.checked_add(*size))
+        .ok_or_else(|| syntax(line, "the sum of the sizes overflows"))
+}
+
+fn parse_numbers<'a, I>(line: usize, tokens: I) -> Result<Vec<usize>, NetlistError>
+where
+    I: Iterator<Item = &'a str>,
+{
+    tokens
+        .map(|token| {
+            token
+                .parse()
+                .map_err(|_| syntax(line, &format!("`{}` is not a number", token)))
+        })
+        .collect()
+}
+
+fn syntax(line: usize, message: &str) -> NetlistError {
+    NetlistError::Syntax {
+        line,
+        message: message.to_string(),
+    }
+}
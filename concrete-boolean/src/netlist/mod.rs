@@ -0,0 +1,692 @@
+//! Evaluation of Boolean circuits described by a netlist.
+//!
+//! This module makes it possible to evaluate homomorphically a Boolean circuit described in a
+//! standard netlist format, without writing the circuit by hand on top of the gates of the
+//! [`ServerKey`]. Two formats are supported:
+//! * the [Bristol Fashion](https://homes.esat.kuleuven.be/~nsmart/MPC/) format, in which many
+//!   reference circuits (AES, SHA-256, adders, ...) are distributed, see
+//!   [`Circuit::from_bristol`];
+//! * the [BLIF](https://www.cs.uic.edu/~jlillis/courses/cs594/spring05/blif.pdf) format, which
+//!   is output by logic synthesis tools such as ABC or Yosys, see [`Circuit::from_blif`].
+//!
+//! A netlist is parsed into a [`Circuit`], a directed acyclic graph of gates sorted by level: the
+//! gates of a level only depend on the inputs of the circuit and on the gates of the previous
+//! levels. The statistics of the circuit (number of gates of each kind, depth) are available
+//! before the evaluation with [`Circuit::stats`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use concrete_boolean::gen_keys;
+//! use concrete_boolean::netlist::Circuit;
+//!
+//! // A full adder, in Bristol Fashion: the inputs are a, b and the carry c, and the outputs are
+//! // the sum and the carry.
+//! let netlist = "\
+//! 5 8
+//! 3 1 1 1
+//! 2 1 1
+//!
+//! 2 1 0 1 3 XOR
+//! 2 1 3 2 6 XOR
+//! 2 1 0 1 4 AND
+//! 2 1 3 2 5 AND
+//! 2 1 4 5 7 XOR
+//! ";
+//! let circuit = Circuit::from_bristol(netlist).unwrap();
+//! assert_eq!(circuit.stats().num_gates(), 5);
+//! assert_eq!(circuit.stats().depth, 3);
+//!
+//! // Generate the client key and the server key:
+//! let (cks, sks) = gen_keys();
+//!
+//! // Encrypt the inputs, evaluate the circuit and decrypt the outputs:
+//! let inputs: Vec<_> = [true, true, false].iter().map(|b| cks.encrypt(*b)).collect();
+//! let outputs = circuit.evaluate(&sks, &inputs);
+//! let outputs: Vec<bool> = outputs.iter().map(|ct| cks.decrypt(ct)).collect();
+//! assert_eq!(outputs, vec![false, true]);
+//! ```
+
+#[cfg(test)]
+mod tests;
+
+mod blif;
+mod bristol;
+
+use crate::ciphertext::Ciphertext;
+use crate::engine::{with_gate_engines_mut, BooleanEngine, BooleanServerEngine};
+use crate::server_key::{GateBuffers, ServerKey};
+use concrete_core::prelude::CoreEngine;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The error which can occur when a netlist is parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetlistError {
+    /// A line of the netlist is malformed.
+    Syntax { line: usize, message: String },
+    /// A line of the netlist uses a gate or a construct which is not supported.
+    Unsupported { line: usize, construct: String },
+    /// A wire is driven more than once.
+    MultipleDrivers(String),
+    /// A wire is used, but never driven.
+    Undriven(String),
+    /// A wire depends on itself.
+    CombinationalLoop(String),
+}
+
+impl Display for NetlistError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NetlistError::Syntax { line, message } => {
+                write!(f, "Syntax error at line {}: {}", line, message)
+            }
+            NetlistError::Unsupported { line, construct } => {
+                write!(f, "Unsupported construct at line {}: {}", line, construct)
+            }
+            NetlistError::MultipleDrivers(wire) => {
+                write!(f, "The wire {} is driven more than once.", wire)
+            }
+            NetlistError::Undriven(wire) => {
+                write!(f, "The wire {} is used, but never driven.", wire)
+            }
+            NetlistError::CombinationalLoop(wire) => {
+                write!(f, "The wire {} belongs to a combinational loop.", wire)
+            }
+        }
+    }
+}
+
+impl Error for NetlistError {}
+
+/// The statistics of a [`Circuit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CircuitStats {
+    /// The number of inputs of the circuit.
+    pub num_inputs: usize,
+    /// The number of outputs of the circuit.
+    pub num_outputs: usize,
+    /// The number of AND gates.
+    pub and: usize,
+    /// The number of MUX gates.
+    pub mux: usize,
+    /// The number of NAND gates.
+    pub nand: usize,
+    /// The number of NOR gates.
+    pub nor: usize,
+    /// The number of NOT gates.
+    pub not: usize,
+    /// The number of OR gates.
+    pub or: usize,
+    /// The number of XNOR gates.
+    pub xnor: usize,
+    /// The number of XOR gates.
+    pub xor: usize,
+    /// The number of levels of the circuit, that is the number of gates on the longest path
+    /// between an input and an output.
+    pub depth: usize,
+}
+
+impl CircuitStats {
+    /// Returns the total number of gates of the circuit.
+    pub fn num_gates(&self) -> usize {
+        self.and + self.mux + self.nand + self.nor + self.not + self.or + self.xnor + self.xor
+    }
+
+    /// Returns the number of bootstraps needed to evaluate the circuit.
+    ///
+    /// The NOT gates do not need any bootstrap, and the MUX gates need two.
+    pub fn num_bootstraps(&self) -> usize {
+        self.and + 2 * self.mux + self.nand + self.nor + self.or + self.xnor + self.xor
+    }
+}
+
+/// A Boolean circuit, whose gates are sorted by level.
+///
+/// The wires of the circuit are identified by their index. The inputs of the circuit are given
+/// to the evaluation in the order of the netlist, and the outputs are returned in the order of
+/// the netlist as well. The gates which do not contribute to any output are discarded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Circuit {
+    num_wires: usize,
+    inputs: Vec<usize>,
+    outputs: Vec<usize>,
+    constants: Vec<(usize, bool)>,
+    levels: Vec<Vec<Node>>,
+}
+
+impl Circuit {
+    /// Parses a circuit in the Bristol Fashion format.
+    ///
+    /// The `XOR`, `AND`, `INV`, `EQ`, `EQW` and `MAND` gates are supported. The inputs of the
+    /// circuit are the first wires, and its outputs are the last wires, as specified by the
+    /// format. When the circuit has several input (resp. output) values, their bits are
+    /// concatenated in the order of the header.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::netlist::Circuit;
+    ///
+    /// let netlist = "\
+    /// 2 4
+    /// 2 1 1
+    /// 1 1
+    ///
+    /// 2 1 0 1 2 AND
+    /// 1 1 2 3 INV
+    /// ";
+    /// let circuit = Circuit::from_bristol(netlist).unwrap();
+    /// assert_eq!(circuit.evaluate_clear(&[true, true]), vec![false]);
+    /// ```
+    pub fn from_bristol(netlist: &str) -> Result<Circuit, NetlistError> {
+        bristol::parse(netlist)
+    }
+
+    /// Parses a circuit in the BLIF format.
+    ///
+    /// Only combinational circuits are supported: the netlist must contain a single model,
+    /// described with `.inputs`, `.outputs` and `.names` constructs. The logic functions with up
+    /// to three inputs are mapped onto a single gate when possible, and the other ones are
+    /// decomposed as a sum of products.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::netlist::Circuit;
+    ///
+    /// let netlist = "\
+    /// .model majority
+    /// .inputs a b c
+    /// .outputs m
+    /// .names a b c m
+    /// 11- 1
+    /// 1-1 1
+    /// -11 1
+    /// .end
+    /// ";
+    /// let circuit = Circuit::from_blif(netlist).unwrap();
+    /// assert_eq!(circuit.evaluate_clear(&[true, false, true]), vec![true]);
+    /// ```
+    pub fn from_blif(netlist: &str) -> Result<Circuit, NetlistError> {
+        blif::parse(netlist)
+    }
+
+    /// Returns the number of inputs of the circuit.
+    pub fn num_inputs(&self) -> usize {
+        self.inputs.len()
+    }
+
+    /// Returns the number of outputs of the circuit.
+    pub fn num_outputs(&self) -> usize {
+        self.outputs.len()
+    }
+
+    /// Returns the statistics of the circuit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::netlist::Circuit;
+    ///
+    /// let netlist = "\
+    /// 2 4
+    /// 2 1 1
+    /// 1 1
+    ///
+    /// 2 1 0 1 2 AND
+    /// 1 1 2 3 INV
+    /// ";
+    /// let stats = Circuit::from_bristol(netlist).unwrap().stats();
+    /// assert_eq!(stats.and, 1);
+    /// assert_eq!(stats.not, 1);
+    /// assert_eq!(stats.depth, 2);
+    /// assert_eq!(stats.num_bootstraps(), 1);
+    /// ```
+    pub fn stats(&self) -> CircuitStats {
+        let mut stats = CircuitStats {
+            num_inputs: self.inputs.len(),
+            num_outputs: self.outputs.len(),
+            depth: self.levels.len(),
+            ..Default::default()
+        };
+        for node in self.levels.iter().flatten() {
+            match node.gate {
+                Gate::And(..) => stats.and += 1,
+                Gate::Mux(..) => stats.mux += 1,
+                Gate::Nand(..) => stats.nand += 1,
+                Gate::Nor(..) => stats.nor += 1,
+                Gate::Not(..) => stats.not += 1,
+                Gate::Or(..) => stats.or += 1,
+                Gate::Xnor(..) => stats.xnor += 1,
+                Gate::Xor(..) => stats.xor += 1,
+            }
+        }
+        stats
+    }
+
+    /// Evaluates the circuit on clear Boolean values.
+    ///
+    /// This is useful to check a circuit before evaluating it homomorphically.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the number of inputs does not match the circuit.
+    pub fn evaluate_clear(&self, inputs: &[bool]) -> Vec<bool> {
+        self.check_inputs(inputs.len());
+        let mut wires = vec![false; self.num_wires];
+        for (wire, value) in self.inputs.iter().zip(inputs.iter()) {
+            wires[*wire] = *value;
+        }
+        for (wire, value) in self.constants.iter() {
+            wires[*wire] = *value;
+        }
+        for node in self.levels.iter().flatten() {
+            wires[node.output] = match node.gate {
+                Gate::And(l, r) => wires[l] && wires[r],
+                Gate::Mux(c, t, e) => {
+                    if wires[c] {
+                        wires[t]
+                    } else {
+                        wires[e]
+                    }
+                }
+                Gate::Nand(l, r) => !(wires[l] && wires[r]),
+                Gate::Nor(l, r) => !(wires[l] || wires[r]),
+                Gate::Not(w) => !wires[w],
+                Gate::Or(l, r) => wires[l] || wires[r],
+                Gate::Xnor(l, r) => wires[l] == wires[r],
+                Gate::Xor(l, r) => wires[l] ^ wires[r],
+            };
+        }
+        self.outputs.iter().map(|wire| wires[*wire]).collect()
+    }
+
+    /// Evaluates the circuit homomorphically on encrypted inputs.
+    ///
+    /// The gates are evaluated one after the other, with the engines of the current thread.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the number of inputs does not match the circuit.
+    pub fn evaluate<Engine>(
+        &self,
+        server_key: &ServerKey<Engine>,
+        inputs: &[Ciphertext],
+    ) -> Vec<Ciphertext>
+    where
+        Engine: BooleanServerEngine,
+    {
+        let mut wires = self.initialize_wires(server_key, inputs);
+        with_gate_engines_mut(|engine, bootstrap_engine| {
+            let mut buffers = server_key.create_buffers(engine);
+            for node in self.levels.iter().flatten() {
+                let mut ct_out = server_key.allocate_ciphertext(engine);
+                node.gate.evaluate(
+                    server_key,
+                    engine,
+                    bootstrap_engine,
+                    &mut buffers,
+                    &mut ct_out,
+                    &wires,
+                );
+                wires[node.output] = Some(ct_out);
+            }
+        });
+        self.collect_outputs(&wires)
+    }
+
+    /// Evaluates the circuit homomorphically on encrypted inputs, using all the threads of the
+    /// rayon pool.
+    ///
+    /// The gates of each level are evaluated in parallel, and the levels are evaluated one after
+    /// the other.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the number of inputs does not match the circuit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    /// use concrete_boolean::netlist::Circuit;
+    ///
+    /// let netlist = "\
+    /// 2 4
+    /// 2 1 1
+    /// 1 1
+    ///
+    /// 2 1 0 1 2 AND
+    /// 1 1 2 3 INV
+    /// ";
+    /// let circuit = Circuit::from_bristol(netlist).unwrap();
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encrypt the inputs, evaluate the circuit and decrypt the output:
+    /// let inputs = vec![cks.encrypt(true), cks.encrypt(false)];
+    /// let outputs = circuit.par_evaluate(&sks, &inputs);
+    /// assert_eq!(cks.decrypt(&outputs[0]), true);
+    /// ```
+    #[cfg(feature = "multithread")]
+    pub fn par_evaluate<Engine>(
+        &self,
+        server_key: &ServerKey<Engine>,
+        inputs: &[Ciphertext],
+    ) -> Vec<Ciphertext>
+    where
+        Engine: BooleanServerEngine,
+        Engine::BootstrapKey: Sync,
+    {
+        let mut wires = self.initialize_wires(server_key, inputs);
+        for level in self.levels.iter() {
+            let outputs = server_key.par_evaluate(
+                level,
+                |engine, bootstrap_engine, buffers, ct_out, node| {
+                    node.gate.evaluate(
+                        server_key,
+                        engine,
+                        bootstrap_engine,
+                        buffers,
+                        ct_out,
+                        &wires,
+                    )
+                },
+            );
+            for (node, ct_out) in level.iter().zip(outputs) {
+                wires[node.output] = Some(ct_out);
+            }
+        }
+        self.collect_outputs(&wires)
+    }
+
+    fn check_inputs(&self, num_inputs: usize) {
+        assert_eq!(
+            num_inputs,
+            self.inputs.len(),
+            "The circuit has {} inputs, but {} were given.",
+            self.inputs.len(),
+            num_inputs
+        );
+    }
+
+    // Creates the wires of an evaluation, with the inputs and the constants already set.
+    fn initialize_wires<Engine>(
+        &self,
+        server_key: &ServerKey<Engine>,
+        inputs: &[Ciphertext],
+    ) -> Vec<Option<Ciphertext>>
+    where
+        Engine: BooleanServerEngine,
+    {
+        self.check_inputs(inputs.len());
+        let mut wires = vec![None; self.num_wires];
+        for (wire, ct) in self.inputs.iter().zip(inputs.iter()) {
+            wires[*wire] = Some(ct.clone());
+        }
+        CoreEngine::with_thread_local_mut(|engine| {
+            for (wire, value) in self.constants.iter() {
+                wires[*wire] = Some(server_key.create_trivial(engine, *value));
+            }
+        });
+        wires
+    }
+
+    fn collect_outputs(&self, wires: &[Option<Ciphertext>]) -> Vec<Ciphertext> {
+        self.outputs
+            .iter()
+            .map(|wire| get_wire(wires, *wire).clone())
+            .collect()
+    }
+}
+
+/// A gate of the circuit, and the wire it drives.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Node {
+    output: usize,
+    gate: Gate,
+}
+
+/// A gate, together with the wires of its inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Gate {
+    And(usize, usize),
+    Mux(usize, usize, usize),
+    Nand(usize, usize),
+    Nor(usize, usize),
+    Not(usize),
+    Or(usize, usize),
+    Xnor(usize, usize),
+    Xor(usize, usize),
+}
+
+impl Gate {
+    fn inputs(&self) -> Vec<usize> {
+        match *self {
+            Gate::Not(w) => vec![w],
+            Gate::Mux(c, t, e) => vec![c, t, e],
+            Gate::And(l, r)
+            | Gate::Nand(l, r)
+            | Gate::Nor(l, r)
+            | Gate::Or(l, r)
+            | Gate::Xnor(l, r)
+            | Gate::Xor(l, r) => vec![l, r],
+        }
+    }
+
+    fn map_inputs<F: Fn(usize) -> usize>(&self, f: F) -> Gate {
+        match *self {
+            Gate::And(l, r) => Gate::And(f(l), f(r)),
+            Gate::Mux(c, t, e) => Gate::Mux(f(c), f(t), f(e)),
+            Gate::Nand(l, r) => Gate::Nand(f(l), f(r)),
+            Gate::Nor(l, r) => Gate::Nor(f(l), f(r)),
+            Gate::Not(w) => Gate::Not(f(w)),
+            Gate::Or(l, r) => Gate::Or(f(l), f(r)),
+            Gate::Xnor(l, r) => Gate::Xnor(f(l), f(r)),
+            Gate::Xor(l, r) => Gate::Xor(f(l), f(r)),
+        }
+    }
+
+    // Evaluates the gate homomorphically, and writes the result in `ct_out`.
+    fn evaluate<Engine>(
+        &self,
+        server_key: &ServerKey<Engine>,
+        engine: &mut CoreEngine,
+        bootstrap_engine: &mut Engine,
+        buffers: &mut GateBuffers,
+        ct_out: &mut Ciphertext,
+        wires: &[Option<Ciphertext>],
+    ) where
+        Engine: BooleanServerEngine,
+    {
+        let w = |index| get_wire(wires, index);
+        match *self {
+            Gate::And(l, r) => {
+                server_key.discard_and(engine, bootstrap_engine, buffers, ct_out, w(l), w(r))
+            }
+            Gate::Mux(c, t, e) => {
+                server_key.discard_mux(engine, bootstrap_engine, buffers, ct_out, w(c), w(t), w(e))
+            }
+            Gate::Nand(l, r) => {
+                server_key.discard_nand(engine, bootstrap_engine, buffers, ct_out, w(l), w(r))
+            }
+            Gate::Nor(l, r) => {
+                server_key.discard_nor(engine, bootstrap_engine, buffers, ct_out, w(l), w(r))
+            }
+            Gate::Not(i) => server_key.discard_not(engine, ct_out, w(i)),
+            Gate::Or(l, r) => {
+                server_key.discard_or(engine, bootstrap_engine, buffers, ct_out, w(l), w(r))
+            }
+            Gate::Xnor(l, r) => {
+                server_key.discard_xnor(engine, bootstrap_engine, buffers, ct_out, w(l), w(r))
+            }
+            Gate::Xor(l, r) => {
+                server_key.discard_xor(engine, bootstrap_engine, buffers, ct_out, w(l), w(r))
+            }
+        }
+    }
+}
+
+fn get_wire(wires: &[Option<Ciphertext>], index: usize) -> &Ciphertext {
+    wires[index]
+        .as_ref()
+        .expect("A gate was evaluated before one of its inputs.")
+}
+
+/// What drives a wire of a netlist being parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Driver {
+    Input,
+    Constant(bool),
+    Alias(usize),
+    Gate(Gate),
+}
+
+/// A netlist being parsed, whose wires may be driven in any order.
+///
+/// The parsers add the wires and their drivers, then [`CircuitBuilder::build`] checks the
+/// netlist and sorts the gates by level.
+struct CircuitBuilder {
+    drivers: Vec<Option<Driver>>,
+    inputs: Vec<usize>,
+    outputs: Vec<usize>,
+}
+
+impl CircuitBuilder {
+    fn new(num_wires: usize) -> CircuitBuilder {
+        CircuitBuilder {
+            drivers: vec![None; num_wires],
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+        }
+    }
+
+    // Adds a new undriven wire, and returns its index.
+    fn add_wire(&mut self) -> usize {
+        self.drivers.push(None);
+        self.drivers.len() - 1
+    }
+
+    // Adds a new wire driven by a gate, and returns its index.
+    fn add_gate(&mut self, gate: Gate) -> usize {
+        self.drivers.push(Some(Driver::Gate(gate)));
+        self.drivers.len() - 1
+    }
+
+    // Drives a wire, and returns `false` if the wire was already driven.
+    fn drive(&mut self, wire: usize, driver: Driver) -> bool {
+        if self.drivers[wire].is_some() {
+            return false;
+        }
+        if let Driver::Input = driver {
+            self.inputs.push(wire);
+        }
+        self.drivers[wire] = Some(driver);
+        true
+    }
+
+    fn add_output(&mut self, wire: usize) {
+        self.outputs.push(wire);
+    }
+
+    // Checks the netlist and sorts its gates by level, using `name` to name the wires in errors.
+    fn build<F>(self, name: F) -> Result<Circuit, NetlistError>
+    where
+        F: Fn(usize) -> String,
+    {
+        let num_wires = self.drivers.len();
+
+        // Resolve the aliases, such that every wire is driven by an input, a constant or a gate.
+        let resolved = (0..num_wires)
+            .map(|wire| {
+                let mut target = wire;
+                let mut steps = 0;
+                while let Some(Driver::Alias(source)) = self.drivers[target] {
+                    target = source;
+                    steps += 1;
+                    if steps > num_wires {
+                        return Err(NetlistError::CombinationalLoop(name(wire)));
+                    }
+                }
+                Ok(target)
+            })
+            .collect::<Result<Vec<usize>, NetlistError>>()?;
+        let resolve = |wire: usize| resolved[wire];
+
+        // Compute the level of every wire reachable from the outputs, with a depth-first search.
+        // The search is iterative, since the circuits can be very deep.
+        let mut levels: Vec<Option<usize>> = vec![None; num_wires];
+        let mut visiting = vec![false; num_wires];
+        for output in self.outputs.iter() {
+            let mut stack = vec![resolve(*output)];
+            while let Some(wire) = stack.last().copied() {
+                if levels[wire].is_some() {
+                    stack.pop();
+                    continue;
+                }
+                let gate = match self.drivers[wire] {
+                    None => return Err(NetlistError::Undriven(name(wire))),
+                    Some(Driver::Input) | Some(Driver::Constant(_)) => {
+                        levels[wire] = Some(0);
+                        stack.pop();
+                        continue;
+                    }
+                    Some(Driver::Alias(_)) => unreachable!(),
+                    Some(Driver::Gate(gate)) => gate.map_inputs(resolve),
+                };
+                let pending: Vec<usize> = gate
+                    .inputs()
+                    .into_iter()
+                    .filter(|input| levels[*input].is_none())
+                    .collect();
+                if pending.is_empty() {
+                    let level = gate
+                        .inputs()
+                        .into_iter()
+                        .map(|input| levels[input].unwrap())
+                        .max()
+                        .unwrap();
+                    levels[wire] = Some(level + 1);
+                    visiting[wire] = false;
+                    stack.pop();
+                } else if visiting[wire] {
+                    // The inputs of the gate were visited, but one of them is still pending.
+                    return Err(NetlistError::CombinationalLoop(name(wire)));
+                } else {
+                    visiting[wire] = true;
+                    for input in pending {
+                        if visiting[input] {
+                            return Err(NetlistError::CombinationalLoop(name(input)));
+                        }
+                        stack.push(input);
+                    }
+                }
+            }
+        }
+
+        // Gather the reachable gates and constants.
+        let depth = levels.iter().flatten().copied().max().unwrap_or(0);
+        let mut sorted_levels = vec![Vec::new(); depth];
+        let mut constants = Vec::new();
+        for (wire, level) in levels.iter().enumerate() {
+            match (level, self.drivers[wire]) {
+                (Some(level), Some(Driver::Gate(gate))) => sorted_levels[level - 1].push(Node {
+                    output: wire,
+                    gate: gate.map_inputs(resolve),
+                }),
+                (Some(_), Some(Driver::Constant(value))) => constants.push((wire, value)),
+                _ => (),
+            }
+        }
+
+        Ok(Circuit {
+            num_wires,
+            inputs: self.inputs,
+            outputs: self.outputs.iter().map(|wire| resolve(*wire)).collect(),
+            constants,
+            levels: sorted_levels,
+        })
+    }
+}
@@ -0,0 +1,251 @@
+use crate::client_key::ClientKey;
+use crate::netlist::{Circuit, NetlistError};
+use crate::parameters::DEFAULT_PARAMETERS;
+use crate::server_key::ServerKey;
+
+/// A full adder in Bristol Fashion, whose inputs are a, b and c and outputs the sum and the carry
+const FULL_ADDER: &str = "\
+5 8
+3 1 1 1
+2 1 1
+
+2 1 0 1 3 XOR
+2 1 3 2 6 XOR
+2 1 0 1 4 AND
+2 1 3 2 5 AND
+2 1 4 5 7 XOR
+";
+
+/// Returns the bits of `m`, from the least significant one
+fn bits(m: usize, size: usize) -> Vec<bool> {
+    (0..size).map(|j| (m >> j) & 1 == 1).collect()
+}
+
+fn full_adder(inputs: &[bool]) -> Vec<bool> {
+    let (a, b, c) = (inputs[0], inputs[1], inputs[2]);
+    vec![a ^ b ^ c, (a && b) || (c && (a ^ b))]
+}
+
+#[test]
+fn test_bristol_full_adder() {
+    let circuit = Circuit::from_bristol(FULL_ADDER).unwrap();
+    let stats = circuit.stats();
+    assert_eq!(stats.num_inputs, 3);
+    assert_eq!(stats.num_outputs, 2);
+    assert_eq!(stats.xor, 3);
+    assert_eq!(stats.and, 2);
+    assert_eq!(stats.depth, 3);
+    for m in 0..8 {
+        let inputs = bits(m, 3);
+        assert_eq!(circuit.evaluate_clear(&inputs), full_adder(&inputs));
+    }
+}
+
+#[test]
+fn test_bristol_constants_and_copies() {
+    // out_0 = a AND 1, out_1 = NOT b, out_2 = a (copied), out_3 = a MAND b
+    let netlist = "\
+6 9
+2 1 1
+1 4
+
+1 1 1 2 EQ
+2 1 0 2 5 AND
+1 1 1 6 INV
+1 1 0 7 EQW
+2 1 0 1 8 MAND
+1 1 0 3 EQ
+";
+    let circuit = Circuit::from_bristol(netlist).unwrap();
+    let stats = circuit.stats();
+    assert_eq!(stats.and, 2);
+    assert_eq!(stats.not, 1);
+    assert_eq!(stats.depth, 1);
+    for m in 0..4 {
+        let inputs = bits(m, 2);
+        let (a, b) = (inputs[0], inputs[1]);
+        assert_eq!(circuit.evaluate_clear(&inputs), vec![a, !b, a, a && b]);
+    }
+}
+
+#[test]
+fn test_bristol_errors() {
+    // the wire 3 is driven twice
+    let netlist = "2 4\n1 2\n1 1\n2 1 0 1 3 AND\n2 1 0 1 3 XOR\n";
+    assert_eq!(
+        Circuit::from_bristol(netlist),
+        Err(NetlistError::MultipleDrivers("3".to_string()))
+    );
+
+    // the wire 2 is never driven
+    let netlist = "1 4\n1 2\n1 1\n2 1 0 2 3 AND\n";
+    assert_eq!(
+        Circuit::from_bristol(netlist),
+        Err(NetlistError::Undriven("2".to_string()))
+    );
+
+    // the wires 2 and 3 depend on each other
+    let netlist = "2 4\n1 2\n1 1\n2 1 0 3 2 AND\n2 1 0 2 3 XOR\n";
+    assert!(matches!(
+        Circuit::from_bristol(netlist),
+        Err(NetlistError::CombinationalLoop(_))
+    ));
+
+    // the OR gate is not part of the format
+    let netlist = "1 3\n1 2\n1 1\n2 1 0 1 2 OR\n";
+    assert_eq!(
+        Circuit::from_bristol(netlist),
+        Err(NetlistError::Unsupported {
+            line: 4,
+            construct: "OR gate".to_string()
+        })
+    );
+
+    // the header announces two gates
+    let netlist = "2 3\n1 2\n1 1\n2 1 0 1 2 AND\n";
+    assert!(matches!(
+        Circuit::from_bristol(netlist),
+        Err(NetlistError::Syntax { line: 1, .. })
+    ));
+
+    // the header announces more wires than the gates can connect
+    let netlist = format!("1 {}\n1 2\n1 1\n2 1 0 1 2 AND\n", usize::MAX);
+    assert!(matches!(
+        Circuit::from_bristol(&netlist),
+        Err(NetlistError::Syntax { line: 1, .. })
+    ));
+
+    // the arity of the gate overflows
+    let netlist = format!("1 3\n1 2\n1 1\n{} 2 0 1 2 AND\n", usize::MAX);
+    assert!(matches!(
+        Circuit::from_bristol(&netlist),
+        Err(NetlistError::Syntax { line: 4, .. })
+    ));
+
+    // the sum of the input sizes overflows
+    let netlist = format!("1 3\n2 {} 2\n1 1\n2 1 0 1 2 AND\n", usize::MAX);
+    assert!(matches!(
+        Circuit::from_bristol(&netlist),
+        Err(NetlistError::Syntax { line: 2, .. })
+    ));
+}
+
+#[test]
+fn test_blif_every_ternary_function() {
+    // every function of three inputs, given by the cover of its ON-set, must be mapped on at most
+    // two gates with a bootstrap
+    for table in 0..256_usize {
+        let mut netlist = String::from(".model f\n.inputs a b c\n.outputs f\n.names a b c f\n");
+        for m in (0..8).filter(|m| (table >> m) & 1 == 1) {
+            let row: String = bits(m, 3)
+                .iter()
+                .map(|b| if *b { '1' } else { '0' })
+                .collect();
+            netlist.push_str(&format!("{} 1\n", row));
+        }
+        netlist.push_str(".end\n");
+        let circuit = Circuit::from_blif(&netlist).unwrap();
+        assert!(
+            circuit.stats().num_bootstraps() <= 4,
+            "function {:#010b}",
+            table
+        );
+        for m in 0..8 {
+            let expected = (table >> m) & 1 == 1;
+            assert_eq!(
+                circuit.evaluate_clear(&bits(m, 3)),
+                vec![expected],
+                "function {:#010b}",
+                table
+            );
+        }
+    }
+}
+
+#[test]
+fn test_blif_sum_of_products() {
+    // f = NOT((a AND b) OR (NOT c AND d)), given by its OFF-set, with a comment and a continued
+    // line
+    let netlist = "\
+# a four-input function
+.model f
+.inputs a b \\
+  c d
+.outputs f g
+.names a b c d f
+11-- 0
+--01 0
+.names a g
+0 1
+.end
+";
+    let circuit = Circuit::from_blif(netlist).unwrap();
+    for m in 0..16 {
+        let inputs = bits(m, 4);
+        let (a, b, c, d) = (inputs[0], inputs[1], inputs[2], inputs[3]);
+        assert_eq!(
+            circuit.evaluate_clear(&inputs),
+            vec![!((a && b) || (!c && d)), !a]
+        );
+    }
+}
+
+#[test]
+fn test_blif_errors() {
+    // the signal x is never driven
+    let netlist = ".model f\n.inputs a\n.outputs f\n.names a x f\n11 1\n.end\n";
+    assert_eq!(
+        Circuit::from_blif(netlist),
+        Err(NetlistError::Undriven("x".to_string()))
+    );
+
+    // latches are not supported
+    let netlist = ".model f\n.inputs a\n.outputs f\n.latch a f 0\n.end\n";
+    assert_eq!(
+        Circuit::from_blif(netlist),
+        Err(NetlistError::Unsupported {
+            line: 4,
+            construct: ".latch construct".to_string()
+        })
+    );
+
+    // a cover cannot mix the ON-set and the OFF-set
+    let netlist = ".model f\n.inputs a b\n.outputs f\n.names a b f\n11 1\n00 0\n.end\n";
+    assert!(matches!(
+        Circuit::from_blif(netlist),
+        Err(NetlistError::Syntax { line: 6, .. })
+    ));
+}
+
+#[test]
+fn test_evaluate_full_adder() {
+    // generate the client key set
+    let cks = ClientKey::new(&DEFAULT_PARAMETERS);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    let circuit = Circuit::from_bristol(FULL_ADDER).unwrap();
+    for m in 0..8 {
+        let inputs = bits(m, 3);
+        let cts: Vec<_> = inputs.iter().map(|b| cks.encrypt(*b)).collect();
+
+        // evaluation, decryption and asserts
+        let outputs: Vec<bool> = circuit
+            .evaluate(&sks, &cts)
+            .iter()
+            .map(|ct| cks.decrypt(ct))
+            .collect();
+        assert_eq!(outputs, full_adder(&inputs));
+
+        #[cfg(feature = "multithread")]
+        {
+            let outputs: Vec<bool> = circuit
+                .par_evaluate(&sks, &cts)
+                .iter()
+                .map(|ct| cks.decrypt(ct))
+                .collect();
+            assert_eq!(outputs, full_adder(&inputs));
+        }
+    }
+}
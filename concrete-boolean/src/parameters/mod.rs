@@ -14,10 +14,26 @@
 //! The two parameter sets offer a trade-off in terms of execution time versus error probability.
 //! The `DEFAULT_PARAMETERS` set offers better performances on homomorphic circuit evaluation
 //! with an higher probability error in comparison with the `TFHE_LIB_PARAMETERS`.
+//!
+//! Two other parameter sets, `MULTI_INPUT_PARAMETERS` and `LOOKUP_TABLE_PARAMETERS`, encode the
+//! Boolean values with a smaller scaling factor, which leaves room to evaluate functions of three
+//! inputs with a single bootstrap.
+//!
 //! Note that if you desire, you can also create your own set of parameters.
 //! This is an unsafe operation as failing to properly fix the parameters will potentially result
 //! with an incorrect and/or insecure computation.
+//!
+//! Alternatively, [`find_parameters`] searches for a parameter set reaching a target error
+//! probability at a given security level, based on the noise estimations of `concrete-npe`.
+
+#[cfg(test)]
+mod tests;
 
+mod search;
+
+pub use search::*;
+
+use crate::PLAINTEXT_LOG_SCALING_FACTOR;
 use concrete_commons::dispersion::StandardDev;
 use concrete_commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
@@ -36,11 +52,17 @@ pub struct BooleanParameters {
     pub(crate) pbs_level: DecompositionLevelCount,
     pub(crate) ks_base_log: DecompositionBaseLog,
     pub(crate) ks_level: DecompositionLevelCount,
+    #[serde(default = "crate::default_plaintext_log_scaling_factor")]
+    pub(crate) plaintext_log_scaling_factor: usize,
 }
 
 impl BooleanParameters {
     /// Constructs a new set of parameters for boolean circuit evaluation.
     ///
+    /// The Boolean values are encrypted as the plaintexts $\pm 2^{-3}$, which is the encoding of
+    /// the provided two-input parameter sets. A different encoding can be set with
+    /// [`with_plaintext_log_scaling_factor`](BooleanParameters::with_plaintext_log_scaling_factor).
+    ///
     /// # Safety
     ///
     /// This function is unsafe, as failing to fix the parameters properly would yield incorrect
@@ -69,6 +91,29 @@ impl BooleanParameters {
             pbs_level,
             ks_level,
             ks_base_log,
+            plaintext_log_scaling_factor: PLAINTEXT_LOG_SCALING_FACTOR,
+        }
+    }
+
+    /// Returns the parameters with a new plaintext log scaling factor.
+    ///
+    /// The Boolean values are encrypted as the plaintexts $\pm 2^{-p}$, where $p$ is the
+    /// `plaintext_log_scaling_factor`, which must be at least 3. A larger $p$ leaves room to
+    /// evaluate functions of more inputs with a single bootstrap, at the price of a smaller noise
+    /// margin.
+    ///
+    /// # Safety
+    ///
+    /// This function is unsafe for the same reasons as
+    /// [`new_insecure`](BooleanParameters::new_insecure): the noise of the parameters must be
+    /// small enough for the chosen encoding, or the computations will be incorrect.
+    pub unsafe fn with_plaintext_log_scaling_factor(
+        self,
+        plaintext_log_scaling_factor: usize,
+    ) -> BooleanParameters {
+        BooleanParameters {
+            plaintext_log_scaling_factor,
+            ..self
         }
     }
 }
@@ -89,6 +134,7 @@ pub const DEFAULT_PARAMETERS: BooleanParameters = BooleanParameters {
     pbs_level: DecompositionLevelCount(2),
     ks_base_log: DecompositionBaseLog(2),
     ks_level: DecompositionLevelCount(5),
+    plaintext_log_scaling_factor: 3,
 };
 
 /// Parameter set used in [TFHE library](https://tfhe.github.io/tfhe/) for 128-bits of security.
@@ -107,4 +153,44 @@ pub const TFHE_LIB_PARAMETERS: BooleanParameters = BooleanParameters {
     pbs_level: DecompositionLevelCount(3),
     ks_base_log: DecompositionBaseLog(2),
     ks_level: DecompositionLevelCount(8),
+    plaintext_log_scaling_factor: 3,
+};
+
+/// Parameter set for the evaluation of three-input gates with a single bootstrap.
+///
+/// This parameter set ensures 128-bits of security. The Boolean values are encoded as
+/// $\pm 2^{-4}$, so that the AND3, OR3 and MAJORITY gates, as well as the lookup tables of every
+/// function of two inputs and every symmetric function of three inputs, are computed with a single
+/// bootstrap. The probability of error of those gates is upper-bounded by $2^{-40}$. The secret keys
+/// generated with this parameter set are uniform binary.
+pub const MULTI_INPUT_PARAMETERS: BooleanParameters = BooleanParameters {
+    lwe_dimension: LweDimension(660),
+    glwe_dimension: GlweDimension(2),
+    polynomial_size: PolynomialSize(512),
+    lwe_modular_std_dev: StandardDev(0.000_023_364_262_846_169_626), // 2^{-15.38...}
+    glwe_modular_std_dev: StandardDev(0.000_000_030_200_633_619_669_65), // 2^{-24.98...}
+    pbs_base_log: DecompositionBaseLog(6),
+    pbs_level: DecompositionLevelCount(3),
+    ks_base_log: DecompositionBaseLog(2),
+    ks_level: DecompositionLevelCount(6),
+    plaintext_log_scaling_factor: 4,
+};
+
+/// Parameter set for the evaluation of arbitrary functions of three inputs with a single bootstrap.
+///
+/// This parameter set ensures 128-bits of security. The Boolean values are encoded as
+/// $\pm 2^{-5}$, so that the lookup table of every function of three inputs is computed with a
+/// single bootstrap. The probability of error of those lookup tables is upper-bounded by
+/// $2^{-40}$. The secret keys generated with this parameter set are uniform binary.
+pub const LOOKUP_TABLE_PARAMETERS: BooleanParameters = BooleanParameters {
+    lwe_dimension: LweDimension(755),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(1024),
+    lwe_modular_std_dev: StandardDev(0.000_004_117_901_196_023_933), // 2^{-17.89...}
+    glwe_modular_std_dev: StandardDev(0.000_000_030_200_633_619_669_65), // 2^{-24.98...}
+    pbs_base_log: DecompositionBaseLog(4),
+    pbs_level: DecompositionLevelCount(5),
+    ks_base_log: DecompositionBaseLog(2),
+    ks_level: DecompositionLevelCount(8),
+    plaintext_log_scaling_factor: 5,
 };
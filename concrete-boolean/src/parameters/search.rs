@@ -0,0 +1,399 @@
+//! A search of the cryptographic parameters, driven by the noise estimations of `concrete-npe`.
+//!
+//! The noise of a ciphertext is tracked through a gate, using the following model:
+//! * the output of a gate is the keyswitch of the output of one bootstrap (or of the sum of two
+//!   bootstraps for the MUX gate);
+//! * the worst linear combination computed at the input of a two-input gate is the sum of two
+//!   outputs of MUX gates, which must be at a distance of less than $\Delta$ from its expected
+//!   value once switched to the modulus $2N$ of the bootstrap, where $\pm\Delta$ are the
+//!   plaintexts of the Boolean values.
+//!
+//! The error probability of a gate is the probability for this centered gaussian noise to exceed
+//! $\Delta$. The minimal noise a ciphertext must have to reach a given security level is estimated
+//! with linear fits of the lattice estimator, in terms of the LWE dimension.
+
+use super::BooleanParameters;
+use crate::PLAINTEXT_LOG_SCALING_FACTOR;
+use concrete_commons::dispersion::{DispersionParameter, StandardDev, Variance};
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+use concrete_npe::{
+    estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms,
+    estimate_modulus_switching_noise_with_binary_key, estimate_pbs_noise,
+};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The linear fits of the base-2 logarithm of the minimal standard deviation of a binary-key LWE
+/// ciphertext, in terms of its dimension, for each supported security level.
+const SECURITY_CURVES: [(usize, f64, f64); 9] = [
+    (80, -0.040_492_955_029_476_23, 1.128_831_822_655_708_1),
+    (96, -0.034_163_140_569_436_81, 1.470_480_606_171_634_5),
+    (112, -0.029_709_843_626_761_78, 1.784_890_778_779_866_7),
+    (128, -0.026_361_288_425_133_814, 2.013_113_406_343_643_3),
+    (144, -0.023_744_920_772_258_275, 2.236_082_262_831_875_5),
+    (160, -0.021_667_030_192_133_627, 2.440_362_409_806_315_5),
+    (176, -0.019_960_050_022_686_124, 2.632_411_301_500_438_6),
+    (192, -0.018_542_284_063_148_525, 2.806_896_172_780_000_7),
+    (256, -0.014_402_537_306_046_107, 3.412_400_013_138_924),
+];
+
+/// The smallest dimension for which the security curves are valid.
+const MIN_CURVE_DIMENSION: usize = 450;
+
+/// The base-2 logarithm of the smallest standard deviation a 32 bits ciphertext can hold.
+const MIN_LOG2_STD_DEV: f64 = -30.;
+
+/// The polynomial sizes explored by the search.
+const POLYNOMIAL_SIZES: [usize; 4] = [512, 1024, 2048, 4096];
+
+/// The largest GLWE dimension explored by the search.
+const MAX_GLWE_DIMENSION: usize = 3;
+
+/// The largest LWE dimension explored by the search.
+const MAX_LWE_DIMENSION: usize = 2048;
+
+/// The largest decomposition level count explored by the search.
+const MAX_LEVEL_COUNT: usize = 16;
+
+/// The largest decomposition base log explored by the search.
+const MAX_BASE_LOG: usize = 16;
+
+/// The squared norm of the sum of two ciphertexts, computed at the input of the two-input gates.
+const TWO_INPUT_SQUARED_NORM: f64 = 2.;
+
+/// The error which can occur when searching for parameters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParameterSearchError {
+    /// The security level has no associated security curve.
+    UnsupportedSecurityLevel(usize),
+    /// The target error probability does not belong to $]0, 1[$.
+    InvalidErrorProbability,
+    /// No parameter set of the search space reaches the target error probability.
+    NoParametersFound,
+}
+
+impl Display for ParameterSearchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParameterSearchError::UnsupportedSecurityLevel(level) => {
+                write!(
+                    f,
+                    "The security level {} is not supported. The supported levels are: {:?}.",
+                    level,
+                    SECURITY_CURVES
+                        .iter()
+                        .map(|(level, _, _)| *level)
+                        .collect::<Vec<_>>()
+                )
+            }
+            ParameterSearchError::InvalidErrorProbability => {
+                write!(f, "The error probability must be strictly between 0 and 1.")
+            }
+            ParameterSearchError::NoParametersFound => {
+                write!(
+                    f,
+                    "No parameter set reaches the error probability at this security level."
+                )
+            }
+        }
+    }
+}
+
+impl Error for ParameterSearchError {}
+
+/// The outcome of a successful parameter search.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParameterSearchResult {
+    /// The parameter set found by the search.
+    pub parameters: BooleanParameters,
+    /// The predicted error probability of a gate evaluated with the parameter set.
+    pub error_probability: f64,
+}
+
+/// Searches for the fastest parameter set reaching a target error probability per gate, at a
+/// given security level.
+///
+/// The search explores the LWE and GLWE dimensions, together with the decomposition parameters
+/// of the bootstrapping and key switching keys. The noise of the keys is the smallest noise
+/// allowed by the security level, and the cost of a gate is estimated from the number of
+/// operations of a bootstrap followed by a key switch.
+///
+/// The supported security levels are 80, 96, 112, 128, 144, 160, 176, 192 and 256 bits.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_boolean::parameters::find_parameters;
+///
+/// // Search for parameters with an error probability below 2^-40 and 128 bits of security:
+/// let result = find_parameters(2_f64.powi(-40), 128).unwrap();
+/// assert!(result.error_probability <= 2_f64.powi(-40));
+/// assert_eq!(result.parameters.error_probability(), result.error_probability);
+/// ```
+pub fn find_parameters(
+    error_probability: f64,
+    security_level: usize,
+) -> Result<ParameterSearchResult, ParameterSearchError> {
+    search_parameters(
+        error_probability,
+        security_level,
+        PLAINTEXT_LOG_SCALING_FACTOR,
+        TWO_INPUT_SQUARED_NORM,
+    )
+}
+
+// Searches for the fastest parameter set whose gates reach a target error probability, when the
+// Boolean values are encoded with the given scaling factor and the inputs of the bootstraps are
+// linear combinations of outputs of MUX gates with the given squared norm.
+fn search_parameters(
+    error_probability: f64,
+    security_level: usize,
+    plaintext_log_scaling_factor: usize,
+    squared_norm: f64,
+) -> Result<ParameterSearchResult, ParameterSearchError> {
+    if error_probability.is_nan() || error_probability <= 0. || error_probability >= 1. {
+        return Err(ParameterSearchError::InvalidErrorProbability);
+    }
+    let (slope, bias) = security_curve(security_level).ok_or(
+        ParameterSearchError::UnsupportedSecurityLevel(security_level),
+    )?;
+    let secure_std_dev = |dimension: usize| {
+        StandardDev(2_f64.powf((slope * dimension as f64 + bias).max(MIN_LOG2_STD_DEV)))
+    };
+
+    let mut best: Option<(f64, ParameterSearchResult)> = None;
+    for polynomial_size in POLYNOMIAL_SIZES.iter().copied().map(PolynomialSize) {
+        for glwe_dimension in (1..=MAX_GLWE_DIMENSION).map(GlweDimension) {
+            if glwe_dimension.0 * polynomial_size.0 < MIN_CURVE_DIMENSION {
+                continue;
+            }
+            let glwe_modular_std_dev = secure_std_dev(glwe_dimension.0 * polynomial_size.0);
+
+            for lwe_dimension in (MIN_CURVE_DIMENSION..=MAX_LWE_DIMENSION).map(LweDimension) {
+                let lwe_modular_std_dev = secure_std_dev(lwe_dimension.0);
+                let cost_of_bootstrap = |pbs_level| {
+                    bootstrap_cost(lwe_dimension, glwe_dimension, polynomial_size, pbs_level)
+                };
+
+                // The cost grows with the dimension, so larger dimensions can only be worse.
+                if is_costlier(&best, cost_of_bootstrap(DecompositionLevelCount(1))) {
+                    break;
+                }
+
+                for pbs_level in (1..=MAX_LEVEL_COUNT).map(DecompositionLevelCount) {
+                    if is_costlier(&best, cost_of_bootstrap(pbs_level)) {
+                        break;
+                    }
+
+                    // The bootstrap noise grows linearly with the LWE dimension, so the best base
+                    // log for a given level does not depend on the other parameters.
+                    // The estimates which are not finite are discarded, since they cannot be
+                    // compared.
+                    let pbs_base_log = match base_logs(pbs_level)
+                        .map(|base_log| {
+                            let variance = estimate_pbs_noise::<u32, _, BinaryKeyKind>(
+                                lwe_dimension,
+                                polynomial_size,
+                                glwe_dimension,
+                                base_log,
+                                pbs_level,
+                                glwe_modular_std_dev,
+                            );
+                            (base_log, variance.get_variance())
+                        })
+                        .filter(|(_, variance)| variance.is_finite())
+                        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                    {
+                        Some((pbs_base_log, _)) => pbs_base_log,
+                        None => continue,
+                    };
+
+                    // The smallest key switching level reaching the error probability is the
+                    // cheapest one.
+                    for ks_level in (1..=MAX_LEVEL_COUNT).map(DecompositionLevelCount) {
+                        let candidate = match base_logs(ks_level)
+                            .map(|ks_base_log| BooleanParameters {
+                                lwe_dimension,
+                                glwe_dimension,
+                                polynomial_size,
+                                lwe_modular_std_dev,
+                                glwe_modular_std_dev,
+                                pbs_base_log,
+                                pbs_level,
+                                ks_base_log,
+                                ks_level,
+                                plaintext_log_scaling_factor,
+                            })
+                            .map(|parameters| ParameterSearchResult {
+                                error_probability: parameters.gate_error_probability(squared_norm),
+                                parameters,
+                            })
+                            .filter(|result| result.error_probability.is_finite())
+                            .min_by(|a, b| a.error_probability.total_cmp(&b.error_probability))
+                        {
+                            Some(candidate) => candidate,
+                            None => continue,
+                        };
+                        if candidate.error_probability <= error_probability {
+                            let cost = gate_cost(&candidate.parameters);
+                            if !is_costlier(&best, cost) {
+                                best = Some((cost, candidate));
+                            }
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    best.map(|(_, result)| result)
+        .ok_or(ParameterSearchError::NoParametersFound)
+}
+
+impl BooleanParameters {
+    /// Returns the predicted probability for a gate evaluated with the parameter set to output a
+    /// wrong result.
+    ///
+    /// The prediction relies on the noise estimations of `concrete-npe`, and assumes that the
+    /// inputs of the gate are outputs of other gates.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::parameters::DEFAULT_PARAMETERS;
+    ///
+    /// assert!(DEFAULT_PARAMETERS.error_probability() <= 2_f64.powi(-25));
+    /// ```
+    pub fn error_probability(&self) -> f64 {
+        self.gate_error_probability(TWO_INPUT_SQUARED_NORM)
+    }
+
+    // Returns the predicted error probability of a gate whose bootstrap takes as input a linear
+    // combination of outputs of MUX gates with the given squared norm.
+    pub(crate) fn gate_error_probability(&self, squared_norm: f64) -> f64 {
+        let pbs_variance = estimate_pbs_noise::<u32, _, BinaryKeyKind>(
+            self.lwe_dimension,
+            self.polynomial_size,
+            self.glwe_dimension,
+            self.pbs_base_log,
+            self.pbs_level,
+            self.glwe_modular_std_dev,
+        );
+
+        // The MUX gate keyswitches the sum of two bootstraps, which makes its output the noisiest.
+        let output_variance =
+            estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<u32, _, _, BinaryKeyKind>(
+                LweDimension(self.glwe_dimension.0 * self.polynomial_size.0),
+                Variance(2. * pbs_variance.get_variance()),
+                self.lwe_modular_std_dev,
+                self.ks_base_log,
+                self.ks_level,
+            );
+
+        // The bootstrap starts by switching the linear combination of outputs to the modulus 2N.
+        let log_modulus = (2 * self.polynomial_size.0).trailing_zeros() as usize;
+        let input_variance = estimate_modulus_switching_noise_with_binary_key::<u32, _>(
+            self.lwe_dimension,
+            log_modulus,
+            Variance(squared_norm * output_variance.get_variance()),
+        );
+
+        // A gate fails when the noise exceeds the plaintext of true.
+        let margin = 2_f64.powi(-(self.plaintext_log_scaling_factor as i32));
+        erfc(margin / (2. * input_variance.get_variance()).sqrt())
+    }
+
+    // Checks whether the noise of the LWE and GLWE ciphertexts of the parameter set reaches the
+    // security level, according to the security curves.
+    #[cfg(test)]
+    pub(crate) fn reaches_security_level(&self, security_level: usize) -> bool {
+        let (slope, bias) = match security_curve(security_level) {
+            Some(curve) => curve,
+            None => return false,
+        };
+        let is_secure = |dimension: usize, std_dev: StandardDev| {
+            // The standard deviations are rounded, hence the tolerance.
+            dimension >= MIN_CURVE_DIMENSION
+                && std_dev.get_standard_dev().log2() >= slope * dimension as f64 + bias - 1e-9
+        };
+        is_secure(self.lwe_dimension.0, self.lwe_modular_std_dev)
+            && is_secure(
+                self.glwe_dimension.0 * self.polynomial_size.0,
+                self.glwe_modular_std_dev,
+            )
+    }
+}
+
+// Returns the slope and the bias of the security curve of the security level.
+fn security_curve(security_level: usize) -> Option<(f64, f64)> {
+    SECURITY_CURVES
+        .iter()
+        .find(|(level, _, _)| *level == security_level)
+        .map(|(_, slope, bias)| (*slope, *bias))
+}
+
+// Checks whether a cost is at least the one of the best parameter set found so far.
+fn is_costlier(best: &Option<(f64, ParameterSearchResult)>, cost: f64) -> bool {
+    matches!(best, Some((best_cost, _)) if cost >= *best_cost)
+}
+
+// Iterates over the base logs which can be used with the given level count in 32 bits.
+fn base_logs(level: DecompositionLevelCount) -> impl Iterator<Item = DecompositionBaseLog> {
+    (1..=(u32::BITS as usize / level.0).min(MAX_BASE_LOG)).map(DecompositionBaseLog)
+}
+
+// Estimates the number of operations of a bootstrap: each of the n CMUXes computes (k+1)l FFTs of
+// size N, and as many products with the k+1 polynomials of each row of the GGSW.
+fn bootstrap_cost(
+    lwe_dimension: LweDimension,
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    pbs_level: DecompositionLevelCount,
+) -> f64 {
+    let glwe_size = glwe_dimension.0 as f64 + 1.;
+    let fft_cost = (polynomial_size.0 as f64).log2();
+    lwe_dimension.0 as f64
+        * glwe_size
+        * pbs_level.0 as f64
+        * polynomial_size.0 as f64
+        * (fft_cost + glwe_size)
+}
+
+// Estimates the number of operations of a gate, made of a bootstrap followed by a key switch
+// which sums l ciphertexts of size n+1 for each of the kN coefficients of the input.
+fn gate_cost(parameters: &BooleanParameters) -> f64 {
+    let keyswitch_cost = (parameters.glwe_dimension.0 * parameters.polynomial_size.0) as f64
+        * parameters.ks_level.0 as f64
+        * (parameters.lwe_dimension.0 + 1) as f64;
+    bootstrap_cost(
+        parameters.lwe_dimension,
+        parameters.glwe_dimension,
+        parameters.polynomial_size,
+        parameters.pbs_level,
+    ) + keyswitch_cost
+}
+
+// Computes the complementary error function, with a relative error below 1.2e-7 everywhere,
+// which keeps tiny probabilities accurate (Numerical Recipes, 6.2).
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1. / (1. + 0.5 * z);
+    let polynomial = -1.265_512_23
+        + t * (1.000_023_68
+            + t * (0.374_091_96
+                + t * (0.096_784_18
+                    + t * (-0.186_288_06
+                        + t * (0.278_868_07
+                            + t * (-1.135_203_98
+                                + t * (1.488_515_87 + t * (-0.822_152_23 + t * 0.170_872_77))))))));
+    let result = t * (-z * z + polynomial).exp();
+    if x >= 0. {
+        result
+    } else {
+        2. - result
+    }
+}
@@ -0,0 +1,112 @@
+use crate::client_key::ClientKey;
+use crate::parameters::{
+    find_parameters, BooleanParameters, ParameterSearchError, DEFAULT_PARAMETERS,
+    LOOKUP_TABLE_PARAMETERS, MULTI_INPUT_PARAMETERS, TFHE_LIB_PARAMETERS,
+};
+use crate::random_boolean;
+use crate::server_key::ServerKey;
+
+/// Number of assert in randomized tests
+const NB_TEST: usize = 32;
+
+#[test]
+fn test_error_probability_of_provided_parameters() {
+    // the predicted error probabilities are below the documented bounds
+    assert!(DEFAULT_PARAMETERS.error_probability() <= 2_f64.powi(-25));
+    assert!(TFHE_LIB_PARAMETERS.error_probability() <= 2_f64.powi(-165));
+
+    // the three-input gates sum three ciphertexts, and the lookup tables of two inputs weight them
+    // by 1 and 2
+    assert!(MULTI_INPUT_PARAMETERS.gate_error_probability(5.) <= 2_f64.powi(-40));
+
+    // the lookup tables of three inputs weight them by at most 1, 2 and 4
+    assert!(LOOKUP_TABLE_PARAMETERS.gate_error_probability(21.) <= 2_f64.powi(-40));
+}
+
+#[test]
+fn test_security_of_multi_input_parameters() {
+    // the parameter sets for the three-input gates are documented with 128 bits of security
+    assert!(MULTI_INPUT_PARAMETERS.reaches_security_level(128));
+    assert!(LOOKUP_TABLE_PARAMETERS.reaches_security_level(128));
+}
+
+#[test]
+fn test_find_parameters() {
+    for log_error_probability in [-40, -64].iter() {
+        let error_probability = 2_f64.powi(*log_error_probability);
+        let result = find_parameters(error_probability, 128).unwrap();
+
+        // the result reaches the target, and its predicted error probability is reproducible
+        assert!(result.error_probability <= error_probability);
+        assert_eq!(
+            result.error_probability,
+            result.parameters.error_probability()
+        );
+    }
+}
+
+#[test]
+fn test_find_parameters_errors() {
+    assert_eq!(
+        find_parameters(2_f64.powi(-40), 100),
+        Err(ParameterSearchError::UnsupportedSecurityLevel(100))
+    );
+    for error_probability in [0., 1., -1., f64::NAN].iter() {
+        assert_eq!(
+            find_parameters(*error_probability, 128),
+            Err(ParameterSearchError::InvalidErrorProbability)
+        );
+    }
+}
+
+#[test]
+fn test_gates_with_found_parameters() {
+    let parameters = find_parameters(2_f64.powi(-40), 128).unwrap().parameters;
+
+    // generate the client key set
+    let cks = ClientKey::new(&parameters);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    for _ in 0..NB_TEST {
+        // generation of two random booleans
+        let b1 = random_boolean();
+        let b2 = random_boolean();
+
+        // encryption of the booleans
+        let ct1 = cks.encrypt(b1);
+        let ct2 = cks.encrypt(b2);
+
+        // the MUX gate outputs the noisiest ciphertexts
+        let ct3 = sks.mux(&ct1, &ct2, &ct1);
+        let ct4 = sks.mux(&ct2, &ct1, &ct2);
+        assert_eq!(b1 && b2, cks.decrypt(&ct3));
+        assert_eq!(b1 && b2, cks.decrypt(&ct4));
+        assert_eq!(b1 && b2, cks.decrypt(&sks.or(&ct3, &ct4)));
+        assert_eq!(b1 ^ b2, cks.decrypt(&sks.xor(&ct1, &ct2)));
+    }
+}
+
+#[test]
+fn test_new_insecure_default_plaintext_log_scaling_factor() {
+    let p = MULTI_INPUT_PARAMETERS;
+    let parameters = unsafe {
+        BooleanParameters::new_insecure(
+            p.lwe_dimension,
+            p.glwe_dimension,
+            p.polynomial_size,
+            p.lwe_modular_std_dev,
+            p.glwe_modular_std_dev,
+            p.pbs_base_log,
+            p.pbs_level,
+            p.ks_base_log,
+            p.ks_level,
+        )
+    };
+    assert_eq!(parameters.plaintext_log_scaling_factor, 3);
+
+    let parameters =
+        unsafe { parameters.with_plaintext_log_scaling_factor(p.plaintext_log_scaling_factor) };
+    assert_eq!(parameters, MULTI_INPUT_PARAMETERS);
+}
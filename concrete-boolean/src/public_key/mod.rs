@@ -0,0 +1,166 @@
+//! The public key of the client.
+//!
+//! This module implements the generation of a public key from the client key, together with the
+//! public-key encryption method. Any party holding the public key can encrypt Boolean messages,
+//! while only the owner of the client key can decrypt them.
+
+#[cfg(test)]
+mod tests;
+
+use crate::ciphertext::Ciphertext;
+use crate::client_key::ClientKey;
+use crate::encode_boolean;
+use crate::engine::BooleanEngine;
+use crate::parameters::BooleanParameters;
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+use concrete_core::prelude::{
+    CleartextVector32, CleartextVectorCreationEngine, CoreEngine, DestructionEngine,
+    LweCiphertext32, LweCiphertextTrivialEncryptionEngine, LweCiphertextVector32,
+    LweCiphertextVectorDecryptionEngine, LweCiphertextVectorDiscardingAffineTransformationEngine,
+    LweCiphertextVectorEntity, LweCiphertextVectorZeroEncryptionEngine, LweSecretKey32,
+    LweSecretKeyCreationEngine, Plaintext32, PlaintextCreationEngine, PlaintextVector32,
+    PlaintextVectorRetrievalEngine,
+};
+use serde::{Deserialize, Serialize};
+
+/// The number of bits of security targeted by the public key.
+const SECURITY_LEVEL: usize = 128;
+
+/// A structure containing the client public key.
+///
+/// The public key is generated by the client and is meant to be published: any party can use it
+/// to encrypt messages that only the client can decrypt.
+///
+/// In more details, it contains:
+/// * `lwe_public_key` - a list of LWE encryptions of zero under the client LWE secret key.
+/// * `parameters` - the cryptographic parameter set.
+///
+/// A public encryption sums a random subset of the encryptions of zero, so the public key holds
+/// $(n+1) \cdot 32 + 256$ of them, where $n$ is the LWE dimension. This makes it large: about
+/// 45 MB with the `DEFAULT_PARAMETERS`.
+#[derive(Serialize, Clone, Deserialize, PartialEq, Debug)]
+pub struct PublicKey {
+    pub(crate) lwe_public_key: LweCiphertextVector32,
+    pub(crate) parameters: BooleanParameters,
+}
+
+impl PublicKey {
+    /// Encrypts a Boolean message using the public key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    /// use concrete_boolean::public_key::PublicKey;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Generate the public key:
+    /// let pk = PublicKey::new(&cks);
+    ///
+    /// // Encryption of one message:
+    /// let ct = pk.encrypt(true);
+    ///
+    /// // Decryption:
+    /// let dec = cks.decrypt(&ct);
+    /// assert_eq!(true, dec);
+    /// ```
+    pub fn encrypt(&self, message: bool) -> Ciphertext {
+        // encode the boolean message
+        let encoded = encode_boolean(message, self.parameters.plaintext_log_scaling_factor);
+
+        CoreEngine::with_thread_local_mut(|engine| {
+            // draw the random subset of encryptions of zero
+            let subset = random_subset(engine, self.lwe_public_key.lwe_ciphertext_count());
+            let weights: CleartextVector32 = engine.create_cleartext_vector(&subset).unwrap();
+
+            // sum the subset of encryptions of zero, and add the encoded boolean to the body: the
+            // sum is accumulated in the output ciphertext, which starts from a trivial zero
+            let plain: Plaintext32 = engine.create_plaintext(&encoded).unwrap();
+            let zero: Plaintext32 = engine.create_plaintext(&0_u32).unwrap();
+            let lwe_size = self.lwe_public_key.lwe_dimension().to_lwe_size();
+            let mut ct: LweCiphertext32 = engine
+                .trivially_encrypt_lwe_ciphertext(lwe_size, &zero)
+                .unwrap();
+            engine
+                .discard_affine_transform_lwe_ciphertext_vector(
+                    &mut ct,
+                    &self.lwe_public_key,
+                    &weights,
+                    &plain,
+                )
+                .unwrap();
+
+            engine.destroy(weights).unwrap();
+            engine.destroy(plain).unwrap();
+            engine.destroy(zero).unwrap();
+            Ciphertext(ct)
+        })
+    }
+
+    /// Allocates and generates a public key from a client key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::client_key::ClientKey;
+    /// use concrete_boolean::parameters::DEFAULT_PARAMETERS;
+    /// use concrete_boolean::public_key::PublicKey;
+    ///
+    /// // Generate the client key:
+    /// let cks = ClientKey::new(&DEFAULT_PARAMETERS);
+    ///
+    /// // Generate the public key:
+    /// let pk = PublicKey::new(&cks);
+    /// ```
+    pub fn new(cks: &ClientKey) -> PublicKey {
+        let lwe_public_key = CoreEngine::with_thread_local_mut(|engine| {
+            // encrypt a list of zeros under the lwe secret key
+            engine
+                .zero_encrypt_lwe_ciphertext_vector(
+                    &cks.lwe_secret_key,
+                    Variance(cks.parameters.lwe_modular_std_dev.get_variance()),
+                    zero_encryption_count(&cks.parameters),
+                )
+                .unwrap()
+        });
+
+        PublicKey {
+            lwe_public_key,
+            parameters: cks.parameters.clone(),
+        }
+    }
+}
+
+// Draws a uniformly random subset of `count` encryptions of zero, as a vector of binary weights.
+//
+// The bits are read from the noise of encryptions of zero with a variance of one: this noise
+// covers the whole torus many times over, so that its least significant bit is uniform.
+fn random_subset(engine: &mut CoreEngine, count: LweCiphertextCount) -> Vec<u32> {
+    let key: LweSecretKey32 = engine.create_lwe_secret_key(LweDimension(1)).unwrap();
+    let samples: LweCiphertextVector32 = engine
+        .zero_encrypt_lwe_ciphertext_vector(&key, Variance(1.), count)
+        .unwrap();
+    let noises: PlaintextVector32 = engine
+        .decrypt_lwe_ciphertext_vector(&key, &samples)
+        .unwrap();
+    let subset = engine
+        .retrieve_plaintext_vector(&noises)
+        .unwrap()
+        .into_iter()
+        .map(|noise| noise & 1)
+        .collect();
+    engine.destroy(key).unwrap();
+    engine.destroy(samples).unwrap();
+    engine.destroy(noises).unwrap();
+    subset
+}
+
+// Returns the number of encryptions of zero in a public key. By the leftover hash lemma, a random
+// subset sum of $(n+1) \cdot \log_2(q) + 2 \lambda$ encryptions is statistically close to a
+// uniform ciphertext for a security level of $\lambda$ bits.
+fn zero_encryption_count(parameters: &BooleanParameters) -> LweCiphertextCount {
+    LweCiphertextCount((parameters.lwe_dimension.0 + 1) * u32::BITS as usize + 2 * SECURITY_LEVEL)
+}
@@ -0,0 +1,100 @@
+use crate::client_key::ClientKey;
+use crate::parameters::{BooleanParameters, DEFAULT_PARAMETERS, TFHE_LIB_PARAMETERS};
+use crate::public_key::{zero_encryption_count, PublicKey, SECURITY_LEVEL};
+use crate::random_boolean;
+use crate::server_key::ServerKey;
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::parameters::LweDimension;
+use concrete_npe::{estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms, estimate_pbs_noise};
+
+/// Number of assert in randomized tests
+const NB_TEST: usize = 32;
+
+#[test]
+fn test_encrypt_decrypt_public_key() {
+    for parameters in [DEFAULT_PARAMETERS, TFHE_LIB_PARAMETERS].iter() {
+        // generate the client key set
+        let cks = ClientKey::new(parameters);
+
+        // generate the public key
+        let pk = PublicKey::new(&cks);
+
+        for _ in 0..NB_TEST {
+            // generation of a random boolean
+            let b = random_boolean();
+
+            // encryption, decryption and assert
+            let ct = pk.encrypt(b);
+            assert_eq!(b, cks.decrypt(&ct));
+        }
+    }
+}
+
+#[test]
+fn test_gates_on_public_encryptions() {
+    // generate the client key set
+    let cks = ClientKey::new(&DEFAULT_PARAMETERS);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    // generate the public key
+    let pk = PublicKey::new(&cks);
+
+    for _ in 0..NB_TEST {
+        // generation of two random booleans
+        let b1 = random_boolean();
+        let b2 = random_boolean();
+
+        // encryption of the booleans with the public key
+        let ct1 = pk.encrypt(b1);
+        let ct2 = pk.encrypt(b2);
+
+        // the XOR gate amplifies the most the noise of its inputs
+        assert_eq!(b1 ^ b2, cks.decrypt(&sks.xor(&ct1, &ct2)));
+        assert_eq!(b1 && b2, cks.decrypt(&sks.and(&ct1, &ct2)));
+        assert_eq!(!b1, cks.decrypt(&sks.not(&ct1)));
+    }
+}
+
+/// Returns the variance of the output of a gate, which the parameter set is designed to handle
+/// at the input of the next gate.
+fn gate_output_variance(parameters: &BooleanParameters) -> Variance {
+    let pbs_variance = estimate_pbs_noise::<u32, _, BinaryKeyKind>(
+        parameters.lwe_dimension,
+        parameters.polynomial_size,
+        parameters.glwe_dimension,
+        parameters.pbs_base_log,
+        parameters.pbs_level,
+        parameters.glwe_modular_std_dev,
+    );
+    estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<u32, _, _, BinaryKeyKind>(
+        LweDimension(parameters.glwe_dimension.0 * parameters.polynomial_size.0),
+        pbs_variance,
+        parameters.lwe_modular_std_dev,
+        parameters.ks_base_log,
+        parameters.ks_level,
+    )
+}
+
+#[test]
+fn test_public_encryption_noise_bound() {
+    for parameters in [DEFAULT_PARAMETERS, TFHE_LIB_PARAMETERS].iter() {
+        // the size of the random subset follows a binomial distribution: by the Chernoff bound,
+        // it exceeds its mean by more than `deviation` with a probability below 2^-128
+        let count = zero_encryption_count(parameters).0 as f64;
+        let deviation = (count * SECURITY_LEVEL as f64 * 2_f64.ln() / 2.).sqrt();
+        let subset_size = count / 2. + deviation;
+
+        // each encryption of zero in the subset adds the variance of a fresh encryption
+        let public_variance = subset_size * parameters.lwe_modular_std_dev.get_variance();
+        let gate_variance = gate_output_variance(parameters).get_variance();
+        assert!(
+            public_variance <= gate_variance,
+            "public encryption variance {:e} exceeds the gate output variance {:e}",
+            public_variance,
+            gate_variance
+        );
+    }
+}
@@ -0,0 +1,189 @@
+//! The evaluation of truth tables with a single bootstrap.
+//!
+//! A Boolean function of several ciphertexts is evaluated by bootstrapping a weighted sum of the
+//! ciphertexts, with a test polynomial mapping each phase the sum can take to the expected output.
+//! The bootstrap being negacyclic, the output for a phase $\varphi + 1/2$ is the negation of the
+//! output for $\varphi$, so the weights must be chosen such that no phase is mapped to two
+//! different outputs.
+
+use crate::encode_boolean;
+use concrete_commons::parameters::PolynomialSize;
+
+/// The log2 of the largest number of weights explored by the search.
+const MAX_SEARCH_LOG_SIZE: usize = 20;
+
+/// Returns the largest number of inputs of a truth table whose weights can be searched for the
+/// plaintext scaling factor, the search exploring $2^{pn}$ weights for $n$ inputs.
+pub(crate) fn max_num_inputs(plaintext_log_scaling_factor: usize) -> usize {
+    MAX_SEARCH_LOG_SIZE / plaintext_log_scaling_factor
+}
+
+/// The weights and the test polynomial used to evaluate a truth table with a single bootstrap.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct LookupTable {
+    pub(crate) weights: Vec<u32>,
+    pub(crate) test_polynomial: Vec<u32>,
+}
+
+impl LookupTable {
+    /// Searches for the weights with which a truth table can be evaluated, and computes the
+    /// associated test polynomial.
+    ///
+    /// The entry of index $\sum_i x_i 2^i$ of the truth table is the output for the inputs
+    /// $(x_0, x_1, \ldots)$. Among the valid weights, the search picks the ones minimizing the
+    /// ratio between the noise of the weighted sum and the square of the smallest distance between
+    /// two phases with different outputs.
+    ///
+    /// Returns `None` if no weights can be found for the plaintext scaling factor.
+    pub(crate) fn new(
+        truth_table: &[bool],
+        plaintext_log_scaling_factor: usize,
+        polynomial_size: PolynomialSize,
+    ) -> Option<LookupTable> {
+        let num_inputs = truth_table.len().trailing_zeros() as usize;
+
+        // The phases are multiples of the plaintext of true Δ, so the torus holds 1/Δ of them.
+        // Adding 1/(2Δ) to a weight shifts the phase by 1/2 or -1/2 depending on the input, so
+        // the weights are explored in ]-1/(2Δ), 1/(2Δ)], the smallest ones first.
+        let torus_size = 1_usize << plaintext_log_scaling_factor;
+        let max_weight = (torus_size / 2) as i64;
+        let candidates: Vec<i64> = (0..2 * max_weight)
+            .map(|rank| {
+                if rank % 2 == 1 {
+                    rank / 2 + 1
+                } else {
+                    -rank / 2
+                }
+            })
+            .collect();
+
+        let mut ranks = vec![0; num_inputs];
+        let mut weights = vec![0; num_inputs];
+        let mut phases = vec![None; torus_size];
+        let mut best: Option<(i64, usize, Vec<i64>)> = None;
+        loop {
+            weights
+                .iter_mut()
+                .zip(ranks.iter())
+                .for_each(|(weight, rank)| *weight = candidates[*rank]);
+
+            // Negating all the weights mirrors the phases, so the first non-zero weight is taken
+            // positive. The distance is at most 1/(2Δ), which bounds the noise ratio from below.
+            let squared_norm = weights.iter().map(|weight| weight * weight).sum::<i64>();
+            let is_mirrored =
+                matches!(weights.iter().find(|weight| **weight != 0), Some(w) if *w < 0);
+            if !is_mirrored
+                && !is_better_or_equal(&best, squared_norm, max_weight as usize)
+                && place_phases(truth_table, &weights, &mut phases)
+            {
+                let distance = min_distance(&phases);
+                if !is_better_or_equal(&best, squared_norm, distance) {
+                    best = Some((squared_norm, distance, weights.clone()));
+                }
+            }
+
+            // Moves to the next weights.
+            match ranks.iter().position(|rank| *rank + 1 < candidates.len()) {
+                Some(index) => {
+                    ranks[index] += 1;
+                    ranks[..index].fill(0);
+                }
+                None => break,
+            }
+        }
+
+        best.map(|(_, _, weights)| {
+            place_phases(truth_table, &weights, &mut phases);
+            let plaintext_true = encode_boolean(true, plaintext_log_scaling_factor);
+            let points: Vec<(u32, bool)> = phases
+                .iter()
+                .enumerate()
+                .filter_map(|(phase, output)| {
+                    output.map(|output| ((phase as u32).wrapping_mul(plaintext_true), output))
+                })
+                .collect();
+
+            // Each coefficient of the test polynomial takes the output of the closest phase.
+            let log_modulus = (2 * polynomial_size.0).trailing_zeros();
+            let test_polynomial = (0..polynomial_size.0 as u32)
+                .map(|coefficient| {
+                    let phase = coefficient << (32 - log_modulus);
+                    let (_, output) = points
+                        .iter()
+                        .min_by_key(|(point, _)| {
+                            point.wrapping_sub(phase).min(phase.wrapping_sub(*point))
+                        })
+                        .unwrap();
+                    encode_boolean(*output, plaintext_log_scaling_factor)
+                })
+                .collect();
+
+            LookupTable {
+                weights: weights.iter().map(|weight| *weight as u32).collect(),
+                test_polynomial,
+            }
+        })
+    }
+}
+
+// Checks whether the best weights found so far have a noise ratio at most the one of weights with
+// the given squared norm and distance, the squared norm breaking ties.
+fn is_better_or_equal(
+    best: &Option<(i64, usize, Vec<i64>)>,
+    squared_norm: i64,
+    distance: usize,
+) -> bool {
+    match best {
+        Some((best_squared_norm, best_distance, _)) => {
+            let ratio = squared_norm as f64 / (distance * distance) as f64;
+            let best_ratio = *best_squared_norm as f64 / (best_distance * best_distance) as f64;
+            best_ratio < ratio || (best_ratio == ratio && *best_squared_norm <= squared_norm)
+        }
+        None => false,
+    }
+}
+
+// Fills the torus with the outputs expected at the phases of the weighted sums, in units of Δ,
+// and at their negacyclic images. Returns `false` if a phase has two different outputs.
+fn place_phases(truth_table: &[bool], weights: &[i64], phases: &mut [Option<bool>]) -> bool {
+    let torus_size = phases.len() as i64;
+    phases.iter_mut().for_each(|phase| *phase = None);
+    for (index, output) in truth_table.iter().enumerate() {
+        let phase: i64 = weights
+            .iter()
+            .enumerate()
+            .map(|(input, weight)| {
+                if (index >> input) & 1 == 1 {
+                    *weight
+                } else {
+                    -*weight
+                }
+            })
+            .sum();
+        for &(phase, output) in &[(phase, *output), (phase + torus_size / 2, !*output)] {
+            let slot = &mut phases[phase.rem_euclid(torus_size) as usize];
+            match slot {
+                Some(existing) if *existing != output => return false,
+                _ => *slot = Some(output),
+            }
+        }
+    }
+    true
+}
+
+// Computes the smallest distance between two consecutive phases with different outputs.
+fn min_distance(phases: &[Option<bool>]) -> usize {
+    let torus_size = phases.len();
+    let first = phases.iter().position(Option::is_some).unwrap();
+    let mut previous = (first, phases[first].unwrap());
+    let mut distance = torus_size;
+    for position in first + 1..=first + torus_size {
+        if let Some(output) = phases[position % torus_size] {
+            if output != previous.1 {
+                distance = distance.min(position - previous.0);
+            }
+            previous = (position, output);
+        }
+    }
+    distance
+}
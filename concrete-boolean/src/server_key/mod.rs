@@ -3,28 +3,52 @@
 //! This module implements the generation of the server's public key, together with all the
 //! available homomorphic Boolean gates ($\mathrm{AND}$, $\mathrm{MUX}$, $\mathrm{NAND}$,
 //! $\mathrm{NOR}$,
-//! $\mathrm{NOT}$, $\mathrm{OR}$, $\mathrm{XNOR}$, $\mathrm{XOR}$).
+//! $\mathrm{NOT}$, $\mathrm{OR}$, $\mathrm{XNOR}$, $\mathrm{XOR}$), the three-input gates
+//! ($\mathrm{AND3}$, $\mathrm{MAJORITY}$, $\mathrm{OR3}$) and the evaluation of arbitrary truth
+//! tables with a single bootstrap.
+//!
+//! The bootstraps of the gates only rely on the engine traits of `concrete-core` gathered in the
+//! [`BooleanServerEngine`] trait, while their linear operations and key switches are computed with
+//! the [`CoreEngine`]. The server key is generic over the engine used to bootstrap the ciphertexts,
+//! and defaults to the [`CoreEngine`].
 
 #[cfg(test)]
 mod tests;
 
+mod lookup_table;
+
 use crate::ciphertext::Ciphertext;
 use crate::client_key::ClientKey;
-use crate::{PLAINTEXT_LOG_SCALING_FACTOR, PLAINTEXT_TRUE};
-use concrete_commons::parameters::LweDimension;
-#[cfg(feature="backend_optalysys")]
-use concrete_core::backends::optalysys::private::crypto::bootstrap::{FourierBootstrapKey, 
-                                             fourier::buffers::FourierBskBuffers as FourierBuffers};
-#[cfg(not(feature="backend_optalysys"))]
-use concrete_core::backends::core::private::crypto::bootstrap::{FourierBootstrapKey, FourierBuffers};
-use concrete_core::backends::core::private::crypto::bootstrap::StandardBootstrapKey;
-use concrete_core::backends::core::private::crypto::encoding::Cleartext;
-use concrete_core::backends::core::private::crypto::glwe::GlweCiphertext;
-use concrete_core::backends::core::private::crypto::lwe::{LweCiphertext, LweKeyswitchKey};
-use concrete_core::backends::core::private::crypto::secret::generators::EncryptionRandomGenerator;
-use concrete_core::backends::core::private::math::fft::{AlignedVec, Complex64};
-use concrete_core::backends::core::private::math::tensor::AsMutTensor;
+use crate::encode_boolean;
+use crate::engine::{with_gate_engines_mut, BooleanEngine, BooleanServerEngine};
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+use concrete_commons::parameters::LweSize;
+use concrete_core::prelude::{
+    Cleartext32, CleartextCreationEngine, CoreEngine, FourierLweBootstrapKey32, GlweCiphertext32,
+    GlweCiphertextTrivialEncryptionEngine, GlweToLweSecretKeyTransmutationEngine,
+    LweBootstrapKeyCreationEngine, LweBootstrapKeyEntity, LweCiphertext32,
+    LweCiphertextCleartextDiscardingMultiplicationEngine,
+    LweCiphertextCleartextFusingMultiplicationEngine, LweCiphertextDiscardingAdditionEngine,
+    LweCiphertextDiscardingKeyswitchEngine, LweCiphertextDiscardingNegationEngine,
+    LweCiphertextFusingAdditionEngine, LweCiphertextFusingNegationEngine,
+    LweCiphertextFusingSubtractionEngine, LweCiphertextPlaintextFusingAdditionEngine,
+    LweCiphertextPlaintextFusingSubtractionEngine, LweCiphertextTrivialEncryptionEngine,
+    LweKeyswitchKey32, LweKeyswitchKeyCreationEngine, LweKeyswitchKeyEntity, LweSecretKey32,
+    LweSeededKeyswitchKey32, LweSeededKeyswitchKeyCreationEngine,
+    LweSeededKeyswitchKeyExpansionEngine, Plaintext32, PlaintextCreationEngine, PlaintextVector32,
+    PlaintextVectorCreationEngine,
+};
+use lookup_table::LookupTable;
+#[cfg(feature = "multithread")]
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::fmt::{Debug, Formatter};
+#[cfg(feature = "multithread")]
+use std::sync::Mutex;
+
+/// The smallest plaintext log scaling factor for which the sum of three Boolean plaintexts,
+/// shifted by twice the plaintext of true, fits in half of the torus.
+const MIN_THREE_INPUT_LOG_SCALING_FACTOR: usize = 4;
 
 /// A structure containing the server public key.
 ///
@@ -34,10 +58,49 @@ use serde::{Deserialize, Serialize};
 /// In more details, it contains:
 /// * `key_switching_key` - a public key, used to perform the key-switching operation.
 /// * `bootstrapping_key` - a public key, used to perform the bootstrapping operation.
-#[derive(Serialize, Clone, Deserialize, PartialEq, Debug)]
-pub struct ServerKey {
-    pub(crate) key_switching_key: LweKeyswitchKey<Vec<u32>>,
-    pub(crate) bootstrapping_key: FourierBootstrapKey<AlignedVec<Complex64>, u32>,
+/// * `plaintext_log_scaling_factor` - the log of the scaling factor of the Boolean plaintexts.
+///
+/// The type of the bootstrapping key depends on the `Engine` used to bootstrap the ciphertexts.
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct ServerKey<Engine: BooleanEngine = CoreEngine> {
+    pub(crate) key_switching_key: LweKeyswitchKey32,
+    pub(crate) bootstrapping_key: Engine::BootstrapKey,
+    #[serde(default = "crate::default_plaintext_log_scaling_factor")]
+    pub(crate) plaintext_log_scaling_factor: usize,
+}
+
+// The following traits are implemented by hand, since deriving them would require the engine to
+// implement them as well.
+impl<Engine: BooleanEngine> Clone for ServerKey<Engine> {
+    fn clone(&self) -> Self {
+        ServerKey {
+            key_switching_key: self.key_switching_key.clone(),
+            bootstrapping_key: self.bootstrapping_key.clone(),
+            plaintext_log_scaling_factor: self.plaintext_log_scaling_factor,
+        }
+    }
+}
+
+impl<Engine: BooleanEngine> PartialEq for ServerKey<Engine> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key_switching_key == other.key_switching_key
+            && self.bootstrapping_key == other.bootstrapping_key
+            && self.plaintext_log_scaling_factor == other.plaintext_log_scaling_factor
+    }
+}
+
+impl<Engine: BooleanEngine> Debug for ServerKey<Engine> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ServerKey")
+            .field("key_switching_key", &self.key_switching_key)
+            .field("bootstrapping_key", &self.bootstrapping_key)
+            .field(
+                "plaintext_log_scaling_factor",
+                &self.plaintext_log_scaling_factor,
+            )
+            .finish()
+    }
 }
 
 impl ServerKey {
@@ -57,61 +120,174 @@ impl ServerKey {
     /// let sks = ServerKey::new(&cks);
     /// ```
     pub fn new(cks: &ClientKey) -> ServerKey {
-        // Allocate and generate the key in coefficient domain:
-        let mut coef_bsk = StandardBootstrapKey::allocate(
-            0_u32,
-            cks.parameters.glwe_dimension.to_glwe_size(),
-            cks.parameters.polynomial_size,
-            cks.parameters.pbs_level,
-            cks.parameters.pbs_base_log,
-            cks.parameters.lwe_dimension,
-        );
-        let mut encryption_generator = EncryptionRandomGenerator::new(None);
-        coef_bsk.par_fill_with_new_key(
-            &cks.lwe_secret_key,
-            &cks.glwe_secret_key,
-            cks.parameters.glwe_modular_std_dev,
-            &mut encryption_generator,
-        );
+        ServerKey::generate(cks)
+    }
+}
 
-        // Allocate the bootstrapping key in Fourier domain and forward FFT:
-        let mut fourier_bsk = FourierBootstrapKey::allocate(
-            Complex64::new(0., 0.),
-            cks.parameters.glwe_dimension.to_glwe_size(),
-            cks.parameters.polynomial_size,
-            cks.parameters.pbs_level,
-            cks.parameters.pbs_base_log,
-            cks.parameters.lwe_dimension,
-        );
-        let mut buffers = FourierBuffers::new(fourier_bsk.polynomial_size(), fourier_bsk.glwe_size());
-        fourier_bsk.fill_with_forward_fourier(&coef_bsk, &mut buffers);
-
-        // Allocate the key switching key:
-        let mut ksk = LweKeyswitchKey::allocate(
-            0_u32,
-            cks.parameters.ks_level,
-            cks.parameters.ks_base_log,
-            LweDimension(cks.parameters.glwe_dimension.0 * cks.parameters.polynomial_size.0),
-            cks.parameters.lwe_dimension,
-        );
+/// A structure containing a compressed server public key.
+///
+/// The key switching key of a server key is made of LWE ciphertexts, whose uniformly random masks
+/// make up nearly all of its size. The compressed server key only stores the bodies of those
+/// ciphertexts, together with the seed of the generator their masks were sampled from. It is meant
+/// to be sent to the server, which decompresses it into a [`ServerKey`] before evaluating gates.
+///
+/// In more details, it contains:
+/// * `key_switching_key` - a seeded public key, expanded into the key-switching key.
+/// * `bootstrapping_key` - a public key, used to perform the bootstrapping operation.
+/// * `plaintext_log_scaling_factor` - the log of the scaling factor of the Boolean plaintexts.
+#[derive(Serialize, Clone, Deserialize, PartialEq, Debug)]
+pub struct CompressedServerKey {
+    pub(crate) key_switching_key: LweSeededKeyswitchKey32,
+    pub(crate) bootstrapping_key: FourierLweBootstrapKey32,
+    #[serde(default = "crate::default_plaintext_log_scaling_factor")]
+    pub(crate) plaintext_log_scaling_factor: usize,
+}
+
+impl CompressedServerKey {
+    /// Allocates and generates a compressed server key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::client_key::ClientKey;
+    /// use concrete_boolean::parameters::DEFAULT_PARAMETERS;
+    /// use concrete_boolean::server_key::CompressedServerKey;
+    ///
+    /// // Generate the client key:
+    /// let cks = ClientKey::new(&DEFAULT_PARAMETERS);
+    ///
+    /// // Generate the compressed server key:
+    /// let compressed_sks = CompressedServerKey::new(&cks);
+    /// ```
+    pub fn new(cks: &ClientKey) -> CompressedServerKey {
+        CoreEngine::with_thread_local_mut(|engine| {
+            // Convert the GLWE secret key into an LWE secret key:
+            let big_lwe_secret_key: LweSecretKey32 = engine
+                .transmute_glwe_secret_key_to_lwe_secret_key(cks.glwe_secret_key.clone())
+                .unwrap();
+
+            // Generate the bootstrapping key:
+            let bootstrapping_key: FourierLweBootstrapKey32 = engine
+                .create_lwe_bootstrap_key(
+                    &cks.lwe_secret_key,
+                    &cks.glwe_secret_key,
+                    cks.parameters.pbs_base_log,
+                    cks.parameters.pbs_level,
+                    Variance(cks.parameters.glwe_modular_std_dev.get_variance()),
+                )
+                .unwrap();
+
+            // Generate the seeded key switching key:
+            let key_switching_key = engine
+                .create_lwe_seeded_keyswitch_key(
+                    &big_lwe_secret_key,
+                    &cks.lwe_secret_key,
+                    cks.parameters.ks_level,
+                    cks.parameters.ks_base_log,
+                    Variance(cks.parameters.lwe_modular_std_dev.get_variance()),
+                )
+                .unwrap();
+
+            // Pack the keys in the compressed server key set:
+            CompressedServerKey {
+                key_switching_key,
+                bootstrapping_key,
+                plaintext_log_scaling_factor: cks.parameters.plaintext_log_scaling_factor,
+            }
+        })
+    }
 
-        // Convert the GLWE secret key into an LWE secret key:
-        let big_lwe_secret_key = cks.glwe_secret_key.clone().into_lwe_secret_key();
+    /// Decompresses the server key, by sampling the masks of the key switching key again from
+    /// the seed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::client_key::ClientKey;
+    /// use concrete_boolean::parameters::DEFAULT_PARAMETERS;
+    /// use concrete_boolean::server_key::CompressedServerKey;
+    ///
+    /// // Generate the client key and the compressed server key:
+    /// let cks = ClientKey::new(&DEFAULT_PARAMETERS);
+    /// let compressed_sks = CompressedServerKey::new(&cks);
+    ///
+    /// // Decompress the server key and use it to compute a gate:
+    /// let sks = compressed_sks.decompress();
+    /// let ct = sks.not(&cks.encrypt(true));
+    /// assert_eq!(false, cks.decrypt(&ct));
+    /// ```
+    pub fn decompress(&self) -> ServerKey {
+        let key_switching_key = CoreEngine::with_thread_local_mut(|engine| {
+            engine
+                .expand_lwe_seeded_keyswitch_key(&self.key_switching_key)
+                .unwrap()
+        });
+        ServerKey {
+            key_switching_key,
+            bootstrapping_key: self.bootstrapping_key.clone(),
+            plaintext_log_scaling_factor: self.plaintext_log_scaling_factor,
+        }
+    }
+}
 
-        // Fill the key switching key:
-        ksk.fill_with_keyswitch_key(
-            &big_lwe_secret_key,
-            &cks.lwe_secret_key,
-            cks.parameters.lwe_modular_std_dev,
-            &mut encryption_generator,
-        );
+impl<Engine> ServerKey<Engine>
+where
+    Engine: BooleanServerEngine,
+{
+    /// Allocates and generates a server key, whose gates are evaluated with the `Engine`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::client_key::ClientKey;
+    /// use concrete_boolean::parameters::DEFAULT_PARAMETERS;
+    /// use concrete_boolean::server_key::ServerKey;
+    /// use concrete_core::prelude::CoreEngine;
+    ///
+    /// // Generate the client key:
+    /// let cks = ClientKey::new(&DEFAULT_PARAMETERS);
+    ///
+    /// // Generate the server key for the core engine:
+    /// let sks = ServerKey::<CoreEngine>::generate(&cks);
+    /// ```
+    pub fn generate(cks: &ClientKey) -> ServerKey<Engine> {
+        let key_switching_key = CoreEngine::with_thread_local_mut(|engine| {
+            // Convert the GLWE secret key into an LWE secret key:
+            let big_lwe_secret_key: LweSecretKey32 = engine
+                .transmute_glwe_secret_key_to_lwe_secret_key(cks.glwe_secret_key.clone())
+                .unwrap();
+
+            // Generate the key switching key:
+            engine
+                .create_lwe_keyswitch_key(
+                    &big_lwe_secret_key,
+                    &cks.lwe_secret_key,
+                    cks.parameters.ks_level,
+                    cks.parameters.ks_base_log,
+                    Variance(cks.parameters.lwe_modular_std_dev.get_variance()),
+                )
+                .unwrap()
+        });
+
+        // Generate the bootstrapping key:
+        let bootstrapping_key = Engine::with_thread_local_mut(|engine| {
+            engine
+                .create_lwe_bootstrap_key(
+                    &cks.lwe_secret_key,
+                    &cks.glwe_secret_key,
+                    cks.parameters.pbs_base_log,
+                    cks.parameters.pbs_level,
+                    Variance(cks.parameters.glwe_modular_std_dev.get_variance()),
+                )
+                .unwrap()
+        });
 
         // Pack the keys in the server key set:
-        let sks: ServerKey = ServerKey {
-            key_switching_key: ksk,
-            bootstrapping_key: fourier_bsk,
-        };
-        sks
+        ServerKey {
+            key_switching_key,
+            bootstrapping_key,
+            plaintext_log_scaling_factor: cks.parameters.plaintext_log_scaling_factor,
+        }
     }
 
     /// Computes homomorphically an AND gate between two ciphertexts encrypting Boolean values:
@@ -137,47 +313,9 @@ impl ServerKey {
     /// assert_eq!(false, dec_and);
     /// ```
     pub fn and(&self, ct_left: &Ciphertext, ct_right: &Ciphertext) -> Ciphertext {
-        // Compute the linear combination for AND: ct_left + ct_right + (0,...,0,-1/8)
-        let mut ct_temp = ct_left.0.clone();
-        ct_temp.update_with_add(&ct_right.0);
-        ct_temp.get_mut_body().0 = ct_temp
-            .get_mut_body()
-            .0
-            .wrapping_sub(1_u32 << (32 - PLAINTEXT_LOG_SCALING_FACTOR)); // -1/8
-
-        // Create the accumulator
-        let mut accumulator = GlweCiphertext::allocate(
-            0_u32,
-            self.bootstrapping_key.polynomial_size(),
-            self.bootstrapping_key.glwe_size(),
-        );
-
-        // Fill the body of accumulator with the Test Polynomial
-        accumulator
-            .get_mut_body()
-            .as_mut_tensor()
-            .fill_with_element(PLAINTEXT_TRUE); // 1/8
-
-        // Allocate the output of the PBS
-        let mut ct_pbs = LweCiphertext::allocate(
-            0_u32,
-            self.bootstrapping_key.output_lwe_dimension().to_lwe_size(),
-        );
-
-        // Compute the programmable bootstrapping with fixed test polynomial
-        let mut buffers = FourierBuffers::new(
-            self.bootstrapping_key.polynomial_size(),
-            self.bootstrapping_key.glwe_size());
-        self.bootstrapping_key
-            .bootstrap(&mut ct_pbs, &ct_temp, &accumulator, &mut buffers);
-
-        // Compute a key switch to get back to input key
-        let mut ct_ks = LweCiphertext::allocate(0_u32, ct_left.0.lwe_size());
-        self.key_switching_key
-            .keyswitch_ciphertext(&mut ct_ks, &ct_pbs);
-
-        // Result
-        Ciphertext(ct_ks)
+        self.evaluate(|engine, bootstrap_engine, buffers, ct_out| {
+            self.discard_and(engine, bootstrap_engine, buffers, ct_out, ct_left, ct_right)
+        })
     }
 
     /// Computes an homomorphic MUX gate between three ciphertexts encrypting Boolean values:
@@ -209,77 +347,17 @@ impl ServerKey {
         ct_then: &Ciphertext,
         ct_else: &Ciphertext,
     ) -> Ciphertext {
-        // In theory MUX gate = (ct_condition AND ct_then) + (!ct_condition AND ct_else)
-
-        // Compute the linear combination for first AND: ct_condition + ct_then + (0,...,0,-1/8)
-        let mut ct_temp_1 = ct_condition.0.clone();
-        ct_temp_1.update_with_add(&ct_then.0);
-        ct_temp_1.get_mut_body().0 = ct_temp_1
-            .get_mut_body()
-            .0
-            .wrapping_sub(1_u32 << (32 - PLAINTEXT_LOG_SCALING_FACTOR)); // -1/8
-
-        // Compute the linear combination for second AND: - ct_condition + ct_else + (0,...,0,-1/8)
-        let mut ct_temp_2 = ct_condition.0.clone();
-        ct_temp_2.update_with_neg();
-        ct_temp_2.update_with_add(&ct_else.0);
-        ct_temp_2.get_mut_body().0 = ct_temp_2
-            .get_mut_body()
-            .0
-            .wrapping_sub(1_u32 << (32 - PLAINTEXT_LOG_SCALING_FACTOR)); // -1/8
-
-        // Create the accumulator:
-        let mut accumulator = GlweCiphertext::allocate(
-            0_u32,
-            self.bootstrapping_key.polynomial_size(),
-            self.bootstrapping_key.glwe_size(),
-        );
-
-        // Fill the body of accumulator with the Test Polynomial
-        accumulator
-            .get_mut_body()
-            .as_mut_tensor()
-            .fill_with_element(PLAINTEXT_TRUE); // 1/8
-
-        // Allocate the output of the first PBS:
-        let mut ct_pbs_1 = LweCiphertext::allocate(
-            0_u32,
-            self.bootstrapping_key.output_lwe_dimension().to_lwe_size(),
-        );
-
-        // Allocate the output of the second PBS:
-        let mut ct_pbs_2 = LweCiphertext::allocate(
-            0_u32,
-            self.bootstrapping_key.output_lwe_dimension().to_lwe_size(),
-        );
-
-        // Compute the first programmable bootstrapping with fixed test polynomial:
-        let mut buffers = FourierBuffers::new(
-            self.bootstrapping_key.polynomial_size(),
-            self.bootstrapping_key.glwe_size());
-        self.bootstrapping_key
-            .bootstrap(&mut ct_pbs_1, &ct_temp_1, &accumulator, &mut buffers);
-
-        // Compute the second programmable bootstrapping with fixed test polynomial:
-        self.bootstrapping_key
-            .bootstrap(&mut ct_pbs_2, &ct_temp_2, &accumulator, &mut buffers);
-
-        // Compute the linear combination to add the two results : ct_pbs_1 + ct_pbs_2 + (0,...,0,
-        // +1/8)
-        let mut ct_temp = ct_pbs_1;
-        ct_temp.update_with_add(&ct_pbs_2);
-        ct_temp.get_mut_body().0 = ct_temp
-            .get_mut_body()
-            .0
-            .wrapping_add(1_u32 << (32 - PLAINTEXT_LOG_SCALING_FACTOR)); // +1/8
-
-        // Compute the key switch to get back to input key
-        let mut ct_ks = LweCiphertext::allocate(0_u32, ct_condition.0.lwe_size());
-        self.key_switching_key
-            .keyswitch_ciphertext(&mut ct_ks, &ct_temp);
-
-        // Output the result:
-        Ciphertext(ct_ks)
+        self.evaluate(|engine, bootstrap_engine, buffers, ct_out| {
+            self.discard_mux(
+                engine,
+                bootstrap_engine,
+                buffers,
+                ct_out,
+                ct_condition,
+                ct_then,
+                ct_else,
+            )
+        })
     }
 
     /// Computes homomorphically a NAND gate between two ciphertexts encrypting Boolean values:
@@ -305,48 +383,9 @@ impl ServerKey {
     /// assert_eq!(true, dec_nand);
     /// ```
     pub fn nand(&self, ct_left: &Ciphertext, ct_right: &Ciphertext) -> Ciphertext {
-        // Compute the linear combination for NAND: - ct_left - ct_right + (0,...,0,1/8)
-        let mut ct_temp = ct_left.0.clone();
-        ct_temp.update_with_neg();
-        ct_temp.update_with_sub(&ct_right.0);
-        ct_temp.get_mut_body().0 = ct_temp
-            .get_mut_body()
-            .0
-            .wrapping_add(1_u32 << (32 - PLAINTEXT_LOG_SCALING_FACTOR)); // 1/8
-
-        // Create the accumulator:
-        let mut accumulator = GlweCiphertext::allocate(
-            0_u32,
-            self.bootstrapping_key.polynomial_size(),
-            self.bootstrapping_key.glwe_size(),
-        );
-
-        // Fill the body of accumulator with the Test Polynomial:
-        accumulator
-            .get_mut_body()
-            .as_mut_tensor()
-            .fill_with_element(PLAINTEXT_TRUE); // 1/8
-
-        // Allocate the output of the PBS:
-        let mut ct_pbs = LweCiphertext::allocate(
-            0_u32,
-            self.bootstrapping_key.output_lwe_dimension().to_lwe_size(),
-        );
-
-        // Compute the programmable bootstrapping with fixed test polynomial:
-        let mut buffers = FourierBuffers::new(
-            self.bootstrapping_key.polynomial_size(),
-            self.bootstrapping_key.glwe_size());
-        self.bootstrapping_key
-            .bootstrap(&mut ct_pbs, &ct_temp, &accumulator, &mut buffers);
-
-        // Compute the key switch to get back to input key:
-        let mut ct_ks = LweCiphertext::allocate(0_u32, ct_left.0.lwe_size());
-        self.key_switching_key
-            .keyswitch_ciphertext(&mut ct_ks, &ct_pbs);
-
-        // Output the result
-        Ciphertext(ct_ks)
+        self.evaluate(|engine, bootstrap_engine, buffers, ct_out| {
+            self.discard_nand(engine, bootstrap_engine, buffers, ct_out, ct_left, ct_right)
+        })
     }
 
     /// Computes homomorphically a NOR gate between two ciphertexts encrypting Boolean values:
@@ -372,48 +411,9 @@ impl ServerKey {
     /// assert_eq!(false, dec_nor);
     /// ```
     pub fn nor(&self, ct_left: &Ciphertext, ct_right: &Ciphertext) -> Ciphertext {
-        // Compute the linear combination for NOR: - ct_left - ct_right + (0,...,0,-1/8)
-        let mut ct_temp = ct_left.0.clone();
-        ct_temp.update_with_neg();
-        ct_temp.update_with_sub(&ct_right.0);
-        ct_temp.get_mut_body().0 = ct_temp
-            .get_mut_body()
-            .0
-            .wrapping_sub(1_u32 << (32 - PLAINTEXT_LOG_SCALING_FACTOR)); // -1/8
-
-        // Create the accumulator:
-        let mut accumulator = GlweCiphertext::allocate(
-            0_u32,
-            self.bootstrapping_key.polynomial_size(),
-            self.bootstrapping_key.glwe_size(),
-        );
-
-        // Fill the body of accumulator with the Test Polynomial:
-        accumulator
-            .get_mut_body()
-            .as_mut_tensor()
-            .fill_with_element(PLAINTEXT_TRUE); // 1/8
-
-        // Allocate the output of the PBS:
-        let mut ct_pbs = LweCiphertext::allocate(
-            0_u32,
-            self.bootstrapping_key.output_lwe_dimension().to_lwe_size(),
-        );
-
-        // Compute the Programmable bootstrapping with fixed test polynomial:
-        let mut buffers = FourierBuffers::new(
-            self.bootstrapping_key.polynomial_size(),
-            self.bootstrapping_key.glwe_size());
-        self.bootstrapping_key
-            .bootstrap(&mut ct_pbs, &ct_temp, &accumulator, &mut buffers);
-
-        // Compute the key switch to get back to input key:
-        let mut ct_ks = LweCiphertext::allocate(0_u32, ct_left.0.lwe_size());
-        self.key_switching_key
-            .keyswitch_ciphertext(&mut ct_ks, &ct_pbs);
-
-        // Output the result:
-        Ciphertext(ct_ks)
+        self.evaluate(|engine, bootstrap_engine, buffers, ct_out| {
+            self.discard_nor(engine, bootstrap_engine, buffers, ct_out, ct_left, ct_right)
+        })
     }
 
     /// Computes homomorphically a NOT gate of a ciphertexts encrypting a Boolean value:
@@ -438,12 +438,14 @@ impl ServerKey {
     /// assert_eq!(false, dec_not);
     /// ```
     pub fn not(&self, ct: &Ciphertext) -> Ciphertext {
-        // Compute the linear combination for NOT: -ct
-        let mut ct = ct.0.clone();
-        ct.update_with_neg();
-
-        // Output the result:
-        Ciphertext(ct)
+        CoreEngine::with_thread_local_mut(|engine| {
+            // Compute the linear combination for NOT: -ct
+            let mut ct = ct.0.clone();
+            engine.fuse_neg_lwe_ciphertext(&mut ct).unwrap();
+
+            // Output the result:
+            Ciphertext(ct)
+        })
     }
 
     /// Computes homomorphically an OR gate between two ciphertexts encrypting Boolean values:
@@ -469,47 +471,9 @@ impl ServerKey {
     /// assert_eq!(true, dec_or);
     /// ```
     pub fn or(&self, ct_left: &Ciphertext, ct_right: &Ciphertext) -> Ciphertext {
-        // Compute the linear combination for OR: ct_left + ct_right + (0,...,0,+1/8)
-        let mut ct_temp = ct_left.0.clone();
-        ct_temp.update_with_add(&ct_right.0);
-        ct_temp.get_mut_body().0 = ct_temp
-            .get_mut_body()
-            .0
-            .wrapping_add(1_u32 << (32 - PLAINTEXT_LOG_SCALING_FACTOR)); // +1/8
-
-        // Create the accumulator:
-        let mut accumulator = GlweCiphertext::allocate(
-            0_u32,
-            self.bootstrapping_key.polynomial_size(),
-            self.bootstrapping_key.glwe_size(),
-        );
-
-        // Fill the body of accumulator with the Test Polynomial:
-        accumulator
-            .get_mut_body()
-            .as_mut_tensor()
-            .fill_with_element(PLAINTEXT_TRUE); // 1/8
-
-        // Allocate the output of the PBS:
-        let mut ct_pbs = LweCiphertext::allocate(
-            0_u32,
-            self.bootstrapping_key.output_lwe_dimension().to_lwe_size(),
-        );
-
-        // Compute the programmable bootstrapping with fixed test polynomial:
-        let mut buffers = FourierBuffers::new(
-            self.bootstrapping_key.polynomial_size(),
-            self.bootstrapping_key.glwe_size());
-        self.bootstrapping_key
-            .bootstrap(&mut ct_pbs, &ct_temp, &accumulator, &mut buffers);
-
-        // Compute a key switch to get back to input key:
-        let mut ct_ks = LweCiphertext::allocate(0_u32, ct_left.0.lwe_size());
-        self.key_switching_key
-            .keyswitch_ciphertext(&mut ct_ks, &ct_pbs);
-
-        // Output the result:
-        Ciphertext(ct_ks)
+        self.evaluate(|engine, bootstrap_engine, buffers, ct_out| {
+            self.discard_or(engine, bootstrap_engine, buffers, ct_out, ct_left, ct_right)
+        })
     }
 
     /// Computes homomorphically an XNOR gate (or equality test) between two ciphertexts encrypting
@@ -536,49 +500,9 @@ impl ServerKey {
     /// assert_eq!(false, dec_xnor);
     /// ```
     pub fn xnor(&self, ct_left: &Ciphertext, ct_right: &Ciphertext) -> Ciphertext {
-        // Compute the linear combination for XNOR: 2*(-ct_left - ct_right) + (0,...,0,-1/4)
-        let mut ct_temp = ct_left.0.clone();
-        ct_temp.update_with_neg();
-        ct_temp.update_with_sub(&ct_right.0);
-        ct_temp.update_with_scalar_mul(Cleartext(2));
-        ct_temp.get_mut_body().0 = ct_temp
-            .get_mut_body()
-            .0
-            .wrapping_sub(1_u32 << (32 - PLAINTEXT_LOG_SCALING_FACTOR + 1)); // -1/4
-
-        // Create the accumulator:
-        let mut accumulator = GlweCiphertext::allocate(
-            0_u32,
-            self.bootstrapping_key.polynomial_size(),
-            self.bootstrapping_key.glwe_size(),
-        );
-
-        // Fill the body of accumulator with the Test Polynomial:
-        accumulator
-            .get_mut_body()
-            .as_mut_tensor()
-            .fill_with_element(PLAINTEXT_TRUE); // 1/8
-
-        // Allocate the output of the PBS:
-        let mut ct_pbs = LweCiphertext::allocate(
-            0_u32,
-            self.bootstrapping_key.output_lwe_dimension().to_lwe_size(),
-        );
-
-        // Compute a programmable bootstrapping with fixed test polynomial:
-        let mut buffers = FourierBuffers::new(
-            self.bootstrapping_key.polynomial_size(),
-            self.bootstrapping_key.glwe_size());
-        self.bootstrapping_key
-            .bootstrap(&mut ct_pbs, &ct_temp, &accumulator, &mut buffers);
-
-        // Compute a key switching to get back to input key:
-        let mut ct_ks = LweCiphertext::allocate(0_u32, ct_left.0.lwe_size());
-        self.key_switching_key
-            .keyswitch_ciphertext(&mut ct_ks, &ct_pbs);
-
-        // Output the result:
-        Ciphertext(ct_ks)
+        self.evaluate(|engine, bootstrap_engine, buffers, ct_out| {
+            self.discard_xnor(engine, bootstrap_engine, buffers, ct_out, ct_left, ct_right)
+        })
     }
 
     /// Computes homomorphically an XOR gate between two ciphertexts encrypting Boolean values:
@@ -604,47 +528,1417 @@ impl ServerKey {
     /// assert_eq!(true, dec_xor);
     /// ```
     pub fn xor(&self, ct_left: &Ciphertext, ct_right: &Ciphertext) -> Ciphertext {
-        // Compute the linear combination for XOR: 2*(ct_left + ct_right) + (0,...,0,1/4)
-        let mut ct_temp = ct_left.0.clone();
-        ct_temp.update_with_add(&ct_right.0);
-        ct_temp.update_with_scalar_mul(Cleartext(2));
-        ct_temp.get_mut_body().0 = ct_temp
-            .get_mut_body()
-            .0
-            .wrapping_add(1_u32 << (32 - PLAINTEXT_LOG_SCALING_FACTOR + 1)); // +1/4
-
-        // Create the accumulator:
-        let mut accumulator = GlweCiphertext::allocate(
-            0_u32,
-            self.bootstrapping_key.polynomial_size(),
-            self.bootstrapping_key.glwe_size(),
-        );
+        self.evaluate(|engine, bootstrap_engine, buffers, ct_out| {
+            self.discard_xor(engine, bootstrap_engine, buffers, ct_out, ct_left, ct_right)
+        })
+    }
 
-        // Fill the body of accumulator with the Test Polynomial:
-        accumulator
-            .get_mut_body()
-            .as_mut_tensor()
-            .fill_with_element(PLAINTEXT_TRUE); // 1/8
+    /// Computes homomorphically an AND gate between three ciphertexts encrypting Boolean values:
+    /// $$ ct_{out} = ct_1~\mathrm{AND}~ct_2~\mathrm{AND}~ct_3 $$
+    ///
+    /// The gate is computed with a single bootstrap when the plaintext log scaling factor of the
+    /// parameter set is at least 4, e.g. with the
+    /// [`MULTI_INPUT_PARAMETERS`](crate::parameters::MULTI_INPUT_PARAMETERS), and with two AND
+    /// gates otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::client_key::ClientKey;
+    /// use concrete_boolean::parameters::MULTI_INPUT_PARAMETERS;
+    /// use concrete_boolean::server_key::ServerKey;
+    ///
+    /// // Generate the client key and the server key:
+    /// let cks = ClientKey::new(&MULTI_INPUT_PARAMETERS);
+    /// let sks = ServerKey::new(&cks);
+    ///
+    /// // Encrypt three messages:
+    /// let ct1 = cks.encrypt(true);
+    /// let ct2 = cks.encrypt(true);
+    /// let ct3 = cks.encrypt(false);
+    ///
+    /// // Compute homomorphically an AND3 gate:
+    /// let ct_res = sks.and3(&ct1, &ct2, &ct3);
+    ///
+    /// // Decrypt:
+    /// let dec_and3 = cks.decrypt(&ct_res);
+    /// assert_eq!(false, dec_and3);
+    /// ```
+    pub fn and3(&self, ct_1: &Ciphertext, ct_2: &Ciphertext, ct_3: &Ciphertext) -> Ciphertext {
+        self.evaluate(|engine, bootstrap_engine, buffers, ct_out| {
+            self.discard_and3(engine, bootstrap_engine, buffers, ct_out, ct_1, ct_2, ct_3)
+        })
+    }
 
-        // Allocate for the output of the PBS:
-        let mut ct_pbs = LweCiphertext::allocate(
-            0_u32,
-            self.bootstrapping_key.output_lwe_dimension().to_lwe_size(),
+    /// Evaluates homomorphically an arbitrary Boolean function of the ciphertexts of `inputs`,
+    /// given by its truth table, with a single bootstrap.
+    ///
+    /// The entry of index $\sum_i x_i 2^i$ of `truth_table` is the output of the function when
+    /// the $i$-th input encrypts $x_i$. The bootstrap is applied to a weighted sum of the inputs,
+    /// whose weights and test polynomial are picked automatically so as to minimize the noise of
+    /// the sum relative to the distance between two sums with different outputs.
+    ///
+    /// The functions which can be evaluated depend on the plaintext log scaling factor of the
+    /// parameter set:
+    /// * with 3, e.g. with the `DEFAULT_PARAMETERS`: every function of two inputs, and 80 of the
+    ///   256 functions of three inputs, such as the majority or the parity;
+    /// * with 4, e.g. with the `MULTI_INPUT_PARAMETERS`: every symmetric function of three inputs,
+    ///   and 208 of the 256 functions of three inputs;
+    /// * with 5, e.g. with the `LOOKUP_TABLE_PARAMETERS`: every function of three inputs.
+    ///
+    /// The search of the weights explores $2^{pn}$ candidates for $n$ inputs and a log scaling
+    /// factor $p$, so the number of inputs is limited to $\lfloor 20 / p \rfloor$. The weights
+    /// and the accumulator are computed on every call: to evaluate a truth table several times,
+    /// prepare it once with [`prepare_lut`](ServerKey::prepare_lut) and evaluate it with
+    /// [`eval_prepared_lut`](ServerKey::eval_prepared_lut).
+    ///
+    /// # Panics
+    ///
+    /// This method panics if there are too many inputs, if `truth_table` does not have $2^n$
+    /// entries, where $n$ is the number of inputs, or if the function cannot be evaluated with the
+    /// parameter set of the key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encrypt three messages:
+    /// let ct1 = cks.encrypt(true);
+    /// let ct2 = cks.encrypt(false);
+    /// let ct3 = cks.encrypt(true);
+    ///
+    /// // Compute homomorphically the parity of the three messages:
+    /// let parity = [false, true, true, false, true, false, false, true];
+    /// let ct_res = sks.eval_lut(&[&ct1, &ct2, &ct3], &parity);
+    ///
+    /// // Decrypt:
+    /// let dec_parity = cks.decrypt(&ct_res);
+    /// assert_eq!(false, dec_parity);
+    /// ```
+    pub fn eval_lut(&self, inputs: &[&Ciphertext], truth_table: &[bool]) -> Ciphertext {
+        self.check_num_inputs(inputs.len());
+        assert_eq!(
+            truth_table.len(),
+            1 << inputs.len(),
+            "A truth table of {} inputs must have {} entries, but {} were given.",
+            inputs.len(),
+            1_usize << inputs.len(),
+            truth_table.len()
         );
+        let lookup_table = self.prepare_lut(truth_table);
+        self.eval_prepared_lut(inputs, &lookup_table)
+    }
 
-        // Compute the programmable bootstrapping with fixed test polynomial:
-        let mut buffers = FourierBuffers::new(
+    /// Prepares a truth table for its evaluation with [`eval_prepared_lut`](
+    /// ServerKey::eval_prepared_lut), by searching the weights of its inputs and computing its
+    /// accumulator.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the number of entries of `truth_table` is not a power of two, if the
+    /// truth table has too many inputs, or if the function cannot be evaluated with the parameter
+    /// set of the key. The limits are the ones of [`eval_lut`](ServerKey::eval_lut).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Prepare the parity of three inputs:
+    /// let parity = sks.prepare_lut(&[false, true, true, false, true, false, false, true]);
+    ///
+    /// // Compute homomorphically the parity of several triples of messages:
+    /// for messages in [[true, false, true], [true, true, true]].iter() {
+    ///     let cts: Vec<_> = messages.iter().map(|m| cks.encrypt(*m)).collect();
+    ///     let ct_res = sks.eval_prepared_lut(&[&cts[0], &cts[1], &cts[2]], &parity);
+    ///     let dec_parity = cks.decrypt(&ct_res);
+    ///     assert_eq!(messages[0] ^ messages[1] ^ messages[2], dec_parity);
+    /// }
+    /// ```
+    pub fn prepare_lut(&self, truth_table: &[bool]) -> PreparedLookupTable {
+        assert!(
+            truth_table.len().is_power_of_two(),
+            "A truth table must have a power of two entries, but {} were given.",
+            truth_table.len()
+        );
+        self.check_num_inputs(truth_table.len().trailing_zeros() as usize);
+        let lookup_table = LookupTable::new(
+            truth_table,
+            self.plaintext_log_scaling_factor,
             self.bootstrapping_key.polynomial_size(),
-            self.bootstrapping_key.glwe_size());
-        self.bootstrapping_key
-            .bootstrap(&mut ct_pbs, &ct_temp, &accumulator, &mut buffers);
+        )
+        .expect("The truth table cannot be evaluated with the parameter set of the key.");
+        CoreEngine::with_thread_local_mut(|engine| {
+            let weights = lookup_table
+                .weights
+                .iter()
+                .map(|weight| engine.create_cleartext(weight).unwrap())
+                .collect();
+
+            // Create the accumulator, whose body is filled with the test polynomial of the table:
+            let test_polynomial: PlaintextVector32 = engine
+                .create_plaintext_vector(&lookup_table.test_polynomial)
+                .unwrap();
+            let accumulator = engine
+                .trivially_encrypt_glwe_ciphertext(
+                    self.bootstrapping_key.glwe_dimension().to_glwe_size(),
+                    &test_polynomial,
+                )
+                .unwrap();
+            PreparedLookupTable {
+                weights,
+                accumulator,
+            }
+        })
+    }
+
+    /// Evaluates homomorphically a truth table prepared with [`prepare_lut`](
+    /// ServerKey::prepare_lut) on the ciphertexts of `inputs`, with a single bootstrap.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the number of inputs differs from the one of the truth table.
+    pub fn eval_prepared_lut(
+        &self,
+        inputs: &[&Ciphertext],
+        lookup_table: &PreparedLookupTable,
+    ) -> Ciphertext {
+        assert_eq!(
+            inputs.len(),
+            lookup_table.weights.len(),
+            "The truth table has {} inputs, but {} were given.",
+            lookup_table.weights.len(),
+            inputs.len()
+        );
+        self.evaluate(|engine, bootstrap_engine, buffers, ct_out| {
+            self.discard_eval_lut(
+                engine,
+                bootstrap_engine,
+                buffers,
+                ct_out,
+                inputs,
+                lookup_table,
+            )
+        })
+    }
+
+    // Checks that the weights of a truth table with the given number of inputs can be searched.
+    fn check_num_inputs(&self, num_inputs: usize) {
+        let max_num_inputs = lookup_table::max_num_inputs(self.plaintext_log_scaling_factor);
+        assert!(
+            num_inputs <= max_num_inputs,
+            "A truth table of {} inputs cannot be evaluated with a plaintext log scaling factor of \
+            {}, which supports at most {} inputs.",
+            num_inputs,
+            self.plaintext_log_scaling_factor,
+            max_num_inputs
+        );
+    }
+
+    /// Computes homomorphically the majority of three ciphertexts encrypting Boolean values:
+    /// $$ ct_{out} = (ct_1~\mathrm{AND}~ct_2)~\mathrm{OR}~(ct_1~\mathrm{AND}~ct_3)~\mathrm{OR}~
+    /// (ct_2~\mathrm{AND}~ct_3) $$
+    ///
+    /// The gate is computed with a single bootstrap of the sum of the three ciphertexts, whose
+    /// noise is larger than the one of the sums computed by the two-input gates.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encrypt three messages:
+    /// let ct1 = cks.encrypt(true);
+    /// let ct2 = cks.encrypt(false);
+    /// let ct3 = cks.encrypt(true);
+    ///
+    /// // Compute homomorphically a MAJORITY gate:
+    /// let ct_res = sks.majority(&ct1, &ct2, &ct3);
+    ///
+    /// // Decrypt:
+    /// let dec_majority = cks.decrypt(&ct_res);
+    /// assert_eq!(true, dec_majority);
+    /// ```
+    pub fn majority(&self, ct_1: &Ciphertext, ct_2: &Ciphertext, ct_3: &Ciphertext) -> Ciphertext {
+        self.evaluate(|engine, bootstrap_engine, buffers, ct_out| {
+            self.discard_majority(engine, bootstrap_engine, buffers, ct_out, ct_1, ct_2, ct_3)
+        })
+    }
 
-        // Compute the key switching to get back to input key:
-        let mut ct_ks = LweCiphertext::allocate(0_u32, ct_left.0.lwe_size());
-        self.key_switching_key
-            .keyswitch_ciphertext(&mut ct_ks, &ct_pbs);
+    /// Computes homomorphically an OR gate between three ciphertexts encrypting Boolean values:
+    /// $$ ct_{out} = ct_1~\mathrm{OR}~ct_2~\mathrm{OR}~ct_3 $$
+    ///
+    /// The gate is computed with a single bootstrap when the plaintext log scaling factor of the
+    /// parameter set is at least 4, e.g. with the
+    /// [`MULTI_INPUT_PARAMETERS`](crate::parameters::MULTI_INPUT_PARAMETERS), and with two OR
+    /// gates otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::client_key::ClientKey;
+    /// use concrete_boolean::parameters::MULTI_INPUT_PARAMETERS;
+    /// use concrete_boolean::server_key::ServerKey;
+    ///
+    /// // Generate the client key and the server key:
+    /// let cks = ClientKey::new(&MULTI_INPUT_PARAMETERS);
+    /// let sks = ServerKey::new(&cks);
+    ///
+    /// // Encrypt three messages:
+    /// let ct1 = cks.encrypt(false);
+    /// let ct2 = cks.encrypt(false);
+    /// let ct3 = cks.encrypt(true);
+    ///
+    /// // Compute homomorphically an OR3 gate:
+    /// let ct_res = sks.or3(&ct1, &ct2, &ct3);
+    ///
+    /// // Decrypt:
+    /// let dec_or3 = cks.decrypt(&ct_res);
+    /// assert_eq!(true, dec_or3);
+    /// ```
+    pub fn or3(&self, ct_1: &Ciphertext, ct_2: &Ciphertext, ct_3: &Ciphertext) -> Ciphertext {
+        self.evaluate(|engine, bootstrap_engine, buffers, ct_out| {
+            self.discard_or3(engine, bootstrap_engine, buffers, ct_out, ct_1, ct_2, ct_3)
+        })
+    }
 
-        // Output the result:
-        Ciphertext(ct_ks)
+    /// Creates a trivial encryption of a Boolean message, which can be used as an input of the
+    /// gates.
+    ///
+    /// A trivial encryption has a null mask, and is thus not secure: it is meant to encrypt
+    /// public constants only, which can be used along with the ciphertexts of the client.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encryption of one message, and trivial encryption of a constant:
+    /// let ct1 = cks.encrypt(true);
+    /// let ct2 = sks.trivial_encrypt(true);
+    ///
+    /// // Compute the AND gate:
+    /// let ct_res = sks.and(&ct1, &ct2);
+    ///
+    /// // Decryption:
+    /// let dec_and = cks.decrypt(&ct_res);
+    /// assert_eq!(true, dec_and);
+    /// ```
+    pub fn trivial_encrypt(&self, message: bool) -> Ciphertext {
+        CoreEngine::with_thread_local_mut(|engine| self.create_trivial(engine, message))
     }
+
+    /// Computes homomorphically an AND gate between a ciphertext and a clear Boolean value:
+    /// $$ct_{out} = ct_{left}~\wedge~m_{right}$$
+    ///
+    /// This gate does not need any bootstrap: the result is either a trivial encryption of `false`,
+    /// or a copy of the ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encryption of one message:
+    /// let ct = cks.encrypt(true);
+    ///
+    /// // Compute the AND gate with a clear value:
+    /// let ct_res = sks.and_clear(&ct, false);
+    ///
+    /// // Decryption:
+    /// let dec_and = cks.decrypt(&ct_res);
+    /// assert_eq!(true && false, dec_and);
+    /// ```
+    pub fn and_clear(&self, ct_left: &Ciphertext, right: bool) -> Ciphertext {
+        if right {
+            ct_left.clone()
+        } else {
+            self.trivial_encrypt(false)
+        }
+    }
+
+    /// Computes homomorphically a NAND gate between a ciphertext and a clear Boolean value:
+    /// $$ct_{out} = \neg(ct_{left}~\wedge~m_{right})$$
+    ///
+    /// This gate does not need any bootstrap: the result is either a trivial encryption of `true`,
+    /// or the negation of the ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encryption of one message:
+    /// let ct = cks.encrypt(true);
+    ///
+    /// // Compute the NAND gate with a clear value:
+    /// let ct_res = sks.nand_clear(&ct, false);
+    ///
+    /// // Decryption:
+    /// let dec_nand = cks.decrypt(&ct_res);
+    /// assert_eq!(!(true && false), dec_nand);
+    /// ```
+    pub fn nand_clear(&self, ct_left: &Ciphertext, right: bool) -> Ciphertext {
+        if right {
+            self.not(ct_left)
+        } else {
+            self.trivial_encrypt(true)
+        }
+    }
+
+    /// Computes homomorphically a NOR gate between a ciphertext and a clear Boolean value:
+    /// $$ct_{out} = \neg(ct_{left}~\vee~m_{right})$$
+    ///
+    /// This gate does not need any bootstrap: the result is either a trivial encryption of `false`,
+    /// or the negation of the ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encryption of one message:
+    /// let ct = cks.encrypt(true);
+    ///
+    /// // Compute the NOR gate with a clear value:
+    /// let ct_res = sks.nor_clear(&ct, false);
+    ///
+    /// // Decryption:
+    /// let dec_nor = cks.decrypt(&ct_res);
+    /// assert_eq!(!(true || false), dec_nor);
+    /// ```
+    pub fn nor_clear(&self, ct_left: &Ciphertext, right: bool) -> Ciphertext {
+        if right {
+            self.trivial_encrypt(false)
+        } else {
+            self.not(ct_left)
+        }
+    }
+
+    /// Computes homomorphically an OR gate between a ciphertext and a clear Boolean value:
+    /// $$ct_{out} = ct_{left}~\vee~m_{right}$$
+    ///
+    /// This gate does not need any bootstrap: the result is either a trivial encryption of `true`,
+    /// or a copy of the ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encryption of one message:
+    /// let ct = cks.encrypt(true);
+    ///
+    /// // Compute the OR gate with a clear value:
+    /// let ct_res = sks.or_clear(&ct, false);
+    ///
+    /// // Decryption:
+    /// let dec_or = cks.decrypt(&ct_res);
+    /// assert_eq!(true || false, dec_or);
+    /// ```
+    pub fn or_clear(&self, ct_left: &Ciphertext, right: bool) -> Ciphertext {
+        if right {
+            self.trivial_encrypt(true)
+        } else {
+            ct_left.clone()
+        }
+    }
+
+    /// Computes homomorphically an XNOR gate between a ciphertext and a clear Boolean value:
+    /// $$ct_{out} = \neg(ct_{left}~\oplus~m_{right})$$
+    ///
+    /// This gate does not need any bootstrap: the result is either a copy of the ciphertext, or its
+    /// negation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encryption of one message:
+    /// let ct = cks.encrypt(true);
+    ///
+    /// // Compute the XNOR gate with a clear value:
+    /// let ct_res = sks.xnor_clear(&ct, false);
+    ///
+    /// // Decryption:
+    /// let dec_xnor = cks.decrypt(&ct_res);
+    /// assert_eq!(!(true ^ false), dec_xnor);
+    /// ```
+    pub fn xnor_clear(&self, ct_left: &Ciphertext, right: bool) -> Ciphertext {
+        if right {
+            ct_left.clone()
+        } else {
+            self.not(ct_left)
+        }
+    }
+
+    /// Computes homomorphically an XOR gate between a ciphertext and a clear Boolean value:
+    /// $$ct_{out} = ct_{left}~\oplus~m_{right}$$
+    ///
+    /// This gate does not need any bootstrap: the result is either the negation of the ciphertext,
+    /// or a copy of it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encryption of one message:
+    /// let ct = cks.encrypt(true);
+    ///
+    /// // Compute the XOR gate with a clear value:
+    /// let ct_res = sks.xor_clear(&ct, false);
+    ///
+    /// // Decryption:
+    /// let dec_xor = cks.decrypt(&ct_res);
+    /// assert_eq!(true ^ false, dec_xor);
+    /// ```
+    pub fn xor_clear(&self, ct_left: &Ciphertext, right: bool) -> Ciphertext {
+        if right {
+            self.not(ct_left)
+        } else {
+            ct_left.clone()
+        }
+    }
+
+    // Evaluates a gate with the engine of the current thread, using freshly allocated buffers.
+    fn evaluate<F>(&self, gate: F) -> Ciphertext
+    where
+        F: FnOnce(&mut CoreEngine, &mut Engine, &mut GateBuffers, &mut Ciphertext),
+    {
+        with_gate_engines_mut(|engine, bootstrap_engine| {
+            let mut buffers = self.create_buffers(engine);
+            let mut ct_out = self.allocate_ciphertext(engine);
+            gate(engine, bootstrap_engine, &mut buffers, &mut ct_out);
+            ct_out
+        })
+    }
+
+    // Creates the buffers used to evaluate the gates with this key.
+    pub(crate) fn create_buffers(&self, engine: &mut CoreEngine) -> GateBuffers {
+        let polynomial_size = self.bootstrapping_key.polynomial_size();
+        let glwe_size = self.bootstrapping_key.glwe_dimension().to_glwe_size();
+        let input_lwe_size = self.bootstrapping_key.input_lwe_dimension().to_lwe_size();
+        let output_lwe_size = self.bootstrapping_key.output_lwe_dimension().to_lwe_size();
+
+        let plaintext_true = encode_boolean(true, self.plaintext_log_scaling_factor);
+
+        // Create the accumulator, whose body is filled with the test polynomial:
+        let test_polynomial: PlaintextVector32 = engine
+            .create_plaintext_vector(&vec![plaintext_true; polynomial_size.0]) // Δ
+            .unwrap();
+        let accumulator = engine
+            .trivially_encrypt_glwe_ciphertext(glwe_size, &test_polynomial)
+            .unwrap();
+
+        GateBuffers {
+            accumulator,
+            delta: engine.create_plaintext(&plaintext_true).unwrap(),
+            two_delta: engine.create_plaintext(&(plaintext_true << 1)).unwrap(),
+            one_quarter: engine.create_plaintext(&(1_u32 << 30)).unwrap(),
+            // maps the sums of two Boolean plaintexts, in {-2Δ, 0, 2Δ}, to {-1/2, 0, 1/2}
+            xor_scaling: engine
+                .create_cleartext(&(1_u32 << (self.plaintext_log_scaling_factor - 2)))
+                .unwrap(),
+            ct_temp_1: allocate_ciphertext(engine, input_lwe_size),
+            ct_temp_2: allocate_ciphertext(engine, input_lwe_size),
+            ct_pbs_1: allocate_ciphertext(engine, output_lwe_size),
+            ct_pbs_2: allocate_ciphertext(engine, output_lwe_size),
+        }
+    }
+
+    // Allocates a ciphertext which can be used as the output of the gates.
+    pub(crate) fn allocate_ciphertext(&self, engine: &mut CoreEngine) -> Ciphertext {
+        Ciphertext(allocate_ciphertext(
+            engine,
+            self.key_switching_key.output_lwe_dimension().to_lwe_size(),
+        ))
+    }
+
+    // Creates a trivial encryption of a Boolean message with the given engine.
+    pub(crate) fn create_trivial(&self, engine: &mut CoreEngine, message: bool) -> Ciphertext {
+        // encode the boolean message
+        let encoded = encode_boolean(message, self.plaintext_log_scaling_factor);
+
+        let plain: Plaintext32 = engine.create_plaintext(&encoded).unwrap();
+        let ct = engine
+            .trivially_encrypt_lwe_ciphertext(
+                self.key_switching_key.output_lwe_dimension().to_lwe_size(),
+                &plain,
+            )
+            .unwrap();
+        Ciphertext(ct)
+    }
+
+    // Computes homomorphically an AND gate, and writes the result in `ct_out`.
+    pub(crate) fn discard_and(
+        &self,
+        engine: &mut CoreEngine,
+        bootstrap_engine: &mut Engine,
+        buffers: &mut GateBuffers,
+        ct_out: &mut Ciphertext,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+    ) {
+        // Compute the linear combination for AND: ct_left + ct_right + (0,...,0,-Δ)
+        engine
+            .discard_add_lwe_ciphertext(&mut buffers.ct_temp_1, &ct_left.0, &ct_right.0)
+            .unwrap();
+        engine
+            .fuse_sub_lwe_ciphertext_plaintext(&mut buffers.ct_temp_1, &buffers.delta)
+            .unwrap(); // -Δ
+
+        // Compute the programmable bootstrapping with fixed test polynomial, and the key switch to
+        // get back to input key:
+        self.bootstrap_keyswitch(engine, bootstrap_engine, buffers, ct_out);
+    }
+
+    // Computes homomorphically an AND gate between three ciphertexts, and writes the result in
+    // `ct_out`.
+    pub(crate) fn discard_and3(
+        &self,
+        engine: &mut CoreEngine,
+        bootstrap_engine: &mut Engine,
+        buffers: &mut GateBuffers,
+        ct_out: &mut Ciphertext,
+        ct_1: &Ciphertext,
+        ct_2: &Ciphertext,
+        ct_3: &Ciphertext,
+    ) {
+        if self.plaintext_log_scaling_factor < MIN_THREE_INPUT_LOG_SCALING_FACTOR {
+            let mut ct_temp = self.allocate_ciphertext(engine);
+            self.discard_and(engine, bootstrap_engine, buffers, &mut ct_temp, ct_1, ct_2);
+            self.discard_and(engine, bootstrap_engine, buffers, ct_out, &ct_temp, ct_3);
+            return;
+        }
+
+        // Compute the linear combination for AND3: ct_1 + ct_2 + ct_3 + (0,...,0,-2Δ)
+        self.sum_three(engine, buffers, ct_1, ct_2, ct_3);
+        engine
+            .fuse_sub_lwe_ciphertext_plaintext(&mut buffers.ct_temp_1, &buffers.two_delta)
+            .unwrap(); // -2Δ
+
+        // Compute the programmable bootstrapping with fixed test polynomial, and the key switch to
+        // get back to input key:
+        self.bootstrap_keyswitch(engine, bootstrap_engine, buffers, ct_out);
+    }
+
+    // Evaluates homomorphically a lookup table, and writes the result in `ct_out`.
+    pub(crate) fn discard_eval_lut(
+        &self,
+        engine: &mut CoreEngine,
+        bootstrap_engine: &mut Engine,
+        buffers: &mut GateBuffers,
+        ct_out: &mut Ciphertext,
+        inputs: &[&Ciphertext],
+        lookup_table: &PreparedLookupTable,
+    ) {
+        // Compute the weighted sum of the inputs, which is zero without inputs:
+        if inputs.is_empty() {
+            buffers.ct_temp_1 = allocate_ciphertext(
+                engine,
+                self.bootstrapping_key.input_lwe_dimension().to_lwe_size(),
+            );
+        }
+        for (index, (ct, weight)) in inputs.iter().zip(lookup_table.weights.iter()).enumerate() {
+            if index == 0 {
+                engine
+                    .discard_mul_lwe_ciphertext_cleartext(&mut buffers.ct_temp_1, &ct.0, weight)
+                    .unwrap();
+            } else {
+                engine
+                    .discard_mul_lwe_ciphertext_cleartext(&mut buffers.ct_temp_2, &ct.0, weight)
+                    .unwrap();
+                engine
+                    .fuse_add_lwe_ciphertext(&mut buffers.ct_temp_1, &buffers.ct_temp_2)
+                    .unwrap();
+            }
+        }
+
+        // Compute the programmable bootstrapping with the test polynomial of the table, and the
+        // key switch to get back to input key:
+        bootstrap_engine
+            .discard_bootstrap_lwe_ciphertext(
+                &mut buffers.ct_pbs_1,
+                &buffers.ct_temp_1,
+                &lookup_table.accumulator,
+                &self.bootstrapping_key,
+            )
+            .unwrap();
+        engine
+            .discard_keyswitch_lwe_ciphertext(
+                &mut ct_out.0,
+                &buffers.ct_pbs_1,
+                &self.key_switching_key,
+            )
+            .unwrap();
+    }
+
+    // Computes homomorphically a MAJORITY gate, and writes the result in `ct_out`.
+    pub(crate) fn discard_majority(
+        &self,
+        engine: &mut CoreEngine,
+        bootstrap_engine: &mut Engine,
+        buffers: &mut GateBuffers,
+        ct_out: &mut Ciphertext,
+        ct_1: &Ciphertext,
+        ct_2: &Ciphertext,
+        ct_3: &Ciphertext,
+    ) {
+        // Compute the linear combination for MAJORITY: ct_1 + ct_2 + ct_3
+        self.sum_three(engine, buffers, ct_1, ct_2, ct_3);
+
+        // Compute the programmable bootstrapping with fixed test polynomial, and the key switch to
+        // get back to input key:
+        self.bootstrap_keyswitch(engine, bootstrap_engine, buffers, ct_out);
+    }
+
+    // Computes homomorphically a MUX gate, and writes the result in `ct_out`.
+    pub(crate) fn discard_mux(
+        &self,
+        engine: &mut CoreEngine,
+        bootstrap_engine: &mut Engine,
+        buffers: &mut GateBuffers,
+        ct_out: &mut Ciphertext,
+        ct_condition: &Ciphertext,
+        ct_then: &Ciphertext,
+        ct_else: &Ciphertext,
+    ) {
+        // In theory MUX gate = (ct_condition AND ct_then) + (!ct_condition AND ct_else)
+
+        // Compute the linear combination for first AND: ct_condition + ct_then + (0,...,0,-Δ)
+        engine
+            .discard_add_lwe_ciphertext(&mut buffers.ct_temp_1, &ct_condition.0, &ct_then.0)
+            .unwrap();
+        engine
+            .fuse_sub_lwe_ciphertext_plaintext(&mut buffers.ct_temp_1, &buffers.delta)
+            .unwrap(); // -Δ
+
+        // Compute the linear combination for second AND: - ct_condition + ct_else + (0,...,0,-Δ)
+        engine
+            .discard_neg_lwe_ciphertext(&mut buffers.ct_temp_2, &ct_condition.0)
+            .unwrap();
+        engine
+            .fuse_add_lwe_ciphertext(&mut buffers.ct_temp_2, &ct_else.0)
+            .unwrap();
+        engine
+            .fuse_sub_lwe_ciphertext_plaintext(&mut buffers.ct_temp_2, &buffers.delta)
+            .unwrap(); // -Δ
+
+        // Compute the first programmable bootstrapping with fixed test polynomial:
+        bootstrap_engine
+            .discard_bootstrap_lwe_ciphertext(
+                &mut buffers.ct_pbs_1,
+                &buffers.ct_temp_1,
+                &buffers.accumulator,
+                &self.bootstrapping_key,
+            )
+            .unwrap();
+
+        // Compute the second programmable bootstrapping with fixed test polynomial:
+        bootstrap_engine
+            .discard_bootstrap_lwe_ciphertext(
+                &mut buffers.ct_pbs_2,
+                &buffers.ct_temp_2,
+                &buffers.accumulator,
+                &self.bootstrapping_key,
+            )
+            .unwrap();
+
+        // Compute the linear combination to add the two results : ct_pbs_1 + ct_pbs_2 +
+        // (0,...,0,+Δ)
+        engine
+            .fuse_add_lwe_ciphertext(&mut buffers.ct_pbs_1, &buffers.ct_pbs_2)
+            .unwrap();
+        engine
+            .fuse_add_lwe_ciphertext_plaintext(&mut buffers.ct_pbs_1, &buffers.delta)
+            .unwrap(); // +Δ
+
+        // Compute the key switch to get back to input key:
+        engine
+            .discard_keyswitch_lwe_ciphertext(
+                &mut ct_out.0,
+                &buffers.ct_pbs_1,
+                &self.key_switching_key,
+            )
+            .unwrap();
+    }
+
+    // Computes homomorphically a NAND gate, and writes the result in `ct_out`.
+    pub(crate) fn discard_nand(
+        &self,
+        engine: &mut CoreEngine,
+        bootstrap_engine: &mut Engine,
+        buffers: &mut GateBuffers,
+        ct_out: &mut Ciphertext,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+    ) {
+        // Compute the linear combination for NAND: - ct_left - ct_right + (0,...,0,Δ)
+        engine
+            .discard_neg_lwe_ciphertext(&mut buffers.ct_temp_1, &ct_left.0)
+            .unwrap();
+        engine
+            .fuse_sub_lwe_ciphertext(&mut buffers.ct_temp_1, &ct_right.0)
+            .unwrap();
+        engine
+            .fuse_add_lwe_ciphertext_plaintext(&mut buffers.ct_temp_1, &buffers.delta)
+            .unwrap(); // Δ
+
+        // Compute the programmable bootstrapping with fixed test polynomial, and the key switch to
+        // get back to input key:
+        self.bootstrap_keyswitch(engine, bootstrap_engine, buffers, ct_out);
+    }
+
+    // Computes homomorphically a NOR gate, and writes the result in `ct_out`.
+    pub(crate) fn discard_nor(
+        &self,
+        engine: &mut CoreEngine,
+        bootstrap_engine: &mut Engine,
+        buffers: &mut GateBuffers,
+        ct_out: &mut Ciphertext,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+    ) {
+        // Compute the linear combination for NOR: - ct_left - ct_right + (0,...,0,-Δ)
+        engine
+            .discard_neg_lwe_ciphertext(&mut buffers.ct_temp_1, &ct_left.0)
+            .unwrap();
+        engine
+            .fuse_sub_lwe_ciphertext(&mut buffers.ct_temp_1, &ct_right.0)
+            .unwrap();
+        engine
+            .fuse_sub_lwe_ciphertext_plaintext(&mut buffers.ct_temp_1, &buffers.delta)
+            .unwrap(); // -Δ
+
+        // Compute the programmable bootstrapping with fixed test polynomial, and the key switch to
+        // get back to input key:
+        self.bootstrap_keyswitch(engine, bootstrap_engine, buffers, ct_out);
+    }
+
+    // Computes homomorphically a NOT gate, and writes the result in `ct_out`.
+    pub(crate) fn discard_not(
+        &self,
+        engine: &mut CoreEngine,
+        ct_out: &mut Ciphertext,
+        ct: &Ciphertext,
+    ) {
+        // Compute the linear combination for NOT: -ct
+        engine
+            .discard_neg_lwe_ciphertext(&mut ct_out.0, &ct.0)
+            .unwrap();
+    }
+
+    // Computes homomorphically an OR gate, and writes the result in `ct_out`.
+    pub(crate) fn discard_or(
+        &self,
+        engine: &mut CoreEngine,
+        bootstrap_engine: &mut Engine,
+        buffers: &mut GateBuffers,
+        ct_out: &mut Ciphertext,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+    ) {
+        // Compute the linear combination for OR: ct_left + ct_right + (0,...,0,+Δ)
+        engine
+            .discard_add_lwe_ciphertext(&mut buffers.ct_temp_1, &ct_left.0, &ct_right.0)
+            .unwrap();
+        engine
+            .fuse_add_lwe_ciphertext_plaintext(&mut buffers.ct_temp_1, &buffers.delta)
+            .unwrap(); // +Δ
+
+        // Compute the programmable bootstrapping with fixed test polynomial, and the key switch to
+        // get back to input key:
+        self.bootstrap_keyswitch(engine, bootstrap_engine, buffers, ct_out);
+    }
+
+    // Computes homomorphically an OR gate between three ciphertexts, and writes the result in
+    // `ct_out`.
+    pub(crate) fn discard_or3(
+        &self,
+        engine: &mut CoreEngine,
+        bootstrap_engine: &mut Engine,
+        buffers: &mut GateBuffers,
+        ct_out: &mut Ciphertext,
+        ct_1: &Ciphertext,
+        ct_2: &Ciphertext,
+        ct_3: &Ciphertext,
+    ) {
+        if self.plaintext_log_scaling_factor < MIN_THREE_INPUT_LOG_SCALING_FACTOR {
+            let mut ct_temp = self.allocate_ciphertext(engine);
+            self.discard_or(engine, bootstrap_engine, buffers, &mut ct_temp, ct_1, ct_2);
+            self.discard_or(engine, bootstrap_engine, buffers, ct_out, &ct_temp, ct_3);
+            return;
+        }
+
+        // Compute the linear combination for OR3: ct_1 + ct_2 + ct_3 + (0,...,0,+2Δ)
+        self.sum_three(engine, buffers, ct_1, ct_2, ct_3);
+        engine
+            .fuse_add_lwe_ciphertext_plaintext(&mut buffers.ct_temp_1, &buffers.two_delta)
+            .unwrap(); // +2Δ
+
+        // Compute the programmable bootstrapping with fixed test polynomial, and the key switch to
+        // get back to input key:
+        self.bootstrap_keyswitch(engine, bootstrap_engine, buffers, ct_out);
+    }
+
+    // Computes homomorphically an XNOR gate, and writes the result in `ct_out`.
+    pub(crate) fn discard_xnor(
+        &self,
+        engine: &mut CoreEngine,
+        bootstrap_engine: &mut Engine,
+        buffers: &mut GateBuffers,
+        ct_out: &mut Ciphertext,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+    ) {
+        // Compute the linear combination for XNOR: m*(-ct_left - ct_right) + (0,...,0,-1/4), with
+        // m = 1/(4Δ)
+        engine
+            .discard_neg_lwe_ciphertext(&mut buffers.ct_temp_1, &ct_left.0)
+            .unwrap();
+        engine
+            .fuse_sub_lwe_ciphertext(&mut buffers.ct_temp_1, &ct_right.0)
+            .unwrap();
+        engine
+            .fuse_mul_lwe_ciphertext_cleartext(&mut buffers.ct_temp_1, &buffers.xor_scaling)
+            .unwrap();
+        engine
+            .fuse_sub_lwe_ciphertext_plaintext(&mut buffers.ct_temp_1, &buffers.one_quarter)
+            .unwrap(); // -1/4
+
+        // Compute the programmable bootstrapping with fixed test polynomial, and the key switch to
+        // get back to input key:
+        self.bootstrap_keyswitch(engine, bootstrap_engine, buffers, ct_out);
+    }
+
+    // Computes homomorphically an XOR gate, and writes the result in `ct_out`.
+    pub(crate) fn discard_xor(
+        &self,
+        engine: &mut CoreEngine,
+        bootstrap_engine: &mut Engine,
+        buffers: &mut GateBuffers,
+        ct_out: &mut Ciphertext,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+    ) {
+        // Compute the linear combination for XOR: m*(ct_left + ct_right) + (0,...,0,1/4), with
+        // m = 1/(4Δ)
+        engine
+            .discard_add_lwe_ciphertext(&mut buffers.ct_temp_1, &ct_left.0, &ct_right.0)
+            .unwrap();
+        engine
+            .fuse_mul_lwe_ciphertext_cleartext(&mut buffers.ct_temp_1, &buffers.xor_scaling)
+            .unwrap();
+        engine
+            .fuse_add_lwe_ciphertext_plaintext(&mut buffers.ct_temp_1, &buffers.one_quarter)
+            .unwrap(); // +1/4
+
+        // Compute the programmable bootstrapping with fixed test polynomial, and the key switch to
+        // get back to input key:
+        self.bootstrap_keyswitch(engine, bootstrap_engine, buffers, ct_out);
+    }
+
+    // Computes the programmable bootstrapping of the first temporary ciphertext of the buffers,
+    // followed by a key switch writing the result in `ct_out`.
+    fn bootstrap_keyswitch(
+        &self,
+        engine: &mut CoreEngine,
+        bootstrap_engine: &mut Engine,
+        buffers: &mut GateBuffers,
+        ct_out: &mut Ciphertext,
+    ) {
+        bootstrap_engine
+            .discard_bootstrap_lwe_ciphertext(
+                &mut buffers.ct_pbs_1,
+                &buffers.ct_temp_1,
+                &buffers.accumulator,
+                &self.bootstrapping_key,
+            )
+            .unwrap();
+        engine
+            .discard_keyswitch_lwe_ciphertext(
+                &mut ct_out.0,
+                &buffers.ct_pbs_1,
+                &self.key_switching_key,
+            )
+            .unwrap();
+    }
+
+    // Computes the sum of three ciphertexts in the first temporary ciphertext of the buffers.
+    fn sum_three(
+        &self,
+        engine: &mut CoreEngine,
+        buffers: &mut GateBuffers,
+        ct_1: &Ciphertext,
+        ct_2: &Ciphertext,
+        ct_3: &Ciphertext,
+    ) {
+        engine
+            .discard_add_lwe_ciphertext(&mut buffers.ct_temp_1, &ct_1.0, &ct_2.0)
+            .unwrap();
+        engine
+            .fuse_add_lwe_ciphertext(&mut buffers.ct_temp_1, &ct_3.0)
+            .unwrap();
+    }
+}
+
+#[cfg(feature = "multithread")]
+impl<Engine> ServerKey<Engine>
+where
+    Engine: BooleanServerEngine,
+    Engine::BootstrapKey: Sync,
+{
+    /// Computes homomorphically an AND gate on every pair of ciphertexts of `inputs`, in parallel.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encrypt two messages:
+    /// let ct1 = cks.encrypt(true);
+    /// let ct2 = cks.encrypt(false);
+    ///
+    /// // Compute homomorphically a batch of AND gates:
+    /// let inputs = vec![(&ct1, &ct2), (&ct2, &ct1)];
+    /// let cts_res = sks.par_and(&inputs);
+    ///
+    /// // Decrypt:
+    /// let dec: Vec<bool> = cts_res.iter().map(|ct| cks.decrypt(ct)).collect();
+    /// assert_eq!(vec![false, false], dec);
+    /// ```
+    pub fn par_and(&self, inputs: &[(&Ciphertext, &Ciphertext)]) -> Vec<Ciphertext> {
+        self.par_evaluate(
+            inputs,
+            |engine, bootstrap_engine, buffers, ct_out, (ct_left, ct_right)| {
+                self.discard_and(engine, bootstrap_engine, buffers, ct_out, ct_left, ct_right)
+            },
+        )
+    }
+
+    /// Computes homomorphically an AND3 gate on every triplet of ciphertexts of `inputs`, in
+    /// parallel.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::client_key::ClientKey;
+    /// use concrete_boolean::parameters::MULTI_INPUT_PARAMETERS;
+    /// use concrete_boolean::server_key::ServerKey;
+    ///
+    /// // Generate the client key and the server key:
+    /// let cks = ClientKey::new(&MULTI_INPUT_PARAMETERS);
+    /// let sks = ServerKey::new(&cks);
+    ///
+    /// // Encrypt two messages:
+    /// let ct1 = cks.encrypt(true);
+    /// let ct2 = cks.encrypt(false);
+    ///
+    /// // Compute homomorphically a batch of AND3 gates:
+    /// let inputs = vec![(&ct1, &ct2, &ct1), (&ct2, &ct2, &ct1)];
+    /// let cts_res = sks.par_and3(&inputs);
+    ///
+    /// // Decrypt:
+    /// let dec: Vec<bool> = cts_res.iter().map(|ct| cks.decrypt(ct)).collect();
+    /// assert_eq!(vec![false, false], dec);
+    /// ```
+    pub fn par_and3(&self, inputs: &[(&Ciphertext, &Ciphertext, &Ciphertext)]) -> Vec<Ciphertext> {
+        self.par_evaluate(
+            inputs,
+            |engine, bootstrap_engine, buffers, ct_out, (ct_1, ct_2, ct_3)| {
+                self.discard_and3(engine, bootstrap_engine, buffers, ct_out, ct_1, ct_2, ct_3)
+            },
+        )
+    }
+
+    /// Computes homomorphically a MAJORITY gate on every triplet of ciphertexts of `inputs`, in
+    /// parallel.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encrypt two messages:
+    /// let ct1 = cks.encrypt(true);
+    /// let ct2 = cks.encrypt(false);
+    ///
+    /// // Compute homomorphically a batch of MAJORITY gates:
+    /// let inputs = vec![(&ct1, &ct2, &ct1), (&ct2, &ct2, &ct1)];
+    /// let cts_res = sks.par_majority(&inputs);
+    ///
+    /// // Decrypt:
+    /// let dec: Vec<bool> = cts_res.iter().map(|ct| cks.decrypt(ct)).collect();
+    /// assert_eq!(vec![true, false], dec);
+    /// ```
+    pub fn par_majority(
+        &self,
+        inputs: &[(&Ciphertext, &Ciphertext, &Ciphertext)],
+    ) -> Vec<Ciphertext> {
+        self.par_evaluate(
+            inputs,
+            |engine, bootstrap_engine, buffers, ct_out, (ct_1, ct_2, ct_3)| {
+                self.discard_majority(engine, bootstrap_engine, buffers, ct_out, ct_1, ct_2, ct_3)
+            },
+        )
+    }
+
+    /// Computes homomorphically a MUX gate on every triplet of ciphertexts of `inputs`, in
+    /// parallel. Each triplet contains the condition, the `then` and the `else` ciphertexts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encrypt two messages:
+    /// let ct1 = cks.encrypt(true);
+    /// let ct2 = cks.encrypt(false);
+    ///
+    /// // Compute homomorphically a batch of MUX gates:
+    /// let inputs = vec![(&ct1, &ct2, &ct1), (&ct2, &ct2, &ct1)];
+    /// let cts_res = sks.par_mux(&inputs);
+    ///
+    /// // Decrypt:
+    /// let dec: Vec<bool> = cts_res.iter().map(|ct| cks.decrypt(ct)).collect();
+    /// assert_eq!(vec![false, false], dec);
+    /// ```
+    pub fn par_mux(&self, inputs: &[(&Ciphertext, &Ciphertext, &Ciphertext)]) -> Vec<Ciphertext> {
+        self.par_evaluate(
+            inputs,
+            |engine, bootstrap_engine, buffers, ct_out, (ct_condition, ct_then, ct_else)| {
+                self.discard_mux(
+                    engine,
+                    bootstrap_engine,
+                    buffers,
+                    ct_out,
+                    ct_condition,
+                    ct_then,
+                    ct_else,
+                )
+            },
+        )
+    }
+
+    /// Computes homomorphically a NAND gate on every pair of ciphertexts of `inputs`, in parallel.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encrypt two messages:
+    /// let ct1 = cks.encrypt(true);
+    /// let ct2 = cks.encrypt(false);
+    ///
+    /// // Compute homomorphically a batch of NAND gates:
+    /// let inputs = vec![(&ct1, &ct2), (&ct2, &ct1)];
+    /// let cts_res = sks.par_nand(&inputs);
+    ///
+    /// // Decrypt:
+    /// let dec: Vec<bool> = cts_res.iter().map(|ct| cks.decrypt(ct)).collect();
+    /// assert_eq!(vec![true, true], dec);
+    /// ```
+    pub fn par_nand(&self, inputs: &[(&Ciphertext, &Ciphertext)]) -> Vec<Ciphertext> {
+        self.par_evaluate(
+            inputs,
+            |engine, bootstrap_engine, buffers, ct_out, (ct_left, ct_right)| {
+                self.discard_nand(engine, bootstrap_engine, buffers, ct_out, ct_left, ct_right)
+            },
+        )
+    }
+
+    /// Computes homomorphically a NOR gate on every pair of ciphertexts of `inputs`, in parallel.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encrypt two messages:
+    /// let ct1 = cks.encrypt(true);
+    /// let ct2 = cks.encrypt(false);
+    ///
+    /// // Compute homomorphically a batch of NOR gates:
+    /// let inputs = vec![(&ct1, &ct2), (&ct2, &ct1)];
+    /// let cts_res = sks.par_nor(&inputs);
+    ///
+    /// // Decrypt:
+    /// let dec: Vec<bool> = cts_res.iter().map(|ct| cks.decrypt(ct)).collect();
+    /// assert_eq!(vec![false, false], dec);
+    /// ```
+    pub fn par_nor(&self, inputs: &[(&Ciphertext, &Ciphertext)]) -> Vec<Ciphertext> {
+        self.par_evaluate(
+            inputs,
+            |engine, bootstrap_engine, buffers, ct_out, (ct_left, ct_right)| {
+                self.discard_nor(engine, bootstrap_engine, buffers, ct_out, ct_left, ct_right)
+            },
+        )
+    }
+
+    /// Computes homomorphically a NOT gate on every ciphertext of `inputs`, in parallel.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encrypt two messages:
+    /// let ct1 = cks.encrypt(true);
+    /// let ct2 = cks.encrypt(false);
+    ///
+    /// // Compute homomorphically a batch of NOT gates:
+    /// let inputs = vec![&ct1, &ct2];
+    /// let cts_res = sks.par_not(&inputs);
+    ///
+    /// // Decrypt:
+    /// let dec: Vec<bool> = cts_res.iter().map(|ct| cks.decrypt(ct)).collect();
+    /// assert_eq!(vec![false, true], dec);
+    /// ```
+    pub fn par_not(&self, inputs: &[&Ciphertext]) -> Vec<Ciphertext> {
+        self.par_evaluate(inputs, |engine, _, _, ct_out, ct| {
+            self.discard_not(engine, ct_out, ct)
+        })
+    }
+
+    /// Computes homomorphically an OR gate on every pair of ciphertexts of `inputs`, in parallel.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encrypt two messages:
+    /// let ct1 = cks.encrypt(true);
+    /// let ct2 = cks.encrypt(false);
+    ///
+    /// // Compute homomorphically a batch of OR gates:
+    /// let inputs = vec![(&ct1, &ct2), (&ct2, &ct1)];
+    /// let cts_res = sks.par_or(&inputs);
+    ///
+    /// // Decrypt:
+    /// let dec: Vec<bool> = cts_res.iter().map(|ct| cks.decrypt(ct)).collect();
+    /// assert_eq!(vec![true, true], dec);
+    /// ```
+    pub fn par_or(&self, inputs: &[(&Ciphertext, &Ciphertext)]) -> Vec<Ciphertext> {
+        self.par_evaluate(
+            inputs,
+            |engine, bootstrap_engine, buffers, ct_out, (ct_left, ct_right)| {
+                self.discard_or(engine, bootstrap_engine, buffers, ct_out, ct_left, ct_right)
+            },
+        )
+    }
+
+    /// Computes homomorphically an OR3 gate on every triplet of ciphertexts of `inputs`, in
+    /// parallel.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::client_key::ClientKey;
+    /// use concrete_boolean::parameters::MULTI_INPUT_PARAMETERS;
+    /// use concrete_boolean::server_key::ServerKey;
+    ///
+    /// // Generate the client key and the server key:
+    /// let cks = ClientKey::new(&MULTI_INPUT_PARAMETERS);
+    /// let sks = ServerKey::new(&cks);
+    ///
+    /// // Encrypt two messages:
+    /// let ct1 = cks.encrypt(true);
+    /// let ct2 = cks.encrypt(false);
+    ///
+    /// // Compute homomorphically a batch of OR3 gates:
+    /// let inputs = vec![(&ct1, &ct2, &ct1), (&ct2, &ct2, &ct1)];
+    /// let cts_res = sks.par_or3(&inputs);
+    ///
+    /// // Decrypt:
+    /// let dec: Vec<bool> = cts_res.iter().map(|ct| cks.decrypt(ct)).collect();
+    /// assert_eq!(vec![true, true], dec);
+    /// ```
+    pub fn par_or3(&self, inputs: &[(&Ciphertext, &Ciphertext, &Ciphertext)]) -> Vec<Ciphertext> {
+        self.par_evaluate(
+            inputs,
+            |engine, bootstrap_engine, buffers, ct_out, (ct_1, ct_2, ct_3)| {
+                self.discard_or3(engine, bootstrap_engine, buffers, ct_out, ct_1, ct_2, ct_3)
+            },
+        )
+    }
+
+    /// Computes homomorphically an XNOR gate on every pair of ciphertexts of `inputs`, in parallel.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encrypt two messages:
+    /// let ct1 = cks.encrypt(true);
+    /// let ct2 = cks.encrypt(false);
+    ///
+    /// // Compute homomorphically a batch of XNOR gates:
+    /// let inputs = vec![(&ct1, &ct2), (&ct2, &ct1)];
+    /// let cts_res = sks.par_xnor(&inputs);
+    ///
+    /// // Decrypt:
+    /// let dec: Vec<bool> = cts_res.iter().map(|ct| cks.decrypt(ct)).collect();
+    /// assert_eq!(vec![false, false], dec);
+    /// ```
+    pub fn par_xnor(&self, inputs: &[(&Ciphertext, &Ciphertext)]) -> Vec<Ciphertext> {
+        self.par_evaluate(
+            inputs,
+            |engine, bootstrap_engine, buffers, ct_out, (ct_left, ct_right)| {
+                self.discard_xnor(engine, bootstrap_engine, buffers, ct_out, ct_left, ct_right)
+            },
+        )
+    }
+
+    /// Computes homomorphically an XOR gate on every pair of ciphertexts of `inputs`, in parallel.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::gen_keys;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encrypt two messages:
+    /// let ct1 = cks.encrypt(true);
+    /// let ct2 = cks.encrypt(false);
+    ///
+    /// // Compute homomorphically a batch of XOR gates:
+    /// let inputs = vec![(&ct1, &ct2), (&ct2, &ct1)];
+    /// let cts_res = sks.par_xor(&inputs);
+    ///
+    /// // Decrypt:
+    /// let dec: Vec<bool> = cts_res.iter().map(|ct| cks.decrypt(ct)).collect();
+    /// assert_eq!(vec![true, true], dec);
+    /// ```
+    pub fn par_xor(&self, inputs: &[(&Ciphertext, &Ciphertext)]) -> Vec<Ciphertext> {
+        self.par_evaluate(
+            inputs,
+            |engine, bootstrap_engine, buffers, ct_out, (ct_left, ct_right)| {
+                self.discard_xor(engine, bootstrap_engine, buffers, ct_out, ct_left, ct_right)
+            },
+        )
+    }
+
+    // Evaluates a gate on every element of `inputs`, in parallel.
+    //
+    // Every worker thread of the rayon pool uses the engine owned by the thread, and thus its
+    // Fourier buffers, as well as its own gate buffers, which are created on first use.
+    pub(crate) fn par_evaluate<Input, F>(&self, inputs: &[Input], gate: F) -> Vec<Ciphertext>
+    where
+        Input: Sync,
+        F: Fn(&mut CoreEngine, &mut Engine, &mut GateBuffers, &mut Ciphertext, &Input) + Sync,
+    {
+        let thread_buffers: Vec<Mutex<Option<GateBuffers>>> = (0..rayon::current_num_threads())
+            .map(|_| Mutex::new(None))
+            .collect();
+        inputs
+            .par_iter()
+            .map(|input| {
+                let thread_index = rayon::current_thread_index()
+                    .expect("The gates must be evaluated from a thread of the rayon pool.");
+                let mut buffers = thread_buffers[thread_index]
+                    .lock()
+                    .expect("The gate buffers of a thread were poisoned.");
+                with_gate_engines_mut(|engine, bootstrap_engine| {
+                    let buffers = buffers.get_or_insert_with(|| self.create_buffers(engine));
+                    let mut ct_out = self.allocate_ciphertext(engine);
+                    gate(engine, bootstrap_engine, buffers, &mut ct_out, input);
+                    ct_out
+                })
+            })
+            .collect()
+    }
+}
+
+/// A truth table prepared by [`ServerKey::prepare_lut`], made of the weights of its inputs and of
+/// the accumulator holding its test polynomial.
+#[derive(Debug, Clone)]
+pub struct PreparedLookupTable {
+    weights: Vec<Cleartext32>,
+    accumulator: GlweCiphertext32,
+}
+
+/// The scratch objects used to evaluate the gates.
+///
+/// The accumulator and the constants are computed once, and the temporary ciphertexts are
+/// overwritten by every gate. The plaintext of true is denoted by Δ.
+pub(crate) struct GateBuffers {
+    accumulator: GlweCiphertext32,
+    delta: Plaintext32,
+    two_delta: Plaintext32,
+    one_quarter: Plaintext32,
+    xor_scaling: Cleartext32,
+    ct_temp_1: LweCiphertext32,
+    ct_temp_2: LweCiphertext32,
+    ct_pbs_1: LweCiphertext32,
+    ct_pbs_2: LweCiphertext32,
+}
+
+// Allocates a trivial encryption of zero, to be used as the output of an operation.
+fn allocate_ciphertext(engine: &mut CoreEngine, lwe_size: LweSize) -> LweCiphertext32 {
+    let zero: Plaintext32 = engine.create_plaintext(&0_u32).unwrap();
+    engine
+        .trivially_encrypt_lwe_ciphertext(lwe_size, &zero)
+        .unwrap()
 }
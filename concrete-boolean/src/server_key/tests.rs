@@ -1,18 +1,11 @@
 use crate::ciphertext::Ciphertext;
 use crate::client_key::ClientKey;
-use crate::parameters::DEFAULT_PARAMETERS;
-use crate::server_key::ServerKey;
-use crate::{
-    random_boolean, random_integer, PLAINTEXT_FALSE, PLAINTEXT_LOG_SCALING_FACTOR, PLAINTEXT_TRUE,
-};
-use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweSize};
-use concrete_core::backends::core::private::crypto::encoding::Plaintext;
-use concrete_core::backends::core::private::crypto::glwe::GlweCiphertext;
-use concrete_core::backends::core::private::crypto::lwe::LweCiphertext;
-use concrete_core::backends::core::private::crypto::secret::LweSecretKey;
+use crate::parameters::{DEFAULT_PARAMETERS, LOOKUP_TABLE_PARAMETERS, MULTI_INPUT_PARAMETERS};
+use crate::server_key::{CompressedServerKey, ServerKey};
+use crate::{encode_boolean, random_boolean, random_integer, PLAINTEXT_LOG_SCALING_FACTOR};
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
 use concrete_core::backends::core::private::math::decomposition::SignedDecomposer;
-use concrete_core::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
-use crate::server_key::FourierBuffers;
+use concrete_core::prelude::*;
 
 /// Number of assert in randomized tests
 const NB_TEST: usize = 128;
@@ -58,9 +51,12 @@ fn test_encrypt_pbs_decrypt() {
     // generate the server key set
     let sks = ServerKey::new(&cks);
 
+    let mut engine = CoreEngine::new().unwrap();
+
     // convert the GLWE secret key into an LWE secret key
-    let big_lwe_secret_key =
-        LweSecretKey::binary_from_container(cks.glwe_secret_key.as_tensor().clone());
+    let big_lwe_secret_key: LweSecretKey32 = engine
+        .transmute_glwe_secret_key_to_lwe_secret_key(cks.glwe_secret_key.clone())
+        .unwrap();
 
     for _ in 0..NB_TEST {
         // encryption of true
@@ -69,49 +65,57 @@ fn test_encrypt_pbs_decrypt() {
         // encryption of false
         let ct_false = cks.encrypt(false);
 
-        // Allocation of the accumulator
-        let mut accumulator = GlweCiphertext::allocate(
-            0_u32,
-            sks.bootstrapping_key.polynomial_size(),
-            sks.bootstrapping_key.glwe_size(),
-        );
-
-        // Fill the body of accumulator with the Test Polynomial
-        accumulator
-            .get_mut_body()
-            .as_mut_tensor()
-            .fill_with_element(PLAINTEXT_TRUE); // 1/8
+        // Allocation of the accumulator, whose body is filled with the Test Polynomial
+        let test_polynomial: PlaintextVector32 = engine
+            .create_plaintext_vector(&vec![
+                encode_boolean(true, PLAINTEXT_LOG_SCALING_FACTOR);
+                sks.bootstrapping_key.polynomial_size().0
+            ])
+            .unwrap();
+        let accumulator: GlweCiphertext32 = engine
+            .trivially_encrypt_glwe_ciphertext(
+                sks.bootstrapping_key.glwe_dimension().to_glwe_size(),
+                &test_polynomial,
+            )
+            .unwrap();
 
         // Allocation for the output of the PBS
-        let mut ct_pbs_true = LweCiphertext::allocate(
-            0_u32,
-            LweSize(
-                sks.bootstrapping_key.polynomial_size().0
-                    * sks.bootstrapping_key.glwe_size().to_glwe_dimension().0
-                    + 1,
-            ),
-        );
-        let mut ct_pbs_false = LweCiphertext::allocate(
-            0_u32,
-            sks.bootstrapping_key.output_lwe_dimension().to_lwe_size(),
-        );
+        let zero: Plaintext32 = engine.create_plaintext(&0_u32).unwrap();
+        let mut ct_pbs_true: LweCiphertext32 = engine
+            .trivially_encrypt_lwe_ciphertext(
+                sks.bootstrapping_key.output_lwe_dimension().to_lwe_size(),
+                &zero,
+            )
+            .unwrap();
+        let mut ct_pbs_false = ct_pbs_true.clone();
 
         // Compute the two PBS
-        let mut buffers = FourierBuffers::new(
-            sks.bootstrapping_key.polynomial_size(),
-            sks.bootstrapping_key.glwe_size());
-        sks.bootstrapping_key
-            .bootstrap(&mut ct_pbs_true, &ct_true.0, &accumulator, &mut buffers);
-        sks.bootstrapping_key
-            .bootstrap(&mut ct_pbs_false, &ct_false.0, &accumulator, &mut buffers);
-
-        // allocation of the plaintexts
-        let mut decrypted_true = Plaintext(0_u32);
-        let mut decrypted_false = Plaintext(0_u32);
+        engine
+            .discard_bootstrap_lwe_ciphertext(
+                &mut ct_pbs_true,
+                &ct_true.0,
+                &accumulator,
+                &sks.bootstrapping_key,
+            )
+            .unwrap();
+        engine
+            .discard_bootstrap_lwe_ciphertext(
+                &mut ct_pbs_false,
+                &ct_false.0,
+                &accumulator,
+                &sks.bootstrapping_key,
+            )
+            .unwrap();
 
         // decryption
-        big_lwe_secret_key.decrypt_lwe(&mut decrypted_true, &ct_pbs_true);
-        big_lwe_secret_key.decrypt_lwe(&mut decrypted_false, &ct_pbs_false);
+        let decrypted_true: Plaintext32 = engine
+            .decrypt_lwe_ciphertext(&big_lwe_secret_key, &ct_pbs_true)
+            .unwrap();
+        let decrypted_false: Plaintext32 = engine
+            .decrypt_lwe_ciphertext(&big_lwe_secret_key, &ct_pbs_false)
+            .unwrap();
+        let decrypted_true: u32 = engine.retrieve_plaintext(&decrypted_true).unwrap();
+        let decrypted_false: u32 = engine.retrieve_plaintext(&decrypted_false).unwrap();
 
         // decomposer
         let decomposer: SignedDecomposer<u32> = SignedDecomposer::<u32>::new(
@@ -120,20 +124,17 @@ fn test_encrypt_pbs_decrypt() {
         );
 
         // rounding
-        let rounded_true = decomposer.closest_representable(decrypted_true.0);
-        let rounded_false = decomposer.closest_representable(decrypted_false.0);
+        let rounded_true = decomposer.closest_representable(decrypted_true);
+        let rounded_false = decomposer.closest_representable(decrypted_false);
 
         // asserts
-        if rounded_true != PLAINTEXT_TRUE {
-            panic!(
-                "fail with the true: {} -> {}",
-                decrypted_true.0, rounded_true
-            )
+        if rounded_true != encode_boolean(true, PLAINTEXT_LOG_SCALING_FACTOR) {
+            panic!("fail with the true: {} -> {}", decrypted_true, rounded_true)
         }
-        if rounded_false != PLAINTEXT_FALSE {
+        if rounded_false != encode_boolean(false, PLAINTEXT_LOG_SCALING_FACTOR) {
             panic!(
                 "fail with the false: {} -> {}",
-                decrypted_false.0, rounded_false
+                decrypted_false, rounded_false
             )
         }
     }
@@ -445,6 +446,189 @@ fn test_xor_gate() {
     }
 }
 
+#[test]
+fn test_three_input_gates() {
+    // the default parameters compute the AND3 and OR3 gates with two gates
+    for parameters in [DEFAULT_PARAMETERS, MULTI_INPUT_PARAMETERS].iter() {
+        // generate the client key set
+        let cks = ClientKey::new(parameters);
+
+        // generate the server key set
+        let sks = ServerKey::new(&cks);
+
+        for _ in 0..NB_TEST {
+            // generation of three random booleans
+            let b1 = random_boolean();
+            let b2 = random_boolean();
+            let b3 = random_boolean();
+
+            // encryption of the booleans
+            let ct1 = cks.encrypt(b1);
+            let ct2 = cks.encrypt(b2);
+            let ct3 = cks.encrypt(b3);
+
+            // three-input gates, decryption and asserts
+            assert_eq!(b1 && b2 && b3, cks.decrypt(&sks.and3(&ct1, &ct2, &ct3)));
+            assert_eq!(
+                (b1 && b2) || (b1 && b3) || (b2 && b3),
+                cks.decrypt(&sks.majority(&ct1, &ct2, &ct3))
+            );
+            assert_eq!(b1 || b2 || b3, cks.decrypt(&sks.or3(&ct1, &ct2, &ct3)));
+        }
+    }
+}
+
+#[test]
+fn test_eval_lut() {
+    // every function of two inputs with the default parameters, and random functions of three
+    // inputs with the lookup table parameters
+    for (parameters, num_inputs) in [(DEFAULT_PARAMETERS, 2), (LOOKUP_TABLE_PARAMETERS, 3)].iter() {
+        // generate the client key set
+        let cks = ClientKey::new(parameters);
+
+        // generate the server key set
+        let sks = ServerKey::new(&cks);
+
+        for i in 0..NB_TEST {
+            // truth table of the function
+            let function = if *num_inputs == 2 {
+                i as u32 % 16
+            } else {
+                random_integer()
+            };
+            let truth_table: Vec<bool> = (0..1 << num_inputs)
+                .map(|index| (function >> index) & 1 == 1)
+                .collect();
+
+            // generation and encryption of random booleans
+            let messages: Vec<bool> = (0..*num_inputs).map(|_| random_boolean()).collect();
+            let cts: Vec<Ciphertext> = messages.iter().map(|b| cks.encrypt(*b)).collect();
+            let inputs: Vec<&Ciphertext> = cts.iter().collect();
+
+            // lookup table evaluation
+            let ct_res = sks.eval_lut(&inputs, &truth_table);
+
+            // decryption and assert
+            let index: usize = messages
+                .iter()
+                .enumerate()
+                .map(|(input, b)| (*b as usize) << input)
+                .sum();
+            assert_eq!(truth_table[index], cks.decrypt(&ct_res));
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_eval_lut_unsupported() {
+    // generate the client key set
+    let cks = ClientKey::new(&DEFAULT_PARAMETERS);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    // the AND of three inputs needs a smaller plaintext scaling factor
+    let ct = cks.encrypt(true);
+    let and3 = [false, false, false, false, false, false, false, true];
+    sks.eval_lut(&[&ct, &ct, &ct], &and3);
+}
+
+#[test]
+#[should_panic(expected = "supports at most")]
+fn test_eval_lut_too_many_inputs() {
+    // generate the client key set
+    let cks = ClientKey::new(&DEFAULT_PARAMETERS);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    // the number of entries of the truth table cannot be computed for 64 inputs
+    let ct = cks.encrypt(true);
+    sks.eval_lut(&[&ct; 64], &[]);
+}
+
+#[test]
+fn test_eval_prepared_lut() {
+    // generate the client key set
+    let cks = ClientKey::new(&MULTI_INPUT_PARAMETERS);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    // the majority of three inputs, and the constant function without inputs
+    let majority = sks.prepare_lut(&[false, false, false, true, false, true, true, true]);
+    let constant = sks.prepare_lut(&[true]);
+
+    for _ in 0..NB_TEST {
+        // generation and encryption of random booleans
+        let b1 = random_boolean();
+        let b2 = random_boolean();
+        let b3 = random_boolean();
+        let ct1 = cks.encrypt(b1);
+        let ct2 = cks.encrypt(b2);
+        let ct3 = cks.encrypt(b3);
+
+        // lookup table evaluation, decryption and assert
+        let ct_res = sks.eval_prepared_lut(&[&ct1, &ct2, &ct3], &majority);
+        assert_eq!((b1 & b2) | (b1 & b3) | (b2 & b3), cks.decrypt(&ct_res));
+        assert!(cks.decrypt(&sks.eval_prepared_lut(&[], &constant)));
+    }
+}
+
+#[test]
+fn test_compressed_encryption() {
+    // generate the client key set
+    let cks = ClientKey::new(&DEFAULT_PARAMETERS);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    for _ in 0..NB_TEST {
+        // generation of two random booleans
+        let b1 = random_boolean();
+        let b2 = random_boolean();
+
+        // compressed encryption of the booleans
+        let compressed1 = cks.encrypt_compressed(b1);
+        let compressed2 = cks.encrypt_compressed(b2);
+
+        // decompression, which is deterministic
+        let ct1 = compressed1.decompress();
+        let ct2 = compressed2.decompress();
+        assert_eq!(ct1.0, compressed1.decompress().0);
+
+        // decryption and gate evaluation on the decompressed ciphertexts
+        assert_eq!(b1, cks.decrypt(&ct1));
+        assert_eq!(b1 ^ b2, cks.decrypt(&sks.xor(&ct1, &ct2)));
+    }
+}
+
+#[test]
+fn test_compressed_server_key() {
+    // generate the client key set
+    let cks = ClientKey::new(&DEFAULT_PARAMETERS);
+
+    // generate the compressed server key, and decompress it
+    let compressed_sks = CompressedServerKey::new(&cks);
+    let sks = compressed_sks.decompress();
+    assert_eq!(sks, compressed_sks.decompress());
+
+    for _ in 0..NB_TEST {
+        // generation of two random booleans
+        let b1 = random_boolean();
+        let b2 = random_boolean();
+
+        // encryption of the booleans
+        let ct1 = cks.encrypt(b1);
+        let ct2 = cks.encrypt(b2);
+
+        // the gates use both the key switching key and the bootstrapping key
+        assert_eq!(b1 && b2, cks.decrypt(&sks.and(&ct1, &ct2)));
+        assert_eq!(b1 ^ b2, cks.decrypt(&sks.xor(&ct1, &ct2)));
+    }
+}
+
 /// generate a random index for the table in the long run tests
 fn random_index() -> usize {
     (random_integer() % (NB_CT as u32)) as usize
@@ -537,3 +721,115 @@ fn test_deep_circuit() {
         assert_eq!(*boolean, dec);
     }
 }
+
+#[test]
+fn test_trivial_encrypt() {
+    // generate the client key set
+    let cks = ClientKey::new(&DEFAULT_PARAMETERS);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    for _ in 0..NB_TEST {
+        // generation of two random booleans
+        let b1 = random_boolean();
+        let b2 = random_boolean();
+
+        // encryption of the first boolean, trivial encryption of the second one
+        let ct1 = cks.encrypt(b1);
+        let ct2 = sks.trivial_encrypt(b2);
+
+        // decryption and asserts
+        assert_eq!(b2, cks.decrypt(&ct2));
+        assert_eq!(b1 && b2, cks.decrypt(&sks.and(&ct1, &ct2)));
+        assert_eq!(b1 ^ b2, cks.decrypt(&sks.xor(&ct2, &ct1)));
+    }
+}
+
+#[test]
+fn test_clear_operand_gates() {
+    // generate the client key set
+    let cks = ClientKey::new(&DEFAULT_PARAMETERS);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    for _ in 0..NB_TEST {
+        // generation of two random booleans
+        let b1 = random_boolean();
+        let b2 = random_boolean();
+
+        // encryption of the first boolean
+        let ct1 = cks.encrypt(b1);
+
+        // gates evaluation, decryption and asserts
+        assert_eq!(b1 && b2, cks.decrypt(&sks.and_clear(&ct1, b2)));
+        assert_eq!(!(b1 && b2), cks.decrypt(&sks.nand_clear(&ct1, b2)));
+        assert_eq!(!(b1 || b2), cks.decrypt(&sks.nor_clear(&ct1, b2)));
+        assert_eq!(b1 || b2, cks.decrypt(&sks.or_clear(&ct1, b2)));
+        assert_eq!(b1 == b2, cks.decrypt(&sks.xnor_clear(&ct1, b2)));
+        assert_eq!(b1 ^ b2, cks.decrypt(&sks.xor_clear(&ct1, b2)));
+    }
+}
+
+#[cfg(feature = "multithread")]
+#[test]
+fn test_par_gates() {
+    // generate the client key set
+    let cks = ClientKey::new(&DEFAULT_PARAMETERS);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    // generation of three arrays of random booleans
+    let b1: Vec<bool> = (0..NB_TEST).map(|_| random_boolean()).collect();
+    let b2: Vec<bool> = (0..NB_TEST).map(|_| random_boolean()).collect();
+    let b3: Vec<bool> = (0..NB_TEST).map(|_| random_boolean()).collect();
+
+    // encryption of the booleans
+    let ct1: Vec<Ciphertext> = b1.iter().map(|b| cks.encrypt(*b)).collect();
+    let ct2: Vec<Ciphertext> = b2.iter().map(|b| cks.encrypt(*b)).collect();
+    let ct3: Vec<Ciphertext> = b3.iter().map(|b| cks.encrypt(*b)).collect();
+
+    // batches of inputs
+    let singles: Vec<&Ciphertext> = ct1.iter().collect();
+    let pairs: Vec<(&Ciphertext, &Ciphertext)> = ct1.iter().zip(ct2.iter()).collect();
+    let triplets: Vec<(&Ciphertext, &Ciphertext, &Ciphertext)> = ct1
+        .iter()
+        .zip(ct2.iter())
+        .zip(ct3.iter())
+        .map(|((c1, c2), c3)| (c1, c2, c3))
+        .collect();
+
+    // batched gates evaluation
+    let and = sks.par_and(&pairs);
+    let and3 = sks.par_and3(&triplets);
+    let majority = sks.par_majority(&triplets);
+    let mux = sks.par_mux(&triplets);
+    let nand = sks.par_nand(&pairs);
+    let nor = sks.par_nor(&pairs);
+    let not = sks.par_not(&singles);
+    let or = sks.par_or(&pairs);
+    let or3 = sks.par_or3(&triplets);
+    let xnor = sks.par_xnor(&pairs);
+    let xor = sks.par_xor(&pairs);
+
+    // decryption and asserts
+    for i in 0..NB_TEST {
+        let (b1, b2, b3) = (b1[i], b2[i], b3[i]);
+        assert_eq!(b1 && b2, cks.decrypt(&and[i]));
+        assert_eq!(b1 && b2 && b3, cks.decrypt(&and3[i]));
+        assert_eq!(
+            (b1 && b2) || (b1 && b3) || (b2 && b3),
+            cks.decrypt(&majority[i])
+        );
+        assert_eq!(if b1 { b2 } else { b3 }, cks.decrypt(&mux[i]));
+        assert_eq!(!(b1 && b2), cks.decrypt(&nand[i]));
+        assert_eq!(!(b1 || b2), cks.decrypt(&nor[i]));
+        assert_eq!(!b1, cks.decrypt(&not[i]));
+        assert_eq!(b1 || b2, cks.decrypt(&or[i]));
+        assert_eq!(b1 || b2 || b3, cks.decrypt(&or3[i]));
+        assert_eq!(b1 == b2, cks.decrypt(&xnor[i]));
+        assert_eq!(b1 ^ b2, cks.decrypt(&xor[i]));
+    }
+}
@@ -33,13 +33,15 @@ pub struct LweCiphertextCount(pub usize);
 pub struct LweCiphertextIndex(pub usize);
 
 /// The range of indices of multiple contiguous ciphertexts in an lwe ciphertext list.
+///
+/// Both bounds are inclusive.
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 pub struct LweCiphertextRange(pub usize, pub usize);
 
 impl LweCiphertextRange {
     pub fn is_ordered(&self) -> bool {
-        self.1 <= self.0
+        self.0 <= self.1
     }
 }
 
@@ -147,3 +149,11 @@ pub struct DecompositionBaseLog(pub usize);
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 pub struct DecompositionLevelCount(pub usize);
+
+/// The logarithm of the scaling factor applied to the messages of a ciphertext.
+///
+/// When a message $m$ is encoded as $\Delta \cdot m$ with $\Delta = 2^D$, this type represents the
+/// $D$ value.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+pub struct DeltaLog(pub usize);
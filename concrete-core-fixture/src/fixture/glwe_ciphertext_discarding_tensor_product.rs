@@ -0,0 +1,394 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweCiphertext, PrototypesGlweRelinearizationKey, PrototypesGlweSecretKey,
+    PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesGlweCiphertext, SynthesizesGlweRelinearizationKey,
+};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::key_kinds::{BinaryKeyKind, GaussianKeyKind, TernaryKeyKind};
+use concrete_commons::numeric::{Numeric, UnsignedInteger};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, DeltaLog, GlweDimension, PolynomialSize,
+};
+use concrete_core::prelude::markers::{
+    BinaryKeyDistribution, GaussianKeyDistribution, KeyDistributionMarker, TernaryKeyDistribution,
+};
+use concrete_core::prelude::{
+    GlweCiphertextDiscardingTensorProductEngine, GlweCiphertextEntity,
+    GlweRelinearizationKeyEntity,
+};
+use std::any::TypeId;
+
+/// A fixture for the types implementing the `GlweCiphertextDiscardingTensorProductEngine` trait.
+pub struct GlweCiphertextDiscardingTensorProductFixture;
+
+#[derive(Debug)]
+pub struct GlweCiphertextDiscardingTensorProductParameters {
+    pub glwe_noise: Variance,
+    pub relinearization_noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub decomposition_base_log: DecompositionBaseLog,
+    pub decomposition_level_count: DecompositionLevelCount,
+    pub message_bits: usize,
+}
+
+impl<Precision, Engine, InputCiphertext1, InputCiphertext2, RelinearizationKey, OutputCiphertext>
+    Fixture<
+        Precision,
+        Engine,
+        (
+            InputCiphertext1,
+            InputCiphertext2,
+            RelinearizationKey,
+            OutputCiphertext,
+        ),
+    > for GlweCiphertextDiscardingTensorProductFixture
+where
+    Precision: IntegerPrecision,
+    Engine: GlweCiphertextDiscardingTensorProductEngine<
+        InputCiphertext1,
+        InputCiphertext2,
+        RelinearizationKey,
+        OutputCiphertext,
+    >,
+    InputCiphertext1: GlweCiphertextEntity,
+    InputCiphertext2: GlweCiphertextEntity<KeyDistribution = InputCiphertext1::KeyDistribution>,
+    RelinearizationKey:
+        GlweRelinearizationKeyEntity<KeyDistribution = InputCiphertext1::KeyDistribution>,
+    OutputCiphertext: GlweCiphertextEntity<KeyDistribution = InputCiphertext1::KeyDistribution>,
+    Maker: SynthesizesGlweCiphertext<Precision, InputCiphertext1>
+        + SynthesizesGlweCiphertext<Precision, InputCiphertext2>
+        + SynthesizesGlweCiphertext<Precision, OutputCiphertext>
+        + SynthesizesGlweRelinearizationKey<Precision, RelinearizationKey>,
+{
+    type Parameters = GlweCiphertextDiscardingTensorProductParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesGlweSecretKey<Precision, InputCiphertext1::KeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesGlweRelinearizationKey<Precision, InputCiphertext1::KeyDistribution>>::GlweRelinearizationKeyProto,
+    );
+    type SamplePrototypes = (
+        Vec<Precision::Raw>,
+        Vec<Precision::Raw>,
+        <Maker as PrototypesGlweCiphertext<Precision, InputCiphertext1::KeyDistribution>>::GlweCiphertextProto,
+        <Maker as PrototypesGlweCiphertext<Precision, InputCiphertext1::KeyDistribution>>::GlweCiphertextProto,
+        <Maker as PrototypesGlweCiphertext<Precision, InputCiphertext1::KeyDistribution>>::GlweCiphertextProto,
+    );
+    type PreExecutionContext = (
+        InputCiphertext1,
+        InputCiphertext2,
+        RelinearizationKey,
+        OutputCiphertext,
+    );
+    type PostExecutionContext = (
+        InputCiphertext1,
+        InputCiphertext2,
+        RelinearizationKey,
+        OutputCiphertext,
+    );
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GlweCiphertextDiscardingTensorProductParameters {
+                    glwe_noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    relinearization_noise: Variance(
+                        LogStandardDev::from_log_standard_dev(-25.).get_variance(),
+                    ),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(256),
+                    decomposition_base_log: DecompositionBaseLog(6),
+                    decomposition_level_count: DecompositionLevelCount(3),
+                    message_bits: 11,
+                },
+                GlweCiphertextDiscardingTensorProductParameters {
+                    glwe_noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    relinearization_noise: Variance(
+                        LogStandardDev::from_log_standard_dev(-25.).get_variance(),
+                    ),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(512),
+                    decomposition_base_log: DecompositionBaseLog(6),
+                    decomposition_level_count: DecompositionLevelCount(3),
+                    message_bits: 12,
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        let proto_relinearization_key = maker.new_glwe_relinearization_key(
+            &proto_secret_key,
+            parameters.decomposition_level_count,
+            parameters.decomposition_base_log,
+            parameters.relinearization_noise,
+        );
+        (proto_secret_key, proto_relinearization_key)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key, _) = repetition_proto;
+        let delta_log = Precision::Raw::BITS - parameters.message_bits;
+        let raw_messages_1 = Precision::Raw::pick_vec(
+            &[Precision::Raw::zero(), Precision::Raw::one()],
+            parameters.polynomial_size.0,
+        );
+        let raw_messages_2 = Precision::Raw::pick_vec(
+            &[Precision::Raw::zero(), Precision::Raw::one()],
+            parameters.polynomial_size.0,
+        );
+        let proto_plaintext_vector_1 = maker.transform_raw_vec_to_plaintext_vector(
+            &encode_messages(raw_messages_1.as_slice(), delta_log),
+        );
+        let proto_plaintext_vector_2 = maker.transform_raw_vec_to_plaintext_vector(
+            &encode_messages(raw_messages_2.as_slice(), delta_log),
+        );
+        let proto_ciphertext_1 = maker.encrypt_plaintext_vector_to_glwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext_vector_1,
+            parameters.glwe_noise,
+        );
+        let proto_ciphertext_2 = maker.encrypt_plaintext_vector_to_glwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext_vector_2,
+            parameters.glwe_noise,
+        );
+        let proto_output_ciphertext = maker.trivially_encrypt_zeros_to_glwe_ciphertext(
+            parameters.glwe_dimension,
+            parameters.polynomial_size,
+        );
+        (
+            raw_messages_1,
+            raw_messages_2,
+            proto_ciphertext_1,
+            proto_ciphertext_2,
+            proto_output_ciphertext,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_relinearization_key) = repetition_proto;
+        let (_, _, proto_ciphertext_1, proto_ciphertext_2, proto_output_ciphertext) =
+            sample_proto;
+        let synth_ciphertext_1 = maker.synthesize_glwe_ciphertext(proto_ciphertext_1);
+        let synth_ciphertext_2 = maker.synthesize_glwe_ciphertext(proto_ciphertext_2);
+        let synth_relinearization_key =
+            maker.synthesize_glwe_relinearization_key(proto_relinearization_key);
+        let synth_output_ciphertext = maker.synthesize_glwe_ciphertext(proto_output_ciphertext);
+        (
+            synth_ciphertext_1,
+            synth_ciphertext_2,
+            synth_relinearization_key,
+            synth_output_ciphertext,
+        )
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (ciphertext_1, ciphertext_2, relinearization_key, mut output_ciphertext) = context;
+        let delta_log = DeltaLog(Precision::Raw::BITS - parameters.message_bits);
+        unsafe {
+            engine.discard_tensor_product_glwe_ciphertext_unchecked(
+                &mut output_ciphertext,
+                &ciphertext_1,
+                &ciphertext_2,
+                &relinearization_key,
+                delta_log,
+            )
+        };
+        (
+            ciphertext_1,
+            ciphertext_2,
+            relinearization_key,
+            output_ciphertext,
+        )
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (ciphertext_1, ciphertext_2, relinearization_key, output_ciphertext) = context;
+        let (proto_secret_key, _) = repetition_proto;
+        let (raw_messages_1, raw_messages_2, ..) = sample_proto;
+        let proto_output_ciphertext = maker.unsynthesize_glwe_ciphertext(&output_ciphertext);
+        maker.destroy_glwe_ciphertext(ciphertext_1);
+        maker.destroy_glwe_ciphertext(ciphertext_2);
+        maker.destroy_glwe_relinearization_key(relinearization_key);
+        maker.destroy_glwe_ciphertext(output_ciphertext);
+        let proto_output_plaintext_vector = maker
+            .decrypt_glwe_ciphertext_to_plaintext_vector(proto_secret_key, &proto_output_ciphertext);
+        let delta_log = Precision::Raw::BITS - parameters.message_bits;
+        let raw_expected = encode_messages(
+            &negacyclic_product(raw_messages_1.as_slice(), raw_messages_2.as_slice()),
+            delta_log,
+        );
+        (
+            raw_expected,
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        let delta = f64::powi(
+            2.,
+            (Precision::Raw::BITS - parameters.message_bits) as i32,
+        );
+        let output_variance = fix_estimate_multiplication_noise::<
+            Precision::Raw,
+            Variance,
+            Variance,
+            Variance,
+            InputCiphertext1::KeyDistribution,
+        >(
+            parameters.polynomial_size,
+            parameters.glwe_dimension,
+            parameters.glwe_noise,
+            parameters.glwe_noise,
+            delta,
+            delta,
+            1.,
+            1.,
+            parameters.relinearization_noise,
+            parameters.decomposition_base_log,
+            parameters.decomposition_level_count,
+        );
+        (output_variance,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means = means.into_iter().flatten().collect::<Vec<_>>();
+        let actual = actual.into_iter().flatten().collect::<Vec<_>>();
+        assert_noise_distribution(&actual, means.as_slice(), criteria.0)
+    }
+}
+
+/// Scales raw messages by $2^{\text{delta\_log}}$.
+pub(crate) fn encode_messages<Raw: RawUnsignedIntegers>(messages: &[Raw], delta_log: usize) -> Vec<Raw> {
+    messages
+        .iter()
+        .map(|m| m.wrapping_shl(delta_log as u32))
+        .collect()
+}
+
+/// Computes the product of two polynomials modulo $X^N + 1$.
+pub(crate) fn negacyclic_product<Raw: RawUnsignedIntegers>(lhs: &[Raw], rhs: &[Raw]) -> Vec<Raw> {
+    let poly_size = lhs.len();
+    let mut output = Raw::zero_vec(poly_size);
+    for (i, lhs_coef) in lhs.iter().enumerate() {
+        for (j, rhs_coef) in rhs.iter().enumerate() {
+            let product = lhs_coef.wrapping_mul(*rhs_coef);
+            if i + j < poly_size {
+                output[i + j] = output[i + j].wrapping_add(product);
+            } else {
+                output[i + j - poly_size] = output[i + j - poly_size].wrapping_sub(product);
+            }
+        }
+    }
+    output
+}
+
+// FIXME:
+// The current NPE does not use the key distribution markers of concrete-core. This function makes
+// the mapping. This function should be removed as soon as the npe uses the types of concrete-core.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn fix_estimate_multiplication_noise<T, D1, D2, D3, K>(
+    poly_size: PolynomialSize,
+    mask_size: GlweDimension,
+    dispersion_glwe1: D1,
+    dispersion_glwe2: D2,
+    delta_1: f64,
+    delta_2: f64,
+    max_msg_1: f64,
+    max_msg_2: f64,
+    dispersion_rlk: D3,
+    base_log: DecompositionBaseLog,
+    level: DecompositionLevelCount,
+) -> Variance
+where
+    T: UnsignedInteger,
+    D1: DispersionParameter,
+    D2: DispersionParameter,
+    D3: DispersionParameter,
+    K: KeyDistributionMarker,
+{
+    let k_type_id = TypeId::of::<K>();
+    if k_type_id == TypeId::of::<BinaryKeyDistribution>() {
+        concrete_npe::estimate_multiplication_noise::<T, D1, D2, D3, BinaryKeyKind>(
+            poly_size,
+            mask_size,
+            dispersion_glwe1,
+            dispersion_glwe2,
+            delta_1,
+            delta_2,
+            max_msg_1,
+            max_msg_2,
+            dispersion_rlk,
+            base_log,
+            level,
+        )
+    } else if k_type_id == TypeId::of::<TernaryKeyDistribution>() {
+        concrete_npe::estimate_multiplication_noise::<T, D1, D2, D3, TernaryKeyKind>(
+            poly_size,
+            mask_size,
+            dispersion_glwe1,
+            dispersion_glwe2,
+            delta_1,
+            delta_2,
+            max_msg_1,
+            max_msg_2,
+            dispersion_rlk,
+            base_log,
+            level,
+        )
+    } else if k_type_id == TypeId::of::<GaussianKeyDistribution>() {
+        concrete_npe::estimate_multiplication_noise::<T, D1, D2, D3, GaussianKeyKind>(
+            poly_size,
+            mask_size,
+            dispersion_glwe1,
+            dispersion_glwe2,
+            delta_1,
+            delta_2,
+            max_msg_1,
+            max_msg_2,
+            dispersion_rlk,
+            base_log,
+            level,
+        )
+    } else {
+        panic!("Unknown key distribution encountered.")
+    }
+}
@@ -0,0 +1,305 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGgswCiphertext, PrototypesGlweCiphertext, PrototypesGlweSecretKey,
+    PrototypesPlaintext, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{SynthesizesGgswCiphertext, SynthesizesGlweCiphertext};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::key_kinds::{BinaryKeyKind, GaussianKeyKind, TernaryKeyKind};
+use concrete_commons::numeric::UnsignedInteger;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+use concrete_core::prelude::markers::{
+    BinaryKeyDistribution, GaussianKeyDistribution, KeyDistributionMarker, TernaryKeyDistribution,
+};
+use concrete_core::prelude::{
+    GgswCiphertextEntity, GlweCiphertextEntity, GlweCiphertextGgswCiphertextDiscardingCmuxEngine,
+};
+use std::any::TypeId;
+
+/// A fixture for the types implementing the `GlweCiphertextGgswCiphertextDiscardingCmuxEngine`
+/// trait.
+pub struct GlweCiphertextGgswCiphertextDiscardingCmuxFixture;
+
+#[derive(Debug)]
+pub struct GlweCiphertextGgswCiphertextDiscardingCmuxParameters {
+    pub ggsw_noise: Variance,
+    pub glwe_noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub decomposition_base_log: DecompositionBaseLog,
+    pub decomposition_level_count: DecompositionLevelCount,
+}
+
+impl<Precision, Engine, GlweInput, GgswInput, GlweOutput>
+    Fixture<Precision, Engine, (GlweInput, GgswInput, GlweOutput)>
+    for GlweCiphertextGgswCiphertextDiscardingCmuxFixture
+where
+    Precision: IntegerPrecision,
+    Engine: GlweCiphertextGgswCiphertextDiscardingCmuxEngine<GlweInput, GgswInput, GlweOutput>,
+    GlweInput: GlweCiphertextEntity,
+    GgswInput: GgswCiphertextEntity<KeyDistribution = GlweInput::KeyDistribution>,
+    GlweOutput: GlweCiphertextEntity<KeyDistribution = GlweInput::KeyDistribution>,
+    Maker: SynthesizesGlweCiphertext<Precision, GlweInput>
+        + SynthesizesGlweCiphertext<Precision, GlweOutput>
+        + SynthesizesGgswCiphertext<Precision, GgswInput>,
+{
+    type Parameters = GlweCiphertextGgswCiphertextDiscardingCmuxParameters;
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+    type RepetitionPrototypes = (
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        <Maker as PrototypesGlweSecretKey<Precision, GlweInput::KeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesGgswCiphertext<Precision, GlweInput::KeyDistribution>>::GgswCiphertextProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesGlweCiphertext<Precision, GlweInput::KeyDistribution>>::GlweCiphertextProto,
+        <Maker as PrototypesGlweCiphertext<Precision, GlweInput::KeyDistribution>>::GlweCiphertextProto,
+        <Maker as PrototypesGlweCiphertext<Precision, GlweInput::KeyDistribution>>::GlweCiphertextProto,
+    );
+    type PreExecutionContext = (GlweOutput, GlweInput, GlweInput, GgswInput);
+    type PostExecutionContext = (GlweOutput, GlweInput, GlweInput, GgswInput);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GlweCiphertextGgswCiphertextDiscardingCmuxParameters {
+                    ggsw_noise: Variance(LogStandardDev(-25.).get_variance()),
+                    glwe_noise: Variance(LogStandardDev(-20.).get_variance()),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(512),
+                    decomposition_base_log: DecompositionBaseLog(6),
+                    decomposition_level_count: DecompositionLevelCount(4),
+                },
+                GlweCiphertextGgswCiphertextDiscardingCmuxParameters {
+                    ggsw_noise: Variance(LogStandardDev(-25.).get_variance()),
+                    glwe_noise: Variance(LogStandardDev(-20.).get_variance()),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(1024),
+                    decomposition_base_log: DecompositionBaseLog(6),
+                    decomposition_level_count: DecompositionLevelCount(4),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        let raw_plaintext = Precision::Raw::pick(&[Precision::Raw::zero(), Precision::Raw::one()]);
+        let proto_plaintext = maker.transform_raw_to_plaintext(&raw_plaintext);
+        let proto_ggsw = maker.encrypt_plaintext_to_ggsw_ciphertext(
+            &proto_secret_key,
+            &proto_plaintext,
+            parameters.ggsw_noise,
+            parameters.decomposition_level_count,
+            parameters.decomposition_base_log,
+        );
+        (proto_plaintext, proto_secret_key, proto_ggsw)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (_, proto_secret_key, _) = repetition_proto;
+        let raw_plaintext_vector_0 = Precision::Raw::uniform_vec(parameters.polynomial_size.0);
+        let raw_plaintext_vector_1 = Precision::Raw::uniform_vec(parameters.polynomial_size.0);
+        let proto_plaintext_vector_0 =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector_0);
+        let proto_plaintext_vector_1 =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector_1);
+        let proto_input_ciphertext_0 = maker.encrypt_plaintext_vector_to_glwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext_vector_0,
+            parameters.glwe_noise,
+        );
+        let proto_input_ciphertext_1 = maker.encrypt_plaintext_vector_to_glwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext_vector_1,
+            parameters.glwe_noise,
+        );
+        let proto_output_ciphertext = maker.trivially_encrypt_zeros_to_glwe_ciphertext(
+            parameters.glwe_dimension,
+            parameters.polynomial_size,
+        );
+        (
+            proto_plaintext_vector_0,
+            proto_plaintext_vector_1,
+            proto_input_ciphertext_0,
+            proto_input_ciphertext_1,
+            proto_output_ciphertext,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, _, proto_ggsw_ciphertext) = repetition_proto;
+        let (_, _, proto_input_ciphertext_0, proto_input_ciphertext_1, proto_output_ciphertext) =
+            sample_proto;
+        let synth_output_ciphertext = maker.synthesize_glwe_ciphertext(proto_output_ciphertext);
+        let synth_input_ciphertext_0 = maker.synthesize_glwe_ciphertext(proto_input_ciphertext_0);
+        let synth_input_ciphertext_1 = maker.synthesize_glwe_ciphertext(proto_input_ciphertext_1);
+        let synth_ggsw_ciphertext = maker.synthesize_ggsw_ciphertext(proto_ggsw_ciphertext);
+        (
+            synth_output_ciphertext,
+            synth_input_ciphertext_0,
+            synth_input_ciphertext_1,
+            synth_ggsw_ciphertext,
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (mut output_ciphertext, input_ciphertext_0, input_ciphertext_1, ggsw_ciphertext) =
+            context;
+        unsafe {
+            engine.discard_cmux_glwe_ciphertext_ggsw_ciphertext_unchecked(
+                &mut output_ciphertext,
+                &input_ciphertext_0,
+                &input_ciphertext_1,
+                &ggsw_ciphertext,
+            )
+        };
+        (
+            output_ciphertext,
+            input_ciphertext_0,
+            input_ciphertext_1,
+            ggsw_ciphertext,
+        )
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (output_ciphertext, input_ciphertext_0, input_ciphertext_1, ggsw_ciphertext) = context;
+        let (proto_ggsw_plaintext, proto_secret_key, _) = repetition_proto;
+        let (proto_plaintext_vector_0, proto_plaintext_vector_1, ..) = sample_proto;
+        let proto_output_ciphertext = maker.unsynthesize_glwe_ciphertext(&output_ciphertext);
+        maker.destroy_glwe_ciphertext(output_ciphertext);
+        maker.destroy_glwe_ciphertext(input_ciphertext_0);
+        maker.destroy_glwe_ciphertext(input_ciphertext_1);
+        maker.destroy_ggsw_ciphertext(ggsw_ciphertext);
+        let proto_output_plaintext_vector = maker.decrypt_glwe_ciphertext_to_plaintext_vector(
+            proto_secret_key,
+            &proto_output_ciphertext,
+        );
+        let proto_selected_plaintext_vector =
+            if maker.transform_plaintext_to_raw(proto_ggsw_plaintext) == Precision::Raw::zero() {
+                proto_plaintext_vector_0
+            } else {
+                proto_plaintext_vector_1
+            };
+        (
+            maker.transform_plaintext_vector_to_raw_vec(proto_selected_plaintext_vector),
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        let output_variance = fix_estimate_cmux_noise_with_binary_ggsw::<
+            Precision::Raw,
+            Variance,
+            Variance,
+            Variance,
+            GlweInput::KeyDistribution,
+        >(
+            parameters.glwe_dimension,
+            parameters.polynomial_size,
+            parameters.decomposition_base_log,
+            parameters.decomposition_level_count,
+            parameters.glwe_noise,
+            parameters.glwe_noise,
+            parameters.ggsw_noise,
+        );
+        (output_variance,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means = means.into_iter().flatten().collect::<Vec<_>>();
+        let actual = actual.into_iter().flatten().collect::<Vec<_>>();
+        assert_noise_distribution(&actual, means.as_slice(), criteria.0)
+    }
+}
+
+// FIXME:
+// The current NPE does not use the key distribution markers of concrete-core. This function makes
+// the mapping. This function should be removed as soon as the npe uses the types of concrete-core.
+fn fix_estimate_cmux_noise_with_binary_ggsw<T, D1, D2, D3, K>(
+    dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    base_log: DecompositionBaseLog,
+    l_gadget: DecompositionLevelCount,
+    dispersion_rlwe_0: D1,
+    dispersion_rlwe_1: D2,
+    dispersion_rgsw: D3,
+) -> Variance
+where
+    T: UnsignedInteger,
+    D1: DispersionParameter,
+    D2: DispersionParameter,
+    D3: DispersionParameter,
+    K: KeyDistributionMarker,
+{
+    let k_type_id = TypeId::of::<K>();
+    if k_type_id == TypeId::of::<BinaryKeyDistribution>() {
+        concrete_npe::estimate_cmux_noise_with_binary_ggsw::<T, D1, D2, D3, BinaryKeyKind>(
+            dimension,
+            polynomial_size,
+            base_log,
+            l_gadget,
+            dispersion_rlwe_0,
+            dispersion_rlwe_1,
+            dispersion_rgsw,
+        )
+    } else if k_type_id == TypeId::of::<TernaryKeyDistribution>() {
+        concrete_npe::estimate_cmux_noise_with_binary_ggsw::<T, D1, D2, D3, TernaryKeyKind>(
+            dimension,
+            polynomial_size,
+            base_log,
+            l_gadget,
+            dispersion_rlwe_0,
+            dispersion_rlwe_1,
+            dispersion_rgsw,
+        )
+    } else if k_type_id == TypeId::of::<GaussianKeyDistribution>() {
+        concrete_npe::estimate_cmux_noise_with_binary_ggsw::<T, D1, D2, D3, GaussianKeyKind>(
+            dimension,
+            polynomial_size,
+            base_log,
+            l_gadget,
+            dispersion_rlwe_0,
+            dispersion_rlwe_1,
+            dispersion_rgsw,
+        )
+    } else {
+        panic!("Unknown key distribution encountered.")
+    }
+}
@@ -0,0 +1,241 @@
+use crate::fixture::glwe_ciphertext_discarding_tensor_product::{
+    encode_messages, fix_estimate_multiplication_noise, negacyclic_product,
+};
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweCiphertext, PrototypesGlweRelinearizationKey, PrototypesGlweSecretKey,
+    PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesGlweRelinearizationKey, SynthesizesGlweSecretKey,
+};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::numeric::Numeric;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, DeltaLog, GlweDimension, PolynomialSize,
+};
+use concrete_core::prelude::{
+    GlweRelinearizationKeyCreationEngine, GlweRelinearizationKeyEntity, GlweSecretKeyEntity,
+};
+
+/// A fixture for the types implementing the `GlweRelinearizationKeyCreationEngine` trait.
+///
+/// The created key is used to relinearize the tensor product of two GLWE ciphertexts, whose
+/// decryption is then checked against the product of the messages.
+pub struct GlweRelinearizationKeyCreationFixture;
+
+#[derive(Debug)]
+pub struct GlweRelinearizationKeyCreationParameters {
+    pub glwe_noise: Variance,
+    pub relinearization_noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub decomposition_base_log: DecompositionBaseLog,
+    pub decomposition_level_count: DecompositionLevelCount,
+    pub message_bits: usize,
+}
+
+impl<Precision, Engine, SecretKey, RelinearizationKey>
+    Fixture<Precision, Engine, (SecretKey, RelinearizationKey)>
+    for GlweRelinearizationKeyCreationFixture
+where
+    Precision: IntegerPrecision,
+    Engine: GlweRelinearizationKeyCreationEngine<SecretKey, RelinearizationKey>,
+    SecretKey: GlweSecretKeyEntity,
+    RelinearizationKey: GlweRelinearizationKeyEntity<KeyDistribution = SecretKey::KeyDistribution>,
+    Maker: SynthesizesGlweSecretKey<Precision, SecretKey>
+        + SynthesizesGlweRelinearizationKey<Precision, RelinearizationKey>,
+{
+    type Parameters = GlweRelinearizationKeyCreationParameters;
+    type RepetitionPrototypes =
+        (<Maker as PrototypesGlweSecretKey<Precision, SecretKey::KeyDistribution>>::GlweSecretKeyProto,);
+    type SamplePrototypes = (
+        Vec<Precision::Raw>,
+        Vec<Precision::Raw>,
+        <Maker as PrototypesGlweCiphertext<Precision, SecretKey::KeyDistribution>>::GlweCiphertextProto,
+        <Maker as PrototypesGlweCiphertext<Precision, SecretKey::KeyDistribution>>::GlweCiphertextProto,
+    );
+    type PreExecutionContext = (SecretKey,);
+    type PostExecutionContext = (SecretKey, RelinearizationKey);
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GlweRelinearizationKeyCreationParameters {
+                    glwe_noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    relinearization_noise: Variance(
+                        LogStandardDev::from_log_standard_dev(-25.).get_variance(),
+                    ),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(256),
+                    decomposition_base_log: DecompositionBaseLog(6),
+                    decomposition_level_count: DecompositionLevelCount(3),
+                    message_bits: 11,
+                },
+                GlweRelinearizationKeyCreationParameters {
+                    glwe_noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    relinearization_noise: Variance(
+                        LogStandardDev::from_log_standard_dev(-25.).get_variance(),
+                    ),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(512),
+                    decomposition_base_log: DecompositionBaseLog(4),
+                    decomposition_level_count: DecompositionLevelCount(5),
+                    message_bits: 12,
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        (proto_secret_key,)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key,) = repetition_proto;
+        let delta_log = Precision::Raw::BITS - parameters.message_bits;
+        let raw_messages_1 = Precision::Raw::pick_vec(
+            &[Precision::Raw::zero(), Precision::Raw::one()],
+            parameters.polynomial_size.0,
+        );
+        let raw_messages_2 = Precision::Raw::pick_vec(
+            &[Precision::Raw::zero(), Precision::Raw::one()],
+            parameters.polynomial_size.0,
+        );
+        let proto_plaintext_vector_1 = maker.transform_raw_vec_to_plaintext_vector(
+            &encode_messages(raw_messages_1.as_slice(), delta_log),
+        );
+        let proto_plaintext_vector_2 = maker.transform_raw_vec_to_plaintext_vector(
+            &encode_messages(raw_messages_2.as_slice(), delta_log),
+        );
+        let proto_ciphertext_1 = maker.encrypt_plaintext_vector_to_glwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext_vector_1,
+            parameters.glwe_noise,
+        );
+        let proto_ciphertext_2 = maker.encrypt_plaintext_vector_to_glwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext_vector_2,
+            parameters.glwe_noise,
+        );
+        (
+            raw_messages_1,
+            raw_messages_2,
+            proto_ciphertext_1,
+            proto_ciphertext_2,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_secret_key,) = repetition_proto;
+        (maker.synthesize_glwe_secret_key(proto_secret_key),)
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (secret_key,) = context;
+        let relinearization_key = unsafe {
+            engine.create_glwe_relinearization_key_unchecked(
+                &secret_key,
+                parameters.decomposition_level_count,
+                parameters.decomposition_base_log,
+                parameters.relinearization_noise,
+            )
+        };
+        (secret_key, relinearization_key)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (secret_key, relinearization_key) = context;
+        let (proto_secret_key,) = repetition_proto;
+        let (raw_messages_1, raw_messages_2, proto_ciphertext_1, proto_ciphertext_2) = sample_proto;
+        let proto_relinearization_key =
+            maker.unsynthesize_glwe_relinearization_key(&relinearization_key);
+        maker.destroy_glwe_secret_key(secret_key);
+        maker.destroy_glwe_relinearization_key(relinearization_key);
+        let delta_log = Precision::Raw::BITS - parameters.message_bits;
+        let proto_output_ciphertext = maker.compute_tensor_product_of_glwe_ciphertexts(
+            proto_ciphertext_1,
+            proto_ciphertext_2,
+            &proto_relinearization_key,
+            DeltaLog(delta_log),
+        );
+        let proto_output_plaintext_vector = maker
+            .decrypt_glwe_ciphertext_to_plaintext_vector(proto_secret_key, &proto_output_ciphertext);
+        let raw_expected = encode_messages(
+            &negacyclic_product(raw_messages_1.as_slice(), raw_messages_2.as_slice()),
+            delta_log,
+        );
+        (
+            raw_expected,
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        let delta = f64::powi(
+            2.,
+            (Precision::Raw::BITS - parameters.message_bits) as i32,
+        );
+        let output_variance = fix_estimate_multiplication_noise::<
+            Precision::Raw,
+            Variance,
+            Variance,
+            Variance,
+            SecretKey::KeyDistribution,
+        >(
+            parameters.polynomial_size,
+            parameters.glwe_dimension,
+            parameters.glwe_noise,
+            parameters.glwe_noise,
+            delta,
+            delta,
+            1.,
+            1.,
+            parameters.relinearization_noise,
+            parameters.decomposition_base_log,
+            parameters.decomposition_level_count,
+        );
+        (output_variance,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means = means.into_iter().flatten().collect::<Vec<_>>();
+        let actual = actual.into_iter().flatten().collect::<Vec<_>>();
+        assert_noise_distribution(&actual, means.as_slice(), criteria.0)
+    }
+}
@@ -0,0 +1,293 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGswCiphertext, PrototypesLweCiphertext, PrototypesLweSecretKey, PrototypesPlaintext,
+};
+use crate::generation::synthesizing::{SynthesizesGswCiphertext, SynthesizesLweCiphertext};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::key_kinds::{BinaryKeyKind, GaussianKeyKind, TernaryKeyKind};
+use concrete_commons::numeric::UnsignedInteger;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+use concrete_core::prelude::markers::{
+    BinaryKeyDistribution, GaussianKeyDistribution, KeyDistributionMarker, TernaryKeyDistribution,
+};
+use concrete_core::prelude::{
+    GswCiphertextEntity, LweCiphertextDiscardingCmuxEngine, LweCiphertextEntity,
+};
+use std::any::TypeId;
+
+/// A fixture for the types implementing the `LweCiphertextDiscardingCmuxEngine` trait.
+pub struct LweCiphertextDiscardingCmuxFixture;
+
+#[derive(Debug)]
+pub struct LweCiphertextDiscardingCmuxParameters {
+    pub gsw_noise: Variance,
+    pub lwe_noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub decomposition_base_log: DecompositionBaseLog,
+    pub decomposition_level_count: DecompositionLevelCount,
+}
+
+impl<Precision, Engine, LweInput, GswInput, LweOutput>
+    Fixture<Precision, Engine, (LweInput, GswInput, LweOutput)>
+    for LweCiphertextDiscardingCmuxFixture
+where
+    Precision: IntegerPrecision,
+    Engine: LweCiphertextDiscardingCmuxEngine<LweInput, GswInput, LweOutput>,
+    LweInput: LweCiphertextEntity,
+    GswInput: GswCiphertextEntity<KeyDistribution = LweInput::KeyDistribution>,
+    LweOutput: LweCiphertextEntity<KeyDistribution = LweInput::KeyDistribution>,
+    Maker: SynthesizesLweCiphertext<Precision, LweInput>
+        + SynthesizesLweCiphertext<Precision, LweOutput>
+        + SynthesizesGswCiphertext<Precision, GswInput>,
+{
+    type Parameters = LweCiphertextDiscardingCmuxParameters;
+    type Criteria = (Variance,);
+    type Outcome = (Precision::Raw, Precision::Raw);
+    type RepetitionPrototypes = (
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        <Maker as PrototypesLweSecretKey<Precision, LweInput::KeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesGswCiphertext<Precision, LweInput::KeyDistribution>>::GswCiphertextProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        <Maker as PrototypesLweCiphertext<Precision, LweInput::KeyDistribution>>::LweCiphertextProto,
+        <Maker as PrototypesLweCiphertext<Precision, LweInput::KeyDistribution>>::LweCiphertextProto,
+        <Maker as PrototypesLweCiphertext<Precision, LweInput::KeyDistribution>>::LweCiphertextProto,
+    );
+    type PreExecutionContext = (LweOutput, LweInput, LweInput, GswInput);
+    type PostExecutionContext = (LweOutput, LweInput, LweInput, GswInput);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweCiphertextDiscardingCmuxParameters {
+                    gsw_noise: Variance(LogStandardDev(-25.).get_variance()),
+                    lwe_noise: Variance(LogStandardDev(-20.).get_variance()),
+                    lwe_dimension: LweDimension(200),
+                    decomposition_base_log: DecompositionBaseLog(6),
+                    decomposition_level_count: DecompositionLevelCount(4),
+                },
+                LweCiphertextDiscardingCmuxParameters {
+                    gsw_noise: Variance(LogStandardDev(-25.).get_variance()),
+                    lwe_noise: Variance(LogStandardDev(-20.).get_variance()),
+                    lwe_dimension: LweDimension(600),
+                    decomposition_base_log: DecompositionBaseLog(6),
+                    decomposition_level_count: DecompositionLevelCount(4),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key = maker.new_lwe_secret_key(parameters.lwe_dimension);
+        let raw_plaintext = Precision::Raw::pick(&[Precision::Raw::zero(), Precision::Raw::one()]);
+        let proto_plaintext = maker.transform_raw_to_plaintext(&raw_plaintext);
+        let proto_gsw = maker.encrypt_plaintext_to_gsw_ciphertext(
+            &proto_secret_key,
+            &proto_plaintext,
+            parameters.gsw_noise,
+            parameters.decomposition_level_count,
+            parameters.decomposition_base_log,
+        );
+        (proto_plaintext, proto_secret_key, proto_gsw)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (_, proto_secret_key, _) = repetition_proto;
+        let raw_plaintext_0 = Precision::Raw::uniform();
+        let raw_plaintext_1 = Precision::Raw::uniform();
+        let proto_plaintext_0 = maker.transform_raw_to_plaintext(&raw_plaintext_0);
+        let proto_plaintext_1 = maker.transform_raw_to_plaintext(&raw_plaintext_1);
+        let proto_input_ciphertext_0 = maker.encrypt_plaintext_to_lwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext_0,
+            parameters.lwe_noise,
+        );
+        let proto_input_ciphertext_1 = maker.encrypt_plaintext_to_lwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext_1,
+            parameters.lwe_noise,
+        );
+        let proto_output_ciphertext =
+            maker.trivially_encrypt_zero_to_lwe_ciphertext(parameters.lwe_dimension);
+        (
+            proto_plaintext_0,
+            proto_plaintext_1,
+            proto_input_ciphertext_0,
+            proto_input_ciphertext_1,
+            proto_output_ciphertext,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, _, proto_gsw_ciphertext) = repetition_proto;
+        let (_, _, proto_input_ciphertext_0, proto_input_ciphertext_1, proto_output_ciphertext) =
+            sample_proto;
+        let synth_output_ciphertext = maker.synthesize_lwe_ciphertext(proto_output_ciphertext);
+        let synth_input_ciphertext_0 = maker.synthesize_lwe_ciphertext(proto_input_ciphertext_0);
+        let synth_input_ciphertext_1 = maker.synthesize_lwe_ciphertext(proto_input_ciphertext_1);
+        let synth_gsw_ciphertext = maker.synthesize_gsw_ciphertext(proto_gsw_ciphertext);
+        (
+            synth_output_ciphertext,
+            synth_input_ciphertext_0,
+            synth_input_ciphertext_1,
+            synth_gsw_ciphertext,
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (mut output_ciphertext, input_ciphertext_0, input_ciphertext_1, gsw_ciphertext) =
+            context;
+        unsafe {
+            engine.discard_cmux_lwe_ciphertext_unchecked(
+                &mut output_ciphertext,
+                &input_ciphertext_0,
+                &input_ciphertext_1,
+                &gsw_ciphertext,
+            )
+        };
+        (
+            output_ciphertext,
+            input_ciphertext_0,
+            input_ciphertext_1,
+            gsw_ciphertext,
+        )
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (output_ciphertext, input_ciphertext_0, input_ciphertext_1, gsw_ciphertext) = context;
+        let (proto_gsw_plaintext, proto_secret_key, _) = repetition_proto;
+        let (proto_plaintext_0, proto_plaintext_1, ..) = sample_proto;
+        let proto_output_ciphertext = maker.unsynthesize_lwe_ciphertext(&output_ciphertext);
+        maker.destroy_lwe_ciphertext(output_ciphertext);
+        maker.destroy_lwe_ciphertext(input_ciphertext_0);
+        maker.destroy_lwe_ciphertext(input_ciphertext_1);
+        maker.destroy_gsw_ciphertext(gsw_ciphertext);
+        let proto_output_plaintext =
+            maker.decrypt_lwe_ciphertext_to_plaintext(proto_secret_key, &proto_output_ciphertext);
+        let proto_selected_plaintext =
+            if maker.transform_plaintext_to_raw(proto_gsw_plaintext) == Precision::Raw::zero() {
+                proto_plaintext_0
+            } else {
+                proto_plaintext_1
+            };
+        (
+            maker.transform_plaintext_to_raw(proto_selected_plaintext),
+            maker.transform_plaintext_to_raw(&proto_output_plaintext),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        // A GSW ciphertext behaves like a GGSW ciphertext with polynomials of size one, whose
+        // GLWE dimension is the LWE dimension.
+        let output_variance = fix_estimate_cmux_noise_with_binary_ggsw::<
+            Precision::Raw,
+            Variance,
+            Variance,
+            Variance,
+            LweInput::KeyDistribution,
+        >(
+            GlweDimension(parameters.lwe_dimension.0),
+            PolynomialSize(1),
+            parameters.decomposition_base_log,
+            parameters.decomposition_level_count,
+            parameters.lwe_noise,
+            parameters.lwe_noise,
+            parameters.gsw_noise,
+        );
+        (output_variance,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        assert_noise_distribution(&actual, means.as_slice(), criteria.0)
+    }
+}
+
+// FIXME:
+// The current NPE does not use the key distribution markers of concrete-core. This function makes
+// the mapping. This function should be removed as soon as the npe uses the types of concrete-core.
+fn fix_estimate_cmux_noise_with_binary_ggsw<T, D1, D2, D3, K>(
+    dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    base_log: DecompositionBaseLog,
+    l_gadget: DecompositionLevelCount,
+    dispersion_rlwe_0: D1,
+    dispersion_rlwe_1: D2,
+    dispersion_rgsw: D3,
+) -> Variance
+where
+    T: UnsignedInteger,
+    D1: DispersionParameter,
+    D2: DispersionParameter,
+    D3: DispersionParameter,
+    K: KeyDistributionMarker,
+{
+    let k_type_id = TypeId::of::<K>();
+    if k_type_id == TypeId::of::<BinaryKeyDistribution>() {
+        concrete_npe::estimate_cmux_noise_with_binary_ggsw::<T, D1, D2, D3, BinaryKeyKind>(
+            dimension,
+            polynomial_size,
+            base_log,
+            l_gadget,
+            dispersion_rlwe_0,
+            dispersion_rlwe_1,
+            dispersion_rgsw,
+        )
+    } else if k_type_id == TypeId::of::<TernaryKeyDistribution>() {
+        concrete_npe::estimate_cmux_noise_with_binary_ggsw::<T, D1, D2, D3, TernaryKeyKind>(
+            dimension,
+            polynomial_size,
+            base_log,
+            l_gadget,
+            dispersion_rlwe_0,
+            dispersion_rlwe_1,
+            dispersion_rgsw,
+        )
+    } else if k_type_id == TypeId::of::<GaussianKeyDistribution>() {
+        concrete_npe::estimate_cmux_noise_with_binary_ggsw::<T, D1, D2, D3, GaussianKeyKind>(
+            dimension,
+            polynomial_size,
+            base_log,
+            l_gadget,
+            dispersion_rlwe_0,
+            dispersion_rlwe_1,
+            dispersion_rgsw,
+        )
+    } else {
+        panic!("Unknown key distribution encountered.")
+    }
+}
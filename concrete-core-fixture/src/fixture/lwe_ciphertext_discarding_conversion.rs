@@ -0,0 +1,197 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesConvertedLweSecretKey, PrototypesLweCiphertext, PrototypesLweSecretKey,
+    PrototypesPlaintext,
+};
+use crate::generation::synthesizing::SynthesizesLweCiphertext;
+use crate::generation::{ConvertiblePrecision, IntegerPrecision, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::parameters::LweDimension;
+use concrete_core::backends::core::private::math::torus::CastTorus;
+use concrete_core::prelude::{LweCiphertextDiscardingConversionEngine, LweCiphertextEntity};
+
+/// A fixture for the types implementing the `LweCiphertextDiscardingConversionEngine` trait between
+/// two integer precisions.
+///
+/// The `Precision` type parameter is the precision of the input ciphertext, and the output
+/// ciphertext is expected to be of the converted precision.
+pub struct LweCiphertextDiscardingConversionFixture;
+
+#[derive(Debug)]
+pub struct LweCiphertextDiscardingConversionParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, Engine, InputCiphertext, OutputCiphertext>
+    Fixture<Precision, Engine, (InputCiphertext, OutputCiphertext)>
+    for LweCiphertextDiscardingConversionFixture
+where
+    Precision: ConvertiblePrecision,
+    Precision::Raw: CastTorus<<Precision::Converted as IntegerPrecision>::Raw>,
+    Engine: LweCiphertextDiscardingConversionEngine<InputCiphertext, OutputCiphertext>,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: LweCiphertextEntity<KeyDistribution = InputCiphertext::KeyDistribution>,
+    Maker: SynthesizesLweCiphertext<Precision, InputCiphertext>
+        + SynthesizesLweCiphertext<Precision::Converted, OutputCiphertext>
+        + PrototypesConvertedLweSecretKey<
+            Precision,
+            Precision::Converted,
+            InputCiphertext::KeyDistribution,
+        >,
+{
+    type Parameters = LweCiphertextDiscardingConversionParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, InputCiphertext::KeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesLweSecretKey<Precision::Converted, InputCiphertext::KeyDistribution>>::LweSecretKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        <Maker as PrototypesLweCiphertext<Precision, InputCiphertext::KeyDistribution>>::LweCiphertextProto,
+        <Maker as PrototypesLweCiphertext<Precision::Converted, InputCiphertext::KeyDistribution>>::LweCiphertextProto,
+    );
+    type PreExecutionContext = (InputCiphertext, OutputCiphertext);
+    type PostExecutionContext = (InputCiphertext, OutputCiphertext);
+    type Criteria = (Variance,);
+    type Outcome = (
+        <Precision::Converted as IntegerPrecision>::Raw,
+        <Precision::Converted as IntegerPrecision>::Raw,
+    );
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweCiphertextDiscardingConversionParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-20.).get_variance()),
+                    lwe_dimension: LweDimension(100),
+                },
+                LweCiphertextDiscardingConversionParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-20.).get_variance()),
+                    lwe_dimension: LweDimension(1000),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_input_secret_key = <Maker as PrototypesLweSecretKey<
+            Precision,
+            InputCiphertext::KeyDistribution,
+        >>::new_lwe_secret_key(maker, parameters.lwe_dimension);
+        let proto_output_secret_key = maker.convert_lwe_secret_key(&proto_input_secret_key);
+        (proto_input_secret_key, proto_output_secret_key)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_input_secret_key, _) = repetition_proto;
+        let raw_plaintext = Precision::Raw::uniform();
+        let proto_plaintext = <Maker as PrototypesPlaintext<Precision>>::transform_raw_to_plaintext(
+            maker,
+            &raw_plaintext,
+        );
+        let proto_input_ciphertext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            InputCiphertext::KeyDistribution,
+        >>::encrypt_plaintext_to_lwe_ciphertext(
+            maker,
+            proto_input_secret_key,
+            &proto_plaintext,
+            parameters.noise,
+        );
+        let proto_output_ciphertext = <Maker as PrototypesLweCiphertext<
+            Precision::Converted,
+            InputCiphertext::KeyDistribution,
+        >>::trivially_encrypt_zero_to_lwe_ciphertext(
+            maker, parameters.lwe_dimension
+        );
+        (
+            proto_plaintext,
+            proto_input_ciphertext,
+            proto_output_ciphertext,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_input_ciphertext, proto_output_ciphertext) = sample_proto;
+        (
+            maker.synthesize_lwe_ciphertext(proto_input_ciphertext),
+            maker.synthesize_lwe_ciphertext(proto_output_ciphertext),
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_ciphertext, mut output_ciphertext) = context;
+        unsafe {
+            engine
+                .discard_convert_lwe_ciphertext_unchecked(&mut output_ciphertext, &input_ciphertext)
+        };
+        (input_ciphertext, output_ciphertext)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (input_ciphertext, output_ciphertext) = context;
+        let (_, proto_output_secret_key) = repetition_proto;
+        let (proto_plaintext, ..) = sample_proto;
+        let raw_plaintext = <Maker as PrototypesPlaintext<Precision>>::transform_plaintext_to_raw(
+            maker,
+            proto_plaintext,
+        );
+        let proto_output_ciphertext = maker.unsynthesize_lwe_ciphertext(&output_ciphertext);
+        let proto_output_plaintext = <Maker as PrototypesLweCiphertext<
+            Precision::Converted,
+            InputCiphertext::KeyDistribution,
+        >>::decrypt_lwe_ciphertext_to_plaintext(
+            maker,
+            proto_output_secret_key,
+            &proto_output_ciphertext,
+        );
+        maker.destroy_lwe_ciphertext(input_ciphertext);
+        maker.destroy_lwe_ciphertext(output_ciphertext);
+        (
+            raw_plaintext.cast_torus(),
+            <Maker as PrototypesPlaintext<Precision::Converted>>::transform_plaintext_to_raw(
+                maker,
+                &proto_output_plaintext,
+            ),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        (parameters.noise,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        assert_noise_distribution(&actual, means.as_slice(), criteria.0)
+    }
+}
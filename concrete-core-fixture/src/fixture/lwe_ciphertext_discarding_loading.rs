@@ -0,0 +1,185 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::numeric::CastInto;
+use concrete_commons::parameters::{LweCiphertextCount, LweCiphertextIndex, LweDimension};
+use concrete_core::prelude::{
+    LweCiphertextDiscardingLoadingEngine, LweCiphertextEntity, LweCiphertextVectorEntity,
+};
+
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesLweCiphertext, PrototypesLweCiphertextVector, PrototypesLweSecretKey,
+    PrototypesPlaintext, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{SynthesizesLweCiphertext, SynthesizesLweCiphertextVector};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+
+/// A fixture for the types implementing the `LweCiphertextDiscardingLoadingEngine` trait.
+pub struct LweCiphertextDiscardingLoadingFixture;
+
+#[derive(Debug)]
+pub struct LweCiphertextDiscardingLoadingParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub lwe_ciphertext_count: LweCiphertextCount,
+}
+
+impl<Precision, Engine, CiphertextVector, Ciphertext>
+    Fixture<Precision, Engine, (CiphertextVector, Ciphertext)>
+    for LweCiphertextDiscardingLoadingFixture
+where
+    Precision: IntegerPrecision,
+    Engine: LweCiphertextDiscardingLoadingEngine<CiphertextVector, Ciphertext>,
+    Ciphertext: LweCiphertextEntity,
+    CiphertextVector: LweCiphertextVectorEntity<KeyDistribution = Ciphertext::KeyDistribution>,
+    Maker: SynthesizesLweCiphertextVector<Precision, CiphertextVector>
+        + SynthesizesLweCiphertext<Precision, Ciphertext>,
+{
+    type Parameters = LweCiphertextDiscardingLoadingParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, CiphertextVector::KeyDistribution>>::LweSecretKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<Precision, CiphertextVector::KeyDistribution>>::LweCiphertextVectorProto,
+        <Maker as PrototypesLweCiphertext<Precision, Ciphertext::KeyDistribution>>::LweCiphertextProto,
+        LweCiphertextIndex,
+    );
+    type PreExecutionContext = (Ciphertext, CiphertextVector, LweCiphertextIndex);
+    type PostExecutionContext = (Ciphertext, CiphertextVector);
+    type Criteria = (Variance,);
+    type Outcome = (Precision::Raw, Precision::Raw);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweCiphertextDiscardingLoadingParameters {
+                    noise: Variance(0.00000001),
+                    lwe_dimension: LweDimension(100),
+                    lwe_ciphertext_count: LweCiphertextCount(1),
+                },
+                LweCiphertextDiscardingLoadingParameters {
+                    noise: Variance(0.00000001),
+                    lwe_dimension: LweDimension(100),
+                    lwe_ciphertext_count: LweCiphertextCount(10),
+                },
+                LweCiphertextDiscardingLoadingParameters {
+                    noise: Variance(0.00000001),
+                    lwe_dimension: LweDimension(600),
+                    lwe_ciphertext_count: LweCiphertextCount(10),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key = maker.new_lwe_secret_key(parameters.lwe_dimension);
+        (proto_secret_key,)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key,) = repetition_proto;
+        let raw_plaintext_vector = Precision::Raw::uniform_vec(parameters.lwe_ciphertext_count.0);
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(raw_plaintext_vector.as_slice());
+        let proto_ciphertext_vector = <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            CiphertextVector::KeyDistribution,
+        >>::encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+            maker,
+            proto_secret_key,
+            &proto_plaintext_vector,
+            parameters.noise,
+        );
+        let proto_ciphertext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            Ciphertext::KeyDistribution,
+        >>::trivially_encrypt_zero_to_lwe_ciphertext(
+            maker, parameters.lwe_dimension
+        );
+        let raw_index: f64 =
+            Precision::Raw::uniform_between(0..parameters.lwe_ciphertext_count.0).cast_into();
+        let index = LweCiphertextIndex(raw_index as usize);
+        (
+            proto_plaintext_vector,
+            proto_ciphertext_vector,
+            proto_ciphertext,
+            index,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_ciphertext_vector, proto_ciphertext, index) = sample_proto;
+        (
+            maker.synthesize_lwe_ciphertext(proto_ciphertext),
+            maker.synthesize_lwe_ciphertext_vector(proto_ciphertext_vector),
+            *index,
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (mut ciphertext, ciphertext_vector, index) = context;
+        unsafe {
+            engine.discard_load_lwe_ciphertext_unchecked(&mut ciphertext, &ciphertext_vector, index)
+        };
+        (ciphertext, ciphertext_vector)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (ciphertext, ciphertext_vector) = context;
+        let (proto_secret_key,) = repetition_proto;
+        let (proto_plaintext_vector, _, _, index) = sample_proto;
+        let raw_plaintext_vector =
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector);
+        let proto_output_ciphertext = maker.unsynthesize_lwe_ciphertext(&ciphertext);
+        let proto_output_plaintext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            Ciphertext::KeyDistribution,
+        >>::decrypt_lwe_ciphertext_to_plaintext(
+            maker, proto_secret_key, &proto_output_ciphertext
+        );
+        maker.destroy_lwe_ciphertext(ciphertext);
+        maker.destroy_lwe_ciphertext_vector(ciphertext_vector);
+        (
+            raw_plaintext_vector[index.0],
+            maker.transform_plaintext_to_raw(&proto_output_plaintext),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        (parameters.noise,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        assert_noise_distribution(&actual, means.as_slice(), criteria.0)
+    }
+}
@@ -0,0 +1,202 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::numeric::CastInto;
+use concrete_commons::parameters::{LweCiphertextCount, LweCiphertextIndex, LweDimension};
+use concrete_core::prelude::{
+    LweCiphertextDiscardingStoringEngine, LweCiphertextEntity, LweCiphertextVectorEntity,
+};
+
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesLweCiphertext, PrototypesLweCiphertextVector, PrototypesLweSecretKey,
+    PrototypesPlaintext, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{SynthesizesLweCiphertext, SynthesizesLweCiphertextVector};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+
+/// A fixture for the types implementing the `LweCiphertextDiscardingStoringEngine` trait.
+pub struct LweCiphertextDiscardingStoringFixture;
+
+#[derive(Debug)]
+pub struct LweCiphertextDiscardingStoringParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub lwe_ciphertext_count: LweCiphertextCount,
+}
+
+impl<Precision, Engine, Ciphertext, CiphertextVector>
+    Fixture<Precision, Engine, (Ciphertext, CiphertextVector)>
+    for LweCiphertextDiscardingStoringFixture
+where
+    Precision: IntegerPrecision,
+    Engine: LweCiphertextDiscardingStoringEngine<Ciphertext, CiphertextVector>,
+    CiphertextVector: LweCiphertextVectorEntity,
+    Ciphertext: LweCiphertextEntity<KeyDistribution = CiphertextVector::KeyDistribution>,
+    Maker: SynthesizesLweCiphertextVector<Precision, CiphertextVector>
+        + SynthesizesLweCiphertext<Precision, Ciphertext>,
+{
+    type Parameters = LweCiphertextDiscardingStoringParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, CiphertextVector::KeyDistribution>>::LweSecretKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        <Maker as PrototypesLweCiphertextVector<Precision, CiphertextVector::KeyDistribution>>::LweCiphertextVectorProto,
+        <Maker as PrototypesLweCiphertext<Precision, Ciphertext::KeyDistribution>>::LweCiphertextProto,
+        LweCiphertextIndex,
+    );
+    type PreExecutionContext = (CiphertextVector, Ciphertext, LweCiphertextIndex);
+    type PostExecutionContext = (CiphertextVector, Ciphertext);
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweCiphertextDiscardingStoringParameters {
+                    noise: Variance(0.00000001),
+                    lwe_dimension: LweDimension(100),
+                    lwe_ciphertext_count: LweCiphertextCount(1),
+                },
+                LweCiphertextDiscardingStoringParameters {
+                    noise: Variance(0.00000001),
+                    lwe_dimension: LweDimension(100),
+                    lwe_ciphertext_count: LweCiphertextCount(10),
+                },
+                LweCiphertextDiscardingStoringParameters {
+                    noise: Variance(0.00000001),
+                    lwe_dimension: LweDimension(600),
+                    lwe_ciphertext_count: LweCiphertextCount(10),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key = maker.new_lwe_secret_key(parameters.lwe_dimension);
+        (proto_secret_key,)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key,) = repetition_proto;
+        let raw_plaintext_vector = Precision::Raw::uniform_vec(parameters.lwe_ciphertext_count.0);
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(raw_plaintext_vector.as_slice());
+        let proto_ciphertext_vector = <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            CiphertextVector::KeyDistribution,
+        >>::encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+            maker,
+            proto_secret_key,
+            &proto_plaintext_vector,
+            parameters.noise,
+        );
+        let raw_plaintext = Precision::Raw::uniform();
+        let proto_plaintext = maker.transform_raw_to_plaintext(&raw_plaintext);
+        let proto_ciphertext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            Ciphertext::KeyDistribution,
+        >>::encrypt_plaintext_to_lwe_ciphertext(
+            maker,
+            proto_secret_key,
+            &proto_plaintext,
+            parameters.noise,
+        );
+        let raw_index: f64 =
+            Precision::Raw::uniform_between(0..parameters.lwe_ciphertext_count.0).cast_into();
+        let index = LweCiphertextIndex(raw_index as usize);
+        (
+            proto_plaintext_vector,
+            proto_plaintext,
+            proto_ciphertext_vector,
+            proto_ciphertext,
+            index,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, _, proto_ciphertext_vector, proto_ciphertext, index) = sample_proto;
+        (
+            maker.synthesize_lwe_ciphertext_vector(proto_ciphertext_vector),
+            maker.synthesize_lwe_ciphertext(proto_ciphertext),
+            *index,
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (mut ciphertext_vector, ciphertext, index) = context;
+        unsafe {
+            engine.discard_store_lwe_ciphertext_unchecked(
+                &mut ciphertext_vector,
+                &ciphertext,
+                index,
+            )
+        };
+        (ciphertext_vector, ciphertext)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (ciphertext_vector, ciphertext) = context;
+        let (proto_secret_key,) = repetition_proto;
+        let (proto_plaintext_vector, proto_plaintext, _, _, index) = sample_proto;
+        let mut raw_expected_vector =
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector);
+        raw_expected_vector[index.0] = maker.transform_plaintext_to_raw(proto_plaintext);
+        let proto_output_ciphertext_vector =
+            maker.unsynthesize_lwe_ciphertext_vector(&ciphertext_vector);
+        let proto_output_plaintext_vector = <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            CiphertextVector::KeyDistribution,
+        >>::decrypt_lwe_ciphertext_vector_to_plaintext_vector(
+            maker,
+            proto_secret_key,
+            &proto_output_ciphertext_vector,
+        );
+        maker.destroy_lwe_ciphertext_vector(ciphertext_vector);
+        maker.destroy_lwe_ciphertext(ciphertext);
+        (
+            raw_expected_vector,
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        (parameters.noise,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means: Vec<Precision::Raw> = means.into_iter().flatten().collect();
+        let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
+        assert_noise_distribution(actual.as_slice(), means.as_slice(), criteria.0)
+    }
+}
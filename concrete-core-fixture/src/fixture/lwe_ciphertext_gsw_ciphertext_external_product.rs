@@ -0,0 +1,248 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGswCiphertext, PrototypesLweCiphertext, PrototypesLweSecretKey, PrototypesPlaintext,
+};
+use crate::generation::synthesizing::{SynthesizesGswCiphertext, SynthesizesLweCiphertext};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::key_kinds::{BinaryKeyKind, GaussianKeyKind, TernaryKeyKind};
+use concrete_commons::numeric::UnsignedInteger;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+use concrete_core::prelude::markers::{
+    BinaryKeyDistribution, GaussianKeyDistribution, KeyDistributionMarker, TernaryKeyDistribution,
+};
+use concrete_core::prelude::{
+    GswCiphertextEntity, LweCiphertextEntity, LweCiphertextGswCiphertextExternalProductEngine,
+};
+use std::any::TypeId;
+
+/// A fixture for the types implementing the `LweCiphertextGswCiphertextExternalProductEngine`
+/// trait.
+pub struct LweCiphertextGswCiphertextExternalProductFixture;
+
+#[derive(Debug)]
+pub struct LweCiphertextGswCiphertextExternalProductParameters {
+    pub gsw_noise: Variance,
+    pub lwe_noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub decomposition_base_log: DecompositionBaseLog,
+    pub decomposition_level_count: DecompositionLevelCount,
+}
+
+impl<Precision, Engine, LweInput, GswInput, LweOutput>
+    Fixture<Precision, Engine, (LweInput, GswInput, LweOutput)>
+    for LweCiphertextGswCiphertextExternalProductFixture
+where
+    Precision: IntegerPrecision,
+    Engine: LweCiphertextGswCiphertextExternalProductEngine<LweInput, GswInput, LweOutput>,
+    LweInput: LweCiphertextEntity,
+    GswInput: GswCiphertextEntity<KeyDistribution = LweInput::KeyDistribution>,
+    LweOutput: LweCiphertextEntity<KeyDistribution = LweInput::KeyDistribution>,
+    Maker: SynthesizesLweCiphertext<Precision, LweInput>
+        + SynthesizesLweCiphertext<Precision, LweOutput>
+        + SynthesizesGswCiphertext<Precision, GswInput>,
+{
+    type Parameters = LweCiphertextGswCiphertextExternalProductParameters;
+    type Criteria = (Variance,);
+    type Outcome = (Precision::Raw, Precision::Raw);
+    type RepetitionPrototypes = (
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        <Maker as PrototypesLweSecretKey<Precision, LweInput::KeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesGswCiphertext<Precision, LweInput::KeyDistribution>>::GswCiphertextProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        <Maker as PrototypesLweCiphertext<Precision, LweInput::KeyDistribution>>::LweCiphertextProto,
+    );
+    type PreExecutionContext = (LweInput, GswInput);
+    type PostExecutionContext = (LweInput, GswInput, LweOutput);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweCiphertextGswCiphertextExternalProductParameters {
+                    gsw_noise: Variance(LogStandardDev(-25.).get_variance()),
+                    lwe_noise: Variance(LogStandardDev(-20.).get_variance()),
+                    lwe_dimension: LweDimension(200),
+                    decomposition_base_log: DecompositionBaseLog(6),
+                    decomposition_level_count: DecompositionLevelCount(4),
+                },
+                LweCiphertextGswCiphertextExternalProductParameters {
+                    gsw_noise: Variance(LogStandardDev(-25.).get_variance()),
+                    lwe_noise: Variance(LogStandardDev(-20.).get_variance()),
+                    lwe_dimension: LweDimension(600),
+                    decomposition_base_log: DecompositionBaseLog(6),
+                    decomposition_level_count: DecompositionLevelCount(4),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key = maker.new_lwe_secret_key(parameters.lwe_dimension);
+        let raw_plaintext = Precision::Raw::pick(&[Precision::Raw::zero(), Precision::Raw::one()]);
+        let proto_plaintext = maker.transform_raw_to_plaintext(&raw_plaintext);
+        let proto_gsw = maker.encrypt_plaintext_to_gsw_ciphertext(
+            &proto_secret_key,
+            &proto_plaintext,
+            parameters.gsw_noise,
+            parameters.decomposition_level_count,
+            parameters.decomposition_base_log,
+        );
+        (proto_plaintext, proto_secret_key, proto_gsw)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (_, proto_secret_key, _) = repetition_proto;
+        let raw_plaintext = Precision::Raw::uniform();
+        let proto_plaintext = maker.transform_raw_to_plaintext(&raw_plaintext);
+        let proto_lwe_ciphertext = maker.encrypt_plaintext_to_lwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext,
+            parameters.lwe_noise,
+        );
+        (proto_plaintext, proto_lwe_ciphertext)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_lwe_ciphertext) = sample_proto;
+        let (_, _, proto_gsw_ciphertext) = repetition_proto;
+        let synth_lwe_ciphertext = maker.synthesize_lwe_ciphertext(proto_lwe_ciphertext);
+        let synth_gsw_ciphertext = maker.synthesize_gsw_ciphertext(proto_gsw_ciphertext);
+        (synth_lwe_ciphertext, synth_gsw_ciphertext)
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (lwe_ciphertext, gsw_ciphertext) = context;
+        let output_lwe_ciphertext = unsafe {
+            engine.compute_external_product_lwe_ciphertext_gsw_ciphertext_unchecked(
+                &lwe_ciphertext,
+                &gsw_ciphertext,
+            )
+        };
+        (lwe_ciphertext, gsw_ciphertext, output_lwe_ciphertext)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (lwe_ciphertext, gsw_ciphertext, output_lwe_ciphertext) = context;
+        let (proto_gsw_plaintext, proto_secret_key, _) = repetition_proto;
+        let (proto_input_plaintext, _) = sample_proto;
+        let proto_output_lwe_ciphertext = maker.unsynthesize_lwe_ciphertext(&output_lwe_ciphertext);
+        maker.destroy_lwe_ciphertext(lwe_ciphertext);
+        maker.destroy_gsw_ciphertext(gsw_ciphertext);
+        maker.destroy_lwe_ciphertext(output_lwe_ciphertext);
+        let proto_output_plaintext = maker
+            .decrypt_lwe_ciphertext_to_plaintext(proto_secret_key, &proto_output_lwe_ciphertext);
+        let raw_gsw_plaintext = maker.transform_plaintext_to_raw(proto_gsw_plaintext);
+        let raw_input_plaintext = maker.transform_plaintext_to_raw(proto_input_plaintext);
+        (
+            raw_input_plaintext.wrapping_mul(raw_gsw_plaintext),
+            maker.transform_plaintext_to_raw(&proto_output_plaintext),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        // A GSW ciphertext behaves like a GGSW ciphertext with polynomials of size one, whose
+        // GLWE dimension is the LWE dimension.
+        let output_variance = fix_estimate_external_product_noise_with_binary_ggsw::<
+            Precision::Raw,
+            Variance,
+            Variance,
+            LweInput::KeyDistribution,
+        >(
+            PolynomialSize(1),
+            GlweDimension(parameters.lwe_dimension.0),
+            parameters.lwe_noise,
+            parameters.gsw_noise,
+            parameters.decomposition_base_log,
+            parameters.decomposition_level_count,
+        );
+        (output_variance,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        assert_noise_distribution(&actual, means.as_slice(), criteria.0)
+    }
+}
+
+// FIXME:
+// The current NPE does not use the key distribution markers of concrete-core. This function makes
+// the mapping. This function should be removed as soon as the npe uses the types of concrete-core.
+fn fix_estimate_external_product_noise_with_binary_ggsw<T, D1, D2, K>(
+    poly_size: PolynomialSize,
+    rlwe_mask_size: GlweDimension,
+    var_glwe: D1,
+    var_ggsw: D2,
+    base_log: DecompositionBaseLog,
+    level: DecompositionLevelCount,
+) -> Variance
+where
+    T: UnsignedInteger,
+    D1: DispersionParameter,
+    D2: DispersionParameter,
+    K: KeyDistributionMarker,
+{
+    let k_type_id = TypeId::of::<K>();
+    if k_type_id == TypeId::of::<BinaryKeyDistribution>() {
+        concrete_npe::estimate_external_product_noise_with_binary_ggsw::<T, D1, D2, BinaryKeyKind>(
+            poly_size,
+            rlwe_mask_size,
+            var_glwe,
+            var_ggsw,
+            base_log,
+            level,
+        )
+    } else if k_type_id == TypeId::of::<TernaryKeyDistribution>() {
+        concrete_npe::estimate_external_product_noise_with_binary_ggsw::<T, D1, D2, TernaryKeyKind>(
+            poly_size,
+            rlwe_mask_size,
+            var_glwe,
+            var_ggsw,
+            base_log,
+            level,
+        )
+    } else if k_type_id == TypeId::of::<GaussianKeyDistribution>() {
+        concrete_npe::estimate_external_product_noise_with_binary_ggsw::<T, D1, D2, GaussianKeyKind>(
+            poly_size,
+            rlwe_mask_size,
+            var_glwe,
+            var_ggsw,
+            base_log,
+            level,
+        )
+    } else {
+        panic!("Unknown key distribution encountered.")
+    }
+}
@@ -0,0 +1,169 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweCiphertext, PrototypesGlweSecretKey, PrototypesLweCiphertextVector,
+    PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{SynthesizesGlweCiphertext, SynthesizesLweCiphertextVector};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    GlweDimension, LweCiphertextCount, LweDimension, PolynomialSize,
+};
+use concrete_core::prelude::{
+    GlweCiphertextEntity, LweCiphertextVectorDiscardingExtractionEngine, LweCiphertextVectorEntity,
+};
+
+/// A fixture for the types implementing the `LweCiphertextVectorDiscardingExtractionEngine` trait.
+pub struct LweCiphertextVectorDiscardingExtractionFixture;
+
+#[derive(Debug)]
+pub struct LweCiphertextVectorDiscardingExtractionParameters {
+    pub noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub poly_size: PolynomialSize,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, Engine, GlweCiphertext, LweCiphertextVector>
+    Fixture<Precision, Engine, (GlweCiphertext, LweCiphertextVector)>
+    for LweCiphertextVectorDiscardingExtractionFixture
+where
+    Precision: IntegerPrecision,
+    Engine: LweCiphertextVectorDiscardingExtractionEngine<GlweCiphertext, LweCiphertextVector>,
+    GlweCiphertext: GlweCiphertextEntity,
+    LweCiphertextVector:
+        LweCiphertextVectorEntity<KeyDistribution = GlweCiphertext::KeyDistribution>,
+    Maker: SynthesizesLweCiphertextVector<Precision, LweCiphertextVector>
+        + SynthesizesGlweCiphertext<Precision, GlweCiphertext>,
+{
+    type Parameters = LweCiphertextVectorDiscardingExtractionParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesGlweSecretKey<Precision, GlweCiphertext::KeyDistribution>>::GlweSecretKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesGlweCiphertext<Precision, GlweCiphertext::KeyDistribution>>::GlweCiphertextProto,
+        <Maker as PrototypesLweCiphertextVector<Precision, GlweCiphertext::KeyDistribution>>::LweCiphertextVectorProto,
+    );
+    type PreExecutionContext = (GlweCiphertext, LweCiphertextVector);
+    type PostExecutionContext = (GlweCiphertext, LweCiphertextVector);
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![LweCiphertextVectorDiscardingExtractionParameters {
+                noise: Variance(0.00000001),
+                glwe_dimension: GlweDimension(2),
+                poly_size: PolynomialSize(256),
+            }]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.poly_size);
+        (proto_secret_key,)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key,) = repetition_proto;
+        let raw_plaintext_vector = Precision::Raw::uniform_vec(parameters.poly_size.0);
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector);
+        let proto_glwe_ciphertext = maker.encrypt_plaintext_vector_to_glwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext_vector,
+            parameters.noise,
+        );
+        let proto_lwe_ciphertext_vector = maker.trivially_encrypt_zeros_to_lwe_ciphertext_vector(
+            LweDimension(parameters.glwe_dimension.0 * parameters.poly_size.0),
+            LweCiphertextCount(parameters.poly_size.0),
+        );
+        (
+            proto_plaintext_vector,
+            proto_glwe_ciphertext,
+            proto_lwe_ciphertext_vector,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_glwe_ciphertext, proto_lwe_ciphertext_vector) = sample_proto;
+        let synth_glwe_ciphertext = maker.synthesize_glwe_ciphertext(proto_glwe_ciphertext);
+        let synth_lwe_ciphertext_vector =
+            maker.synthesize_lwe_ciphertext_vector(proto_lwe_ciphertext_vector);
+        (synth_glwe_ciphertext, synth_lwe_ciphertext_vector)
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (glwe_ciphertext, mut lwe_ciphertext_vector) = context;
+        unsafe {
+            engine.discard_extract_lwe_ciphertext_vector_unchecked(
+                &mut lwe_ciphertext_vector,
+                &glwe_ciphertext,
+            )
+        };
+        (glwe_ciphertext, lwe_ciphertext_vector)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (glwe_ciphertext, lwe_ciphertext_vector) = context;
+        let (proto_glwe_secret_key,) = repetition_proto;
+        let (proto_plaintext_vector, ..) = sample_proto;
+        let proto_output_ciphertext_vector =
+            maker.unsynthesize_lwe_ciphertext_vector(&lwe_ciphertext_vector);
+        let proto_lwe_secret_key =
+            maker.transmute_glwe_secret_key_to_lwe_secret_key(proto_glwe_secret_key);
+        let proto_output_plaintext_vector = maker
+            .decrypt_lwe_ciphertext_vector_to_plaintext_vector(
+                &proto_lwe_secret_key,
+                &proto_output_ciphertext_vector,
+            );
+        maker.destroy_lwe_ciphertext_vector(lwe_ciphertext_vector);
+        maker.destroy_glwe_ciphertext(glwe_ciphertext);
+        (
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector),
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        (parameters.noise,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means: Vec<Precision::Raw> = means.into_iter().flatten().collect();
+        let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
+        assert_noise_distribution(&actual, means.as_slice(), criteria.0)
+    }
+}
@@ -0,0 +1,185 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::numeric::CastInto;
+use concrete_commons::parameters::{LweCiphertextCount, LweCiphertextRange, LweDimension};
+use concrete_core::prelude::{LweCiphertextVectorEntity, LweCiphertextVectorLoadingEngine};
+
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesLweCiphertextVector, PrototypesLweSecretKey, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::SynthesizesLweCiphertextVector;
+use crate::generation::{IntegerPrecision, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+
+/// A fixture for the types implementing the `LweCiphertextVectorLoadingEngine` trait.
+pub struct LweCiphertextVectorLoadingFixture;
+
+#[derive(Debug)]
+pub struct LweCiphertextVectorLoadingParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub lwe_ciphertext_count: LweCiphertextCount,
+}
+
+impl<Precision, Engine, CiphertextVector, SubCiphertextVector>
+    Fixture<Precision, Engine, (CiphertextVector, SubCiphertextVector)>
+    for LweCiphertextVectorLoadingFixture
+where
+    Precision: IntegerPrecision,
+    Engine: LweCiphertextVectorLoadingEngine<CiphertextVector, SubCiphertextVector>,
+    CiphertextVector: LweCiphertextVectorEntity,
+    SubCiphertextVector:
+        LweCiphertextVectorEntity<KeyDistribution = CiphertextVector::KeyDistribution>,
+    Maker: SynthesizesLweCiphertextVector<Precision, CiphertextVector>
+        + SynthesizesLweCiphertextVector<Precision, SubCiphertextVector>,
+{
+    type Parameters = LweCiphertextVectorLoadingParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, CiphertextVector::KeyDistribution>>::LweSecretKeyProto,
+    );
+    type SamplePrototypes =
+        (
+            <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+            <Maker as PrototypesLweCiphertextVector<
+                Precision,
+                CiphertextVector::KeyDistribution,
+            >>::LweCiphertextVectorProto,
+            LweCiphertextRange,
+        );
+    type PreExecutionContext = (CiphertextVector, LweCiphertextRange);
+    type PostExecutionContext = (CiphertextVector, SubCiphertextVector);
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweCiphertextVectorLoadingParameters {
+                    noise: Variance(0.00000001),
+                    lwe_dimension: LweDimension(100),
+                    lwe_ciphertext_count: LweCiphertextCount(1),
+                },
+                LweCiphertextVectorLoadingParameters {
+                    noise: Variance(0.00000001),
+                    lwe_dimension: LweDimension(100),
+                    lwe_ciphertext_count: LweCiphertextCount(10),
+                },
+                LweCiphertextVectorLoadingParameters {
+                    noise: Variance(0.00000001),
+                    lwe_dimension: LweDimension(600),
+                    lwe_ciphertext_count: LweCiphertextCount(10),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key = maker.new_lwe_secret_key(parameters.lwe_dimension);
+        (proto_secret_key,)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key,) = repetition_proto;
+        let raw_plaintext_vector = Precision::Raw::uniform_vec(parameters.lwe_ciphertext_count.0);
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(raw_plaintext_vector.as_slice());
+        let proto_ciphertext_vector = <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            CiphertextVector::KeyDistribution,
+        >>::encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+            maker,
+            proto_secret_key,
+            &proto_plaintext_vector,
+            parameters.noise,
+        );
+        let raw_start: f64 =
+            Precision::Raw::uniform_between(0..parameters.lwe_ciphertext_count.0).cast_into();
+        let start = raw_start as usize;
+        let raw_end: f64 =
+            Precision::Raw::uniform_between(start..parameters.lwe_ciphertext_count.0).cast_into();
+        let end = raw_end as usize;
+        (
+            proto_plaintext_vector,
+            proto_ciphertext_vector,
+            LweCiphertextRange(start, end),
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_ciphertext_vector, range) = sample_proto;
+        (
+            maker.synthesize_lwe_ciphertext_vector(proto_ciphertext_vector),
+            *range,
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (ciphertext_vector, range) = context;
+        let sub_ciphertext_vector =
+            unsafe { engine.load_lwe_ciphertext_vector_unchecked(&ciphertext_vector, range) };
+        (ciphertext_vector, sub_ciphertext_vector)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (ciphertext_vector, sub_ciphertext_vector) = context;
+        let (proto_secret_key,) = repetition_proto;
+        let (proto_plaintext_vector, _, range) = sample_proto;
+        let raw_plaintext_vector =
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector);
+        let proto_output_ciphertext_vector =
+            maker.unsynthesize_lwe_ciphertext_vector(&sub_ciphertext_vector);
+        let proto_output_plaintext_vector = <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            SubCiphertextVector::KeyDistribution,
+        >>::decrypt_lwe_ciphertext_vector_to_plaintext_vector(
+            maker,
+            proto_secret_key,
+            &proto_output_ciphertext_vector,
+        );
+        maker.destroy_lwe_ciphertext_vector(ciphertext_vector);
+        maker.destroy_lwe_ciphertext_vector(sub_ciphertext_vector);
+        (
+            raw_plaintext_vector[range.0..=range.1].to_vec(),
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        (parameters.noise,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means: Vec<Precision::Raw> = means.into_iter().flatten().collect();
+        let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
+        assert_noise_distribution(actual.as_slice(), means.as_slice(), criteria.0)
+    }
+}
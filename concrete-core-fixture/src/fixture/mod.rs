@@ -287,6 +287,9 @@ pub use lwe_ciphertext_cleartext_fusing_multiplication::*;
 mod lwe_ciphertext_vector_discarding_affine_transformation;
 pub use lwe_ciphertext_vector_discarding_affine_transformation::*;
 
+mod lwe_ciphertext_vector_discarding_bootstrap;
+pub use lwe_ciphertext_vector_discarding_bootstrap::*;
+
 mod lwe_ciphertext_vector_trivial_decryption;
 pub use lwe_ciphertext_vector_trivial_decryption::*;
 
@@ -299,6 +302,12 @@ pub use lwe_ciphertext_vector_discarding_decryption::*;
 mod lwe_ciphertext_vector_discarding_encryption;
 pub use lwe_ciphertext_vector_discarding_encryption::*;
 
+mod lwe_ciphertext_vector_discarding_extraction;
+pub use lwe_ciphertext_vector_discarding_extraction::*;
+
+mod lwe_ciphertext_vector_discarding_keyswitch;
+pub use lwe_ciphertext_vector_discarding_keyswitch::*;
+
 mod lwe_ciphertext_discarding_keyswitch;
 pub use lwe_ciphertext_discarding_keyswitch::*;
 
@@ -368,6 +377,18 @@ pub use lwe_ciphertext_discarding_bootstrap_2::*;
 mod lwe_ciphertext_discarding_extraction;
 pub use lwe_ciphertext_discarding_extraction::*;
 
+mod lwe_ciphertext_loading;
+pub use lwe_ciphertext_loading::*;
+
+mod lwe_ciphertext_discarding_loading;
+pub use lwe_ciphertext_discarding_loading::*;
+
+mod lwe_ciphertext_discarding_storing;
+pub use lwe_ciphertext_discarding_storing::*;
+
+mod lwe_ciphertext_vector_loading;
+pub use lwe_ciphertext_vector_loading::*;
+
 mod plaintext_creation;
 pub use plaintext_creation::*;
 
@@ -412,3 +433,39 @@ pub use lwe_bootstrap_key_conversion::*;
 
 mod lwe_bootstrap_key_discarding_conversion;
 pub use lwe_bootstrap_key_discarding_conversion::*;
+
+mod lwe_ciphertext_conversion;
+pub use lwe_ciphertext_conversion::*;
+
+mod lwe_ciphertext_discarding_conversion;
+pub use lwe_ciphertext_discarding_conversion::*;
+
+mod lwe_ciphertext_gsw_ciphertext_external_product;
+pub use lwe_ciphertext_gsw_ciphertext_external_product::*;
+
+mod lwe_ciphertext_discarding_cmux;
+pub use lwe_ciphertext_discarding_cmux::*;
+
+mod glwe_ciphertext_ggsw_ciphertext_discarding_cmux;
+pub use glwe_ciphertext_ggsw_ciphertext_discarding_cmux::*;
+
+mod lwe_ciphertext_blind_rotation;
+pub use lwe_ciphertext_blind_rotation::*;
+
+mod lwe_seeded_ciphertext_encryption;
+pub use lwe_seeded_ciphertext_encryption::*;
+
+mod lwe_seeded_ciphertext_expansion;
+pub use lwe_seeded_ciphertext_expansion::*;
+
+mod lwe_seeded_keyswitch_key_creation;
+pub use lwe_seeded_keyswitch_key_creation::*;
+
+mod lwe_seeded_keyswitch_key_expansion;
+pub use lwe_seeded_keyswitch_key_expansion::*;
+
+mod glwe_ciphertext_discarding_tensor_product;
+pub use glwe_ciphertext_discarding_tensor_product::*;
+
+mod glwe_relinearization_key_creation;
+pub use glwe_relinearization_key_creation::*;
@@ -19,7 +19,7 @@
 //! prototypical ones, and extracting _raw_ outputs. Also, the fixture developer should ensure that
 //! the entities are destroyed after the execution of the engine. Again, this can be done by the
 //! [`Maker`] instance and the `Synthesizes*` traits, which contains functions to destroy data.
-use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::generation::{seed_raw_generator, RawUnsignedIntegers};
 use concrete_core::prelude::AbstractEngine;
 
 pub mod prototypes;
@@ -43,6 +43,20 @@ impl IntegerPrecision for Precision64 {
     type Raw = u64;
 }
 
+/// A trait for marker types representing a precision from which entities can be converted.
+pub trait ConvertiblePrecision: IntegerPrecision {
+    /// The precision the entities are converted to.
+    type Converted: IntegerPrecision;
+}
+
+impl ConvertiblePrecision for Precision32 {
+    type Converted = Precision64;
+}
+
+impl ConvertiblePrecision for Precision64 {
+    type Converted = Precision32;
+}
+
 /// The central structure used to generate the pre-execution context for all the fixtures.
 ///
 /// This structure contains the necessary tools to:
@@ -62,3 +76,17 @@ impl Default for Maker {
         }
     }
 }
+
+impl Maker {
+    /// Creates a maker whose randomness is entirely derived from the given seed.
+    ///
+    /// This seeds both the engine used by the maker and the raw generator of the current thread,
+    /// such that the contexts generated for a fixture can be replayed exactly.
+    pub fn new_with_seed(seed: u128) -> Self {
+        seed_raw_generator(seed);
+        Maker {
+            core_engine: concrete_core::backends::core::engines::CoreEngine::new_with_seed(seed)
+                .unwrap(),
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use crate::generation::prototypes::{
+    GlweRelinearizationKeyPrototype, ProtoBinaryGlweRelinearizationKey32,
+    ProtoBinaryGlweRelinearizationKey64,
+};
+use crate::generation::prototyping::glwe_ciphertext::PrototypesGlweCiphertext;
+use crate::generation::{IntegerPrecision, Maker, Precision32, Precision64};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, DeltaLog};
+use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
+use concrete_core::prelude::{
+    GlweCiphertextDiscardingTensorProductEngine, GlweCiphertextEntity,
+    GlweRelinearizationKeyCreationEngine,
+};
+
+/// A trait allowing to manipulate glwe relinearization key prototypes.
+pub trait PrototypesGlweRelinearizationKey<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+>: PrototypesGlweCiphertext<Precision, KeyDistribution>
+{
+    type GlweRelinearizationKeyProto: GlweRelinearizationKeyPrototype<
+        Precision = Precision,
+        KeyDistribution = KeyDistribution,
+    >;
+    fn new_glwe_relinearization_key(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::GlweRelinearizationKeyProto;
+    fn compute_tensor_product_of_glwe_ciphertexts(
+        &mut self,
+        input_1: &Self::GlweCiphertextProto,
+        input_2: &Self::GlweCiphertextProto,
+        relinearization_key: &Self::GlweRelinearizationKeyProto,
+        delta_log: DeltaLog,
+    ) -> Self::GlweCiphertextProto;
+}
+
+impl PrototypesGlweRelinearizationKey<Precision32, BinaryKeyDistribution> for Maker {
+    type GlweRelinearizationKeyProto = ProtoBinaryGlweRelinearizationKey32;
+
+    fn new_glwe_relinearization_key(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::GlweRelinearizationKeyProto {
+        ProtoBinaryGlweRelinearizationKey32(
+            self.core_engine
+                .create_glwe_relinearization_key(
+                    &secret_key.0,
+                    decomposition_level,
+                    decomposition_base_log,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn compute_tensor_product_of_glwe_ciphertexts(
+        &mut self,
+        input_1: &Self::GlweCiphertextProto,
+        input_2: &Self::GlweCiphertextProto,
+        relinearization_key: &Self::GlweRelinearizationKeyProto,
+        delta_log: DeltaLog,
+    ) -> Self::GlweCiphertextProto {
+        let mut output = <Self as PrototypesGlweCiphertext<
+            Precision32,
+            BinaryKeyDistribution,
+        >>::trivially_encrypt_zeros_to_glwe_ciphertext(
+            self,
+            input_1.0.glwe_dimension(),
+            input_1.0.polynomial_size(),
+        );
+        self.core_engine
+            .discard_tensor_product_glwe_ciphertext(
+                &mut output.0,
+                &input_1.0,
+                &input_2.0,
+                &relinearization_key.0,
+                delta_log,
+            )
+            .unwrap();
+        output
+    }
+}
+
+impl PrototypesGlweRelinearizationKey<Precision64, BinaryKeyDistribution> for Maker {
+    type GlweRelinearizationKeyProto = ProtoBinaryGlweRelinearizationKey64;
+
+    fn new_glwe_relinearization_key(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::GlweRelinearizationKeyProto {
+        ProtoBinaryGlweRelinearizationKey64(
+            self.core_engine
+                .create_glwe_relinearization_key(
+                    &secret_key.0,
+                    decomposition_level,
+                    decomposition_base_log,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn compute_tensor_product_of_glwe_ciphertexts(
+        &mut self,
+        input_1: &Self::GlweCiphertextProto,
+        input_2: &Self::GlweCiphertextProto,
+        relinearization_key: &Self::GlweRelinearizationKeyProto,
+        delta_log: DeltaLog,
+    ) -> Self::GlweCiphertextProto {
+        let mut output = <Self as PrototypesGlweCiphertext<
+            Precision64,
+            BinaryKeyDistribution,
+        >>::trivially_encrypt_zeros_to_glwe_ciphertext(
+            self,
+            input_1.0.glwe_dimension(),
+            input_1.0.polynomial_size(),
+        );
+        self.core_engine
+            .discard_tensor_product_glwe_ciphertext(
+                &mut output.0,
+                &input_1.0,
+                &input_2.0,
+                &relinearization_key.0,
+                delta_log,
+            )
+            .unwrap();
+        output
+    }
+}
@@ -2,12 +2,15 @@ use crate::generation::prototypes::{
     LweKeyswitchKeyPrototype, ProtoBinaryBinaryLweKeyswitchKey32,
     ProtoBinaryBinaryLweKeyswitchKey64,
 };
+use crate::generation::prototyping::lwe_ciphertext::PrototypesLweCiphertext;
 use crate::generation::prototyping::lwe_secret_key::PrototypesLweSecretKey;
 use crate::generation::{IntegerPrecision, Maker, Precision32, Precision64};
 use concrete_commons::dispersion::Variance;
 use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
 use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
-use concrete_core::prelude::LweKeyswitchKeyCreationEngine;
+use concrete_core::prelude::{
+    LweCiphertextDiscardingKeyswitchEngine, LweKeyswitchKeyCreationEngine, LweKeyswitchKeyEntity,
+};
 
 /// A trait allowing to manipulate lwe keyswitch key prototypes.
 pub trait PrototypesLweKeyswitchKey<
@@ -15,8 +18,8 @@ pub trait PrototypesLweKeyswitchKey<
     InputKeyDistribution: KeyDistributionMarker,
     OutputKeyDistribution: KeyDistributionMarker,
 >:
-    PrototypesLweSecretKey<Precision, InputKeyDistribution>
-    + PrototypesLweSecretKey<Precision, OutputKeyDistribution>
+    PrototypesLweCiphertext<Precision, InputKeyDistribution>
+    + PrototypesLweCiphertext<Precision, OutputKeyDistribution>
 {
     type LweKeyswitchKeyProto: LweKeyswitchKeyPrototype<
         Precision = Precision,
@@ -31,6 +34,11 @@ pub trait PrototypesLweKeyswitchKey<
         decomposition_base_log: DecompositionBaseLog,
         noise: Variance,
     ) -> Self::LweKeyswitchKeyProto;
+    fn keyswitch_lwe_ciphertext_with_lwe_keyswitch_key(
+        &mut self,
+        keyswitch_key: &Self::LweKeyswitchKeyProto,
+        ciphertext: &<Self as PrototypesLweCiphertext<Precision, InputKeyDistribution>>::LweCiphertextProto,
+    ) -> <Self as PrototypesLweCiphertext<Precision, OutputKeyDistribution>>::LweCiphertextProto;
 }
 
 impl PrototypesLweKeyswitchKey<Precision32, BinaryKeyDistribution, BinaryKeyDistribution>
@@ -58,6 +66,25 @@ impl PrototypesLweKeyswitchKey<Precision32, BinaryKeyDistribution, BinaryKeyDist
                 .unwrap(),
         )
     }
+
+    fn keyswitch_lwe_ciphertext_with_lwe_keyswitch_key(
+        &mut self,
+        keyswitch_key: &Self::LweKeyswitchKeyProto,
+        ciphertext: &<Self as PrototypesLweCiphertext<Precision32, BinaryKeyDistribution>>::LweCiphertextProto,
+    ) -> <Self as PrototypesLweCiphertext<Precision32, BinaryKeyDistribution>>::LweCiphertextProto
+    {
+        let mut output = <Self as PrototypesLweCiphertext<
+            Precision32,
+            BinaryKeyDistribution,
+        >>::trivially_encrypt_zero_to_lwe_ciphertext(
+            self,
+            keyswitch_key.0.output_lwe_dimension(),
+        );
+        self.core_engine
+            .discard_keyswitch_lwe_ciphertext(&mut output.0, &ciphertext.0, &keyswitch_key.0)
+            .unwrap();
+        output
+    }
 }
 
 impl PrototypesLweKeyswitchKey<Precision64, BinaryKeyDistribution, BinaryKeyDistribution>
@@ -85,4 +112,23 @@ impl PrototypesLweKeyswitchKey<Precision64, BinaryKeyDistribution, BinaryKeyDist
                 .unwrap(),
         )
     }
+
+    fn keyswitch_lwe_ciphertext_with_lwe_keyswitch_key(
+        &mut self,
+        keyswitch_key: &Self::LweKeyswitchKeyProto,
+        ciphertext: &<Self as PrototypesLweCiphertext<Precision64, BinaryKeyDistribution>>::LweCiphertextProto,
+    ) -> <Self as PrototypesLweCiphertext<Precision64, BinaryKeyDistribution>>::LweCiphertextProto
+    {
+        let mut output = <Self as PrototypesLweCiphertext<
+            Precision64,
+            BinaryKeyDistribution,
+        >>::trivially_encrypt_zero_to_lwe_ciphertext(
+            self,
+            keyswitch_key.0.output_lwe_dimension(),
+        );
+        self.core_engine
+            .discard_keyswitch_lwe_ciphertext(&mut output.0, &ciphertext.0, &keyswitch_key.0)
+            .unwrap();
+        output
+    }
 }
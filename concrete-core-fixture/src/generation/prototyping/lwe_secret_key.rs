@@ -4,7 +4,7 @@ use crate::generation::prototypes::{
 use crate::generation::{IntegerPrecision, Maker, Precision32, Precision64};
 use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
-use concrete_core::prelude::LweSecretKeyCreationEngine;
+use concrete_core::prelude::{LweSecretKeyConversionEngine, LweSecretKeyCreationEngine};
 
 /// A trait allowing to manipulate lwe secret key prototypes.
 pub trait PrototypesLweSecretKey<
@@ -42,3 +42,36 @@ impl PrototypesLweSecretKey<Precision64, BinaryKeyDistribution> for Maker {
         )
     }
 }
+
+/// A trait allowing to convert lwe secret key prototypes from one precision to another.
+pub trait PrototypesConvertedLweSecretKey<
+    InputPrecision: IntegerPrecision,
+    OutputPrecision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+>:
+    PrototypesLweSecretKey<InputPrecision, KeyDistribution>
+    + PrototypesLweSecretKey<OutputPrecision, KeyDistribution>
+{
+    fn convert_lwe_secret_key(
+        &mut self,
+        input: &<Self as PrototypesLweSecretKey<InputPrecision, KeyDistribution>>::LweSecretKeyProto,
+    ) -> <Self as PrototypesLweSecretKey<OutputPrecision, KeyDistribution>>::LweSecretKeyProto;
+}
+
+impl PrototypesConvertedLweSecretKey<Precision32, Precision64, BinaryKeyDistribution> for Maker {
+    fn convert_lwe_secret_key(
+        &mut self,
+        input: &ProtoBinaryLweSecretKey32,
+    ) -> ProtoBinaryLweSecretKey64 {
+        ProtoBinaryLweSecretKey64(self.core_engine.convert_lwe_secret_key(&input.0).unwrap())
+    }
+}
+
+impl PrototypesConvertedLweSecretKey<Precision64, Precision32, BinaryKeyDistribution> for Maker {
+    fn convert_lwe_secret_key(
+        &mut self,
+        input: &ProtoBinaryLweSecretKey64,
+    ) -> ProtoBinaryLweSecretKey32 {
+        ProtoBinaryLweSecretKey32(self.core_engine.convert_lwe_secret_key(&input.0).unwrap())
+    }
+}
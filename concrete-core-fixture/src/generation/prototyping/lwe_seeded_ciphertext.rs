@@ -0,0 +1,89 @@
+use crate::generation::prototypes::{
+    LweSeededCiphertextPrototype, ProtoBinaryLweCiphertext32, ProtoBinaryLweCiphertext64,
+    ProtoBinaryLweSeededCiphertext32, ProtoBinaryLweSeededCiphertext64,
+};
+use crate::generation::prototyping::lwe_ciphertext::PrototypesLweCiphertext;
+use crate::generation::{IntegerPrecision, Maker, Precision32, Precision64};
+use concrete_commons::dispersion::Variance;
+use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
+use concrete_core::prelude::{
+    LweSeededCiphertextEncryptionEngine, LweSeededCiphertextExpansionEngine,
+};
+
+/// A trait allowing to manipulate seeded LWE ciphertext prototypes.
+pub trait PrototypesLweSeededCiphertext<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+>: PrototypesLweCiphertext<Precision, KeyDistribution>
+{
+    type LweSeededCiphertextProto: LweSeededCiphertextPrototype<
+        Precision = Precision,
+        KeyDistribution = KeyDistribution,
+    >;
+    fn encrypt_plaintext_to_lwe_seeded_ciphertext(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+        plaintext: &Self::PlaintextProto,
+        noise: Variance,
+    ) -> Self::LweSeededCiphertextProto;
+    fn transform_lwe_seeded_ciphertext_to_lwe_ciphertext(
+        &mut self,
+        seeded_ciphertext: &Self::LweSeededCiphertextProto,
+    ) -> Self::LweCiphertextProto;
+}
+
+impl PrototypesLweSeededCiphertext<Precision32, BinaryKeyDistribution> for Maker {
+    type LweSeededCiphertextProto = ProtoBinaryLweSeededCiphertext32;
+
+    fn encrypt_plaintext_to_lwe_seeded_ciphertext(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+        plaintext: &Self::PlaintextProto,
+        noise: Variance,
+    ) -> Self::LweSeededCiphertextProto {
+        ProtoBinaryLweSeededCiphertext32(
+            self.core_engine
+                .encrypt_lwe_seeded_ciphertext(&secret_key.0, &plaintext.0, noise)
+                .unwrap(),
+        )
+    }
+
+    fn transform_lwe_seeded_ciphertext_to_lwe_ciphertext(
+        &mut self,
+        seeded_ciphertext: &Self::LweSeededCiphertextProto,
+    ) -> Self::LweCiphertextProto {
+        ProtoBinaryLweCiphertext32(
+            self.core_engine
+                .expand_lwe_seeded_ciphertext(&seeded_ciphertext.0)
+                .unwrap(),
+        )
+    }
+}
+
+impl PrototypesLweSeededCiphertext<Precision64, BinaryKeyDistribution> for Maker {
+    type LweSeededCiphertextProto = ProtoBinaryLweSeededCiphertext64;
+
+    fn encrypt_plaintext_to_lwe_seeded_ciphertext(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+        plaintext: &Self::PlaintextProto,
+        noise: Variance,
+    ) -> Self::LweSeededCiphertextProto {
+        ProtoBinaryLweSeededCiphertext64(
+            self.core_engine
+                .encrypt_lwe_seeded_ciphertext(&secret_key.0, &plaintext.0, noise)
+                .unwrap(),
+        )
+    }
+
+    fn transform_lwe_seeded_ciphertext_to_lwe_ciphertext(
+        &mut self,
+        seeded_ciphertext: &Self::LweSeededCiphertextProto,
+    ) -> Self::LweCiphertextProto {
+        ProtoBinaryLweCiphertext64(
+            self.core_engine
+                .expand_lwe_seeded_ciphertext(&seeded_ciphertext.0)
+                .unwrap(),
+        )
+    }
+}
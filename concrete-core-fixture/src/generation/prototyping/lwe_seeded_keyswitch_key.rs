@@ -0,0 +1,116 @@
+use crate::generation::prototypes::{
+    LweSeededKeyswitchKeyPrototype, ProtoBinaryBinaryLweKeyswitchKey32,
+    ProtoBinaryBinaryLweKeyswitchKey64, ProtoBinaryBinaryLweSeededKeyswitchKey32,
+    ProtoBinaryBinaryLweSeededKeyswitchKey64,
+};
+use crate::generation::prototyping::lwe_keyswitch_key::PrototypesLweKeyswitchKey;
+use crate::generation::prototyping::lwe_secret_key::PrototypesLweSecretKey;
+use crate::generation::{IntegerPrecision, Maker, Precision32, Precision64};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
+use concrete_core::prelude::{
+    LweSeededKeyswitchKeyCreationEngine, LweSeededKeyswitchKeyExpansionEngine,
+};
+
+/// A trait allowing to manipulate seeded lwe keyswitch key prototypes.
+pub trait PrototypesLweSeededKeyswitchKey<
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+>: PrototypesLweKeyswitchKey<Precision, InputKeyDistribution, OutputKeyDistribution>
+{
+    type LweSeededKeyswitchKeyProto: LweSeededKeyswitchKeyPrototype<
+        Precision = Precision,
+        InputKeyDistribution = InputKeyDistribution,
+        OutputKeyDistribution = OutputKeyDistribution,
+    >;
+    fn new_lwe_seeded_keyswitch_key(
+        &mut self,
+        input_key: &<Self as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::LweSecretKeyProto,
+        output_key: &<Self as PrototypesLweSecretKey<Precision, OutputKeyDistribution>>::LweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::LweSeededKeyswitchKeyProto;
+    fn transform_lwe_seeded_keyswitch_key_to_lwe_keyswitch_key(
+        &mut self,
+        seeded_keyswitch_key: &Self::LweSeededKeyswitchKeyProto,
+    ) -> Self::LweKeyswitchKeyProto;
+}
+
+impl PrototypesLweSeededKeyswitchKey<Precision32, BinaryKeyDistribution, BinaryKeyDistribution>
+    for Maker
+{
+    type LweSeededKeyswitchKeyProto = ProtoBinaryBinaryLweSeededKeyswitchKey32;
+
+    fn new_lwe_seeded_keyswitch_key(
+        &mut self,
+        input_key: &<Self as PrototypesLweSecretKey<Precision32, BinaryKeyDistribution>>::LweSecretKeyProto,
+        output_key: &<Self as PrototypesLweSecretKey<Precision32, BinaryKeyDistribution>>::LweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::LweSeededKeyswitchKeyProto {
+        ProtoBinaryBinaryLweSeededKeyswitchKey32(
+            self.core_engine
+                .create_lwe_seeded_keyswitch_key(
+                    &input_key.0,
+                    &output_key.0,
+                    decomposition_level,
+                    decomposition_base_log,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn transform_lwe_seeded_keyswitch_key_to_lwe_keyswitch_key(
+        &mut self,
+        seeded_keyswitch_key: &Self::LweSeededKeyswitchKeyProto,
+    ) -> Self::LweKeyswitchKeyProto {
+        ProtoBinaryBinaryLweKeyswitchKey32(
+            self.core_engine
+                .expand_lwe_seeded_keyswitch_key(&seeded_keyswitch_key.0)
+                .unwrap(),
+        )
+    }
+}
+
+impl PrototypesLweSeededKeyswitchKey<Precision64, BinaryKeyDistribution, BinaryKeyDistribution>
+    for Maker
+{
+    type LweSeededKeyswitchKeyProto = ProtoBinaryBinaryLweSeededKeyswitchKey64;
+
+    fn new_lwe_seeded_keyswitch_key(
+        &mut self,
+        input_key: &<Self as PrototypesLweSecretKey<Precision64, BinaryKeyDistribution>>::LweSecretKeyProto,
+        output_key: &<Self as PrototypesLweSecretKey<Precision64, BinaryKeyDistribution>>::LweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::LweSeededKeyswitchKeyProto {
+        ProtoBinaryBinaryLweSeededKeyswitchKey64(
+            self.core_engine
+                .create_lwe_seeded_keyswitch_key(
+                    &input_key.0,
+                    &output_key.0,
+                    decomposition_level,
+                    decomposition_base_log,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn transform_lwe_seeded_keyswitch_key_to_lwe_keyswitch_key(
+        &mut self,
+        seeded_keyswitch_key: &Self::LweSeededKeyswitchKeyProto,
+    ) -> Self::LweKeyswitchKeyProto {
+        ProtoBinaryBinaryLweKeyswitchKey64(
+            self.core_engine
+                .expand_lwe_seeded_keyswitch_key(&seeded_keyswitch_key.0)
+                .unwrap(),
+        )
+    }
+}
@@ -0,0 +1,62 @@
+use crate::generation::prototyping::PrototypesGswCiphertext;
+use crate::generation::IntegerPrecision;
+use concrete_core::prelude::GswCiphertextEntity;
+
+/// A trait allowing to synthesize an actual gsw ciphertext entity from a prototype.
+pub trait SynthesizesGswCiphertext<Precision: IntegerPrecision, GswCiphertext>:
+    PrototypesGswCiphertext<Precision, GswCiphertext::KeyDistribution>
+where
+    GswCiphertext: GswCiphertextEntity,
+{
+    fn synthesize_gsw_ciphertext(&mut self, prototype: &Self::GswCiphertextProto) -> GswCiphertext;
+    fn unsynthesize_gsw_ciphertext(&mut self, entity: &GswCiphertext) -> Self::GswCiphertextProto;
+    fn destroy_gsw_ciphertext(&mut self, entity: GswCiphertext);
+}
+
+#[cfg(feature = "backend_core")]
+mod backend_core {
+    use crate::generation::prototypes::{ProtoBinaryGswCiphertext32, ProtoBinaryGswCiphertext64};
+    use crate::generation::synthesizing::SynthesizesGswCiphertext;
+    use crate::generation::{Maker, Precision32, Precision64};
+    use concrete_core::prelude::{DestructionEngine, GswCiphertext32, GswCiphertext64};
+
+    impl SynthesizesGswCiphertext<Precision32, GswCiphertext32> for Maker {
+        fn synthesize_gsw_ciphertext(
+            &mut self,
+            prototype: &Self::GswCiphertextProto,
+        ) -> GswCiphertext32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_gsw_ciphertext(
+            &mut self,
+            entity: &GswCiphertext32,
+        ) -> Self::GswCiphertextProto {
+            ProtoBinaryGswCiphertext32(entity.to_owned())
+        }
+
+        fn destroy_gsw_ciphertext(&mut self, entity: GswCiphertext32) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+
+    impl SynthesizesGswCiphertext<Precision64, GswCiphertext64> for Maker {
+        fn synthesize_gsw_ciphertext(
+            &mut self,
+            prototype: &Self::GswCiphertextProto,
+        ) -> GswCiphertext64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_gsw_ciphertext(
+            &mut self,
+            entity: &GswCiphertext64,
+        ) -> Self::GswCiphertextProto {
+            ProtoBinaryGswCiphertext64(entity.to_owned())
+        }
+
+        fn destroy_gsw_ciphertext(&mut self, entity: GswCiphertext64) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::generation::prototyping::PrototypesLweSeededKeyswitchKey;
+use crate::generation::IntegerPrecision;
+use concrete_core::prelude::LweSeededKeyswitchKeyEntity;
+
+pub trait SynthesizesLweSeededKeyswitchKey<Precision: IntegerPrecision, LweSeededKeyswitchKey>:
+    PrototypesLweSeededKeyswitchKey<
+    Precision,
+    LweSeededKeyswitchKey::InputKeyDistribution,
+    LweSeededKeyswitchKey::OutputKeyDistribution,
+>
+where
+    LweSeededKeyswitchKey: LweSeededKeyswitchKeyEntity,
+{
+    fn synthesize_lwe_seeded_keyswitch_key(
+        &mut self,
+        prototype: &Self::LweSeededKeyswitchKeyProto,
+    ) -> LweSeededKeyswitchKey;
+    fn unsynthesize_lwe_seeded_keyswitch_key(
+        &mut self,
+        entity: &LweSeededKeyswitchKey,
+    ) -> Self::LweSeededKeyswitchKeyProto;
+    fn destroy_lwe_seeded_keyswitch_key(&mut self, entity: LweSeededKeyswitchKey);
+}
+
+#[cfg(feature = "backend_core")]
+mod backend_core {
+    use crate::generation::prototypes::{
+        ProtoBinaryBinaryLweSeededKeyswitchKey32, ProtoBinaryBinaryLweSeededKeyswitchKey64,
+    };
+    use crate::generation::synthesizing::SynthesizesLweSeededKeyswitchKey;
+    use crate::generation::{Maker, Precision32, Precision64};
+    use concrete_core::prelude::{
+        DestructionEngine, LweSeededKeyswitchKey32, LweSeededKeyswitchKey64,
+    };
+
+    impl SynthesizesLweSeededKeyswitchKey<Precision32, LweSeededKeyswitchKey32> for Maker {
+        fn synthesize_lwe_seeded_keyswitch_key(
+            &mut self,
+            prototype: &Self::LweSeededKeyswitchKeyProto,
+        ) -> LweSeededKeyswitchKey32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_seeded_keyswitch_key(
+            &mut self,
+            entity: &LweSeededKeyswitchKey32,
+        ) -> Self::LweSeededKeyswitchKeyProto {
+            ProtoBinaryBinaryLweSeededKeyswitchKey32(entity.to_owned())
+        }
+
+        fn destroy_lwe_seeded_keyswitch_key(&mut self, entity: LweSeededKeyswitchKey32) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+
+    impl SynthesizesLweSeededKeyswitchKey<Precision64, LweSeededKeyswitchKey64> for Maker {
+        fn synthesize_lwe_seeded_keyswitch_key(
+            &mut self,
+            prototype: &Self::LweSeededKeyswitchKeyProto,
+        ) -> LweSeededKeyswitchKey64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_seeded_keyswitch_key(
+            &mut self,
+            entity: &LweSeededKeyswitchKey64,
+        ) -> Self::LweSeededKeyswitchKeyProto {
+            ProtoBinaryBinaryLweSeededKeyswitchKey64(entity.to_owned())
+        }
+
+        fn destroy_lwe_seeded_keyswitch_key(&mut self, entity: LweSeededKeyswitchKey64) {
+            self.core_engine.destroy(entity).unwrap();
+        }
+    }
+}
@@ -8,12 +8,16 @@ mod cleartext_vector;
 mod ggsw_ciphertext;
 mod glwe_ciphertext;
 mod glwe_ciphertext_vector;
+mod glwe_relinearization_key;
 mod glwe_secret_key;
+mod gsw_ciphertext;
 mod lwe_bootstrap_key;
 mod lwe_ciphertext;
 mod lwe_ciphertext_vector;
 mod lwe_keyswitch_key;
 mod lwe_secret_key;
+mod lwe_seeded_ciphertext;
+mod lwe_seeded_keyswitch_key;
 mod plaintext;
 mod plaintext_vector;
 
@@ -22,11 +26,15 @@ pub use cleartext_vector::*;
 pub use ggsw_ciphertext::*;
 pub use glwe_ciphertext::*;
 pub use glwe_ciphertext_vector::*;
+pub use glwe_relinearization_key::*;
 pub use glwe_secret_key::*;
+pub use gsw_ciphertext::*;
 pub use lwe_bootstrap_key::*;
 pub use lwe_ciphertext::*;
 pub use lwe_ciphertext_vector::*;
 pub use lwe_keyswitch_key::*;
 pub use lwe_secret_key::*;
+pub use lwe_seeded_ciphertext::*;
+pub use lwe_seeded_keyswitch_key::*;
 pub use plaintext::*;
 pub use plaintext_vector::*;
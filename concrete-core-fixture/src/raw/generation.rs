@@ -1,9 +1,28 @@
 //! A module containing sampling entry points for raw integers
 use concrete_commons::numeric::{CastInto, UnsignedInteger};
 use concrete_core::backends::core::private::math::random::RandomGenerator;
+use std::cell::RefCell;
 use std::fmt::Debug;
 use std::ops::Range;
 
+thread_local! {
+    static RAW_GENERATOR: RefCell<RandomGenerator> = RefCell::new(RandomGenerator::new(None));
+}
+
+/// Seeds the generator used to sample raw values on the current thread.
+///
+/// All the raw values sampled afterwards on this thread are derived from this seed, which allows
+/// to replay the generation of the inputs of a fixture.
+pub fn seed_raw_generator(seed: u128) {
+    RAW_GENERATOR.with(|generator| *generator.borrow_mut() = RandomGenerator::new(Some(seed)));
+}
+
+/// Returns a new random generator, seeded from the raw generator of the current thread.
+pub(crate) fn new_raw_generator() -> RandomGenerator {
+    RAW_GENERATOR
+        .with(|generator| RandomGenerator::new(Some(generator.borrow_mut().random_uniform())))
+}
+
 /// A trait to generate raw unsigned integer values.
 pub trait RawUnsignedIntegers: UnsignedInteger + CastInto<f64> + CastInto<i64> + Debug {
     fn one() -> Self;
@@ -47,14 +66,14 @@ impl RawUnsignedIntegers for u32 {
     }
 
     fn pick(array: &[Self]) -> Self {
-        let mut generator = RandomGenerator::new(None);
+        let mut generator = new_raw_generator();
         let index: u16 = generator.random_uniform();
         let index = index % array.len() as u16;
         array[index as usize]
     }
 
     fn pick_vec(array: &[Self], size: usize) -> Vec<Self> {
-        let mut generator = RandomGenerator::new(None);
+        let mut generator = new_raw_generator();
         (0..size)
             .map(|_| {
                 let index: u16 = generator.random_uniform();
@@ -65,32 +84,32 @@ impl RawUnsignedIntegers for u32 {
     }
 
     fn uniform() -> Self {
-        let mut generator = RandomGenerator::new(None);
+        let mut generator = new_raw_generator();
         generator.random_uniform()
     }
     fn uniform_vec(size: usize) -> Vec<Self> {
-        let mut generator = RandomGenerator::new(None);
+        let mut generator = new_raw_generator();
         generator.random_uniform_tensor(size).into_container()
     }
     fn uniform_n_msb(n: usize) -> Self {
-        let mut generator = RandomGenerator::new(None);
+        let mut generator = new_raw_generator();
         generator.random_uniform_n_msb(n)
     }
     fn uniform_n_msb_vec(n: usize, size: usize) -> Vec<Self> {
-        let mut generator = RandomGenerator::new(None);
+        let mut generator = new_raw_generator();
         generator
             .random_uniform_n_msb_tensor(size, n)
             .into_container()
     }
 
     fn uniform_between(range: Range<usize>) -> Self {
-        let mut generator = RandomGenerator::new(None);
+        let mut generator = new_raw_generator();
         let val: u32 = generator.random_uniform();
         val % ((range.end as u32) - (range.start as u32)) + (range.start as u32)
     }
 
     fn uniform_between_vec(range: Range<usize>, size: usize) -> Vec<Self> {
-        let mut generator = RandomGenerator::new(None);
+        let mut generator = new_raw_generator();
         let mut output = generator.random_uniform_tensor(size).into_container();
         output.iter_mut().for_each(|val| {
             *val %= ((range.end as u32) - (range.start as u32)) + (range.start as u32)
@@ -106,7 +125,7 @@ impl RawUnsignedIntegers for u32 {
         val as u32
     }
     fn uniform_zero_centered_vec(width: usize, size: usize) -> Vec<Self> {
-        let mut generator = RandomGenerator::new(None);
+        let mut generator = new_raw_generator();
         let mut output = generator.random_uniform_tensor(size).into_container();
         output.iter_mut().for_each(|val| {
             let v = *val % (width as u32);
@@ -140,14 +159,14 @@ impl RawUnsignedIntegers for u64 {
     }
 
     fn pick(array: &[Self]) -> Self {
-        let mut generator = RandomGenerator::new(None);
+        let mut generator = new_raw_generator();
         let index: u16 = generator.random_uniform();
         let index = index % array.len() as u16;
         array[index as usize]
     }
 
     fn pick_vec(array: &[Self], size: usize) -> Vec<Self> {
-        let mut generator = RandomGenerator::new(None);
+        let mut generator = new_raw_generator();
         (0..size)
             .map(|_| {
                 let index: u16 = generator.random_uniform();
@@ -157,32 +176,32 @@ impl RawUnsignedIntegers for u64 {
             .collect()
     }
     fn uniform() -> Self {
-        let mut generator = RandomGenerator::new(None);
+        let mut generator = new_raw_generator();
         generator.random_uniform()
     }
     fn uniform_vec(size: usize) -> Vec<Self> {
-        let mut generator = RandomGenerator::new(None);
+        let mut generator = new_raw_generator();
         generator.random_uniform_tensor(size).into_container()
     }
     fn uniform_n_msb(n: usize) -> Self {
-        let mut generator = RandomGenerator::new(None);
+        let mut generator = new_raw_generator();
         generator.random_uniform_n_msb(n)
     }
     fn uniform_n_msb_vec(n: usize, size: usize) -> Vec<Self> {
-        let mut generator = RandomGenerator::new(None);
+        let mut generator = new_raw_generator();
         generator
             .random_uniform_n_msb_tensor(size, n)
             .into_container()
     }
 
     fn uniform_between(range: Range<usize>) -> Self {
-        let mut generator = RandomGenerator::new(None);
+        let mut generator = new_raw_generator();
         let val: u64 = generator.random_uniform();
         val % ((range.end as u64) - (range.start as u64)) + (range.start as u64)
     }
 
     fn uniform_between_vec(range: Range<usize>, size: usize) -> Vec<Self> {
-        let mut generator = RandomGenerator::new(None);
+        let mut generator = new_raw_generator();
         let mut output = generator.random_uniform_tensor(size).into_container();
         output.iter_mut().for_each(|val| {
             *val %= ((range.end as u64) - (range.start as u64)) + (range.start as u64)
@@ -198,7 +217,7 @@ impl RawUnsignedIntegers for u64 {
         val as u64
     }
     fn uniform_zero_centered_vec(width: usize, size: usize) -> Vec<Self> {
-        let mut generator = RandomGenerator::new(None);
+        let mut generator = new_raw_generator();
         let mut output = generator.random_uniform_tensor(size).into_container();
         output.iter_mut().for_each(|val| {
             let v = *val % (width as u64);
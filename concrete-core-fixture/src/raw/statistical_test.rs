@@ -1,7 +1,6 @@
 //! A module containing statistical testing entry points for raw integers
-use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::generation::{new_raw_generator, RawUnsignedIntegers};
 use concrete_commons::dispersion::{DispersionParameter, Variance};
-use concrete_core::backends::core::private::math::random::RandomGenerator;
 use kolmogorov_smirnov;
 
 /// A function performing a Kolmogorov Smirnov statistical test.
@@ -22,7 +21,7 @@ where
     let std_dev = expected_variance.get_standard_dev();
     let confidence = 0.95;
     let n_slots = expected_means.len();
-    let mut generator = RandomGenerator::new(None);
+    let mut generator = new_raw_generator();
 
     // allocate 2 slices: one for the error samples obtained, the second for fresh samples
     // according to the std_dev computed
@@ -1,4 +1,4 @@
-use crate::{REPETITIONS, SAMPLE_SIZE};
+use crate::{test_seed, REPETITIONS, SAMPLE_SIZE};
 use concrete_core::prelude::*;
 use concrete_core_fixture::fixture::*;
 use concrete_core_fixture::generation::{Maker, Precision32, Precision64};
@@ -9,8 +9,9 @@ macro_rules! test {
         paste!{
             #[test]
             fn [< test_ $fixture:snake _ $precision:snake _ $($types:snake)_+ >]() {
-                let mut maker = Maker::default();
-                let mut engine = CoreEngine::new().unwrap();
+                let seed = test_seed();
+                let mut maker = Maker::new_with_seed(seed);
+                let mut engine = CoreEngine::new_with_seed(seed.wrapping_add(1)).unwrap();
                 let test_result =
                     <$fixture as Fixture<
                         $precision,
@@ -95,7 +96,14 @@ test! {
     (LweCiphertextPlaintextFusingSubtractionFixture, (Plaintext, LweCiphertext)),
     (LweCiphertextDiscardingBootstrapFixture1, (FourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext)),
     (LweCiphertextDiscardingBootstrapFixture2, (FourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext)),
+    (LweCiphertextVectorDiscardingBootstrapFixture, (FourierLweBootstrapKey, GlweCiphertextVector, LweCiphertextVector, LweCiphertextVector)),
+    (LweCiphertextVectorDiscardingKeyswitchFixture, (LweKeyswitchKey, LweCiphertextVector, LweCiphertextVector)),
     (LweCiphertextDiscardingExtractionFixture, (GlweCiphertext, LweCiphertext)),
+    (LweCiphertextVectorDiscardingExtractionFixture, (GlweCiphertext, LweCiphertextVector)),
+    (LweCiphertextLoadingFixture, (LweCiphertextVector, LweCiphertext)),
+    (LweCiphertextDiscardingLoadingFixture, (LweCiphertextVector, LweCiphertext)),
+    (LweCiphertextDiscardingStoringFixture, (LweCiphertext, LweCiphertextVector)),
+    (LweCiphertextVectorLoadingFixture, (LweCiphertextVector, LweCiphertextVector)),
     (PlaintextCreationFixture, (Plaintext)),
     (PlaintextDiscardingRetrievalFixture, (Plaintext)),
     (PlaintextRetrievalFixture, (Plaintext)),
@@ -103,5 +111,22 @@ test! {
     (PlaintextVectorCreationFixture, (PlaintextVector)),
     (PlaintextVectorRetrievalFixture, (PlaintextVector)),
     (GlweCiphertextGgswCiphertextExternalProductFixture, (GlweCiphertext, FourierGgswCiphertext, GlweCiphertext)),
-    (GlweCiphertextGgswCiphertextDiscardingExternalProductFixture, (GlweCiphertext, FourierGgswCiphertext, GlweCiphertext))
+    (GlweCiphertextGgswCiphertextDiscardingExternalProductFixture, (GlweCiphertext, FourierGgswCiphertext, GlweCiphertext)),
+    (LweCiphertextGswCiphertextExternalProductFixture, (LweCiphertext, GswCiphertext, LweCiphertext)),
+    (LweCiphertextDiscardingCmuxFixture, (LweCiphertext, GswCiphertext, LweCiphertext)),
+    (GlweCiphertextGgswCiphertextDiscardingCmuxFixture, (GlweCiphertext, FourierGgswCiphertext, GlweCiphertext)),
+    (LweCiphertextBlindRotationFixture, (FourierLweBootstrapKey, GlweCiphertext, LweCiphertext, GlweCiphertext)),
+    (LweSeededCiphertextEncryptionFixture, (Plaintext, LweSecretKey, LweSeededCiphertext)),
+    (LweSeededCiphertextExpansionFixture, (LweSeededCiphertext, LweCiphertext)),
+    (LweSeededKeyswitchKeyCreationFixture, (LweSecretKey, LweSecretKey, LweSeededKeyswitchKey)),
+    (LweSeededKeyswitchKeyExpansionFixture, (LweSeededKeyswitchKey, LweKeyswitchKey)),
+    (GlweCiphertextDiscardingTensorProductFixture, (GlweCiphertext, GlweCiphertext, GlweRelinearizationKey, GlweCiphertext)),
+    (GlweRelinearizationKeyCreationFixture, (GlweSecretKey, GlweRelinearizationKey))
+}
+
+test! {
+    (LweCiphertextConversionFixture, Precision32, (LweCiphertext32, LweCiphertext64)),
+    (LweCiphertextConversionFixture, Precision64, (LweCiphertext64, LweCiphertext32)),
+    (LweCiphertextDiscardingConversionFixture, Precision32, (LweCiphertext32, LweCiphertext64)),
+    (LweCiphertextDiscardingConversionFixture, Precision64, (LweCiphertext64, LweCiphertext32))
 }
@@ -0,0 +1,47 @@
+use crate::{test_seed, REPETITIONS, SAMPLE_SIZE};
+use concrete_core::prelude::*;
+use concrete_core_fixture::fixture::*;
+use concrete_core_fixture::generation::{Maker, Precision32, Precision64};
+use paste::paste;
+
+macro_rules! test {
+    ($fixture: ident, $precision: ident, ($($types:ident),+)) => {
+        paste!{
+            #[test]
+            fn [< test_ $fixture:snake _ $precision:snake _ $($types:snake)_+ >]() {
+                let seed = test_seed();
+                let mut maker = Maker::new_with_seed(seed);
+                let mut engine = CoreParallelEngine::new_with_seed(seed.wrapping_add(1)).unwrap();
+                let test_result =
+                    <$fixture as Fixture<
+                        $precision,
+                        CoreParallelEngine,
+                        ($($types,)+),
+                    >>::stress_all_parameters(&mut maker, &mut engine, REPETITIONS, SAMPLE_SIZE);
+                assert!(test_result);
+            }
+        }
+    };
+    ($(($fixture: ident, $precision: ident, ($($types:ident),+))),+) => {
+        $(
+            test!{$fixture, $precision, ($($types),+)}
+        )+
+    };
+    ($(($fixture: ident, ($($types:ident),+))),+) => {
+        $(
+            paste!{
+                test!{$fixture, Precision32, ($([< $types 32 >]),+)}
+                test!{$fixture, Precision64, ($([< $types 64 >]),+)}
+            }
+        )+
+    };
+}
+
+test! {
+    (LweCiphertextVectorZeroEncryptionFixture, (LweSecretKey, LweCiphertextVector)),
+    (LweCiphertextVectorEncryptionFixture, (PlaintextVector, LweSecretKey, LweCiphertextVector)),
+    (LweCiphertextVectorDiscardingEncryptionFixture, (PlaintextVector, LweSecretKey, LweCiphertextVector)),
+    (LweCiphertextVectorDiscardingAffineTransformationFixture, (LweCiphertextVector, CleartextVector, Plaintext, LweCiphertext)),
+    (LweCiphertextVectorDiscardingBootstrapFixture, (FourierLweBootstrapKey, GlweCiphertextVector, LweCiphertextVector, LweCiphertextVector)),
+    (LweCiphertextVectorDiscardingKeyswitchFixture, (LweKeyswitchKey, LweCiphertextVector, LweCiphertextVector))
+}
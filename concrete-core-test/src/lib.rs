@@ -4,6 +4,7 @@
 //! Each submodule here is expected to be activated by a given feature flag (matching the
 //! `backend_*` naming), and to contain the instantiation of a generic correctness test for every
 //! implemented operator.
+use concrete_core::backends::core::private::math::random::RandomGenerator;
 use concrete_core_fixture::{Repetitions, SampleSize};
 
 /// The number of time a test is repeated for a single set of parameter.
@@ -12,8 +13,29 @@ pub const REPETITIONS: Repetitions = Repetitions(10);
 /// The size of the sample used to perform statistical tests.
 pub const SAMPLE_SIZE: SampleSize = SampleSize(100);
 
+/// The environment variable used to replay a test with a given seed.
+pub const SEED_VARIABLE: &str = "CONCRETE_TEST_SEED";
+
+/// Returns the seed from which all the randomness of a test is derived.
+///
+/// The seed is read from the `CONCRETE_TEST_SEED` environment variable if it is set, and drawn at
+/// random otherwise. In both cases, it is printed such that a failing test can be replayed.
+pub fn test_seed() -> u128 {
+    let seed = match std::env::var(SEED_VARIABLE) {
+        Ok(value) => value
+            .parse()
+            .unwrap_or_else(|_| panic!("{} must hold an unsigned integer.", SEED_VARIABLE)),
+        Err(_) => RandomGenerator::new(None).random_uniform(),
+    };
+    println!("{}={}", SEED_VARIABLE, seed);
+    seed
+}
+
 #[cfg(all(test, feature = "backend_core"))]
 pub mod core;
 
+#[cfg(all(test, feature = "backend_core", feature = "multithread"))]
+pub mod core_parallel;
+
 #[cfg(all(test, feature = "backend_optalysys"))]
 pub mod optalysys;
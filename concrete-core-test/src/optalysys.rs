@@ -1,4 +1,4 @@
-use crate::{REPETITIONS, SAMPLE_SIZE};
+use crate::{test_seed, REPETITIONS, SAMPLE_SIZE};
 use concrete_core::prelude::*;
 use concrete_core_fixture::fixture::*;
 use concrete_core_fixture::generation::{Maker, Precision32, Precision64};
@@ -9,8 +9,9 @@ macro_rules! test {
         paste!{
             #[test]
             fn [< test_ $fixture:snake _ $precision:snake _ $($types:snake)_+ >]() {
-                let mut maker = Maker::default();
-                let mut engine = CoreEngine::new().unwrap();
+                let seed = test_seed();
+                let mut maker = Maker::new_with_seed(seed);
+                let mut engine = OptalysysEngine::new_with_seed(seed.wrapping_add(1)).unwrap();
                 let test_result =
                     <$fixture as Fixture<
                         $precision,
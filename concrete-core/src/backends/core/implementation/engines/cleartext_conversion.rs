@@ -0,0 +1,77 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{Cleartext32, Cleartext64};
+use crate::backends::core::private::crypto::encoding::Cleartext as ImplCleartext;
+use crate::specification::engines::{CleartextConversionEngine, CleartextConversionError};
+use concrete_commons::numeric::CastInto;
+
+/// # Description:
+/// Implementation of [`CleartextConversionEngine`] for [`CoreEngine`] that converts a 32 bits
+/// cleartext into a 64 bits cleartext, by casting the integer values.
+impl CleartextConversionEngine<Cleartext32, Cleartext64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let input: Cleartext32 = engine.create_cleartext(&3_u32)?;
+    ///
+    /// let output: Cleartext64 = engine.convert_cleartext(&input)?;
+    /// #
+    /// let value: u64 = engine.retrieve_cleartext(&output)?;
+    /// assert_eq!(value, 3);
+    ///
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_cleartext(
+        &mut self,
+        input: &Cleartext32,
+    ) -> Result<Cleartext64, CleartextConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_cleartext_unchecked(input) })
+    }
+
+    unsafe fn convert_cleartext_unchecked(&mut self, input: &Cleartext32) -> Cleartext64 {
+        Cleartext64(ImplCleartext(input.0 .0.cast_into()))
+    }
+}
+
+/// # Description:
+/// Implementation of [`CleartextConversionEngine`] for [`CoreEngine`] that converts a 64 bits
+/// cleartext into a 32 bits cleartext, by casting the integer values.
+impl CleartextConversionEngine<Cleartext64, Cleartext32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let input: Cleartext64 = engine.create_cleartext(&3_u64)?;
+    ///
+    /// let output: Cleartext32 = engine.convert_cleartext(&input)?;
+    /// #
+    /// let value: u32 = engine.retrieve_cleartext(&output)?;
+    /// assert_eq!(value, 3);
+    ///
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_cleartext(
+        &mut self,
+        input: &Cleartext64,
+    ) -> Result<Cleartext32, CleartextConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_cleartext_unchecked(input) })
+    }
+
+    unsafe fn convert_cleartext_unchecked(&mut self, input: &Cleartext64) -> Cleartext32 {
+        Cleartext32(ImplCleartext(input.0 .0.cast_into()))
+    }
+}
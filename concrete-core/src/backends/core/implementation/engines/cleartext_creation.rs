@@ -1,5 +1,5 @@
 use crate::backends::core::implementation::engines::CoreEngine;
-use crate::backends::core::implementation::entities::{Cleartext32, Cleartext64};
+use crate::backends::core::implementation::entities::{Cleartext32, Cleartext64, CleartextF64};
 use crate::backends::core::private::crypto::encoding::Cleartext as ImplCleartext;
 use crate::specification::engines::{CleartextCreationEngine, CleartextCreationError};
 
@@ -64,3 +64,34 @@ impl CleartextCreationEngine<u64, Cleartext64> for CoreEngine {
         Cleartext64(ImplCleartext(*input))
     }
 }
+
+/// # Description:
+/// Implementation of [`CleartextCreationEngine`] for [`CoreEngine`] that operates on 64 bits
+/// floating point numbers.
+impl CleartextCreationEngine<f64, CleartextF64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input: f64 = 3.;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let cleartext: CleartextF64 = engine.create_cleartext(&input)?;
+    /// engine.destroy(cleartext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_cleartext(
+        &mut self,
+        input: &f64,
+    ) -> Result<CleartextF64, CleartextCreationError<Self::EngineError>> {
+        Ok(unsafe { self.create_cleartext_unchecked(input) })
+    }
+
+    unsafe fn create_cleartext_unchecked(&mut self, input: &f64) -> CleartextF64 {
+        CleartextF64(ImplCleartext(*input))
+    }
+}
@@ -0,0 +1,92 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{Cleartext32, Cleartext64};
+use crate::specification::engines::{
+    CleartextDiscardingConversionEngine, CleartextDiscardingConversionError,
+};
+use concrete_commons::numeric::CastInto;
+
+/// # Description:
+/// Implementation of [`CleartextDiscardingConversionEngine`] for [`CoreEngine`] that converts a 32
+/// bits cleartext into a 64 bits cleartext, by casting the integer values.
+impl CleartextDiscardingConversionEngine<Cleartext32, Cleartext64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let input: Cleartext32 = engine.create_cleartext(&3_u32)?;
+    /// let mut output: Cleartext64 = engine.create_cleartext(&0_u64)?;
+    ///
+    /// engine.discard_convert_cleartext(&mut output, &input)?;
+    /// #
+    /// let value: u64 = engine.retrieve_cleartext(&output)?;
+    /// assert_eq!(value, 3);
+    ///
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_cleartext(
+        &mut self,
+        output: &mut Cleartext64,
+        input: &Cleartext32,
+    ) -> Result<(), CleartextDiscardingConversionError<Self::EngineError>> {
+        unsafe { self.discard_convert_cleartext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_cleartext_unchecked(
+        &mut self,
+        output: &mut Cleartext64,
+        input: &Cleartext32,
+    ) {
+        output.0 .0 = input.0 .0.cast_into();
+    }
+}
+
+/// # Description:
+/// Implementation of [`CleartextDiscardingConversionEngine`] for [`CoreEngine`] that converts a 64
+/// bits cleartext into a 32 bits cleartext, by casting the integer values.
+impl CleartextDiscardingConversionEngine<Cleartext64, Cleartext32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let input: Cleartext64 = engine.create_cleartext(&3_u64)?;
+    /// let mut output: Cleartext32 = engine.create_cleartext(&0_u32)?;
+    ///
+    /// engine.discard_convert_cleartext(&mut output, &input)?;
+    /// #
+    /// let value: u32 = engine.retrieve_cleartext(&output)?;
+    /// assert_eq!(value, 3);
+    ///
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_cleartext(
+        &mut self,
+        output: &mut Cleartext32,
+        input: &Cleartext64,
+    ) -> Result<(), CleartextDiscardingConversionError<Self::EngineError>> {
+        unsafe { self.discard_convert_cleartext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_cleartext_unchecked(
+        &mut self,
+        output: &mut Cleartext32,
+        input: &Cleartext64,
+    ) {
+        output.0 .0 = input.0 .0.cast_into();
+    }
+}
@@ -0,0 +1,224 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    Cleartext32, Cleartext64, CleartextF64, FloatEncoder, IntegerEncoder, Plaintext32, Plaintext64,
+};
+use crate::prelude::CoreError;
+use crate::specification::engines::{CleartextEncodingEngine, CleartextEncodingError};
+
+impl From<CoreError> for CleartextEncodingError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`CleartextEncodingEngine`] for [`CoreEngine`] that encodes 64 bits floating
+/// point numbers into 32 bits integers.
+impl CleartextEncodingEngine<FloatEncoder, CleartextF64, Plaintext32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let encoder = engine.create_encoder(&FloatEncoderMinMaxConfig {
+    ///     min: 0.,
+    ///     max: 10.,
+    ///     nb_bit_precision: 8,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    /// let cleartext: CleartextF64 = engine.create_cleartext(&5.)?;
+    /// let plaintext: Plaintext32 = engine.encode_cleartext(&encoder, &cleartext)?;
+    /// #
+    /// engine.destroy(encoder)?;
+    /// engine.destroy(cleartext)?;
+    /// engine.destroy(plaintext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encode_cleartext(
+        &mut self,
+        encoder: &FloatEncoder,
+        cleartext: &CleartextF64,
+    ) -> Result<Plaintext32, CleartextEncodingError<Self::EngineError>> {
+        if !encoder.0.fits_in::<u32>() {
+            return Err(CleartextEncodingError::from(
+                CoreError::EncodingPrecisionTooLarge,
+            ));
+        }
+        if !encoder.0.is_message_in_interval(cleartext.0 .0) {
+            return Err(CleartextEncodingError::from(
+                CoreError::MessageOutsideEncoderInterval,
+            ));
+        }
+        Ok(unsafe { self.encode_cleartext_unchecked(encoder, cleartext) })
+    }
+
+    unsafe fn encode_cleartext_unchecked(
+        &mut self,
+        encoder: &FloatEncoder,
+        cleartext: &CleartextF64,
+    ) -> Plaintext32 {
+        Plaintext32(encoder.0.encode(cleartext.0))
+    }
+}
+
+/// # Description:
+/// Implementation of [`CleartextEncodingEngine`] for [`CoreEngine`] that encodes 64 bits floating
+/// point numbers into 64 bits integers.
+impl CleartextEncodingEngine<FloatEncoder, CleartextF64, Plaintext64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let encoder = engine.create_encoder(&FloatEncoderMinMaxConfig {
+    ///     min: 0.,
+    ///     max: 10.,
+    ///     nb_bit_precision: 8,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    /// let cleartext: CleartextF64 = engine.create_cleartext(&5.)?;
+    /// let plaintext: Plaintext64 = engine.encode_cleartext(&encoder, &cleartext)?;
+    /// #
+    /// engine.destroy(encoder)?;
+    /// engine.destroy(cleartext)?;
+    /// engine.destroy(plaintext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encode_cleartext(
+        &mut self,
+        encoder: &FloatEncoder,
+        cleartext: &CleartextF64,
+    ) -> Result<Plaintext64, CleartextEncodingError<Self::EngineError>> {
+        if !encoder.0.fits_in::<u64>() {
+            return Err(CleartextEncodingError::from(
+                CoreError::EncodingPrecisionTooLarge,
+            ));
+        }
+        if !encoder.0.is_message_in_interval(cleartext.0 .0) {
+            return Err(CleartextEncodingError::from(
+                CoreError::MessageOutsideEncoderInterval,
+            ));
+        }
+        Ok(unsafe { self.encode_cleartext_unchecked(encoder, cleartext) })
+    }
+
+    unsafe fn encode_cleartext_unchecked(
+        &mut self,
+        encoder: &FloatEncoder,
+        cleartext: &CleartextF64,
+    ) -> Plaintext64 {
+        Plaintext64(encoder.0.encode(cleartext.0))
+    }
+}
+
+/// # Description:
+/// Implementation of [`CleartextEncodingEngine`] for [`CoreEngine`] that encodes 32 bits unsigned
+/// integers into 32 bits integers.
+impl CleartextEncodingEngine<IntegerEncoder, Cleartext32, Plaintext32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let encoder = engine.create_encoder(&IntegerEncoderConfig {
+    ///     nb_bit_precision: 4,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    /// let cleartext: Cleartext32 = engine.create_cleartext(&5_u32)?;
+    /// let plaintext: Plaintext32 = engine.encode_cleartext(&encoder, &cleartext)?;
+    /// #
+    /// engine.destroy(encoder)?;
+    /// engine.destroy(cleartext)?;
+    /// engine.destroy(plaintext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encode_cleartext(
+        &mut self,
+        encoder: &IntegerEncoder,
+        cleartext: &Cleartext32,
+    ) -> Result<Plaintext32, CleartextEncodingError<Self::EngineError>> {
+        if !encoder.0.fits_in::<u32>() {
+            return Err(CleartextEncodingError::from(
+                CoreError::EncodingPrecisionTooLarge,
+            ));
+        }
+        if !encoder.0.is_message_in_interval(cleartext.0 .0) {
+            return Err(CleartextEncodingError::from(
+                CoreError::MessageOutsideEncoderInterval,
+            ));
+        }
+        Ok(unsafe { self.encode_cleartext_unchecked(encoder, cleartext) })
+    }
+
+    unsafe fn encode_cleartext_unchecked(
+        &mut self,
+        encoder: &IntegerEncoder,
+        cleartext: &Cleartext32,
+    ) -> Plaintext32 {
+        Plaintext32(encoder.0.encode(cleartext.0))
+    }
+}
+
+/// # Description:
+/// Implementation of [`CleartextEncodingEngine`] for [`CoreEngine`] that encodes 64 bits unsigned
+/// integers into 64 bits integers.
+impl CleartextEncodingEngine<IntegerEncoder, Cleartext64, Plaintext64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let encoder = engine.create_encoder(&IntegerEncoderConfig {
+    ///     nb_bit_precision: 4,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    /// let cleartext: Cleartext64 = engine.create_cleartext(&5_u64)?;
+    /// let plaintext: Plaintext64 = engine.encode_cleartext(&encoder, &cleartext)?;
+    /// #
+    /// engine.destroy(encoder)?;
+    /// engine.destroy(cleartext)?;
+    /// engine.destroy(plaintext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encode_cleartext(
+        &mut self,
+        encoder: &IntegerEncoder,
+        cleartext: &Cleartext64,
+    ) -> Result<Plaintext64, CleartextEncodingError<Self::EngineError>> {
+        if !encoder.0.fits_in::<u64>() {
+            return Err(CleartextEncodingError::from(
+                CoreError::EncodingPrecisionTooLarge,
+            ));
+        }
+        if !encoder.0.is_message_in_interval(cleartext.0 .0) {
+            return Err(CleartextEncodingError::from(
+                CoreError::MessageOutsideEncoderInterval,
+            ));
+        }
+        Ok(unsafe { self.encode_cleartext_unchecked(encoder, cleartext) })
+    }
+
+    unsafe fn encode_cleartext_unchecked(
+        &mut self,
+        encoder: &IntegerEncoder,
+        cleartext: &Cleartext64,
+    ) -> Plaintext64 {
+        Plaintext64(encoder.0.encode(cleartext.0))
+    }
+}
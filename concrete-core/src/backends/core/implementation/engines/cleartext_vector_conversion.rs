@@ -0,0 +1,100 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{CleartextVector32, CleartextVector64};
+use crate::backends::core::private::crypto::encoding::CleartextList as ImplCleartextList;
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::specification::engines::{
+    CleartextVectorConversionEngine, CleartextVectorConversionError,
+};
+use concrete_commons::numeric::CastInto;
+
+/// # Description:
+/// Implementation of [`CleartextVectorConversionEngine`] for [`CoreEngine`] that converts a 32 bits
+/// cleartext vector into a 64 bits cleartext vector, by casting the integer values.
+impl CleartextVectorConversionEngine<CleartextVector32, CleartextVector64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let input: CleartextVector32 = engine.create_cleartext_vector(&vec![3_u32; 3])?;
+    ///
+    /// let output: CleartextVector64 = engine.convert_cleartext_vector(&input)?;
+    /// #
+    /// let values: Vec<u64> = engine.retrieve_cleartext_vector(&output)?;
+    /// assert_eq!(values, vec![3_u64; 3]);
+    ///
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_cleartext_vector(
+        &mut self,
+        input: &CleartextVector32,
+    ) -> Result<CleartextVector64, CleartextVectorConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_cleartext_vector_unchecked(input) })
+    }
+
+    unsafe fn convert_cleartext_vector_unchecked(
+        &mut self,
+        input: &CleartextVector32,
+    ) -> CleartextVector64 {
+        CleartextVector64(ImplCleartextList::from_container(
+            input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|&x| x.cast_into())
+                .collect::<Vec<u64>>(),
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`CleartextVectorConversionEngine`] for [`CoreEngine`] that converts a 64 bits
+/// cleartext vector into a 32 bits cleartext vector, by casting the integer values.
+impl CleartextVectorConversionEngine<CleartextVector64, CleartextVector32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let input: CleartextVector64 = engine.create_cleartext_vector(&vec![3_u64; 3])?;
+    ///
+    /// let output: CleartextVector32 = engine.convert_cleartext_vector(&input)?;
+    /// #
+    /// let values: Vec<u32> = engine.retrieve_cleartext_vector(&output)?;
+    /// assert_eq!(values, vec![3_u32; 3]);
+    ///
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_cleartext_vector(
+        &mut self,
+        input: &CleartextVector64,
+    ) -> Result<CleartextVector32, CleartextVectorConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_cleartext_vector_unchecked(input) })
+    }
+
+    unsafe fn convert_cleartext_vector_unchecked(
+        &mut self,
+        input: &CleartextVector64,
+    ) -> CleartextVector32 {
+        CleartextVector32(ImplCleartextList::from_container(
+            input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|&x| x.cast_into())
+                .collect::<Vec<u32>>(),
+        ))
+    }
+}
@@ -1,5 +1,7 @@
 use crate::backends::core::implementation::engines::CoreEngine;
-use crate::backends::core::implementation::entities::{CleartextVector32, CleartextVector64};
+use crate::backends::core::implementation::entities::{
+    CleartextVector32, CleartextVector64, CleartextVectorF64,
+};
 use crate::backends::core::private::crypto::encoding::CleartextList as ImplCleartextList;
 use crate::specification::engines::{CleartextVectorCreationEngine, CleartextVectorCreationError};
 
@@ -72,3 +74,38 @@ impl CleartextVectorCreationEngine<u64, CleartextVector64> for CoreEngine {
         CleartextVector64(ImplCleartextList::from_container(input.to_vec()))
     }
 }
+
+/// # Description:
+/// Implementation of [`CleartextVectorCreationEngine`] for [`CoreEngine`] that operates on 64 bits
+/// floating point numbers.
+impl CleartextVectorCreationEngine<f64, CleartextVectorF64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::CleartextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input = vec![3_f64; 100];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let cleartext_vector: CleartextVectorF64 = engine.create_cleartext_vector(&input)?;
+    /// #
+    /// assert_eq!(cleartext_vector.cleartext_count(), CleartextCount(100));
+    /// engine.destroy(cleartext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_cleartext_vector(
+        &mut self,
+        input: &[f64],
+    ) -> Result<CleartextVectorF64, CleartextVectorCreationError<Self::EngineError>> {
+        CleartextVectorCreationError::perform_generic_checks(input)?;
+        Ok(unsafe { self.create_cleartext_vector_unchecked(input) })
+    }
+
+    unsafe fn create_cleartext_vector_unchecked(&mut self, input: &[f64]) -> CleartextVectorF64 {
+        CleartextVectorF64(ImplCleartextList::from_container(input.to_vec()))
+    }
+}
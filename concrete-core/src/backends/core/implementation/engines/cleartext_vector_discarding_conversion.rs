@@ -0,0 +1,107 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{CleartextVector32, CleartextVector64};
+use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::specification::engines::{
+    CleartextVectorDiscardingConversionEngine, CleartextVectorDiscardingConversionError,
+};
+use concrete_commons::numeric::CastInto;
+
+/// # Description:
+/// Implementation of [`CleartextVectorDiscardingConversionEngine`] for [`CoreEngine`] that converts
+/// a 32 bits cleartext vector into a 64 bits cleartext vector, by casting the integer values.
+impl CleartextVectorDiscardingConversionEngine<CleartextVector32, CleartextVector64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let input: CleartextVector32 = engine.create_cleartext_vector(&vec![3_u32; 3])?;
+    /// let mut output: CleartextVector64 =
+    ///     engine.create_cleartext_vector(&vec![0_u64; 3])?;
+    ///
+    /// engine.discard_convert_cleartext_vector(&mut output, &input)?;
+    /// #
+    /// let values: Vec<u64> = engine.retrieve_cleartext_vector(&output)?;
+    /// assert_eq!(values, vec![3_u64; 3]);
+    ///
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_cleartext_vector(
+        &mut self,
+        output: &mut CleartextVector64,
+        input: &CleartextVector32,
+    ) -> Result<(), CleartextVectorDiscardingConversionError<Self::EngineError>> {
+        CleartextVectorDiscardingConversionError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_convert_cleartext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_cleartext_vector_unchecked(
+        &mut self,
+        output: &mut CleartextVector64,
+        input: &CleartextVector32,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |&x| x.cast_into());
+    }
+}
+
+/// # Description:
+/// Implementation of [`CleartextVectorDiscardingConversionEngine`] for [`CoreEngine`] that converts
+/// a 64 bits cleartext vector into a 32 bits cleartext vector, by casting the integer values.
+impl CleartextVectorDiscardingConversionEngine<CleartextVector64, CleartextVector32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let input: CleartextVector64 = engine.create_cleartext_vector(&vec![3_u64; 3])?;
+    /// let mut output: CleartextVector32 =
+    ///     engine.create_cleartext_vector(&vec![0_u32; 3])?;
+    ///
+    /// engine.discard_convert_cleartext_vector(&mut output, &input)?;
+    /// #
+    /// let values: Vec<u32> = engine.retrieve_cleartext_vector(&output)?;
+    /// assert_eq!(values, vec![3_u32; 3]);
+    ///
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_cleartext_vector(
+        &mut self,
+        output: &mut CleartextVector32,
+        input: &CleartextVector64,
+    ) -> Result<(), CleartextVectorDiscardingConversionError<Self::EngineError>> {
+        CleartextVectorDiscardingConversionError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_convert_cleartext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_cleartext_vector_unchecked(
+        &mut self,
+        output: &mut CleartextVector32,
+        input: &CleartextVector64,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |&x| x.cast_into());
+    }
+}
@@ -0,0 +1,326 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    CleartextVector32, CleartextVector64, CleartextVectorF64, FloatEncoderVector,
+    IntegerEncoderVector, PlaintextVector32, PlaintextVector64,
+};
+use crate::backends::core::private::crypto::encoding::PlaintextList as ImplPlaintextList;
+use crate::prelude::CoreError;
+use crate::specification::engines::{CleartextVectorEncodingEngine, CleartextVectorEncodingError};
+
+impl From<CoreError> for CleartextVectorEncodingError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`CleartextVectorEncodingEngine`] for [`CoreEngine`] that encodes 64 bits
+/// floating point numbers into 32 bits integers.
+impl CleartextVectorEncodingEngine<FloatEncoderVector, CleartextVectorF64, PlaintextVector32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::PlaintextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let encoder_vector = engine.create_encoder_vector(&vec![
+    ///     FloatEncoderMinMaxConfig {
+    ///         min: 0.,
+    ///         max: 10.,
+    ///         nb_bit_precision: 8,
+    ///         nb_bit_padding: 1,
+    ///     };
+    ///     100
+    /// ])?;
+    /// let cleartext_vector: CleartextVectorF64 = engine.create_cleartext_vector(&vec![5.; 100])?;
+    /// let plaintext_vector: PlaintextVector32 =
+    ///     engine.encode_cleartext_vector(&encoder_vector, &cleartext_vector)?;
+    /// #
+    /// assert_eq!(plaintext_vector.plaintext_count(), PlaintextCount(100));
+    /// engine.destroy(encoder_vector)?;
+    /// engine.destroy(cleartext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encode_cleartext_vector(
+        &mut self,
+        encoder_vector: &FloatEncoderVector,
+        cleartext_vector: &CleartextVectorF64,
+    ) -> Result<PlaintextVector32, CleartextVectorEncodingError<Self::EngineError>> {
+        CleartextVectorEncodingError::perform_generic_checks(encoder_vector, cleartext_vector)?;
+        if encoder_vector
+            .0
+            .iter()
+            .any(|encoder| !encoder.fits_in::<u32>())
+        {
+            return Err(CleartextVectorEncodingError::from(
+                CoreError::EncodingPrecisionTooLarge,
+            ));
+        }
+        if encoder_vector
+            .0
+            .iter()
+            .zip(cleartext_vector.0.cleartext_iter())
+            .any(|(encoder, cleartext)| !encoder.is_message_in_interval(cleartext.0))
+        {
+            return Err(CleartextVectorEncodingError::from(
+                CoreError::MessageOutsideEncoderInterval,
+            ));
+        }
+        Ok(unsafe { self.encode_cleartext_vector_unchecked(encoder_vector, cleartext_vector) })
+    }
+
+    unsafe fn encode_cleartext_vector_unchecked(
+        &mut self,
+        encoder_vector: &FloatEncoderVector,
+        cleartext_vector: &CleartextVectorF64,
+    ) -> PlaintextVector32 {
+        PlaintextVector32(ImplPlaintextList::from_container(
+            encoder_vector
+                .0
+                .iter()
+                .zip(cleartext_vector.0.cleartext_iter())
+                .map(|(encoder, cleartext)| encoder.encode(*cleartext).0)
+                .collect::<Vec<_>>(),
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`CleartextVectorEncodingEngine`] for [`CoreEngine`] that encodes 64 bits
+/// floating point numbers into 64 bits integers.
+impl CleartextVectorEncodingEngine<FloatEncoderVector, CleartextVectorF64, PlaintextVector64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::PlaintextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let encoder_vector = engine.create_encoder_vector(&vec![
+    ///     FloatEncoderMinMaxConfig {
+    ///         min: 0.,
+    ///         max: 10.,
+    ///         nb_bit_precision: 8,
+    ///         nb_bit_padding: 1,
+    ///     };
+    ///     100
+    /// ])?;
+    /// let cleartext_vector: CleartextVectorF64 = engine.create_cleartext_vector(&vec![5.; 100])?;
+    /// let plaintext_vector: PlaintextVector64 =
+    ///     engine.encode_cleartext_vector(&encoder_vector, &cleartext_vector)?;
+    /// #
+    /// assert_eq!(plaintext_vector.plaintext_count(), PlaintextCount(100));
+    /// engine.destroy(encoder_vector)?;
+    /// engine.destroy(cleartext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encode_cleartext_vector(
+        &mut self,
+        encoder_vector: &FloatEncoderVector,
+        cleartext_vector: &CleartextVectorF64,
+    ) -> Result<PlaintextVector64, CleartextVectorEncodingError<Self::EngineError>> {
+        CleartextVectorEncodingError::perform_generic_checks(encoder_vector, cleartext_vector)?;
+        if encoder_vector
+            .0
+            .iter()
+            .any(|encoder| !encoder.fits_in::<u64>())
+        {
+            return Err(CleartextVectorEncodingError::from(
+                CoreError::EncodingPrecisionTooLarge,
+            ));
+        }
+        if encoder_vector
+            .0
+            .iter()
+            .zip(cleartext_vector.0.cleartext_iter())
+            .any(|(encoder, cleartext)| !encoder.is_message_in_interval(cleartext.0))
+        {
+            return Err(CleartextVectorEncodingError::from(
+                CoreError::MessageOutsideEncoderInterval,
+            ));
+        }
+        Ok(unsafe { self.encode_cleartext_vector_unchecked(encoder_vector, cleartext_vector) })
+    }
+
+    unsafe fn encode_cleartext_vector_unchecked(
+        &mut self,
+        encoder_vector: &FloatEncoderVector,
+        cleartext_vector: &CleartextVectorF64,
+    ) -> PlaintextVector64 {
+        PlaintextVector64(ImplPlaintextList::from_container(
+            encoder_vector
+                .0
+                .iter()
+                .zip(cleartext_vector.0.cleartext_iter())
+                .map(|(encoder, cleartext)| encoder.encode(*cleartext).0)
+                .collect::<Vec<_>>(),
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`CleartextVectorEncodingEngine`] for [`CoreEngine`] that encodes 32 bits
+/// unsigned integers into 32 bits integers.
+impl CleartextVectorEncodingEngine<IntegerEncoderVector, CleartextVector32, PlaintextVector32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::PlaintextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let encoder_vector = engine.create_encoder_vector(&vec![
+    ///     IntegerEncoderConfig {
+    ///         nb_bit_precision: 4,
+    ///         nb_bit_padding: 1,
+    ///     };
+    ///     100
+    /// ])?;
+    /// let cleartext_vector: CleartextVector32 = engine.create_cleartext_vector(&vec![5_u32; 100])?;
+    /// let plaintext_vector: PlaintextVector32 =
+    ///     engine.encode_cleartext_vector(&encoder_vector, &cleartext_vector)?;
+    /// #
+    /// assert_eq!(plaintext_vector.plaintext_count(), PlaintextCount(100));
+    /// engine.destroy(encoder_vector)?;
+    /// engine.destroy(cleartext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encode_cleartext_vector(
+        &mut self,
+        encoder_vector: &IntegerEncoderVector,
+        cleartext_vector: &CleartextVector32,
+    ) -> Result<PlaintextVector32, CleartextVectorEncodingError<Self::EngineError>> {
+        CleartextVectorEncodingError::perform_generic_checks(encoder_vector, cleartext_vector)?;
+        if encoder_vector
+            .0
+            .iter()
+            .any(|encoder| !encoder.fits_in::<u32>())
+        {
+            return Err(CleartextVectorEncodingError::from(
+                CoreError::EncodingPrecisionTooLarge,
+            ));
+        }
+        if encoder_vector
+            .0
+            .iter()
+            .zip(cleartext_vector.0.cleartext_iter())
+            .any(|(encoder, cleartext)| !encoder.is_message_in_interval(cleartext.0))
+        {
+            return Err(CleartextVectorEncodingError::from(
+                CoreError::MessageOutsideEncoderInterval,
+            ));
+        }
+        Ok(unsafe { self.encode_cleartext_vector_unchecked(encoder_vector, cleartext_vector) })
+    }
+
+    unsafe fn encode_cleartext_vector_unchecked(
+        &mut self,
+        encoder_vector: &IntegerEncoderVector,
+        cleartext_vector: &CleartextVector32,
+    ) -> PlaintextVector32 {
+        PlaintextVector32(ImplPlaintextList::from_container(
+            encoder_vector
+                .0
+                .iter()
+                .zip(cleartext_vector.0.cleartext_iter())
+                .map(|(encoder, cleartext)| encoder.encode(*cleartext).0)
+                .collect::<Vec<_>>(),
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`CleartextVectorEncodingEngine`] for [`CoreEngine`] that encodes 64 bits
+/// unsigned integers into 64 bits integers.
+impl CleartextVectorEncodingEngine<IntegerEncoderVector, CleartextVector64, PlaintextVector64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::PlaintextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let encoder_vector = engine.create_encoder_vector(&vec![
+    ///     IntegerEncoderConfig {
+    ///         nb_bit_precision: 4,
+    ///         nb_bit_padding: 1,
+    ///     };
+    ///     100
+    /// ])?;
+    /// let cleartext_vector: CleartextVector64 = engine.create_cleartext_vector(&vec![5_u64; 100])?;
+    /// let plaintext_vector: PlaintextVector64 =
+    ///     engine.encode_cleartext_vector(&encoder_vector, &cleartext_vector)?;
+    /// #
+    /// assert_eq!(plaintext_vector.plaintext_count(), PlaintextCount(100));
+    /// engine.destroy(encoder_vector)?;
+    /// engine.destroy(cleartext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encode_cleartext_vector(
+        &mut self,
+        encoder_vector: &IntegerEncoderVector,
+        cleartext_vector: &CleartextVector64,
+    ) -> Result<PlaintextVector64, CleartextVectorEncodingError<Self::EngineError>> {
+        CleartextVectorEncodingError::perform_generic_checks(encoder_vector, cleartext_vector)?;
+        if encoder_vector
+            .0
+            .iter()
+            .any(|encoder| !encoder.fits_in::<u64>())
+        {
+            return Err(CleartextVectorEncodingError::from(
+                CoreError::EncodingPrecisionTooLarge,
+            ));
+        }
+        if encoder_vector
+            .0
+            .iter()
+            .zip(cleartext_vector.0.cleartext_iter())
+            .any(|(encoder, cleartext)| !encoder.is_message_in_interval(cleartext.0))
+        {
+            return Err(CleartextVectorEncodingError::from(
+                CoreError::MessageOutsideEncoderInterval,
+            ));
+        }
+        Ok(unsafe { self.encode_cleartext_vector_unchecked(encoder_vector, cleartext_vector) })
+    }
+
+    unsafe fn encode_cleartext_vector_unchecked(
+        &mut self,
+        encoder_vector: &IntegerEncoderVector,
+        cleartext_vector: &CleartextVector64,
+    ) -> PlaintextVector64 {
+        PlaintextVector64(ImplPlaintextList::from_container(
+            encoder_vector
+                .0
+                .iter()
+                .zip(cleartext_vector.0.cleartext_iter())
+                .map(|(encoder, cleartext)| encoder.encode(*cleartext).0)
+                .collect::<Vec<_>>(),
+        ))
+    }
+}
@@ -1,5 +1,7 @@
 use crate::backends::core::implementation::engines::CoreEngine;
-use crate::backends::core::implementation::entities::{CleartextVector32, CleartextVector64};
+use crate::backends::core::implementation::entities::{
+    CleartextVector32, CleartextVector64, CleartextVectorF64,
+};
 use crate::backends::core::private::math::tensor::AsRefTensor;
 use crate::specification::engines::{
     CleartextVectorRetrievalEngine, CleartextVectorRetrievalError,
@@ -80,3 +82,40 @@ impl CleartextVectorRetrievalEngine<CleartextVector64, u64> for CoreEngine {
         cleartext.0.as_tensor().as_container().to_vec()
     }
 }
+
+/// # Description:
+/// Implementation of [`CleartextVectorRetrievalEngine`] for [`CoreEngine`] that operates on 64 bits
+/// floating point numbers.
+impl CleartextVectorRetrievalEngine<CleartextVectorF64, f64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input = vec![3_f64; 100];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let cleartext_vector: CleartextVectorF64 = engine.create_cleartext_vector(&input)?;
+    /// let retrieved: Vec<f64> = engine.retrieve_cleartext_vector(&cleartext_vector)?;
+    ///
+    /// assert_eq!(retrieved[0], 3_f64);
+    /// engine.destroy(cleartext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn retrieve_cleartext_vector(
+        &mut self,
+        cleartext: &CleartextVectorF64,
+    ) -> Result<Vec<f64>, CleartextVectorRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.retrieve_cleartext_vector_unchecked(cleartext) })
+    }
+
+    unsafe fn retrieve_cleartext_vector_unchecked(
+        &mut self,
+        cleartext: &CleartextVectorF64,
+    ) -> Vec<f64> {
+        cleartext.0.as_tensor().as_container().to_vec()
+    }
+}
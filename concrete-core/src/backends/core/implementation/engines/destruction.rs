@@ -1,13 +1,16 @@
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    Cleartext32, Cleartext64, CleartextVector32, CleartextVector64, FourierGgswCiphertext32,
+    Cleartext32, Cleartext64, CleartextF64, CleartextVector32, CleartextVector64,
+    CleartextVectorF64, FloatEncoder, FloatEncoderVector, FourierGgswCiphertext32,
     FourierGgswCiphertext64, FourierGlweCiphertext32, FourierGlweCiphertext64,
     FourierLweBootstrapKey32, FourierLweBootstrapKey64, GgswCiphertext32, GgswCiphertext64,
     GlweCiphertext32, GlweCiphertext64, GlweCiphertextVector32, GlweCiphertextVector64,
-    GlweSecretKey32, GlweSecretKey64, LweBootstrapKey32, LweBootstrapKey64, LweCiphertext32,
-    LweCiphertext64, LweCiphertextVector32, LweCiphertextVector64, LweKeyswitchKey32,
-    LweKeyswitchKey64, LweSecretKey32, LweSecretKey64, Plaintext32, Plaintext64, PlaintextVector32,
-    PlaintextVector64,
+    GlweRelinearizationKey32, GlweRelinearizationKey64, GlweSecretKey32, GlweSecretKey64,
+    GswCiphertext32, GswCiphertext64, IntegerEncoder, IntegerEncoderVector, LweBootstrapKey32,
+    LweBootstrapKey64, LweCiphertext32, LweCiphertext64, LweCiphertextVector32,
+    LweCiphertextVector64, LweKeyswitchKey32, LweKeyswitchKey64, LweSecretKey32, LweSecretKey64,
+    LweSeededCiphertext32, LweSeededCiphertext64, LweSeededKeyswitchKey32, LweSeededKeyswitchKey64,
+    Plaintext32, Plaintext64, PlaintextVector32, PlaintextVector64,
 };
 use crate::backends::core::private::math::tensor::AsMutTensor;
 use crate::specification::engines::{DestructionEngine, DestructionError};
@@ -54,6 +57,72 @@ impl DestructionEngine<CleartextVector64> for CoreEngine {
     unsafe fn destroy_unchecked(&mut self, _entity: CleartextVector64) {}
 }
 
+impl DestructionEngine<CleartextF64> for CoreEngine {
+    fn destroy(&mut self, entity: CleartextF64) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextF64) {}
+}
+
+impl DestructionEngine<CleartextVectorF64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextVectorF64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextVectorF64) {}
+}
+
+impl DestructionEngine<FloatEncoder> for CoreEngine {
+    fn destroy(&mut self, entity: FloatEncoder) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: FloatEncoder) {}
+}
+
+impl DestructionEngine<FloatEncoderVector> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: FloatEncoderVector,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: FloatEncoderVector) {}
+}
+
+impl DestructionEngine<IntegerEncoder> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: IntegerEncoder,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: IntegerEncoder) {}
+}
+
+impl DestructionEngine<IntegerEncoderVector> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: IntegerEncoderVector,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: IntegerEncoderVector) {}
+}
+
 impl DestructionEngine<Plaintext32> for CoreEngine {
     fn destroy(&mut self, entity: Plaintext32) -> Result<(), DestructionError<Self::EngineError>> {
         unsafe { self.destroy_unchecked(entity) };
@@ -144,6 +213,54 @@ impl DestructionEngine<LweCiphertextVector64> for CoreEngine {
     unsafe fn destroy_unchecked(&mut self, _entity: LweCiphertextVector64) {}
 }
 
+impl DestructionEngine<LweSeededCiphertext32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweSeededCiphertext32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweSeededCiphertext32) {}
+}
+
+impl DestructionEngine<LweSeededCiphertext64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweSeededCiphertext64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweSeededCiphertext64) {}
+}
+
+impl DestructionEngine<LweSeededKeyswitchKey32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweSeededKeyswitchKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweSeededKeyswitchKey32) {}
+}
+
+impl DestructionEngine<LweSeededKeyswitchKey64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweSeededKeyswitchKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweSeededKeyswitchKey64) {}
+}
+
 impl DestructionEngine<GlweCiphertext32> for CoreEngine {
     fn destroy(
         &mut self,
@@ -264,6 +381,30 @@ impl DestructionEngine<FourierGgswCiphertext64> for CoreEngine {
     unsafe fn destroy_unchecked(&mut self, _entity: FourierGgswCiphertext64) {}
 }
 
+impl DestructionEngine<GswCiphertext32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GswCiphertext32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: GswCiphertext32) {}
+}
+
+impl DestructionEngine<GswCiphertext64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GswCiphertext64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: GswCiphertext64) {}
+}
+
 impl DestructionEngine<LweBootstrapKey32> for CoreEngine {
     fn destroy(
         &mut self,
@@ -366,6 +507,30 @@ impl DestructionEngine<LweSecretKey64> for CoreEngine {
     }
 }
 
+impl DestructionEngine<GlweRelinearizationKey32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweRelinearizationKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: GlweRelinearizationKey32) {}
+}
+
+impl DestructionEngine<GlweRelinearizationKey64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweRelinearizationKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: GlweRelinearizationKey64) {}
+}
+
 impl DestructionEngine<GlweSecretKey32> for CoreEngine {
     fn destroy(
         &mut self,
@@ -0,0 +1,154 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    FloatEncoder, FloatEncoderCenterRadiusConfig, FloatEncoderMinMaxConfig, IntegerEncoder,
+    IntegerEncoderConfig,
+};
+use crate::prelude::CoreError;
+use crate::specification::engines::{EncoderCreationEngine, EncoderCreationError};
+
+impl From<CoreError> for EncoderCreationError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EncoderCreationEngine`] for [`CoreEngine`] that creates an encoder to
+/// encode 64 bits floating point numbers, from the bounds of its interval.
+impl EncoderCreationEngine<FloatEncoderMinMaxConfig, FloatEncoder> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let encoder = engine.create_encoder(&FloatEncoderMinMaxConfig {
+    ///     min: 0.,
+    ///     max: 10.,
+    ///     nb_bit_precision: 8,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    /// engine.destroy(encoder)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_encoder(
+        &mut self,
+        config: &FloatEncoderMinMaxConfig,
+    ) -> Result<FloatEncoder, EncoderCreationError<Self::EngineError>> {
+        if !config.min.is_finite() || !config.max.is_finite() {
+            return Err(EncoderCreationError::from(
+                CoreError::FloatEncoderNonFiniteParameter,
+            ));
+        }
+        if config.min >= config.max {
+            return Err(EncoderCreationError::from(
+                CoreError::FloatEncoderMinMaxOrder,
+            ));
+        }
+        if config.nb_bit_precision == 0 {
+            return Err(EncoderCreationError::from(
+                CoreError::FloatEncoderNullPrecision,
+            ));
+        }
+        Ok(unsafe { self.create_encoder_unchecked(config) })
+    }
+
+    unsafe fn create_encoder_unchecked(
+        &mut self,
+        config: &FloatEncoderMinMaxConfig,
+    ) -> FloatEncoder {
+        FloatEncoder(config.to_float_encoder())
+    }
+}
+
+/// # Description:
+/// Implementation of [`EncoderCreationEngine`] for [`CoreEngine`] that creates an encoder to
+/// encode 64 bits floating point numbers, from the center and radius of its interval.
+impl EncoderCreationEngine<FloatEncoderCenterRadiusConfig, FloatEncoder> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let encoder = engine.create_encoder(&FloatEncoderCenterRadiusConfig {
+    ///     center: 10.,
+    ///     radius: 5.,
+    ///     nb_bit_precision: 8,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    /// engine.destroy(encoder)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_encoder(
+        &mut self,
+        config: &FloatEncoderCenterRadiusConfig,
+    ) -> Result<FloatEncoder, EncoderCreationError<Self::EngineError>> {
+        if !config.center.is_finite() || !config.radius.is_finite() {
+            return Err(EncoderCreationError::from(
+                CoreError::FloatEncoderNonFiniteParameter,
+            ));
+        }
+        if config.radius <= 0. {
+            return Err(EncoderCreationError::from(
+                CoreError::FloatEncoderNullRadius,
+            ));
+        }
+        if config.nb_bit_precision == 0 {
+            return Err(EncoderCreationError::from(
+                CoreError::FloatEncoderNullPrecision,
+            ));
+        }
+        Ok(unsafe { self.create_encoder_unchecked(config) })
+    }
+
+    unsafe fn create_encoder_unchecked(
+        &mut self,
+        config: &FloatEncoderCenterRadiusConfig,
+    ) -> FloatEncoder {
+        FloatEncoder(config.to_float_encoder())
+    }
+}
+
+/// # Description:
+/// Implementation of [`EncoderCreationEngine`] for [`CoreEngine`] that creates an encoder to
+/// encode unsigned integers on a given number of bits.
+impl EncoderCreationEngine<IntegerEncoderConfig, IntegerEncoder> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let encoder = engine.create_encoder(&IntegerEncoderConfig {
+    ///     nb_bit_precision: 4,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    /// engine.destroy(encoder)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_encoder(
+        &mut self,
+        config: &IntegerEncoderConfig,
+    ) -> Result<IntegerEncoder, EncoderCreationError<Self::EngineError>> {
+        if config.nb_bit_precision == 0 {
+            return Err(EncoderCreationError::from(
+                CoreError::IntegerEncoderNullPrecision,
+            ));
+        }
+        Ok(unsafe { self.create_encoder_unchecked(config) })
+    }
+
+    unsafe fn create_encoder_unchecked(&mut self, config: &IntegerEncoderConfig) -> IntegerEncoder {
+        IntegerEncoder(config.to_integer_encoder())
+    }
+}
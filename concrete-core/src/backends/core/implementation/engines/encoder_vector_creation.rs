@@ -0,0 +1,187 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    FloatEncoderCenterRadiusConfig, FloatEncoderMinMaxConfig, FloatEncoderVector,
+    IntegerEncoderConfig, IntegerEncoderVector,
+};
+use crate::prelude::CoreError;
+use crate::specification::engines::{EncoderVectorCreationEngine, EncoderVectorCreationError};
+
+impl From<CoreError> for EncoderVectorCreationError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EncoderVectorCreationEngine`] for [`CoreEngine`] that creates a vector of
+/// encoders to encode 64 bits floating point numbers, from the bounds of their intervals.
+impl EncoderVectorCreationEngine<FloatEncoderMinMaxConfig, FloatEncoderVector> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::EncoderCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let encoder_vector = engine.create_encoder_vector(&vec![
+    ///     FloatEncoderMinMaxConfig {
+    ///         min: 0.,
+    ///         max: 10.,
+    ///         nb_bit_precision: 8,
+    ///         nb_bit_padding: 1,
+    ///     };
+    ///     1
+    /// ])?;
+    /// #
+    /// assert_eq!(encoder_vector.encoder_count(), EncoderCount(1));
+    /// engine.destroy(encoder_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_encoder_vector(
+        &mut self,
+        config: &[FloatEncoderMinMaxConfig],
+    ) -> Result<FloatEncoderVector, EncoderVectorCreationError<Self::EngineError>> {
+        EncoderVectorCreationError::perform_generic_checks(config)?;
+        if config
+            .iter()
+            .any(|c| !c.min.is_finite() || !c.max.is_finite())
+        {
+            return Err(EncoderVectorCreationError::from(
+                CoreError::FloatEncoderNonFiniteParameter,
+            ));
+        }
+        if config.iter().any(|c| c.min >= c.max) {
+            return Err(EncoderVectorCreationError::from(
+                CoreError::FloatEncoderMinMaxOrder,
+            ));
+        }
+        if config.iter().any(|c| c.nb_bit_precision == 0) {
+            return Err(EncoderVectorCreationError::from(
+                CoreError::FloatEncoderNullPrecision,
+            ));
+        }
+        Ok(unsafe { self.create_encoder_vector_unchecked(config) })
+    }
+
+    unsafe fn create_encoder_vector_unchecked(
+        &mut self,
+        config: &[FloatEncoderMinMaxConfig],
+    ) -> FloatEncoderVector {
+        FloatEncoderVector(config.iter().map(|c| c.to_float_encoder()).collect())
+    }
+}
+
+/// # Description:
+/// Implementation of [`EncoderVectorCreationEngine`] for [`CoreEngine`] that creates a vector of
+/// encoders to encode 64 bits floating point numbers, from the centers and radii of their
+/// intervals.
+impl EncoderVectorCreationEngine<FloatEncoderCenterRadiusConfig, FloatEncoderVector>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::EncoderCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let encoder_vector = engine.create_encoder_vector(&vec![
+    ///     FloatEncoderCenterRadiusConfig {
+    ///         center: 10.,
+    ///         radius: 5.,
+    ///         nb_bit_precision: 8,
+    ///         nb_bit_padding: 1,
+    ///     };
+    ///     1
+    /// ])?;
+    /// #
+    /// assert_eq!(encoder_vector.encoder_count(), EncoderCount(1));
+    /// engine.destroy(encoder_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_encoder_vector(
+        &mut self,
+        config: &[FloatEncoderCenterRadiusConfig],
+    ) -> Result<FloatEncoderVector, EncoderVectorCreationError<Self::EngineError>> {
+        EncoderVectorCreationError::perform_generic_checks(config)?;
+        if config
+            .iter()
+            .any(|c| !c.center.is_finite() || !c.radius.is_finite())
+        {
+            return Err(EncoderVectorCreationError::from(
+                CoreError::FloatEncoderNonFiniteParameter,
+            ));
+        }
+        if config.iter().any(|c| c.radius <= 0.) {
+            return Err(EncoderVectorCreationError::from(
+                CoreError::FloatEncoderNullRadius,
+            ));
+        }
+        if config.iter().any(|c| c.nb_bit_precision == 0) {
+            return Err(EncoderVectorCreationError::from(
+                CoreError::FloatEncoderNullPrecision,
+            ));
+        }
+        Ok(unsafe { self.create_encoder_vector_unchecked(config) })
+    }
+
+    unsafe fn create_encoder_vector_unchecked(
+        &mut self,
+        config: &[FloatEncoderCenterRadiusConfig],
+    ) -> FloatEncoderVector {
+        FloatEncoderVector(config.iter().map(|c| c.to_float_encoder()).collect())
+    }
+}
+
+/// # Description:
+/// Implementation of [`EncoderVectorCreationEngine`] for [`CoreEngine`] that creates a vector of
+/// encoders to encode unsigned integers on given numbers of bits.
+impl EncoderVectorCreationEngine<IntegerEncoderConfig, IntegerEncoderVector> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::EncoderCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let encoder_vector = engine.create_encoder_vector(&vec![
+    ///     IntegerEncoderConfig {
+    ///         nb_bit_precision: 4,
+    ///         nb_bit_padding: 1,
+    ///     };
+    ///     1
+    /// ])?;
+    /// #
+    /// assert_eq!(encoder_vector.encoder_count(), EncoderCount(1));
+    /// engine.destroy(encoder_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_encoder_vector(
+        &mut self,
+        config: &[IntegerEncoderConfig],
+    ) -> Result<IntegerEncoderVector, EncoderVectorCreationError<Self::EngineError>> {
+        EncoderVectorCreationError::perform_generic_checks(config)?;
+        if config.iter().any(|c| c.nb_bit_precision == 0) {
+            return Err(EncoderVectorCreationError::from(
+                CoreError::IntegerEncoderNullPrecision,
+            ));
+        }
+        Ok(unsafe { self.create_encoder_vector_unchecked(config) })
+    }
+
+    unsafe fn create_encoder_vector_unchecked(
+        &mut self,
+        config: &[IntegerEncoderConfig],
+    ) -> IntegerEncoderVector {
+        IntegerEncoderVector(config.iter().map(|c| c.to_integer_encoder()).collect())
+    }
+}
@@ -0,0 +1,133 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    Cleartext32, Cleartext64, GlweCiphertext32, GlweCiphertext64,
+};
+use crate::backends::core::private::math::tensor::AsMutTensor;
+use crate::specification::engines::{
+    GlweCiphertextCleartextFusingMultiplicationEngine,
+    GlweCiphertextCleartextFusingMultiplicationError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextCleartextFusingMultiplicationEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers.
+impl GlweCiphertextCleartextFusingMultiplicationEngine<GlweCiphertext32, Cleartext32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let cleartext_input = 12_u32;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let cleartext: Cleartext32 = engine.create_cleartext(&cleartext_input)?;
+    /// let key: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let mut ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// engine.fuse_mul_glwe_ciphertext_cleartext(&mut ciphertext, &cleartext)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(cleartext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_mul_glwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &Cleartext32,
+    ) -> Result<(), GlweCiphertextCleartextFusingMultiplicationError<Self::EngineError>> {
+        unsafe { self.fuse_mul_glwe_ciphertext_cleartext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_mul_glwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &Cleartext32,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .update_with_wrapping_scalar_mul(&(input.0).0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextCleartextFusingMultiplicationEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers.
+impl GlweCiphertextCleartextFusingMultiplicationEngine<GlweCiphertext64, Cleartext64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let cleartext_input = 12_u64;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let cleartext: Cleartext64 = engine.create_cleartext(&cleartext_input)?;
+    /// let key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let mut ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// engine.fuse_mul_glwe_ciphertext_cleartext(&mut ciphertext, &cleartext)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(cleartext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_mul_glwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &Cleartext64,
+    ) -> Result<(), GlweCiphertextCleartextFusingMultiplicationError<Self::EngineError>> {
+        unsafe { self.fuse_mul_glwe_ciphertext_cleartext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_mul_glwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &Cleartext64,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .update_with_wrapping_scalar_mul(&(input.0).0);
+    }
+}
@@ -2,8 +2,12 @@ use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
     FourierGlweCiphertext32, FourierGlweCiphertext64, GlweCiphertext32, GlweCiphertext64,
 };
-use crate::backends::core::private::crypto::glwe::FourierGlweCiphertext;
+use crate::backends::core::private::crypto::glwe::{
+    FourierGlweCiphertext, GlweCiphertext as ImplGlweCiphertext,
+};
 use crate::backends::core::private::math::fft::{Complex64, ALLOWED_POLY_SIZE};
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::backends::core::private::math::torus::CastTorus;
 use crate::prelude::CoreError;
 use crate::specification::engines::{
     GlweCiphertextConversionEngine, GlweCiphertextConversionError,
@@ -157,6 +161,142 @@ impl GlweCiphertextConversionEngine<GlweCiphertext64, FourierGlweCiphertext64> f
     }
 }
 
+/// # Description:
+/// Implementation of [`GlweCiphertextConversionEngine`] for [`CoreEngine`] that converts a 32 bits
+/// GLWE ciphertext into a 64 bits GLWE ciphertext, by rescaling the torus elements.
+impl GlweCiphertextConversionEngine<GlweCiphertext32, GlweCiphertext64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 4];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let output: GlweCiphertext64 = engine.convert_glwe_ciphertext(&ciphertext)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    ///
+    /// let output_key: GlweSecretKey64 = engine.convert_glwe_secret_key(&key)?;
+    /// let decrypted = engine.decrypt_glwe_ciphertext(&output_key, &output)?;
+    /// let raw: Vec<u64> = engine.retrieve_plaintext_vector(&decrypted)?;
+    /// // The messages are recovered with the encoding of the output precision.
+    /// assert!(raw.iter().all(|p| p.wrapping_add(1 << 51) >> 52 == 3));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(output)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(decrypted)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_ciphertext(
+        &mut self,
+        input: &GlweCiphertext32,
+    ) -> Result<GlweCiphertext64, GlweCiphertextConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_glwe_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn convert_glwe_ciphertext_unchecked(
+        &mut self,
+        input: &GlweCiphertext32,
+    ) -> GlweCiphertext64 {
+        GlweCiphertext64(ImplGlweCiphertext::from_container(
+            input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|&x| x.cast_torus())
+                .collect::<Vec<u64>>(),
+            input.polynomial_size(),
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextConversionEngine`] for [`CoreEngine`] that converts a 64 bits
+/// GLWE ciphertext into a 32 bits GLWE ciphertext, by rescaling the torus elements.
+impl GlweCiphertextConversionEngine<GlweCiphertext64, GlweCiphertext32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 4];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let output: GlweCiphertext32 = engine.convert_glwe_ciphertext(&ciphertext)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    ///
+    /// let output_key: GlweSecretKey32 = engine.convert_glwe_secret_key(&key)?;
+    /// let decrypted = engine.decrypt_glwe_ciphertext(&output_key, &output)?;
+    /// let raw: Vec<u32> = engine.retrieve_plaintext_vector(&decrypted)?;
+    /// // The messages are recovered with the encoding of the output precision.
+    /// assert!(raw.iter().all(|p| p.wrapping_add(1 << 17) >> 18 == 3));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(output)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(decrypted)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_ciphertext(
+        &mut self,
+        input: &GlweCiphertext64,
+    ) -> Result<GlweCiphertext32, GlweCiphertextConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_glwe_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn convert_glwe_ciphertext_unchecked(
+        &mut self,
+        input: &GlweCiphertext64,
+    ) -> GlweCiphertext32 {
+        GlweCiphertext32(ImplGlweCiphertext::from_container(
+            input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|&x| x.cast_torus())
+                .collect::<Vec<u32>>(),
+            input.polynomial_size(),
+        ))
+    }
+}
+
 /// This blanket implementation allows to convert from a type to itself by just cloning the value.
 impl<Ciphertext> GlweCiphertextConversionEngine<Ciphertext, Ciphertext> for CoreEngine
 where
@@ -0,0 +1,235 @@
+use concrete_commons::parameters::DeltaLog;
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, GlweRelinearizationKey32, GlweRelinearizationKey64,
+};
+use crate::backends::core::private::crypto::glwe::GlweTensorProductCiphertext;
+use crate::specification::engines::{
+    GlweCiphertextDiscardingTensorProductEngine, GlweCiphertextDiscardingTensorProductError,
+};
+use crate::specification::entities::GlweCiphertextEntity;
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingTensorProductEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers.
+impl
+    GlweCiphertextDiscardingTensorProductEngine<
+        GlweCiphertext32,
+        GlweCiphertext32,
+        GlweRelinearizationKey32,
+        GlweCiphertext32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, DeltaLog, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let delta_log = DeltaLog(20);
+    /// let input_1 = vec![3_u32 << 20; polynomial_size.0];
+    /// let input_2 = vec![2_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let relinearization_key: GlweRelinearizationKey32 = engine.create_glwe_relinearization_key(
+    ///     &key,
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector(&input_2)?;
+    /// let ciphertext_1 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    /// let mut ciphertext_3 = engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_tensor_product_glwe_ciphertext(
+    ///     &mut ciphertext_3,
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     &relinearization_key,
+    ///     delta_log,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_3.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_3.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(relinearization_key)?;
+    /// engine.destroy(plaintext_vector_1)?;
+    /// engine.destroy(plaintext_vector_2)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// engine.destroy(ciphertext_3)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_tensor_product_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input_1: &GlweCiphertext32,
+        input_2: &GlweCiphertext32,
+        relinearization_key: &GlweRelinearizationKey32,
+        delta_log: DeltaLog,
+    ) -> Result<(), GlweCiphertextDiscardingTensorProductError<Self::EngineError>> {
+        GlweCiphertextDiscardingTensorProductError::perform_generic_checks(
+            output,
+            input_1,
+            input_2,
+            relinearization_key,
+            delta_log,
+            32,
+        )?;
+        unsafe {
+            self.discard_tensor_product_glwe_ciphertext_unchecked(
+                output,
+                input_1,
+                input_2,
+                relinearization_key,
+                delta_log,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_tensor_product_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input_1: &GlweCiphertext32,
+        input_2: &GlweCiphertext32,
+        relinearization_key: &GlweRelinearizationKey32,
+        delta_log: DeltaLog,
+    ) {
+        let mut tensor = GlweTensorProductCiphertext::allocate(
+            0u32,
+            input_1.polynomial_size(),
+            input_1.glwe_dimension(),
+        );
+        tensor.fill_with_tensor_product(&input_1.0, &input_2.0, delta_log);
+        relinearization_key.0.relinearize(&mut output.0, &tensor);
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingTensorProductEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers.
+impl
+    GlweCiphertextDiscardingTensorProductEngine<
+        GlweCiphertext64,
+        GlweCiphertext64,
+        GlweRelinearizationKey64,
+        GlweCiphertext64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, DeltaLog, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let delta_log = DeltaLog(50);
+    /// let input_1 = vec![3_u64 << 50; polynomial_size.0];
+    /// let input_2 = vec![2_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let relinearization_key: GlweRelinearizationKey64 = engine.create_glwe_relinearization_key(
+    ///     &key,
+    ///     DecompositionLevelCount(4),
+    ///     DecompositionBaseLog(10),
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector(&input_2)?;
+    /// let ciphertext_1 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    /// let mut ciphertext_3 = engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_tensor_product_glwe_ciphertext(
+    ///     &mut ciphertext_3,
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     &relinearization_key,
+    ///     delta_log,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_3.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_3.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(relinearization_key)?;
+    /// engine.destroy(plaintext_vector_1)?;
+    /// engine.destroy(plaintext_vector_2)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// engine.destroy(ciphertext_3)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_tensor_product_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input_1: &GlweCiphertext64,
+        input_2: &GlweCiphertext64,
+        relinearization_key: &GlweRelinearizationKey64,
+        delta_log: DeltaLog,
+    ) -> Result<(), GlweCiphertextDiscardingTensorProductError<Self::EngineError>> {
+        GlweCiphertextDiscardingTensorProductError::perform_generic_checks(
+            output,
+            input_1,
+            input_2,
+            relinearization_key,
+            delta_log,
+            64,
+        )?;
+        unsafe {
+            self.discard_tensor_product_glwe_ciphertext_unchecked(
+                output,
+                input_1,
+                input_2,
+                relinearization_key,
+                delta_log,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_tensor_product_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input_1: &GlweCiphertext64,
+        input_2: &GlweCiphertext64,
+        relinearization_key: &GlweRelinearizationKey64,
+        delta_log: DeltaLog,
+    ) {
+        let mut tensor = GlweTensorProductCiphertext::allocate(
+            0u64,
+            input_1.polynomial_size(),
+            input_1.glwe_dimension(),
+        );
+        tensor.fill_with_tensor_product(&input_1.0, &input_2.0, delta_log);
+        relinearization_key.0.relinearize(&mut output.0, &tensor);
+    }
+}
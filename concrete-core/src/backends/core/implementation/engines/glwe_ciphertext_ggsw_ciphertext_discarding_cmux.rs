@@ -0,0 +1,245 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    FourierGgswCiphertext32, FourierGgswCiphertext64, GlweCiphertext32, GlweCiphertext64,
+};
+use crate::backends::core::private::math::fft::ALLOWED_POLY_SIZE;
+use crate::prelude::{CoreError, GgswCiphertextEntity};
+use crate::specification::engines::{
+    GlweCiphertextGgswCiphertextDiscardingCmuxEngine,
+    GlweCiphertextGgswCiphertextDiscardingCmuxError,
+};
+use crate::specification::entities::GlweCiphertextEntity;
+
+impl From<CoreError> for GlweCiphertextGgswCiphertextDiscardingCmuxError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextGgswCiphertextDiscardingCmuxEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers.
+impl
+    GlweCiphertextGgswCiphertextDiscardingCmuxEngine<
+        GlweCiphertext32,
+        FourierGgswCiphertext32,
+        GlweCiphertext32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(7);
+    /// // The GGSW ciphertext encrypts a raw one (selecting the second input), while a hard-set
+    /// // encoding is applied to the GLWE ciphertexts (shift by 20 bits)
+    /// let input_ggsw = 1_u32;
+    /// let input_0 = vec![3_u32 << 20; polynomial_size.0];
+    /// let input_1 = vec![5_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_ggsw = engine.create_plaintext(&input_ggsw)?;
+    /// let plaintext_vector_0 = engine.create_plaintext_vector(&input_0)?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector(&input_1)?;
+    ///
+    /// let ggsw =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext_ggsw, noise, level, base_log)?;
+    /// let complex_ggsw: FourierGgswCiphertext32 = engine.convert_ggsw_ciphertext(&ggsw)?;
+    /// let glwe_0 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_0, noise)?;
+    /// let glwe_1 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let mut output = engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_cmux_glwe_ciphertext_ggsw_ciphertext(
+    ///     &mut output,
+    ///     &glwe_0,
+    ///     &glwe_1,
+    ///     &complex_ggsw,
+    /// )?;
+    /// #
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_ggsw)?;
+    /// engine.destroy(plaintext_vector_0)?;
+    /// engine.destroy(plaintext_vector_1)?;
+    /// engine.destroy(ggsw)?;
+    /// engine.destroy(complex_ggsw)?;
+    /// engine.destroy(glwe_0)?;
+    /// engine.destroy(glwe_1)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_cmux_glwe_ciphertext_ggsw_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        glwe_input_0: &GlweCiphertext32,
+        glwe_input_1: &GlweCiphertext32,
+        ggsw_input: &FourierGgswCiphertext32,
+    ) -> Result<(), GlweCiphertextGgswCiphertextDiscardingCmuxError<Self::EngineError>> {
+        if !ALLOWED_POLY_SIZE.contains(&output.polynomial_size().0) {
+            return Err(GlweCiphertextGgswCiphertextDiscardingCmuxError::from(
+                CoreError::UnsupportedPolynomialSize,
+            ));
+        }
+        GlweCiphertextGgswCiphertextDiscardingCmuxError::perform_generic_checks(
+            output,
+            glwe_input_0,
+            glwe_input_1,
+            ggsw_input,
+        )?;
+        unsafe {
+            self.discard_cmux_glwe_ciphertext_ggsw_ciphertext_unchecked(
+                output,
+                glwe_input_0,
+                glwe_input_1,
+                ggsw_input,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_cmux_glwe_ciphertext_ggsw_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        glwe_input_0: &GlweCiphertext32,
+        glwe_input_1: &GlweCiphertext32,
+        ggsw_input: &FourierGgswCiphertext32,
+    ) {
+        let buffers = self.get_fourier_u32_buffer(
+            ggsw_input.polynomial_size(),
+            ggsw_input.glwe_dimension().to_glwe_size(),
+        );
+        ggsw_input
+            .0
+            .cmux(&mut output.0, &glwe_input_0.0, &glwe_input_1.0, buffers);
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextGgswCiphertextDiscardingCmuxEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers.
+impl
+    GlweCiphertextGgswCiphertextDiscardingCmuxEngine<
+        GlweCiphertext64,
+        FourierGgswCiphertext64,
+        GlweCiphertext64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(7);
+    /// // The GGSW ciphertext encrypts a raw one (selecting the second input), while a hard-set
+    /// // encoding is applied to the GLWE ciphertexts (shift by 50 bits)
+    /// let input_ggsw = 1_u64;
+    /// let input_0 = vec![3_u64 << 50; polynomial_size.0];
+    /// let input_1 = vec![5_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_ggsw = engine.create_plaintext(&input_ggsw)?;
+    /// let plaintext_vector_0 = engine.create_plaintext_vector(&input_0)?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector(&input_1)?;
+    ///
+    /// let ggsw =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext_ggsw, noise, level, base_log)?;
+    /// let complex_ggsw: FourierGgswCiphertext64 = engine.convert_ggsw_ciphertext(&ggsw)?;
+    /// let glwe_0 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_0, noise)?;
+    /// let glwe_1 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let mut output = engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_cmux_glwe_ciphertext_ggsw_ciphertext(
+    ///     &mut output,
+    ///     &glwe_0,
+    ///     &glwe_1,
+    ///     &complex_ggsw,
+    /// )?;
+    /// #
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_ggsw)?;
+    /// engine.destroy(plaintext_vector_0)?;
+    /// engine.destroy(plaintext_vector_1)?;
+    /// engine.destroy(ggsw)?;
+    /// engine.destroy(complex_ggsw)?;
+    /// engine.destroy(glwe_0)?;
+    /// engine.destroy(glwe_1)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_cmux_glwe_ciphertext_ggsw_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        glwe_input_0: &GlweCiphertext64,
+        glwe_input_1: &GlweCiphertext64,
+        ggsw_input: &FourierGgswCiphertext64,
+    ) -> Result<(), GlweCiphertextGgswCiphertextDiscardingCmuxError<Self::EngineError>> {
+        if !ALLOWED_POLY_SIZE.contains(&output.polynomial_size().0) {
+            return Err(GlweCiphertextGgswCiphertextDiscardingCmuxError::from(
+                CoreError::UnsupportedPolynomialSize,
+            ));
+        }
+        GlweCiphertextGgswCiphertextDiscardingCmuxError::perform_generic_checks(
+            output,
+            glwe_input_0,
+            glwe_input_1,
+            ggsw_input,
+        )?;
+        unsafe {
+            self.discard_cmux_glwe_ciphertext_ggsw_ciphertext_unchecked(
+                output,
+                glwe_input_0,
+                glwe_input_1,
+                ggsw_input,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_cmux_glwe_ciphertext_ggsw_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        glwe_input_0: &GlweCiphertext64,
+        glwe_input_1: &GlweCiphertext64,
+        ggsw_input: &FourierGgswCiphertext64,
+    ) {
+        let buffers = self.get_fourier_u64_buffer(
+            ggsw_input.polynomial_size(),
+            ggsw_input.glwe_dimension().to_glwe_size(),
+        );
+        ggsw_input
+            .0
+            .cmux(&mut output.0, &glwe_input_0.0, &glwe_input_1.0, buffers);
+    }
+}
@@ -0,0 +1,163 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    GlweCiphertextVector32, GlweCiphertextVector64,
+};
+use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::backends::core::private::math::torus::CastTorus;
+use crate::specification::engines::{
+    GlweCiphertextVectorDiscardingConversionEngine, GlweCiphertextVectorDiscardingConversionError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorDiscardingConversionEngine`] for [`CoreEngine`] that
+/// converts a 32 bits GLWE ciphertext vector into a 64 bits GLWE ciphertext vector, by rescaling
+/// the torus elements.
+impl GlweCiphertextVectorDiscardingConversionEngine<GlweCiphertextVector32, GlweCiphertextVector64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 8];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let output_key: GlweSecretKey64 = engine.convert_glwe_secret_key(&key)?;
+    /// let mut output: GlweCiphertextVector64 = engine.zero_encrypt_glwe_ciphertext_vector(
+    ///     &output_key,
+    ///     Variance(2_f64.powf(-50.)),
+    ///     GlweCiphertextCount(2),
+    /// )?;
+    ///
+    /// engine.discard_convert_glwe_ciphertext_vector(&mut output, &ciphertext_vector)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    /// assert_eq!(output.glwe_ciphertext_count(), GlweCiphertextCount(2));
+    ///
+    /// let decrypted = engine.decrypt_glwe_ciphertext_vector(&output_key, &output)?;
+    /// let raw: Vec<u64> = engine.retrieve_plaintext_vector(&decrypted)?;
+    /// // The messages are recovered with the encoding of the output precision.
+    /// assert!(raw.iter().all(|p| p.wrapping_add(1 << 51) >> 52 == 3));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(output)?;
+    /// engine.destroy(decrypted)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_glwe_ciphertext_vector(
+        &mut self,
+        output: &mut GlweCiphertextVector64,
+        input: &GlweCiphertextVector32,
+    ) -> Result<(), GlweCiphertextVectorDiscardingConversionError<Self::EngineError>> {
+        GlweCiphertextVectorDiscardingConversionError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_convert_glwe_ciphertext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut GlweCiphertextVector64,
+        input: &GlweCiphertextVector32,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |&x| x.cast_torus());
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorDiscardingConversionEngine`] for [`CoreEngine`] that
+/// converts a 64 bits GLWE ciphertext vector into a 32 bits GLWE ciphertext vector, by rescaling
+/// the torus elements.
+impl GlweCiphertextVectorDiscardingConversionEngine<GlweCiphertextVector64, GlweCiphertextVector32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 8];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let output_key: GlweSecretKey32 = engine.convert_glwe_secret_key(&key)?;
+    /// let mut output: GlweCiphertextVector32 = engine.zero_encrypt_glwe_ciphertext_vector(
+    ///     &output_key,
+    ///     Variance(2_f64.powf(-25.)),
+    ///     GlweCiphertextCount(2),
+    /// )?;
+    ///
+    /// engine.discard_convert_glwe_ciphertext_vector(&mut output, &ciphertext_vector)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    /// assert_eq!(output.glwe_ciphertext_count(), GlweCiphertextCount(2));
+    ///
+    /// let decrypted = engine.decrypt_glwe_ciphertext_vector(&output_key, &output)?;
+    /// let raw: Vec<u32> = engine.retrieve_plaintext_vector(&decrypted)?;
+    /// // The messages are recovered with the encoding of the output precision.
+    /// assert!(raw.iter().all(|p| p.wrapping_add(1 << 17) >> 18 == 3));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(output)?;
+    /// engine.destroy(decrypted)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_glwe_ciphertext_vector(
+        &mut self,
+        output: &mut GlweCiphertextVector32,
+        input: &GlweCiphertextVector64,
+    ) -> Result<(), GlweCiphertextVectorDiscardingConversionError<Self::EngineError>> {
+        GlweCiphertextVectorDiscardingConversionError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_convert_glwe_ciphertext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut GlweCiphertextVector32,
+        input: &GlweCiphertextVector64,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |&x| x.cast_torus());
+    }
+}
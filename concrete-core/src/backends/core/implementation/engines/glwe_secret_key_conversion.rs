@@ -0,0 +1,109 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{GlweSecretKey32, GlweSecretKey64};
+use crate::backends::core::private::crypto::secret::GlweSecretKey as ImplGlweSecretKey;
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::specification::engines::{GlweSecretKeyConversionEngine, GlweSecretKeyConversionError};
+use crate::specification::entities::GlweSecretKeyEntity;
+use concrete_commons::numeric::CastInto;
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyConversionEngine`] for [`CoreEngine`] that converts a 32 bits
+/// GLWE secret key into a 64 bits GLWE secret key, by casting the integer values.
+impl GlweSecretKeyConversionEngine<GlweSecretKey32, GlweSecretKey64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let output: GlweSecretKey64 = engine.convert_glwe_secret_key(&key)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_secret_key(
+        &mut self,
+        input: &GlweSecretKey32,
+    ) -> Result<GlweSecretKey64, GlweSecretKeyConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_glwe_secret_key_unchecked(input) })
+    }
+
+    unsafe fn convert_glwe_secret_key_unchecked(
+        &mut self,
+        input: &GlweSecretKey32,
+    ) -> GlweSecretKey64 {
+        GlweSecretKey64(ImplGlweSecretKey::binary_from_container(
+            input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|&x| x.cast_into())
+                .collect::<Vec<u64>>(),
+            input.polynomial_size(),
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyConversionEngine`] for [`CoreEngine`] that converts a 64 bits
+/// GLWE secret key into a 32 bits GLWE secret key, by casting the integer values.
+impl GlweSecretKeyConversionEngine<GlweSecretKey64, GlweSecretKey32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let output: GlweSecretKey32 = engine.convert_glwe_secret_key(&key)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_secret_key(
+        &mut self,
+        input: &GlweSecretKey64,
+    ) -> Result<GlweSecretKey32, GlweSecretKeyConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_glwe_secret_key_unchecked(input) })
+    }
+
+    unsafe fn convert_glwe_secret_key_unchecked(
+        &mut self,
+        input: &GlweSecretKey64,
+    ) -> GlweSecretKey32 {
+        GlweSecretKey32(ImplGlweSecretKey::binary_from_container(
+            input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|&x| x.cast_into())
+                .collect::<Vec<u32>>(),
+            input.polynomial_size(),
+        ))
+    }
+}
@@ -2,13 +2,25 @@ use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
     FourierLweBootstrapKey32, FourierLweBootstrapKey64, LweBootstrapKey32, LweBootstrapKey64,
 };
-use crate::backends::core::private::crypto::bootstrap::FourierBootstrapKey as ImplFourierBootstrapKey;
+use crate::backends::core::private::crypto::bootstrap::{
+    FourierBootstrapKey as ImplFourierBootstrapKey,
+    StandardBootstrapKey as ImplStandardBootstrapKey,
+};
 use crate::backends::core::private::math::fft::Complex64;
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::backends::core::private::math::torus::CastTorus;
+use crate::prelude::CoreError;
 use crate::specification::engines::{
     LweBootstrapKeyConversionEngine, LweBootstrapKeyConversionError,
 };
 use crate::specification::entities::LweBootstrapKeyEntity;
 
+impl From<CoreError> for LweBootstrapKeyConversionError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
 /// # Description:
 /// Implementation of [`LweBootstrapKeyConversionEngine`] for [`CoreEngine`] that operates on
 /// 32 bits integers. It converts a bootstrap key from the standard to the Fourier domain.
@@ -149,6 +161,147 @@ impl LweBootstrapKeyConversionEngine<LweBootstrapKey64, FourierLweBootstrapKey64
     }
 }
 
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`CoreEngine`] that converts a 32 bits
+/// LWE bootstrap key into a 64 bits LWE bootstrap key, by rescaling the torus elements.
+impl LweBootstrapKeyConversionEngine<LweBootstrapKey32, LweBootstrapKey64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let output: LweBootstrapKey64 = engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dim);
+    /// assert_eq!(output.polynomial_size(), poly_size);
+    /// assert_eq!(output.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(output.decomposition_base_log(), dec_bl);
+    /// assert_eq!(output.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &LweBootstrapKey32,
+    ) -> Result<LweBootstrapKey64, LweBootstrapKeyConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &LweBootstrapKey32,
+    ) -> LweBootstrapKey64 {
+        LweBootstrapKey64(ImplStandardBootstrapKey::from_container(
+            input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|&x| x.cast_torus())
+                .collect::<Vec<u64>>(),
+            input.glwe_dimension().to_glwe_size(),
+            input.polynomial_size(),
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`CoreEngine`] that converts a 64 bits
+/// LWE bootstrap key into a 32 bits LWE bootstrap key, by rescaling the torus elements.
+///
+/// The decomposition precision of the key must fit in the output integers.
+impl LweBootstrapKeyConversionEngine<LweBootstrapKey64, LweBootstrapKey32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let output: LweBootstrapKey32 = engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dim);
+    /// assert_eq!(output.polynomial_size(), poly_size);
+    /// assert_eq!(output.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(output.decomposition_base_log(), dec_bl);
+    /// assert_eq!(output.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &LweBootstrapKey64,
+    ) -> Result<LweBootstrapKey32, LweBootstrapKeyConversionError<Self::EngineError>> {
+        if input.decomposition_base_log().0 * input.decomposition_level_count().0 > 32 {
+            return Err(LweBootstrapKeyConversionError::from(
+                CoreError::DecompositionPrecisionTooLarge,
+            ));
+        }
+        Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &LweBootstrapKey64,
+    ) -> LweBootstrapKey32 {
+        LweBootstrapKey32(ImplStandardBootstrapKey::from_container(
+            input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|&x| x.cast_torus())
+                .collect::<Vec<u32>>(),
+            input.glwe_dimension().to_glwe_size(),
+            input.polynomial_size(),
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+        ))
+    }
+}
+
 impl<Key> LweBootstrapKeyConversionEngine<Key, Key> for CoreEngine
 where
     Key: LweBootstrapKeyEntity + Clone,
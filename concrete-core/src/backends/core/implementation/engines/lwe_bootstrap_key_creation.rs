@@ -95,12 +95,20 @@ impl LweBootstrapKeyCreationEngine<LweSecretKey32, GlweSecretKey32, LweBootstrap
             decomposition_base_log,
             input_key.0.key_size(),
         );
+        #[cfg(not(feature = "multithread"))]
         key.fill_with_new_key(
             &input_key.0,
             &output_key.0,
             noise,
             &mut self.encryption_generator,
         );
+        #[cfg(feature = "multithread")]
+        key.par_fill_with_new_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
         LweBootstrapKey32(key)
     }
 }
@@ -186,12 +194,20 @@ impl LweBootstrapKeyCreationEngine<LweSecretKey64, GlweSecretKey64, LweBootstrap
             decomposition_base_log,
             input_key.0.key_size(),
         );
+        #[cfg(not(feature = "multithread"))]
         key.fill_with_new_key(
             &input_key.0,
             &output_key.0,
             noise,
             &mut self.encryption_generator,
         );
+        #[cfg(feature = "multithread")]
+        key.par_fill_with_new_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
         LweBootstrapKey64(key)
     }
 }
@@ -277,12 +293,20 @@ impl LweBootstrapKeyCreationEngine<LweSecretKey32, GlweSecretKey32, FourierLweBo
             decomposition_base_log,
             input_key.0.key_size(),
         );
+        #[cfg(not(feature = "multithread"))]
         key.fill_with_new_key(
             &input_key.0,
             &output_key.0,
             noise,
             &mut self.encryption_generator,
         );
+        #[cfg(feature = "multithread")]
+        key.par_fill_with_new_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
         let fourier_key = ImplFourierBootstrapKey::allocate(
             Complex64::new(0., 0.),
             output_key.0.key_size().to_glwe_size(),
@@ -383,12 +407,20 @@ impl LweBootstrapKeyCreationEngine<LweSecretKey64, GlweSecretKey64, FourierLweBo
             decomposition_base_log,
             input_key.0.key_size(),
         );
+        #[cfg(not(feature = "multithread"))]
         key.fill_with_new_key(
             &input_key.0,
             &output_key.0,
             noise,
             &mut self.encryption_generator,
         );
+        #[cfg(feature = "multithread")]
+        key.par_fill_with_new_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
         let fourier_key = ImplFourierBootstrapKey::allocate(
             Complex64::new(0., 0.),
             output_key.0.key_size().to_glwe_size(),
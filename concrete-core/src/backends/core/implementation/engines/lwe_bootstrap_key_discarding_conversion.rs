@@ -0,0 +1,174 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{LweBootstrapKey32, LweBootstrapKey64};
+use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::backends::core::private::math::torus::CastTorus;
+use crate::prelude::CoreError;
+use crate::specification::engines::{
+    LweBootstrapKeyDiscardingConversionEngine, LweBootstrapKeyDiscardingConversionError,
+};
+use crate::specification::entities::LweBootstrapKeyEntity;
+
+impl From<CoreError> for LweBootstrapKeyDiscardingConversionError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyDiscardingConversionEngine`] for [`CoreEngine`] that converts
+/// a 32 bits LWE bootstrap key into a 64 bits LWE bootstrap key, by rescaling the torus elements.
+impl LweBootstrapKeyDiscardingConversionEngine<LweBootstrapKey32, LweBootstrapKey64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let other_lwe_sk: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let other_glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let mut output: LweBootstrapKey64 = engine.create_lwe_bootstrap_key(
+    ///     &other_lwe_sk,
+    ///     &other_glwe_sk,
+    ///     dec_bl,
+    ///     dec_lc,
+    ///     Variance(2_f64.powf(-50.)),
+    /// )?;
+    ///
+    /// engine.discard_convert_lwe_bootstrap_key(&mut output, &bsk)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dim);
+    /// assert_eq!(output.polynomial_size(), poly_size);
+    /// assert_eq!(output.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(output.decomposition_base_log(), dec_bl);
+    /// assert_eq!(output.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(other_lwe_sk)?;
+    /// engine.destroy(other_glwe_sk)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_bootstrap_key(
+        &mut self,
+        output: &mut LweBootstrapKey64,
+        input: &LweBootstrapKey32,
+    ) -> Result<(), LweBootstrapKeyDiscardingConversionError<Self::EngineError>> {
+        LweBootstrapKeyDiscardingConversionError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_convert_lwe_bootstrap_key_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        output: &mut LweBootstrapKey64,
+        input: &LweBootstrapKey32,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |&x| x.cast_torus());
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyDiscardingConversionEngine`] for [`CoreEngine`] that converts
+/// a 64 bits LWE bootstrap key into a 32 bits LWE bootstrap key, by rescaling the torus elements.
+///
+/// The decomposition precision of the key must fit in the output integers.
+impl LweBootstrapKeyDiscardingConversionEngine<LweBootstrapKey64, LweBootstrapKey32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let other_lwe_sk: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let other_glwe_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let mut output: LweBootstrapKey32 = engine.create_lwe_bootstrap_key(
+    ///     &other_lwe_sk,
+    ///     &other_glwe_sk,
+    ///     dec_bl,
+    ///     dec_lc,
+    ///     Variance(2_f64.powf(-25.)),
+    /// )?;
+    ///
+    /// engine.discard_convert_lwe_bootstrap_key(&mut output, &bsk)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dim);
+    /// assert_eq!(output.polynomial_size(), poly_size);
+    /// assert_eq!(output.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(output.decomposition_base_log(), dec_bl);
+    /// assert_eq!(output.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(other_lwe_sk)?;
+    /// engine.destroy(other_glwe_sk)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_bootstrap_key(
+        &mut self,
+        output: &mut LweBootstrapKey32,
+        input: &LweBootstrapKey64,
+    ) -> Result<(), LweBootstrapKeyDiscardingConversionError<Self::EngineError>> {
+        LweBootstrapKeyDiscardingConversionError::perform_generic_checks(output, input)?;
+        if input.decomposition_base_log().0 * input.decomposition_level_count().0 > 32 {
+            return Err(LweBootstrapKeyDiscardingConversionError::from(
+                CoreError::DecompositionPrecisionTooLarge,
+            ));
+        }
+        unsafe { self.discard_convert_lwe_bootstrap_key_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        output: &mut LweBootstrapKey32,
+        input: &LweBootstrapKey64,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |&x| x.cast_torus());
+    }
+}
@@ -0,0 +1,203 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    FourierLweBootstrapKey32, FourierLweBootstrapKey64, GlweCiphertext32, GlweCiphertext64,
+    LweCiphertext32, LweCiphertext64,
+};
+use crate::backends::core::private::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
+use crate::backends::core::private::math::fft::ALLOWED_POLY_SIZE;
+use crate::prelude::{CoreError, GlweCiphertextEntity, LweBootstrapKeyEntity};
+use crate::specification::engines::{
+    LweCiphertextBlindRotationEngine, LweCiphertextBlindRotationError,
+};
+
+impl From<CoreError> for LweCiphertextBlindRotationError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextBlindRotationEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers.
+impl
+    LweCiphertextBlindRotationEngine<
+        FourierLweBootstrapKey32,
+        GlweCiphertext32,
+        LweCiphertext32,
+        GlweCiphertext32,
+    > for CoreEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(1), PolynomialSize(1024));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is encoded in the accumulator
+    /// let lut = vec![8_u32 << 20; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: FourierLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc =
+    ///     engine.trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    ///
+    /// let output = engine.blind_rotate_lwe_ciphertext(&input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dim);
+    /// assert_eq!(output.polynomial_size(), poly_size);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn blind_rotate_lwe_ciphertext(
+        &mut self,
+        input: &LweCiphertext32,
+        acc: &GlweCiphertext32,
+        bsk: &FourierLweBootstrapKey32,
+    ) -> Result<GlweCiphertext32, LweCiphertextBlindRotationError<Self::EngineError>> {
+        if !ALLOWED_POLY_SIZE.contains(&acc.polynomial_size().0) {
+            return Err(LweCiphertextBlindRotationError::from(
+                CoreError::UnsupportedPolynomialSize,
+            ));
+        }
+        LweCiphertextBlindRotationError::perform_generic_checks(input, acc, bsk)?;
+        Ok(unsafe { self.blind_rotate_lwe_ciphertext_unchecked(input, acc, bsk) })
+    }
+
+    unsafe fn blind_rotate_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &LweCiphertext32,
+        acc: &GlweCiphertext32,
+        bsk: &FourierLweBootstrapKey32,
+    ) -> GlweCiphertext32 {
+        let mut output = ImplGlweCiphertext::allocate(
+            0u32,
+            acc.polynomial_size(),
+            acc.glwe_dimension().to_glwe_size(),
+        );
+        let buffers =
+            self.get_fourier_u32_buffer(bsk.polynomial_size(), bsk.glwe_dimension().to_glwe_size());
+        bsk.0
+            .blind_rotate_accumulator(&mut output, &input.0, &acc.0, buffers);
+        GlweCiphertext32(output)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextBlindRotationEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers.
+impl
+    LweCiphertextBlindRotationEngine<
+        FourierLweBootstrapKey64,
+        GlweCiphertext64,
+        LweCiphertext64,
+        GlweCiphertext64,
+    > for CoreEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(1), PolynomialSize(1024));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is encoded in the accumulator
+    /// let lut = vec![8_u64 << 50; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: FourierLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc =
+    ///     engine.trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    ///
+    /// let output = engine.blind_rotate_lwe_ciphertext(&input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dim);
+    /// assert_eq!(output.polynomial_size(), poly_size);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn blind_rotate_lwe_ciphertext(
+        &mut self,
+        input: &LweCiphertext64,
+        acc: &GlweCiphertext64,
+        bsk: &FourierLweBootstrapKey64,
+    ) -> Result<GlweCiphertext64, LweCiphertextBlindRotationError<Self::EngineError>> {
+        if !ALLOWED_POLY_SIZE.contains(&acc.polynomial_size().0) {
+            return Err(LweCiphertextBlindRotationError::from(
+                CoreError::UnsupportedPolynomialSize,
+            ));
+        }
+        LweCiphertextBlindRotationError::perform_generic_checks(input, acc, bsk)?;
+        Ok(unsafe { self.blind_rotate_lwe_ciphertext_unchecked(input, acc, bsk) })
+    }
+
+    unsafe fn blind_rotate_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &LweCiphertext64,
+        acc: &GlweCiphertext64,
+        bsk: &FourierLweBootstrapKey64,
+    ) -> GlweCiphertext64 {
+        let mut output = ImplGlweCiphertext::allocate(
+            0u64,
+            acc.polynomial_size(),
+            acc.glwe_dimension().to_glwe_size(),
+        );
+        let buffers =
+            self.get_fourier_u64_buffer(bsk.polynomial_size(), bsk.glwe_dimension().to_glwe_size());
+        bsk.0
+            .blind_rotate_accumulator(&mut output, &input.0, &acc.0, buffers);
+        GlweCiphertext64(output)
+    }
+}
@@ -0,0 +1,136 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{LweCiphertext32, LweCiphertext64};
+use crate::backends::core::private::crypto::lwe::LweCiphertext as ImplLweCiphertext;
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::backends::core::private::math::torus::CastTorus;
+use crate::specification::engines::{LweCiphertextConversionEngine, LweCiphertextConversionError};
+
+/// # Description:
+/// Implementation of [`LweCiphertextConversionEngine`] for [`CoreEngine`] that converts a 32 bits
+/// LWE ciphertext into a 64 bits LWE ciphertext, by rescaling the torus elements.
+impl LweCiphertextConversionEngine<LweCiphertext32, LweCiphertext64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let output: LweCiphertext64 = engine.convert_lwe_ciphertext(&ciphertext)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    ///
+    /// let output_key: LweSecretKey64 = engine.convert_lwe_secret_key(&key)?;
+    /// let decrypted = engine.decrypt_lwe_ciphertext(&output_key, &output)?;
+    /// let raw: u64 = engine.retrieve_plaintext(&decrypted)?;
+    /// // The message is recovered with the encoding of the output precision.
+    /// assert_eq!(raw.wrapping_add(1 << 51) >> 52, 3);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(output)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(decrypted)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_ciphertext(
+        &mut self,
+        input: &LweCiphertext32,
+    ) -> Result<LweCiphertext64, LweCiphertextConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &LweCiphertext32,
+    ) -> LweCiphertext64 {
+        LweCiphertext64(ImplLweCiphertext::from_container(
+            input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|&x| x.cast_torus())
+                .collect::<Vec<u64>>(),
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextConversionEngine`] for [`CoreEngine`] that converts a 64 bits
+/// LWE ciphertext into a 32 bits LWE ciphertext, by rescaling the torus elements.
+impl LweCiphertextConversionEngine<LweCiphertext64, LweCiphertext32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let output: LweCiphertext32 = engine.convert_lwe_ciphertext(&ciphertext)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    ///
+    /// let output_key: LweSecretKey32 = engine.convert_lwe_secret_key(&key)?;
+    /// let decrypted = engine.decrypt_lwe_ciphertext(&output_key, &output)?;
+    /// let raw: u32 = engine.retrieve_plaintext(&decrypted)?;
+    /// // The message is recovered with the encoding of the output precision.
+    /// assert_eq!(raw.wrapping_add(1 << 17) >> 18, 3);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(output)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(decrypted)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_ciphertext(
+        &mut self,
+        input: &LweCiphertext64,
+    ) -> Result<LweCiphertext32, LweCiphertextConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &LweCiphertext64,
+    ) -> LweCiphertext32 {
+        LweCiphertext32(ImplLweCiphertext::from_container(
+            input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|&x| x.cast_torus())
+                .collect::<Vec<u32>>(),
+        ))
+    }
+}
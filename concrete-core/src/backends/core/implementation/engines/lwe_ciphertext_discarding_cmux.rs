@@ -0,0 +1,167 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    GswCiphertext32, GswCiphertext64, LweCiphertext32, LweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingCmuxEngine, LweCiphertextDiscardingCmuxError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingCmuxEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers.
+impl LweCiphertextDiscardingCmuxEngine<LweCiphertext32, GswCiphertext32, LweCiphertext32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(4);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(7);
+    /// // The GSW ciphertext encrypts a raw one (selecting the second input), while a hard-set
+    /// // encoding is applied to the LWE ciphertexts (shift by 20 bits)
+    /// let input_gsw = 1_u32;
+    /// let input_0 = 3_u32 << 20;
+    /// let input_1 = 5_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_gsw = engine.create_plaintext(&input_gsw)?;
+    /// let plaintext_0 = engine.create_plaintext(&input_0)?;
+    /// let plaintext_1 = engine.create_plaintext(&input_1)?;
+    ///
+    /// let gsw =
+    ///     engine.encrypt_scalar_gsw_ciphertext(&key, &plaintext_gsw, noise, level, base_log)?;
+    /// let ciphertext_0 = engine.encrypt_lwe_ciphertext(&key, &plaintext_0, noise)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&key, &plaintext_1, noise)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_cmux_lwe_ciphertext(&mut output, &ciphertext_0, &ciphertext_1, &gsw)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_gsw)?;
+    /// engine.destroy(plaintext_0)?;
+    /// engine.destroy(plaintext_1)?;
+    /// engine.destroy(gsw)?;
+    /// engine.destroy(ciphertext_0)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_cmux_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input_0: &LweCiphertext32,
+        input_1: &LweCiphertext32,
+        gsw_input: &GswCiphertext32,
+    ) -> Result<(), LweCiphertextDiscardingCmuxError<Self::EngineError>> {
+        LweCiphertextDiscardingCmuxError::perform_generic_checks(
+            output, input_0, input_1, gsw_input,
+        )?;
+        unsafe { self.discard_cmux_lwe_ciphertext_unchecked(output, input_0, input_1, gsw_input) };
+        Ok(())
+    }
+
+    unsafe fn discard_cmux_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input_0: &LweCiphertext32,
+        input_1: &LweCiphertext32,
+        gsw_input: &GswCiphertext32,
+    ) {
+        gsw_input.0.cmux(&mut output.0, &input_0.0, &input_1.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingCmuxEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers.
+impl LweCiphertextDiscardingCmuxEngine<LweCiphertext64, GswCiphertext64, LweCiphertext64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(4);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(7);
+    /// // The GSW ciphertext encrypts a raw one (selecting the second input), while a hard-set
+    /// // encoding is applied to the LWE ciphertexts (shift by 50 bits)
+    /// let input_gsw = 1_u64;
+    /// let input_0 = 3_u64 << 50;
+    /// let input_1 = 5_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_gsw = engine.create_plaintext(&input_gsw)?;
+    /// let plaintext_0 = engine.create_plaintext(&input_0)?;
+    /// let plaintext_1 = engine.create_plaintext(&input_1)?;
+    ///
+    /// let gsw =
+    ///     engine.encrypt_scalar_gsw_ciphertext(&key, &plaintext_gsw, noise, level, base_log)?;
+    /// let ciphertext_0 = engine.encrypt_lwe_ciphertext(&key, &plaintext_0, noise)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&key, &plaintext_1, noise)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_cmux_lwe_ciphertext(&mut output, &ciphertext_0, &ciphertext_1, &gsw)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_gsw)?;
+    /// engine.destroy(plaintext_0)?;
+    /// engine.destroy(plaintext_1)?;
+    /// engine.destroy(gsw)?;
+    /// engine.destroy(ciphertext_0)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_cmux_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input_0: &LweCiphertext64,
+        input_1: &LweCiphertext64,
+        gsw_input: &GswCiphertext64,
+    ) -> Result<(), LweCiphertextDiscardingCmuxError<Self::EngineError>> {
+        LweCiphertextDiscardingCmuxError::perform_generic_checks(
+            output, input_0, input_1, gsw_input,
+        )?;
+        unsafe { self.discard_cmux_lwe_ciphertext_unchecked(output, input_0, input_1, gsw_input) };
+        Ok(())
+    }
+
+    unsafe fn discard_cmux_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input_0: &LweCiphertext64,
+        input_1: &LweCiphertext64,
+        gsw_input: &GswCiphertext64,
+    ) {
+        gsw_input.0.cmux(&mut output.0, &input_0.0, &input_1.0);
+    }
+}
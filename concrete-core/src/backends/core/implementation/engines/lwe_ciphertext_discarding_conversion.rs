@@ -0,0 +1,141 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{LweCiphertext32, LweCiphertext64};
+use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::backends::core::private::math::torus::CastTorus;
+use crate::specification::engines::{
+    LweCiphertextDiscardingConversionEngine, LweCiphertextDiscardingConversionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingConversionEngine`] for [`CoreEngine`] that converts a
+/// 32 bits LWE ciphertext into a 64 bits LWE ciphertext, by rescaling the torus elements.
+impl LweCiphertextDiscardingConversionEngine<LweCiphertext32, LweCiphertext64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let output_key: LweSecretKey64 = engine.convert_lwe_secret_key(&key)?;
+    /// let mut output: LweCiphertext64 =
+    ///     engine.zero_encrypt_lwe_ciphertext(&output_key, Variance(2_f64.powf(-50.)))?;
+    ///
+    /// engine.discard_convert_lwe_ciphertext(&mut output, &ciphertext)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    ///
+    /// let decrypted = engine.decrypt_lwe_ciphertext(&output_key, &output)?;
+    /// let raw: u64 = engine.retrieve_plaintext(&decrypted)?;
+    /// // The message is recovered with the encoding of the output precision.
+    /// assert_eq!(raw.wrapping_add(1 << 51) >> 52, 3);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(output)?;
+    /// engine.destroy(decrypted)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext32,
+    ) -> Result<(), LweCiphertextDiscardingConversionError<Self::EngineError>> {
+        LweCiphertextDiscardingConversionError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_convert_lwe_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext32,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |&x| x.cast_torus());
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingConversionEngine`] for [`CoreEngine`] that converts a
+/// 64 bits LWE ciphertext into a 32 bits LWE ciphertext, by rescaling the torus elements.
+impl LweCiphertextDiscardingConversionEngine<LweCiphertext64, LweCiphertext32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let output_key: LweSecretKey32 = engine.convert_lwe_secret_key(&key)?;
+    /// let mut output: LweCiphertext32 =
+    ///     engine.zero_encrypt_lwe_ciphertext(&output_key, Variance(2_f64.powf(-25.)))?;
+    ///
+    /// engine.discard_convert_lwe_ciphertext(&mut output, &ciphertext)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    ///
+    /// let decrypted = engine.decrypt_lwe_ciphertext(&output_key, &output)?;
+    /// let raw: u32 = engine.retrieve_plaintext(&decrypted)?;
+    /// // The message is recovered with the encoding of the output precision.
+    /// assert_eq!(raw.wrapping_add(1 << 17) >> 18, 3);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(output)?;
+    /// engine.destroy(decrypted)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext64,
+    ) -> Result<(), LweCiphertextDiscardingConversionError<Self::EngineError>> {
+        LweCiphertextDiscardingConversionError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_convert_lwe_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext64,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |&x| x.cast_torus());
+    }
+}
@@ -0,0 +1,142 @@
+use concrete_commons::parameters::LweCiphertextIndex;
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertext32, LweCiphertext64, LweCiphertextVector32, LweCiphertextVector64,
+};
+use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::specification::engines::{
+    LweCiphertextDiscardingLoadingEngine, LweCiphertextDiscardingLoadingError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingLoadingEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers.
+impl LweCiphertextDiscardingLoadingEngine<LweCiphertextVector32, LweCiphertext32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextIndex, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let mut ciphertext = engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_load_lwe_ciphertext(
+    ///     &mut ciphertext,
+    ///     &ciphertext_vector,
+    ///     LweCiphertextIndex(1),
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_load_lwe_ciphertext(
+        &mut self,
+        ciphertext: &mut LweCiphertext32,
+        vector: &LweCiphertextVector32,
+        i: LweCiphertextIndex,
+    ) -> Result<(), LweCiphertextDiscardingLoadingError<Self::EngineError>> {
+        LweCiphertextDiscardingLoadingError::perform_generic_checks(ciphertext, vector, i)?;
+        unsafe { self.discard_load_lwe_ciphertext_unchecked(ciphertext, vector, i) };
+        Ok(())
+    }
+
+    unsafe fn discard_load_lwe_ciphertext_unchecked(
+        &mut self,
+        ciphertext: &mut LweCiphertext32,
+        vector: &LweCiphertextVector32,
+        i: LweCiphertextIndex,
+    ) {
+        let input = vector.0.ciphertext_iter().nth(i.0).unwrap();
+        ciphertext
+            .0
+            .as_mut_tensor()
+            .fill_with_copy(input.as_tensor());
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingLoadingEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers.
+impl LweCiphertextDiscardingLoadingEngine<LweCiphertextVector64, LweCiphertext64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextIndex, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let mut ciphertext = engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_load_lwe_ciphertext(
+    ///     &mut ciphertext,
+    ///     &ciphertext_vector,
+    ///     LweCiphertextIndex(1),
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_load_lwe_ciphertext(
+        &mut self,
+        ciphertext: &mut LweCiphertext64,
+        vector: &LweCiphertextVector64,
+        i: LweCiphertextIndex,
+    ) -> Result<(), LweCiphertextDiscardingLoadingError<Self::EngineError>> {
+        LweCiphertextDiscardingLoadingError::perform_generic_checks(ciphertext, vector, i)?;
+        unsafe { self.discard_load_lwe_ciphertext_unchecked(ciphertext, vector, i) };
+        Ok(())
+    }
+
+    unsafe fn discard_load_lwe_ciphertext_unchecked(
+        &mut self,
+        ciphertext: &mut LweCiphertext64,
+        vector: &LweCiphertextVector64,
+        i: LweCiphertextIndex,
+    ) {
+        let input = vector.0.ciphertext_iter().nth(i.0).unwrap();
+        ciphertext
+            .0
+            .as_mut_tensor()
+            .fill_with_copy(input.as_tensor());
+    }
+}
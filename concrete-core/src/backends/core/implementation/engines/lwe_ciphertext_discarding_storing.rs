@@ -0,0 +1,142 @@
+use concrete_commons::parameters::LweCiphertextIndex;
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertext32, LweCiphertext64, LweCiphertextVector32, LweCiphertextVector64,
+};
+use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::specification::engines::{
+    LweCiphertextDiscardingStoringEngine, LweCiphertextDiscardingStoringError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingStoringEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers.
+impl LweCiphertextDiscardingStoringEngine<LweCiphertext32, LweCiphertextVector32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweCiphertextIndex, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let mut ciphertext_vector =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&key, noise, LweCiphertextCount(3))?;
+    ///
+    /// engine.discard_store_lwe_ciphertext(
+    ///     &mut ciphertext_vector,
+    ///     &ciphertext,
+    ///     LweCiphertextIndex(1),
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_store_lwe_ciphertext(
+        &mut self,
+        vector: &mut LweCiphertextVector32,
+        ciphertext: &LweCiphertext32,
+        i: LweCiphertextIndex,
+    ) -> Result<(), LweCiphertextDiscardingStoringError<Self::EngineError>> {
+        LweCiphertextDiscardingStoringError::perform_generic_checks(vector, ciphertext, i)?;
+        unsafe { self.discard_store_lwe_ciphertext_unchecked(vector, ciphertext, i) };
+        Ok(())
+    }
+
+    unsafe fn discard_store_lwe_ciphertext_unchecked(
+        &mut self,
+        vector: &mut LweCiphertextVector32,
+        ciphertext: &LweCiphertext32,
+        i: LweCiphertextIndex,
+    ) {
+        let mut output = vector.0.ciphertext_iter_mut().nth(i.0).unwrap();
+        output
+            .as_mut_tensor()
+            .fill_with_copy(ciphertext.0.as_tensor());
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingStoringEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers.
+impl LweCiphertextDiscardingStoringEngine<LweCiphertext64, LweCiphertextVector64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweCiphertextIndex, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let mut ciphertext_vector =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&key, noise, LweCiphertextCount(3))?;
+    ///
+    /// engine.discard_store_lwe_ciphertext(
+    ///     &mut ciphertext_vector,
+    ///     &ciphertext,
+    ///     LweCiphertextIndex(1),
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_store_lwe_ciphertext(
+        &mut self,
+        vector: &mut LweCiphertextVector64,
+        ciphertext: &LweCiphertext64,
+        i: LweCiphertextIndex,
+    ) -> Result<(), LweCiphertextDiscardingStoringError<Self::EngineError>> {
+        LweCiphertextDiscardingStoringError::perform_generic_checks(vector, ciphertext, i)?;
+        unsafe { self.discard_store_lwe_ciphertext_unchecked(vector, ciphertext, i) };
+        Ok(())
+    }
+
+    unsafe fn discard_store_lwe_ciphertext_unchecked(
+        &mut self,
+        vector: &mut LweCiphertextVector64,
+        ciphertext: &LweCiphertext64,
+        i: LweCiphertextIndex,
+    ) {
+        let mut output = vector.0.ciphertext_iter_mut().nth(i.0).unwrap();
+        output
+            .as_mut_tensor()
+            .fill_with_copy(ciphertext.0.as_tensor());
+    }
+}
@@ -0,0 +1,171 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    GswCiphertext32, GswCiphertext64, LweCiphertext32, LweCiphertext64,
+};
+use crate::backends::core::private::crypto::lwe::LweCiphertext as ImplLweCiphertext;
+use crate::specification::engines::{
+    LweCiphertextGswCiphertextExternalProductEngine, LweCiphertextGswCiphertextExternalProductError,
+};
+use crate::specification::entities::LweCiphertextEntity;
+
+/// # Description:
+/// Implementation of [`LweCiphertextGswCiphertextExternalProductEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers.
+impl
+    LweCiphertextGswCiphertextExternalProductEngine<
+        LweCiphertext32,
+        GswCiphertext32,
+        LweCiphertext32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(4);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(7);
+    /// // The GSW ciphertext encrypts a raw one, while a hard-set encoding is applied to the LWE
+    /// // ciphertext (shift by 20 bits)
+    /// let input_gsw = 1_u32;
+    /// let input_lwe = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_gsw = engine.create_plaintext(&input_gsw)?;
+    /// let plaintext_lwe = engine.create_plaintext(&input_lwe)?;
+    ///
+    /// let gsw =
+    ///     engine.encrypt_scalar_gsw_ciphertext(&key, &plaintext_gsw, noise, level, base_log)?;
+    /// let lwe = engine.encrypt_lwe_ciphertext(&key, &plaintext_lwe, noise)?;
+    ///
+    /// // Compute the external product.
+    /// let product = engine.compute_external_product_lwe_ciphertext_gsw_ciphertext(&lwe, &gsw)?;
+    /// #
+    /// assert_eq!(product.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_gsw)?;
+    /// engine.destroy(plaintext_lwe)?;
+    /// engine.destroy(gsw)?;
+    /// engine.destroy(lwe)?;
+    /// engine.destroy(product)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn compute_external_product_lwe_ciphertext_gsw_ciphertext(
+        &mut self,
+        lwe_input: &LweCiphertext32,
+        gsw_input: &GswCiphertext32,
+    ) -> Result<LweCiphertext32, LweCiphertextGswCiphertextExternalProductError<Self::EngineError>>
+    {
+        LweCiphertextGswCiphertextExternalProductError::perform_generic_checks(
+            lwe_input, gsw_input,
+        )?;
+        Ok(unsafe {
+            self.compute_external_product_lwe_ciphertext_gsw_ciphertext_unchecked(
+                lwe_input, gsw_input,
+            )
+        })
+    }
+
+    unsafe fn compute_external_product_lwe_ciphertext_gsw_ciphertext_unchecked(
+        &mut self,
+        lwe_input: &LweCiphertext32,
+        gsw_input: &GswCiphertext32,
+    ) -> LweCiphertext32 {
+        let mut output = ImplLweCiphertext::allocate(0u32, lwe_input.lwe_dimension().to_lwe_size());
+        gsw_input.0.external_product(&mut output, &lwe_input.0);
+        LweCiphertext32(output)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextGswCiphertextExternalProductEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers.
+impl
+    LweCiphertextGswCiphertextExternalProductEngine<
+        LweCiphertext64,
+        GswCiphertext64,
+        LweCiphertext64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(4);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(7);
+    /// // The GSW ciphertext encrypts a raw one, while a hard-set encoding is applied to the LWE
+    /// // ciphertext (shift by 50 bits)
+    /// let input_gsw = 1_u64;
+    /// let input_lwe = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_gsw = engine.create_plaintext(&input_gsw)?;
+    /// let plaintext_lwe = engine.create_plaintext(&input_lwe)?;
+    ///
+    /// let gsw =
+    ///     engine.encrypt_scalar_gsw_ciphertext(&key, &plaintext_gsw, noise, level, base_log)?;
+    /// let lwe = engine.encrypt_lwe_ciphertext(&key, &plaintext_lwe, noise)?;
+    ///
+    /// // Compute the external product.
+    /// let product = engine.compute_external_product_lwe_ciphertext_gsw_ciphertext(&lwe, &gsw)?;
+    /// #
+    /// assert_eq!(product.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_gsw)?;
+    /// engine.destroy(plaintext_lwe)?;
+    /// engine.destroy(gsw)?;
+    /// engine.destroy(lwe)?;
+    /// engine.destroy(product)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn compute_external_product_lwe_ciphertext_gsw_ciphertext(
+        &mut self,
+        lwe_input: &LweCiphertext64,
+        gsw_input: &GswCiphertext64,
+    ) -> Result<LweCiphertext64, LweCiphertextGswCiphertextExternalProductError<Self::EngineError>>
+    {
+        LweCiphertextGswCiphertextExternalProductError::perform_generic_checks(
+            lwe_input, gsw_input,
+        )?;
+        Ok(unsafe {
+            self.compute_external_product_lwe_ciphertext_gsw_ciphertext_unchecked(
+                lwe_input, gsw_input,
+            )
+        })
+    }
+
+    unsafe fn compute_external_product_lwe_ciphertext_gsw_ciphertext_unchecked(
+        &mut self,
+        lwe_input: &LweCiphertext64,
+        gsw_input: &GswCiphertext64,
+    ) -> LweCiphertext64 {
+        let mut output = ImplLweCiphertext::allocate(0u64, lwe_input.lwe_dimension().to_lwe_size());
+        gsw_input.0.external_product(&mut output, &lwe_input.0);
+        LweCiphertext64(output)
+    }
+}
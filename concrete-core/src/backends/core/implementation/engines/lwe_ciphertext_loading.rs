@@ -0,0 +1,125 @@
+use concrete_commons::parameters::LweCiphertextIndex;
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertext32, LweCiphertext64, LweCiphertextVector32, LweCiphertextVector64,
+};
+use crate::backends::core::private::crypto::lwe::LweCiphertext as ImplLweCiphertext;
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::specification::engines::{LweCiphertextLoadingEngine, LweCiphertextLoadingError};
+
+/// # Description:
+/// Implementation of [`LweCiphertextLoadingEngine`] for [`CoreEngine`] that operates on 32 bits
+/// integers.
+impl LweCiphertextLoadingEngine<LweCiphertextVector32, LweCiphertext32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextIndex, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let ciphertext: LweCiphertext32 =
+    ///     engine.load_lwe_ciphertext(&ciphertext_vector, LweCiphertextIndex(1))?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn load_lwe_ciphertext(
+        &mut self,
+        vector: &LweCiphertextVector32,
+        i: LweCiphertextIndex,
+    ) -> Result<LweCiphertext32, LweCiphertextLoadingError<Self::EngineError>> {
+        LweCiphertextLoadingError::perform_generic_checks::<LweCiphertext32, _>(vector, i)?;
+        Ok(unsafe { self.load_lwe_ciphertext_unchecked(vector, i) })
+    }
+
+    unsafe fn load_lwe_ciphertext_unchecked(
+        &mut self,
+        vector: &LweCiphertextVector32,
+        i: LweCiphertextIndex,
+    ) -> LweCiphertext32 {
+        let ciphertext = vector.0.ciphertext_iter().nth(i.0).unwrap();
+        LweCiphertext32(ImplLweCiphertext::from_container(
+            ciphertext.as_tensor().as_container().to_vec(),
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextLoadingEngine`] for [`CoreEngine`] that operates on 64 bits
+/// integers.
+impl LweCiphertextLoadingEngine<LweCiphertextVector64, LweCiphertext64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextIndex, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let ciphertext: LweCiphertext64 =
+    ///     engine.load_lwe_ciphertext(&ciphertext_vector, LweCiphertextIndex(1))?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn load_lwe_ciphertext(
+        &mut self,
+        vector: &LweCiphertextVector64,
+        i: LweCiphertextIndex,
+    ) -> Result<LweCiphertext64, LweCiphertextLoadingError<Self::EngineError>> {
+        LweCiphertextLoadingError::perform_generic_checks::<LweCiphertext64, _>(vector, i)?;
+        Ok(unsafe { self.load_lwe_ciphertext_unchecked(vector, i) })
+    }
+
+    unsafe fn load_lwe_ciphertext_unchecked(
+        &mut self,
+        vector: &LweCiphertextVector64,
+        i: LweCiphertextIndex,
+    ) -> LweCiphertext64 {
+        let ciphertext = vector.0.ciphertext_iter().nth(i.0).unwrap();
+        LweCiphertext64(ImplLweCiphertext::from_container(
+            ciphertext.as_tensor().as_container().to_vec(),
+        ))
+    }
+}
@@ -0,0 +1,154 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertextVector32, LweCiphertextVector64,
+};
+use crate::backends::core::private::crypto::lwe::LweList as ImplLweList;
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::backends::core::private::math::torus::CastTorus;
+use crate::specification::engines::{
+    LweCiphertextVectorConversionEngine, LweCiphertextVectorConversionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorConversionEngine`] for [`CoreEngine`] that converts a 32
+/// bits LWE ciphertext vector into a 64 bits LWE ciphertext vector, by rescaling the torus
+/// elements.
+impl LweCiphertextVectorConversionEngine<LweCiphertextVector32, LweCiphertextVector64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let output: LweCiphertextVector64 =
+    ///     engine.convert_lwe_ciphertext_vector(&ciphertext_vector)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// let output_key: LweSecretKey64 = engine.convert_lwe_secret_key(&key)?;
+    /// let decrypted = engine.decrypt_lwe_ciphertext_vector(&output_key, &output)?;
+    /// let raw: Vec<u64> = engine.retrieve_plaintext_vector(&decrypted)?;
+    /// // The messages are recovered with the encoding of the output precision.
+    /// assert!(raw.iter().all(|p| p.wrapping_add(1 << 51) >> 52 == 3));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(decrypted)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_ciphertext_vector(
+        &mut self,
+        input: &LweCiphertextVector32,
+    ) -> Result<LweCiphertextVector64, LweCiphertextVectorConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_ciphertext_vector_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        input: &LweCiphertextVector32,
+    ) -> LweCiphertextVector64 {
+        LweCiphertextVector64(ImplLweList::from_container(
+            input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|&x| x.cast_torus())
+                .collect::<Vec<u64>>(),
+            input.0.lwe_size(),
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorConversionEngine`] for [`CoreEngine`] that converts a 64
+/// bits LWE ciphertext vector into a 32 bits LWE ciphertext vector, by rescaling the torus
+/// elements.
+impl LweCiphertextVectorConversionEngine<LweCiphertextVector64, LweCiphertextVector32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let output: LweCiphertextVector32 =
+    ///     engine.convert_lwe_ciphertext_vector(&ciphertext_vector)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// let output_key: LweSecretKey32 = engine.convert_lwe_secret_key(&key)?;
+    /// let decrypted = engine.decrypt_lwe_ciphertext_vector(&output_key, &output)?;
+    /// let raw: Vec<u32> = engine.retrieve_plaintext_vector(&decrypted)?;
+    /// // The messages are recovered with the encoding of the output precision.
+    /// assert!(raw.iter().all(|p| p.wrapping_add(1 << 17) >> 18 == 3));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(decrypted)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_ciphertext_vector(
+        &mut self,
+        input: &LweCiphertextVector64,
+    ) -> Result<LweCiphertextVector32, LweCiphertextVectorConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_ciphertext_vector_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        input: &LweCiphertextVector64,
+    ) -> LweCiphertextVector32 {
+        LweCiphertextVector32(ImplLweList::from_container(
+            input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|&x| x.cast_torus())
+                .collect::<Vec<u32>>(),
+            input.0.lwe_size(),
+        ))
+    }
+}
@@ -1,6 +1,8 @@
 use concrete_commons::dispersion::Variance;
 
 use crate::backends::core::implementation::engines::CoreEngine;
+#[cfg(feature = "multithread")]
+use crate::backends::core::implementation::engines::CoreParallelEngine;
 use crate::backends::core::implementation::entities::{
     LweCiphertextVector32, LweCiphertextVector64, LweSecretKey32, LweSecretKey64,
     PlaintextVector32, PlaintextVector64,
@@ -164,3 +166,167 @@ impl
         );
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingEncryptionEngine`] for [`CoreParallelEngine`]
+/// that operates on 32 bits integers.
+///
+/// The ciphertexts of the output vector are encrypted in parallel, using forked generators.
+#[cfg(feature = "multithread")]
+impl
+    LweCiphertextVectorDiscardingEncryptionEngine<
+        LweSecretKey32,
+        PlaintextVector32,
+        LweCiphertextVector32,
+    > for CoreParallelEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let mut parallel_engine = CoreParallelEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector(&input)?;
+    /// let mut ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&key, noise, LweCiphertextCount(3))?;
+    ///
+    /// parallel_engine.discard_encrypt_lwe_ciphertext_vector(
+    ///     &key,
+    ///     &mut ciphertext_vector,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    /// #     ciphertext_vector.lwe_ciphertext_count(),
+    /// #     LweCiphertextCount(3)
+    /// # );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &LweSecretKey32,
+        output: &mut LweCiphertextVector32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<(), LweCiphertextVectorDiscardingEncryptionError<Self::EngineError>> {
+        LweCiphertextVectorDiscardingEncryptionError::perform_generic_checks(key, output, input)?;
+        unsafe { self.discard_encrypt_lwe_ciphertext_vector_unchecked(key, output, input, noise) };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &LweSecretKey32,
+        output: &mut LweCiphertextVector32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) {
+        key.0.par_encrypt_lwe_list(
+            &mut output.0,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingEncryptionEngine`] for [`CoreParallelEngine`]
+/// that operates on 64 bits integers.
+///
+/// The ciphertexts of the output vector are encrypted in parallel, using forked generators.
+#[cfg(feature = "multithread")]
+impl
+    LweCiphertextVectorDiscardingEncryptionEngine<
+        LweSecretKey64,
+        PlaintextVector64,
+        LweCiphertextVector64,
+    > for CoreParallelEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let mut parallel_engine = CoreParallelEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector(&input)?;
+    /// let mut ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&key, noise, LweCiphertextCount(3))?;
+    ///
+    /// parallel_engine.discard_encrypt_lwe_ciphertext_vector(
+    ///     &key,
+    ///     &mut ciphertext_vector,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// );
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    /// #     ciphertext_vector.lwe_ciphertext_count(),
+    /// #     LweCiphertextCount(3)
+    /// # );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &LweSecretKey64,
+        output: &mut LweCiphertextVector64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<(), LweCiphertextVectorDiscardingEncryptionError<Self::EngineError>> {
+        LweCiphertextVectorDiscardingEncryptionError::perform_generic_checks(key, output, input)?;
+        unsafe { self.discard_encrypt_lwe_ciphertext_vector_unchecked(key, output, input, noise) };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &LweSecretKey64,
+        output: &mut LweCiphertextVector64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) {
+        key.0.par_encrypt_lwe_list(
+            &mut output.0,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+    }
+}
@@ -0,0 +1,174 @@
+#[allow(deprecated)]
+use concrete_commons::parameters::MonomialDegree;
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, LweCiphertextVector32, LweCiphertextVector64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorDiscardingExtractionEngine, LweCiphertextVectorDiscardingExtractionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingExtractionEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers.
+impl LweCiphertextVectorDiscardingExtractionEngine<GlweCiphertext32, LweCiphertextVector32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, LweCiphertextCount, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// // The target LWE dimension should be equal to the GLWE dimension times the polynomial size
+    /// let lwe_dimension = LweDimension(8);
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext, and we are
+    /// // going to extract all of them
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let glwe_key: GlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let lwe_key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let glwe_ciphertext = engine.encrypt_glwe_ciphertext(&glwe_key, &plaintext_vector, noise)?;
+    /// // We first create an LWE ciphertext vector encrypting zeros
+    /// let mut lwe_ciphertext_vector = engine.zero_encrypt_lwe_ciphertext_vector(
+    ///     &lwe_key,
+    ///     noise,
+    ///     LweCiphertextCount(polynomial_size.0),
+    /// )?;
+    ///
+    /// // Then we extract all the samples from the GLWE ciphertext to store them into the vector
+    /// engine.discard_extract_lwe_ciphertext_vector(&mut lwe_ciphertext_vector, &glwe_ciphertext)?;
+    /// #
+    /// assert_eq!(lwe_ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    ///     lwe_ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(polynomial_size.0)
+    /// );
+    ///
+    /// engine.destroy(glwe_key)?;
+    /// engine.destroy(lwe_key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(glwe_ciphertext)?;
+    /// engine.destroy(lwe_ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_extract_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &GlweCiphertext32,
+    ) -> Result<(), LweCiphertextVectorDiscardingExtractionError<Self::EngineError>> {
+        LweCiphertextVectorDiscardingExtractionError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_extract_lwe_ciphertext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_extract_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &GlweCiphertext32,
+    ) {
+        for (nth, mut lwe) in output.0.ciphertext_iter_mut().enumerate() {
+            #[allow(deprecated)]
+            input
+                .0
+                .fill_lwe_with_sample_extraction(&mut lwe, MonomialDegree(nth));
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingExtractionEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers.
+impl LweCiphertextVectorDiscardingExtractionEngine<GlweCiphertext64, LweCiphertextVector64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, LweCiphertextCount, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// // The target LWE dimension should be equal to the GLWE dimension times the polynomial size
+    /// let lwe_dimension = LweDimension(8);
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext, and we are
+    /// // going to extract all of them
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let glwe_key: GlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let lwe_key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let glwe_ciphertext = engine.encrypt_glwe_ciphertext(&glwe_key, &plaintext_vector, noise)?;
+    /// // We first create an LWE ciphertext vector encrypting zeros
+    /// let mut lwe_ciphertext_vector = engine.zero_encrypt_lwe_ciphertext_vector(
+    ///     &lwe_key,
+    ///     noise,
+    ///     LweCiphertextCount(polynomial_size.0),
+    /// )?;
+    ///
+    /// // Then we extract all the samples from the GLWE ciphertext to store them into the vector
+    /// engine.discard_extract_lwe_ciphertext_vector(&mut lwe_ciphertext_vector, &glwe_ciphertext)?;
+    /// #
+    /// assert_eq!(lwe_ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    ///     lwe_ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(polynomial_size.0)
+    /// );
+    ///
+    /// engine.destroy(glwe_key)?;
+    /// engine.destroy(lwe_key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(glwe_ciphertext)?;
+    /// engine.destroy(lwe_ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_extract_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &GlweCiphertext64,
+    ) -> Result<(), LweCiphertextVectorDiscardingExtractionError<Self::EngineError>> {
+        LweCiphertextVectorDiscardingExtractionError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_extract_lwe_ciphertext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_extract_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &GlweCiphertext64,
+    ) {
+        for (nth, mut lwe) in output.0.ciphertext_iter_mut().enumerate() {
+            #[allow(deprecated)]
+            input
+                .0
+                .fill_lwe_with_sample_extraction(&mut lwe, MonomialDegree(nth));
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{LweKeyswitchKey32, LweKeyswitchKey64};
+use crate::backends::core::private::crypto::lwe::LweKeyswitchKey as ImplLweKeyswitchKey;
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::backends::core::private::math::torus::CastTorus;
+use crate::prelude::CoreError;
+use crate::specification::engines::{
+    LweKeyswitchKeyConversionEngine, LweKeyswitchKeyConversionError,
+};
+use crate::specification::entities::LweKeyswitchKeyEntity;
+
+impl From<CoreError> for LweKeyswitchKeyConversionError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyConversionEngine`] for [`CoreEngine`] that converts a 32 bits
+/// LWE keyswitch key into a 64 bits LWE keyswitch key, by rescaling the torus elements.
+impl LweKeyswitchKeyConversionEngine<LweKeyswitchKey32, LweKeyswitchKey64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-60.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let output: LweKeyswitchKey64 = engine.convert_lwe_keyswitch_key(&keyswitch_key)?;
+    /// #
+    /// assert_eq!(output.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(output.output_lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(output.decomposition_level_count(), decomposition_level_count);
+    /// assert_eq!(output.decomposition_base_log(), decomposition_base_log);
+    ///
+    /// // The converted key switches the keys of ciphertexts of the output precision.
+    /// let converted_input_key: LweSecretKey64 = engine.convert_lwe_secret_key(&input_key)?;
+    /// let converted_output_key: LweSecretKey64 = engine.convert_lwe_secret_key(&output_key)?;
+    /// let plaintext = engine.create_plaintext(&(3_u64 << 52))?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&converted_input_key, &plaintext, noise)?;
+    /// let mut switched = engine.zero_encrypt_lwe_ciphertext(&converted_output_key, noise)?;
+    /// engine.discard_keyswitch_lwe_ciphertext(&mut switched, &ciphertext, &output)?;
+    /// let decrypted = engine.decrypt_lwe_ciphertext(&converted_output_key, &switched)?;
+    /// let raw: u64 = engine.retrieve_plaintext(&decrypted)?;
+    /// assert_eq!(raw.wrapping_add(1 << 51) >> 52, 3);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(output)?;
+    /// engine.destroy(converted_input_key)?;
+    /// engine.destroy(converted_output_key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(switched)?;
+    /// engine.destroy(decrypted)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_keyswitch_key(
+        &mut self,
+        input: &LweKeyswitchKey32,
+    ) -> Result<LweKeyswitchKey64, LweKeyswitchKeyConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_keyswitch_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input: &LweKeyswitchKey32,
+    ) -> LweKeyswitchKey64 {
+        LweKeyswitchKey64(ImplLweKeyswitchKey::from_container(
+            input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|&x| x.cast_torus())
+                .collect::<Vec<u64>>(),
+            input.decomposition_base_log(),
+            input.decomposition_level_count(),
+            input.output_lwe_dimension(),
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyConversionEngine`] for [`CoreEngine`] that converts a 64 bits
+/// LWE keyswitch key into a 32 bits LWE keyswitch key, by rescaling the torus elements.
+///
+/// The decomposition precision of the key must fit in the output integers.
+impl LweKeyswitchKeyConversionEngine<LweKeyswitchKey64, LweKeyswitchKey32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-60.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let output: LweKeyswitchKey32 = engine.convert_lwe_keyswitch_key(&keyswitch_key)?;
+    /// #
+    /// assert_eq!(output.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(output.output_lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(output.decomposition_level_count(), decomposition_level_count);
+    /// assert_eq!(output.decomposition_base_log(), decomposition_base_log);
+    ///
+    /// // The converted key switches the keys of ciphertexts of the output precision.
+    /// let converted_input_key: LweSecretKey32 = engine.convert_lwe_secret_key(&input_key)?;
+    /// let converted_output_key: LweSecretKey32 = engine.convert_lwe_secret_key(&output_key)?;
+    /// let plaintext = engine.create_plaintext(&(3_u32 << 20))?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&converted_input_key, &plaintext, noise)?;
+    /// let mut switched = engine.zero_encrypt_lwe_ciphertext(&converted_output_key, noise)?;
+    /// engine.discard_keyswitch_lwe_ciphertext(&mut switched, &ciphertext, &output)?;
+    /// let decrypted = engine.decrypt_lwe_ciphertext(&converted_output_key, &switched)?;
+    /// let raw: u32 = engine.retrieve_plaintext(&decrypted)?;
+    /// assert_eq!(raw.wrapping_add(1 << 19) >> 20, 3);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(output)?;
+    /// engine.destroy(converted_input_key)?;
+    /// engine.destroy(converted_output_key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(switched)?;
+    /// engine.destroy(decrypted)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_keyswitch_key(
+        &mut self,
+        input: &LweKeyswitchKey64,
+    ) -> Result<LweKeyswitchKey32, LweKeyswitchKeyConversionError<Self::EngineError>> {
+        if input.decomposition_base_log().0 * input.decomposition_level_count().0 > 32 {
+            return Err(LweKeyswitchKeyConversionError::from(
+                CoreError::DecompositionPrecisionTooLarge,
+            ));
+        }
+        Ok(unsafe { self.convert_lwe_keyswitch_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input: &LweKeyswitchKey64,
+    ) -> LweKeyswitchKey32 {
+        LweKeyswitchKey32(ImplLweKeyswitchKey::from_container(
+            input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|&x| x.cast_torus())
+                .collect::<Vec<u32>>(),
+            input.decomposition_base_log(),
+            input.decomposition_level_count(),
+            input.output_lwe_dimension(),
+        ))
+    }
+}
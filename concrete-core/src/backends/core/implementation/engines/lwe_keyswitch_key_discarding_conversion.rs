@@ -0,0 +1,188 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{LweKeyswitchKey32, LweKeyswitchKey64};
+use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::backends::core::private::math::torus::CastTorus;
+use crate::prelude::CoreError;
+use crate::specification::engines::{
+    LweKeyswitchKeyDiscardingConversionEngine, LweKeyswitchKeyDiscardingConversionError,
+};
+use crate::specification::entities::LweKeyswitchKeyEntity;
+
+impl From<CoreError> for LweKeyswitchKeyDiscardingConversionError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyDiscardingConversionEngine`] for [`CoreEngine`] that converts
+/// a 32 bits LWE keyswitch key into a 64 bits LWE keyswitch key, by rescaling the torus elements.
+impl LweKeyswitchKeyDiscardingConversionEngine<LweKeyswitchKey32, LweKeyswitchKey64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let other_input_key: LweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let other_output_key: LweSecretKey64 =
+    ///     engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let mut output: LweKeyswitchKey64 = engine.create_lwe_keyswitch_key(
+    ///     &other_input_key,
+    ///     &other_output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     Variance(2_f64.powf(-50.)),
+    /// )?;
+    ///
+    /// engine.discard_convert_lwe_keyswitch_key(&mut output, &keyswitch_key)?;
+    /// #
+    /// assert_eq!(output.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(output.output_lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(output.decomposition_level_count(), decomposition_level_count);
+    /// assert_eq!(output.decomposition_base_log(), decomposition_base_log);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(other_input_key)?;
+    /// engine.destroy(other_output_key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_keyswitch_key(
+        &mut self,
+        output: &mut LweKeyswitchKey64,
+        input: &LweKeyswitchKey32,
+    ) -> Result<(), LweKeyswitchKeyDiscardingConversionError<Self::EngineError>> {
+        LweKeyswitchKeyDiscardingConversionError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_convert_lwe_keyswitch_key_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_keyswitch_key_unchecked(
+        &mut self,
+        output: &mut LweKeyswitchKey64,
+        input: &LweKeyswitchKey32,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |&x| x.cast_torus());
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyDiscardingConversionEngine`] for [`CoreEngine`] that converts
+/// a 64 bits LWE keyswitch key into a 32 bits LWE keyswitch key, by rescaling the torus elements.
+///
+/// The decomposition precision of the key must fit in the output integers.
+impl LweKeyswitchKeyDiscardingConversionEngine<LweKeyswitchKey64, LweKeyswitchKey32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let other_input_key: LweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let other_output_key: LweSecretKey32 =
+    ///     engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let mut output: LweKeyswitchKey32 = engine.create_lwe_keyswitch_key(
+    ///     &other_input_key,
+    ///     &other_output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     Variance(2_f64.powf(-25.)),
+    /// )?;
+    ///
+    /// engine.discard_convert_lwe_keyswitch_key(&mut output, &keyswitch_key)?;
+    /// #
+    /// assert_eq!(output.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(output.output_lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(output.decomposition_level_count(), decomposition_level_count);
+    /// assert_eq!(output.decomposition_base_log(), decomposition_base_log);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(other_input_key)?;
+    /// engine.destroy(other_output_key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_keyswitch_key(
+        &mut self,
+        output: &mut LweKeyswitchKey32,
+        input: &LweKeyswitchKey64,
+    ) -> Result<(), LweKeyswitchKeyDiscardingConversionError<Self::EngineError>> {
+        LweKeyswitchKeyDiscardingConversionError::perform_generic_checks(output, input)?;
+        if input.decomposition_base_log().0 * input.decomposition_level_count().0 > 32 {
+            return Err(LweKeyswitchKeyDiscardingConversionError::from(
+                CoreError::DecompositionPrecisionTooLarge,
+            ));
+        }
+        unsafe { self.discard_convert_lwe_keyswitch_key_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_keyswitch_key_unchecked(
+        &mut self,
+        output: &mut LweKeyswitchKey32,
+        input: &LweKeyswitchKey64,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |&x| x.cast_torus());
+    }
+}
@@ -0,0 +1,105 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{LweSecretKey32, LweSecretKey64};
+use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::specification::engines::{
+    LweSecretKeyDiscardingConversionEngine, LweSecretKeyDiscardingConversionError,
+};
+use concrete_commons::numeric::CastInto;
+
+/// # Description:
+/// Implementation of [`LweSecretKeyDiscardingConversionEngine`] for [`CoreEngine`] that converts a
+/// 32 bits LWE secret key into a 64 bits LWE secret key, by casting the integer values.
+impl LweSecretKeyDiscardingConversionEngine<LweSecretKey32, LweSecretKey64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let mut output: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// engine.discard_convert_lwe_secret_key(&mut output, &key)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_secret_key(
+        &mut self,
+        output: &mut LweSecretKey64,
+        input: &LweSecretKey32,
+    ) -> Result<(), LweSecretKeyDiscardingConversionError<Self::EngineError>> {
+        LweSecretKeyDiscardingConversionError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_convert_lwe_secret_key_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_secret_key_unchecked(
+        &mut self,
+        output: &mut LweSecretKey64,
+        input: &LweSecretKey32,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |&x| x.cast_into());
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSecretKeyDiscardingConversionEngine`] for [`CoreEngine`] that converts a
+/// 64 bits LWE secret key into a 32 bits LWE secret key, by casting the integer values.
+impl LweSecretKeyDiscardingConversionEngine<LweSecretKey64, LweSecretKey32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let mut output: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// engine.discard_convert_lwe_secret_key(&mut output, &key)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_secret_key(
+        &mut self,
+        output: &mut LweSecretKey32,
+        input: &LweSecretKey64,
+    ) -> Result<(), LweSecretKeyDiscardingConversionError<Self::EngineError>> {
+        LweSecretKeyDiscardingConversionError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_convert_lwe_secret_key_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_secret_key_unchecked(
+        &mut self,
+        output: &mut LweSecretKey32,
+        input: &LweSecretKey64,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |&x| x.cast_into());
+    }
+}
@@ -0,0 +1,115 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertext32, LweCiphertext64, LweSeededCiphertext32, LweSeededCiphertext64,
+};
+use crate::backends::core::private::crypto::lwe::LweCiphertext as ImplLweCiphertext;
+use crate::specification::engines::{
+    LweSeededCiphertextExpansionEngine, LweSeededCiphertextExpansionError,
+};
+use crate::specification::entities::LweSeededCiphertextEntity;
+
+/// # Description:
+/// Implementation of [`LweSeededCiphertextExpansionEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers.
+impl LweSeededCiphertextExpansionEngine<LweSeededCiphertext32, LweCiphertext32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let seeded: LweSeededCiphertext32 =
+    ///     engine.encrypt_lwe_seeded_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let ciphertext: LweCiphertext32 = engine.expand_lwe_seeded_ciphertext(&seeded)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(seeded)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_lwe_seeded_ciphertext(
+        &mut self,
+        input: &LweSeededCiphertext32,
+    ) -> Result<LweCiphertext32, LweSeededCiphertextExpansionError<Self::EngineError>> {
+        Ok(unsafe { self.expand_lwe_seeded_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn expand_lwe_seeded_ciphertext_unchecked(
+        &mut self,
+        input: &LweSeededCiphertext32,
+    ) -> LweCiphertext32 {
+        let mut ciphertext = ImplLweCiphertext::allocate(0u32, input.lwe_dimension().to_lwe_size());
+        input.0.expand_into(&mut ciphertext);
+        LweCiphertext32(ciphertext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSeededCiphertextExpansionEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers.
+impl LweSeededCiphertextExpansionEngine<LweSeededCiphertext64, LweCiphertext64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let seeded: LweSeededCiphertext64 =
+    ///     engine.encrypt_lwe_seeded_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let ciphertext: LweCiphertext64 = engine.expand_lwe_seeded_ciphertext(&seeded)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(seeded)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_lwe_seeded_ciphertext(
+        &mut self,
+        input: &LweSeededCiphertext64,
+    ) -> Result<LweCiphertext64, LweSeededCiphertextExpansionError<Self::EngineError>> {
+        Ok(unsafe { self.expand_lwe_seeded_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn expand_lwe_seeded_ciphertext_unchecked(
+        &mut self,
+        input: &LweSeededCiphertext64,
+    ) -> LweCiphertext64 {
+        let mut ciphertext = ImplLweCiphertext::allocate(0u64, input.lwe_dimension().to_lwe_size());
+        input.0.expand_into(&mut ciphertext);
+        LweCiphertext64(ciphertext)
+    }
+}
@@ -0,0 +1,167 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweKeyswitchKey32, LweKeyswitchKey64, LweSeededKeyswitchKey32, LweSeededKeyswitchKey64,
+};
+use crate::backends::core::private::crypto::lwe::LweKeyswitchKey as ImplLweKeyswitchKey;
+use crate::specification::engines::{
+    LweSeededKeyswitchKeyExpansionEngine, LweSeededKeyswitchKeyExpansionError,
+};
+use crate::specification::entities::LweSeededKeyswitchKeyEntity;
+
+/// # Description:
+/// Implementation of [`LweSeededKeyswitchKeyExpansionEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers.
+impl LweSeededKeyswitchKeyExpansionEngine<LweSeededKeyswitchKey32, LweKeyswitchKey32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let seeded_keyswitch_key = engine.create_lwe_seeded_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let keyswitch_key: LweKeyswitchKey32 =
+    ///     engine.expand_lwe_seeded_keyswitch_key(&seeded_keyswitch_key)?;
+    /// #
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(seeded_keyswitch_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_lwe_seeded_keyswitch_key(
+        &mut self,
+        input: &LweSeededKeyswitchKey32,
+    ) -> Result<LweKeyswitchKey32, LweSeededKeyswitchKeyExpansionError<Self::EngineError>> {
+        Ok(unsafe { self.expand_lwe_seeded_keyswitch_key_unchecked(input) })
+    }
+
+    unsafe fn expand_lwe_seeded_keyswitch_key_unchecked(
+        &mut self,
+        input: &LweSeededKeyswitchKey32,
+    ) -> LweKeyswitchKey32 {
+        let mut ksk = ImplLweKeyswitchKey::allocate(
+            0u32,
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+            input.input_lwe_dimension(),
+            input.output_lwe_dimension(),
+        );
+        input.0.expand_into(&mut ksk);
+        LweKeyswitchKey32(ksk)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSeededKeyswitchKeyExpansionEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers.
+impl LweSeededKeyswitchKeyExpansionEngine<LweSeededKeyswitchKey64, LweKeyswitchKey64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let seeded_keyswitch_key = engine.create_lwe_seeded_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let keyswitch_key: LweKeyswitchKey64 =
+    ///     engine.expand_lwe_seeded_keyswitch_key(&seeded_keyswitch_key)?;
+    /// #
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(seeded_keyswitch_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_lwe_seeded_keyswitch_key(
+        &mut self,
+        input: &LweSeededKeyswitchKey64,
+    ) -> Result<LweKeyswitchKey64, LweSeededKeyswitchKeyExpansionError<Self::EngineError>> {
+        Ok(unsafe { self.expand_lwe_seeded_keyswitch_key_unchecked(input) })
+    }
+
+    unsafe fn expand_lwe_seeded_keyswitch_key_unchecked(
+        &mut self,
+        input: &LweSeededKeyswitchKey64,
+    ) -> LweKeyswitchKey64 {
+        let mut ksk = ImplLweKeyswitchKey::allocate(
+            0u64,
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+            input.input_lwe_dimension(),
+            input.output_lwe_dimension(),
+        );
+        input.0.expand_into(&mut ksk);
+        LweKeyswitchKey64(ksk)
+    }
+}
@@ -0,0 +1,121 @@
+use concrete_commons::parameters::PolynomialSize;
+
+use crate::backends::core::engines::CoreEngine;
+use crate::backends::core::entities::{
+    GlweSecretKey32, GlweSecretKey64, LweSecretKey32, LweSecretKey64,
+};
+use crate::backends::core::private::crypto::secret::GlweSecretKey as ImplGlweSecretKey;
+use crate::backends::core::private::math::tensor::IntoTensor;
+use crate::specification::engines::{
+    LweToGlweSecretKeyTransmutationEngine, LweToGlweSecretKeyTransmutationEngineError,
+};
+
+impl LweToGlweSecretKeyTransmutationEngine<LweSecretKey32, GlweSecretKey32> for CoreEngine {
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use concrete_commons::parameters::{GlweDimension, LweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    ///
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(8);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    ///
+    /// let lwe_secret_key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// assert_eq!(lwe_secret_key.lwe_dimension(), lwe_dimension);
+    ///
+    /// let glwe_secret_key =
+    ///     engine.transmute_lwe_secret_key_to_glwe_secret_key(lwe_secret_key, polynomial_size)?;
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), GlweDimension(2));
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn transmute_lwe_secret_key_to_glwe_secret_key(
+        &mut self,
+        lwe_secret_key: LweSecretKey32,
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweSecretKey32, LweToGlweSecretKeyTransmutationEngineError<Self::EngineError>>
+    {
+        LweToGlweSecretKeyTransmutationEngineError::perform_generic_checks(
+            &lwe_secret_key,
+            polynomial_size,
+        )?;
+        Ok(unsafe {
+            self.transmute_lwe_secret_key_to_glwe_secret_key_unchecked(
+                lwe_secret_key,
+                polynomial_size,
+            )
+        })
+    }
+
+    unsafe fn transmute_lwe_secret_key_to_glwe_secret_key_unchecked(
+        &mut self,
+        lwe_secret_key: LweSecretKey32,
+        polynomial_size: PolynomialSize,
+    ) -> GlweSecretKey32 {
+        GlweSecretKey32(ImplGlweSecretKey::binary_from_container(
+            lwe_secret_key.0.into_tensor().into_container(),
+            polynomial_size,
+        ))
+    }
+}
+
+impl LweToGlweSecretKeyTransmutationEngine<LweSecretKey64, GlweSecretKey64> for CoreEngine {
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use concrete_commons::parameters::{GlweDimension, LweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    ///
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(8);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    ///
+    /// let lwe_secret_key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// assert_eq!(lwe_secret_key.lwe_dimension(), lwe_dimension);
+    ///
+    /// let glwe_secret_key =
+    ///     engine.transmute_lwe_secret_key_to_glwe_secret_key(lwe_secret_key, polynomial_size)?;
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), GlweDimension(2));
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn transmute_lwe_secret_key_to_glwe_secret_key(
+        &mut self,
+        lwe_secret_key: LweSecretKey64,
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweSecretKey64, LweToGlweSecretKeyTransmutationEngineError<Self::EngineError>>
+    {
+        LweToGlweSecretKeyTransmutationEngineError::perform_generic_checks(
+            &lwe_secret_key,
+            polynomial_size,
+        )?;
+        Ok(unsafe {
+            self.transmute_lwe_secret_key_to_glwe_secret_key_unchecked(
+                lwe_secret_key,
+                polynomial_size,
+            )
+        })
+    }
+
+    unsafe fn transmute_lwe_secret_key_to_glwe_secret_key_unchecked(
+        &mut self,
+        lwe_secret_key: LweSecretKey64,
+        polynomial_size: PolynomialSize,
+    ) -> GlweSecretKey64 {
+        GlweSecretKey64(ImplGlweSecretKey::binary_from_container(
+            lwe_secret_key.0.into_tensor().into_container(),
+            polynomial_size,
+        ))
+    }
+}
@@ -3,6 +3,8 @@
 use std::collections::BTreeMap;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
+#[cfg(feature = "multithread")]
+use std::sync::Mutex;
 
 use concrete_commons::parameters::{GlweSize, PolynomialSize};
 
@@ -11,6 +13,9 @@ use crate::backends::core::private::crypto::secret::generators::{
     EncryptionRandomGenerator as ImplEncryptionRandomGenerator,
     SecretRandomGenerator as ImplSecretRandomGenerator,
 };
+use crate::backends::core::private::math::random::RandomGenerator as ImplRandomGenerator;
+#[cfg(feature = "multithread")]
+use crate::backends::core::private::math::torus::UnsignedTorus;
 use crate::specification::engines::sealed::AbstractEngineSeal;
 use crate::specification::engines::AbstractEngine;
 
@@ -24,6 +29,14 @@ use crate::specification::engines::AbstractEngine;
 pub enum CoreError {
     Borrow,
     UnsupportedPolynomialSize,
+    FloatEncoderMinMaxOrder,
+    FloatEncoderNullRadius,
+    FloatEncoderNullPrecision,
+    FloatEncoderNonFiniteParameter,
+    IntegerEncoderNullPrecision,
+    MessageOutsideEncoderInterval,
+    EncodingPrecisionTooLarge,
+    DecompositionPrecisionTooLarge,
 }
 
 impl Display for CoreError {
@@ -39,6 +52,51 @@ impl Display for CoreError {
                 1024, 2048, 4096, 8192, 16384."
                 )
             }
+            CoreError::FloatEncoderMinMaxOrder => {
+                write!(
+                    f,
+                    "The minimum of the float encoder interval must be smaller than its maximum."
+                )
+            }
+            CoreError::FloatEncoderNullRadius => {
+                write!(
+                    f,
+                    "The radius of the float encoder interval must be positive."
+                )
+            }
+            CoreError::FloatEncoderNullPrecision => {
+                write!(
+                    f,
+                    "The float encoder must have at least one bit of precision."
+                )
+            }
+            CoreError::FloatEncoderNonFiniteParameter => {
+                write!(
+                    f,
+                    "The parameters of the float encoder interval must be finite numbers."
+                )
+            }
+            CoreError::IntegerEncoderNullPrecision => {
+                write!(
+                    f,
+                    "The integer encoder must have at least one bit of precision."
+                )
+            }
+            CoreError::MessageOutsideEncoderInterval => {
+                write!(f, "The message must belong to the interval of the encoder.")
+            }
+            CoreError::EncodingPrecisionTooLarge => {
+                write!(
+                    f,
+                    "The precision and padding of the encoder must fit in the plaintext integers."
+                )
+            }
+            CoreError::DecompositionPrecisionTooLarge => {
+                write!(
+                    f,
+                    "The decomposition precision of the key must fit in the output integers."
+                )
+            }
         }
     }
 }
@@ -57,6 +115,28 @@ pub struct CoreEngine {
 }
 
 impl CoreEngine {
+    /// Creates a new engine whose random generators are all derived from the given seed.
+    ///
+    /// Two engines created with the same seed generate the same secret keys and the same
+    /// encryptions, which makes it possible to replay a computation exactly.
+    ///
+    /// # Note:
+    ///
+    /// The noise of the encryptions is seeded as well, which is not secure. This constructor is
+    /// meant for testing and debugging purposes only, and [`AbstractEngine::new`] should be used
+    /// otherwise.
+    pub fn new_with_seed(seed: u128) -> Result<Self, CoreError> {
+        let (secret_seed, mask_seed, noise_seed) = derive_engine_seeds(seed);
+        Ok(CoreEngine {
+            secret_generator: ImplSecretRandomGenerator::new(Some(secret_seed)),
+            encryption_generator: ImplEncryptionRandomGenerator::new_deterministic(
+                mask_seed, noise_seed,
+            ),
+            fourier_buffers_u32: Default::default(),
+            fourier_buffers_u64: Default::default(),
+        })
+    }
+
     pub(crate) fn get_fourier_u32_buffer(
         &mut self,
         poly_size: PolynomialSize,
@@ -80,6 +160,16 @@ impl CoreEngine {
     }
 }
 
+// Derives the seeds of the secret, mask and noise generators of an engine from a single seed.
+pub(crate) fn derive_engine_seeds(seed: u128) -> (u128, u128, u128) {
+    let mut generator = ImplRandomGenerator::new(Some(seed));
+    (
+        generator.random_uniform(),
+        generator.random_uniform(),
+        generator.random_uniform(),
+    )
+}
+
 impl AbstractEngineSeal for CoreEngine {}
 
 impl AbstractEngine for CoreEngine {
@@ -95,28 +185,147 @@ impl AbstractEngine for CoreEngine {
     }
 }
 
+#[cfg(feature = "multithread")]
+type ThreadFourierBuffers<Scalar> = Mutex<BTreeMap<FourierBufferKey, FourierBuffers<Scalar>>>;
+
+/// A multithreaded engine exposed by the core backend.
+///
+/// This engine splits the work of the operations on ciphertext vectors across the threads of the
+/// current rayon pool. Every thread of the pool uses its own Fourier buffers, and the encryption
+/// generator is forked once per ciphertext, such that the results do not depend on the way the
+/// work is scheduled.
+///
+/// # Note:
+///
+/// This engine is hidden behind the "multithread" feature gate.
+#[cfg(feature = "multithread")]
+pub struct CoreParallelEngine {
+    encryption_generator: ImplEncryptionRandomGenerator,
+    fourier_buffers_u32: Vec<ThreadFourierBuffers<u32>>,
+    fourier_buffers_u64: Vec<ThreadFourierBuffers<u64>>,
+}
+
+#[cfg(feature = "multithread")]
+impl CoreParallelEngine {
+    /// Creates a new engine whose encryption generator is derived from the given seed.
+    ///
+    /// # Note:
+    ///
+    /// The noise of the encryptions is seeded as well, which is not secure. This constructor is
+    /// meant for testing and debugging purposes only, and [`AbstractEngine::new`] should be used
+    /// otherwise.
+    pub fn new_with_seed(seed: u128) -> Result<Self, CoreError> {
+        let (_, mask_seed, noise_seed) = derive_engine_seeds(seed);
+        Ok(CoreParallelEngine {
+            encryption_generator: ImplEncryptionRandomGenerator::new_deterministic(
+                mask_seed, noise_seed,
+            ),
+            fourier_buffers_u32: Vec::new(),
+            fourier_buffers_u64: Vec::new(),
+        })
+    }
+
+    pub(crate) fn get_fourier_u32_buffers(&mut self) -> &[ThreadFourierBuffers<u32>] {
+        // One set of buffers per thread of the pool, plus one for the calling thread.
+        let n_buffers = rayon::current_num_threads() + 1;
+        if self.fourier_buffers_u32.len() < n_buffers {
+            self.fourier_buffers_u32
+                .resize_with(n_buffers, Default::default);
+        }
+        &self.fourier_buffers_u32
+    }
+
+    pub(crate) fn get_fourier_u64_buffers(&mut self) -> &[ThreadFourierBuffers<u64>] {
+        // One set of buffers per thread of the pool, plus one for the calling thread.
+        let n_buffers = rayon::current_num_threads() + 1;
+        if self.fourier_buffers_u64.len() < n_buffers {
+            self.fourier_buffers_u64
+                .resize_with(n_buffers, Default::default);
+        }
+        &self.fourier_buffers_u64
+    }
+}
+
+/// Executes `f` with the Fourier buffers owned by the calling thread of the rayon pool.
+///
+/// The buffers are created the first time a thread needs them for a given set of parameters. A
+/// parallel iterator which is not split is run by the thread calling it, which may not belong to
+/// the pool: this thread uses the last set of buffers.
+#[cfg(feature = "multithread")]
+pub(crate) fn with_thread_fourier_buffers<Scalar, F>(
+    buffers: &[ThreadFourierBuffers<Scalar>],
+    poly_size: PolynomialSize,
+    glwe_size: GlweSize,
+    f: F,
+) where
+    Scalar: UnsignedTorus,
+    F: FnOnce(&mut FourierBuffers<Scalar>),
+{
+    let thread_index = rayon::current_thread_index().unwrap_or(buffers.len() - 1);
+    let mut thread_buffers = buffers[thread_index]
+        .lock()
+        .expect("The Fourier buffers of a thread were poisoned.");
+    let buffer = thread_buffers
+        .entry(FourierBufferKey(poly_size, glwe_size))
+        .or_insert_with(|| FourierBuffers::for_params(poly_size, glwe_size));
+    f(buffer)
+}
+
+#[cfg(feature = "multithread")]
+impl AbstractEngineSeal for CoreParallelEngine {}
+
+#[cfg(feature = "multithread")]
+impl AbstractEngine for CoreParallelEngine {
+    type EngineError = CoreError;
+
+    fn new() -> Result<Self, Self::EngineError> {
+        Ok(CoreParallelEngine {
+            encryption_generator: ImplEncryptionRandomGenerator::new(None),
+            fourier_buffers_u32: Vec::new(),
+            fourier_buffers_u64: Vec::new(),
+        })
+    }
+}
+
+mod cleartext_conversion;
 mod cleartext_creation;
+mod cleartext_discarding_conversion;
 mod cleartext_discarding_retrieval;
+mod cleartext_encoding;
 mod cleartext_retrieval;
+mod cleartext_vector_conversion;
 mod cleartext_vector_creation;
+mod cleartext_vector_discarding_conversion;
 mod cleartext_vector_discarding_retrieval;
+mod cleartext_vector_encoding;
 mod cleartext_vector_retrieval;
 mod destruction;
+mod encoder_creation;
+mod encoder_vector_creation;
 mod ggsw_ciphertext_conversion;
 mod ggsw_ciphertext_discarding_conversion;
 mod ggsw_ciphertext_scalar_discarding_encryption;
 mod ggsw_ciphertext_scalar_encryption;
 mod ggsw_ciphertext_scalar_trivial_encryption;
+mod glwe_ciphertext_cleartext_fusing_multiplication;
 mod glwe_ciphertext_conversion;
 mod glwe_ciphertext_decryption;
+mod glwe_ciphertext_discarding_conversion;
 mod glwe_ciphertext_discarding_decryption;
 mod glwe_ciphertext_discarding_encryption;
+mod glwe_ciphertext_discarding_tensor_product;
 mod glwe_ciphertext_encryption;
+mod glwe_ciphertext_fusing_addition;
+mod glwe_ciphertext_fusing_subtraction;
+mod glwe_ciphertext_ggsw_ciphertext_discarding_cmux;
 mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product;
 mod glwe_ciphertext_ggsw_ciphertext_external_product;
+mod glwe_ciphertext_plaintext_vector_fusing_addition;
 mod glwe_ciphertext_trivial_decryption;
 mod glwe_ciphertext_trivial_encryption;
+mod glwe_ciphertext_vector_conversion;
 mod glwe_ciphertext_vector_decryption;
+mod glwe_ciphertext_vector_discarding_conversion;
 mod glwe_ciphertext_vector_discarding_decryption;
 mod glwe_ciphertext_vector_discarding_encryption;
 mod glwe_ciphertext_vector_encryption;
@@ -124,49 +333,83 @@ mod glwe_ciphertext_vector_trivial_decryption;
 mod glwe_ciphertext_vector_trivial_encryption;
 mod glwe_ciphertext_vector_zero_encryption;
 mod glwe_ciphertext_zero_encryption;
+mod glwe_relinearization_key_creation;
+mod glwe_secret_key_conversion;
 mod glwe_secret_key_creation;
+mod glwe_secret_key_discarding_conversion;
 mod glwe_secret_key_to_lwe_secret_key_transmutation;
+mod gsw_ciphertext_scalar_encryption;
 mod lwe_bootstrap_key_conversion;
 mod lwe_bootstrap_key_creation;
+mod lwe_bootstrap_key_discarding_conversion;
+mod lwe_ciphertext_blind_rotation;
 mod lwe_ciphertext_cleartext_discarding_multiplication;
 mod lwe_ciphertext_cleartext_fusing_multiplication;
+mod lwe_ciphertext_conversion;
 mod lwe_ciphertext_decryption;
 mod lwe_ciphertext_discarding_addition;
 mod lwe_ciphertext_discarding_bootstrap;
+mod lwe_ciphertext_discarding_cmux;
+mod lwe_ciphertext_discarding_conversion;
 mod lwe_ciphertext_discarding_decryption;
 mod lwe_ciphertext_discarding_encryption;
 mod lwe_ciphertext_discarding_extraction;
 mod lwe_ciphertext_discarding_keyswitch;
+mod lwe_ciphertext_discarding_loading;
 mod lwe_ciphertext_discarding_negation;
+mod lwe_ciphertext_discarding_storing;
 mod lwe_ciphertext_discarding_subtraction;
 mod lwe_ciphertext_encryption;
 mod lwe_ciphertext_fusing_addition;
 mod lwe_ciphertext_fusing_negation;
 mod lwe_ciphertext_fusing_subtraction;
+mod lwe_ciphertext_gsw_ciphertext_external_product;
+mod lwe_ciphertext_loading;
 mod lwe_ciphertext_plaintext_discarding_addition;
 mod lwe_ciphertext_plaintext_discarding_subtraction;
 mod lwe_ciphertext_plaintext_fusing_addition;
 mod lwe_ciphertext_plaintext_fusing_subtraction;
 mod lwe_ciphertext_trivial_decryption;
 mod lwe_ciphertext_trivial_encryption;
+mod lwe_ciphertext_vector_conversion;
 mod lwe_ciphertext_vector_decryption;
 mod lwe_ciphertext_vector_discarding_addition;
 mod lwe_ciphertext_vector_discarding_affine_transformation;
+mod lwe_ciphertext_vector_discarding_bootstrap;
+mod lwe_ciphertext_vector_discarding_conversion;
 mod lwe_ciphertext_vector_discarding_decryption;
 mod lwe_ciphertext_vector_discarding_encryption;
+mod lwe_ciphertext_vector_discarding_extraction;
+mod lwe_ciphertext_vector_discarding_keyswitch;
 mod lwe_ciphertext_vector_discarding_subtraction;
 mod lwe_ciphertext_vector_encryption;
 mod lwe_ciphertext_vector_fusing_addition;
 mod lwe_ciphertext_vector_fusing_subtraction;
+mod lwe_ciphertext_vector_loading;
 mod lwe_ciphertext_vector_trivial_decryption;
 mod lwe_ciphertext_vector_trivial_encryption;
 mod lwe_ciphertext_vector_zero_encryption;
 mod lwe_ciphertext_zero_encryption;
+mod lwe_keyswitch_key_conversion;
 mod lwe_keyswitch_key_creation;
+mod lwe_keyswitch_key_discarding_conversion;
+mod lwe_secret_key_conversion;
 mod lwe_secret_key_creation;
+mod lwe_secret_key_discarding_conversion;
+mod lwe_seeded_ciphertext_encryption;
+mod lwe_seeded_ciphertext_expansion;
+mod lwe_seeded_keyswitch_key_creation;
+mod lwe_seeded_keyswitch_key_expansion;
+mod lwe_to_glwe_secret_key_transmutation;
+mod plaintext_conversion;
 mod plaintext_creation;
+mod plaintext_decoding;
+mod plaintext_discarding_conversion;
 mod plaintext_discarding_retrieval;
 mod plaintext_retrieval;
+mod plaintext_vector_conversion;
 mod plaintext_vector_creation;
+mod plaintext_vector_decoding;
+mod plaintext_vector_discarding_conversion;
 mod plaintext_vector_discarding_retrieval;
 mod plaintext_vector_retrieval;
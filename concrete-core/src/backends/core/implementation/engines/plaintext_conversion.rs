@@ -0,0 +1,79 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{Plaintext32, Plaintext64};
+use crate::backends::core::private::crypto::encoding::Plaintext as ImplPlaintext;
+use crate::backends::core::private::math::torus::CastTorus;
+use crate::specification::engines::{PlaintextConversionEngine, PlaintextConversionError};
+
+/// # Description:
+/// Implementation of [`PlaintextConversionEngine`] for [`CoreEngine`] that converts a 32 bits
+/// plaintext into a 64 bits plaintext, by rescaling the torus elements.
+impl PlaintextConversionEngine<Plaintext32, Plaintext64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input: Plaintext32 = engine.create_plaintext(&(3_u32 << 20))?;
+    ///
+    /// let output: Plaintext64 = engine.convert_plaintext(&input)?;
+    /// #
+    /// let value: u64 = engine.retrieve_plaintext(&output)?;
+    /// assert_eq!(value, 3_u64 << 52);
+    ///
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_plaintext(
+        &mut self,
+        input: &Plaintext32,
+    ) -> Result<Plaintext64, PlaintextConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_plaintext_unchecked(input) })
+    }
+
+    unsafe fn convert_plaintext_unchecked(&mut self, input: &Plaintext32) -> Plaintext64 {
+        Plaintext64(ImplPlaintext(input.0 .0.cast_torus()))
+    }
+}
+
+/// # Description:
+/// Implementation of [`PlaintextConversionEngine`] for [`CoreEngine`] that converts a 64 bits
+/// plaintext into a 32 bits plaintext, by rescaling the torus elements.
+impl PlaintextConversionEngine<Plaintext64, Plaintext32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input: Plaintext64 = engine.create_plaintext(&(3_u64 << 50))?;
+    ///
+    /// let output: Plaintext32 = engine.convert_plaintext(&input)?;
+    /// #
+    /// let value: u32 = engine.retrieve_plaintext(&output)?;
+    /// assert_eq!(value, 3_u32 << 18);
+    ///
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_plaintext(
+        &mut self,
+        input: &Plaintext64,
+    ) -> Result<Plaintext32, PlaintextConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_plaintext_unchecked(input) })
+    }
+
+    unsafe fn convert_plaintext_unchecked(&mut self, input: &Plaintext64) -> Plaintext32 {
+        Plaintext32(ImplPlaintext(input.0 .0.cast_torus()))
+    }
+}
@@ -0,0 +1,220 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    Cleartext32, Cleartext64, CleartextF64, FloatEncoder, IntegerEncoder, Plaintext32, Plaintext64,
+};
+use crate::prelude::CoreError;
+use crate::specification::engines::{PlaintextDecodingEngine, PlaintextDecodingError};
+
+impl From<CoreError> for PlaintextDecodingError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`PlaintextDecodingEngine`] for [`CoreEngine`] that decodes 32 bits integers
+/// into 64 bits floating point numbers.
+impl PlaintextDecodingEngine<FloatEncoder, Plaintext32, CleartextF64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let encoder = engine.create_encoder(&FloatEncoderMinMaxConfig {
+    ///     min: 0.,
+    ///     max: 7.,
+    ///     nb_bit_precision: 3,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    /// let cleartext: CleartextF64 = engine.create_cleartext(&3.)?;
+    /// let plaintext: Plaintext32 = engine.encode_cleartext(&encoder, &cleartext)?;
+    /// let recovered_cleartext: CleartextF64 = engine.decode_plaintext(&encoder, &plaintext)?;
+    /// #
+    /// let recovered: f64 = engine.retrieve_cleartext(&recovered_cleartext)?;
+    /// assert_eq!(recovered, 3.);
+    /// engine.destroy(encoder)?;
+    /// engine.destroy(cleartext)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(recovered_cleartext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decode_plaintext(
+        &mut self,
+        encoder: &FloatEncoder,
+        input: &Plaintext32,
+    ) -> Result<CleartextF64, PlaintextDecodingError<Self::EngineError>> {
+        if !encoder.0.fits_in::<u32>() {
+            return Err(PlaintextDecodingError::from(
+                CoreError::EncodingPrecisionTooLarge,
+            ));
+        }
+        Ok(unsafe { self.decode_plaintext_unchecked(input, encoder) })
+    }
+
+    unsafe fn decode_plaintext_unchecked(
+        &mut self,
+        input: &Plaintext32,
+        encoder: &FloatEncoder,
+    ) -> CleartextF64 {
+        CleartextF64(encoder.0.decode(input.0))
+    }
+}
+
+/// # Description:
+/// Implementation of [`PlaintextDecodingEngine`] for [`CoreEngine`] that decodes 64 bits integers
+/// into 64 bits floating point numbers.
+impl PlaintextDecodingEngine<FloatEncoder, Plaintext64, CleartextF64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let encoder = engine.create_encoder(&FloatEncoderMinMaxConfig {
+    ///     min: 0.,
+    ///     max: 7.,
+    ///     nb_bit_precision: 3,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    /// let cleartext: CleartextF64 = engine.create_cleartext(&3.)?;
+    /// let plaintext: Plaintext64 = engine.encode_cleartext(&encoder, &cleartext)?;
+    /// let recovered_cleartext: CleartextF64 = engine.decode_plaintext(&encoder, &plaintext)?;
+    /// #
+    /// let recovered: f64 = engine.retrieve_cleartext(&recovered_cleartext)?;
+    /// assert_eq!(recovered, 3.);
+    /// engine.destroy(encoder)?;
+    /// engine.destroy(cleartext)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(recovered_cleartext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decode_plaintext(
+        &mut self,
+        encoder: &FloatEncoder,
+        input: &Plaintext64,
+    ) -> Result<CleartextF64, PlaintextDecodingError<Self::EngineError>> {
+        if !encoder.0.fits_in::<u64>() {
+            return Err(PlaintextDecodingError::from(
+                CoreError::EncodingPrecisionTooLarge,
+            ));
+        }
+        Ok(unsafe { self.decode_plaintext_unchecked(input, encoder) })
+    }
+
+    unsafe fn decode_plaintext_unchecked(
+        &mut self,
+        input: &Plaintext64,
+        encoder: &FloatEncoder,
+    ) -> CleartextF64 {
+        CleartextF64(encoder.0.decode(input.0))
+    }
+}
+
+/// # Description:
+/// Implementation of [`PlaintextDecodingEngine`] for [`CoreEngine`] that decodes 32 bits integers
+/// into 32 bits unsigned integers.
+impl PlaintextDecodingEngine<IntegerEncoder, Plaintext32, Cleartext32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let encoder = engine.create_encoder(&IntegerEncoderConfig {
+    ///     nb_bit_precision: 4,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    /// let cleartext: Cleartext32 = engine.create_cleartext(&5_u32)?;
+    /// let plaintext: Plaintext32 = engine.encode_cleartext(&encoder, &cleartext)?;
+    /// let recovered_cleartext: Cleartext32 = engine.decode_plaintext(&encoder, &plaintext)?;
+    /// #
+    /// let recovered: u32 = engine.retrieve_cleartext(&recovered_cleartext)?;
+    /// assert_eq!(recovered, 5);
+    /// engine.destroy(encoder)?;
+    /// engine.destroy(cleartext)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(recovered_cleartext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decode_plaintext(
+        &mut self,
+        encoder: &IntegerEncoder,
+        input: &Plaintext32,
+    ) -> Result<Cleartext32, PlaintextDecodingError<Self::EngineError>> {
+        if !encoder.0.fits_in::<u32>() {
+            return Err(PlaintextDecodingError::from(
+                CoreError::EncodingPrecisionTooLarge,
+            ));
+        }
+        Ok(unsafe { self.decode_plaintext_unchecked(input, encoder) })
+    }
+
+    unsafe fn decode_plaintext_unchecked(
+        &mut self,
+        input: &Plaintext32,
+        encoder: &IntegerEncoder,
+    ) -> Cleartext32 {
+        Cleartext32(encoder.0.decode(input.0))
+    }
+}
+
+/// # Description:
+/// Implementation of [`PlaintextDecodingEngine`] for [`CoreEngine`] that decodes 64 bits integers
+/// into 64 bits unsigned integers.
+impl PlaintextDecodingEngine<IntegerEncoder, Plaintext64, Cleartext64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let encoder = engine.create_encoder(&IntegerEncoderConfig {
+    ///     nb_bit_precision: 4,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    /// let cleartext: Cleartext64 = engine.create_cleartext(&5_u64)?;
+    /// let plaintext: Plaintext64 = engine.encode_cleartext(&encoder, &cleartext)?;
+    /// let recovered_cleartext: Cleartext64 = engine.decode_plaintext(&encoder, &plaintext)?;
+    /// #
+    /// let recovered: u64 = engine.retrieve_cleartext(&recovered_cleartext)?;
+    /// assert_eq!(recovered, 5);
+    /// engine.destroy(encoder)?;
+    /// engine.destroy(cleartext)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(recovered_cleartext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decode_plaintext(
+        &mut self,
+        encoder: &IntegerEncoder,
+        input: &Plaintext64,
+    ) -> Result<Cleartext64, PlaintextDecodingError<Self::EngineError>> {
+        if !encoder.0.fits_in::<u64>() {
+            return Err(PlaintextDecodingError::from(
+                CoreError::EncodingPrecisionTooLarge,
+            ));
+        }
+        Ok(unsafe { self.decode_plaintext_unchecked(input, encoder) })
+    }
+
+    unsafe fn decode_plaintext_unchecked(
+        &mut self,
+        input: &Plaintext64,
+        encoder: &IntegerEncoder,
+    ) -> Cleartext64 {
+        Cleartext64(encoder.0.decode(input.0))
+    }
+}
@@ -0,0 +1,94 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{Plaintext32, Plaintext64};
+use crate::backends::core::private::math::torus::CastTorus;
+use crate::specification::engines::{
+    PlaintextDiscardingConversionEngine, PlaintextDiscardingConversionError,
+};
+
+/// # Description:
+/// Implementation of [`PlaintextDiscardingConversionEngine`] for [`CoreEngine`] that converts a 32
+/// bits plaintext into a 64 bits plaintext, by rescaling the torus elements.
+impl PlaintextDiscardingConversionEngine<Plaintext32, Plaintext64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input: Plaintext32 = engine.create_plaintext(&(3_u32 << 20))?;
+    /// let mut output: Plaintext64 = engine.create_plaintext(&0_u64)?;
+    ///
+    /// engine.discard_convert_plaintext(&mut output, &input)?;
+    /// #
+    /// let value: u64 = engine.retrieve_plaintext(&output)?;
+    /// assert_eq!(value, 3_u64 << 52);
+    ///
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_plaintext(
+        &mut self,
+        output: &mut Plaintext64,
+        input: &Plaintext32,
+    ) -> Result<(), PlaintextDiscardingConversionError<Self::EngineError>> {
+        unsafe { self.discard_convert_plaintext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_plaintext_unchecked(
+        &mut self,
+        output: &mut Plaintext64,
+        input: &Plaintext32,
+    ) {
+        output.0 .0 = input.0 .0.cast_torus();
+    }
+}
+
+/// # Description:
+/// Implementation of [`PlaintextDiscardingConversionEngine`] for [`CoreEngine`] that converts a 64
+/// bits plaintext into a 32 bits plaintext, by rescaling the torus elements.
+impl PlaintextDiscardingConversionEngine<Plaintext64, Plaintext32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input: Plaintext64 = engine.create_plaintext(&(3_u64 << 50))?;
+    /// let mut output: Plaintext32 = engine.create_plaintext(&0_u32)?;
+    ///
+    /// engine.discard_convert_plaintext(&mut output, &input)?;
+    /// #
+    /// let value: u32 = engine.retrieve_plaintext(&output)?;
+    /// assert_eq!(value, 3_u32 << 18);
+    ///
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_plaintext(
+        &mut self,
+        output: &mut Plaintext32,
+        input: &Plaintext64,
+    ) -> Result<(), PlaintextDiscardingConversionError<Self::EngineError>> {
+        unsafe { self.discard_convert_plaintext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_plaintext_unchecked(
+        &mut self,
+        output: &mut Plaintext32,
+        input: &Plaintext64,
+    ) {
+        output.0 .0 = input.0 .0.cast_torus();
+    }
+}
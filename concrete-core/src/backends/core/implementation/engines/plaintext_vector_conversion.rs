@@ -0,0 +1,102 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{PlaintextVector32, PlaintextVector64};
+use crate::backends::core::private::crypto::encoding::PlaintextList as ImplPlaintextList;
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::backends::core::private::math::torus::CastTorus;
+use crate::specification::engines::{
+    PlaintextVectorConversionEngine, PlaintextVectorConversionError,
+};
+
+/// # Description:
+/// Implementation of [`PlaintextVectorConversionEngine`] for [`CoreEngine`] that converts a 32 bits
+/// plaintext vector into a 64 bits plaintext vector, by rescaling the torus elements.
+impl PlaintextVectorConversionEngine<PlaintextVector32, PlaintextVector64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input: PlaintextVector32 = engine.create_plaintext_vector(&vec![3_u32 << 20; 3])?;
+    ///
+    /// let output: PlaintextVector64 = engine.convert_plaintext_vector(&input)?;
+    /// #
+    /// let values: Vec<u64> = engine.retrieve_plaintext_vector(&output)?;
+    /// assert_eq!(values, vec![3_u64 << 52; 3]);
+    ///
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_plaintext_vector(
+        &mut self,
+        input: &PlaintextVector32,
+    ) -> Result<PlaintextVector64, PlaintextVectorConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_plaintext_vector_unchecked(input) })
+    }
+
+    unsafe fn convert_plaintext_vector_unchecked(
+        &mut self,
+        input: &PlaintextVector32,
+    ) -> PlaintextVector64 {
+        PlaintextVector64(ImplPlaintextList::from_container(
+            input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|&x| x.cast_torus())
+                .collect::<Vec<u64>>(),
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`PlaintextVectorConversionEngine`] for [`CoreEngine`] that converts a 64 bits
+/// plaintext vector into a 32 bits plaintext vector, by rescaling the torus elements.
+impl PlaintextVectorConversionEngine<PlaintextVector64, PlaintextVector32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input: PlaintextVector64 = engine.create_plaintext_vector(&vec![3_u64 << 50; 3])?;
+    ///
+    /// let output: PlaintextVector32 = engine.convert_plaintext_vector(&input)?;
+    /// #
+    /// let values: Vec<u32> = engine.retrieve_plaintext_vector(&output)?;
+    /// assert_eq!(values, vec![3_u32 << 18; 3]);
+    ///
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_plaintext_vector(
+        &mut self,
+        input: &PlaintextVector64,
+    ) -> Result<PlaintextVector32, PlaintextVectorConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_plaintext_vector_unchecked(input) })
+    }
+
+    unsafe fn convert_plaintext_vector_unchecked(
+        &mut self,
+        input: &PlaintextVector64,
+    ) -> PlaintextVector32 {
+        PlaintextVector32(ImplPlaintextList::from_container(
+            input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|&x| x.cast_torus())
+                .collect::<Vec<u32>>(),
+        ))
+    }
+}
@@ -0,0 +1,302 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    CleartextVector32, CleartextVector64, CleartextVectorF64, FloatEncoderVector,
+    IntegerEncoderVector, PlaintextVector32, PlaintextVector64,
+};
+use crate::backends::core::private::crypto::encoding::CleartextList as ImplCleartextList;
+use crate::prelude::CoreError;
+use crate::specification::engines::{PlaintextVectorDecodingEngine, PlaintextVectorDecodingError};
+
+impl From<CoreError> for PlaintextVectorDecodingError<CoreError> {
+    fn from(err: CoreError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`PlaintextVectorDecodingEngine`] for [`CoreEngine`] that decodes 32 bits
+/// integers into 64 bits floating point numbers.
+impl PlaintextVectorDecodingEngine<FloatEncoderVector, PlaintextVector32, CleartextVectorF64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::CleartextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let encoder_vector = engine.create_encoder_vector(&vec![
+    ///     FloatEncoderMinMaxConfig {
+    ///         min: 0.,
+    ///         max: 7.,
+    ///         nb_bit_precision: 3,
+    ///         nb_bit_padding: 1,
+    ///     };
+    ///     100
+    /// ])?;
+    /// let cleartext_vector: CleartextVectorF64 = engine.create_cleartext_vector(&vec![3.; 100])?;
+    /// let plaintext_vector: PlaintextVector32 =
+    ///     engine.encode_cleartext_vector(&encoder_vector, &cleartext_vector)?;
+    /// let recovered_cleartext_vector: CleartextVectorF64 =
+    ///     engine.decode_plaintext_vector(&encoder_vector, &plaintext_vector)?;
+    /// #
+    /// assert_eq!(
+    ///     recovered_cleartext_vector.cleartext_count(),
+    ///     CleartextCount(100)
+    /// );
+    /// let recovered: Vec<f64> = engine.retrieve_cleartext_vector(&recovered_cleartext_vector)?;
+    /// assert_eq!(recovered, vec![3.; 100]);
+    /// engine.destroy(encoder_vector)?;
+    /// engine.destroy(cleartext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(recovered_cleartext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decode_plaintext_vector(
+        &mut self,
+        encoder: &FloatEncoderVector,
+        input: &PlaintextVector32,
+    ) -> Result<CleartextVectorF64, PlaintextVectorDecodingError<Self::EngineError>> {
+        PlaintextVectorDecodingError::perform_generic_checks(encoder, input)?;
+        if encoder.0.iter().any(|encoder| !encoder.fits_in::<u32>()) {
+            return Err(PlaintextVectorDecodingError::from(
+                CoreError::EncodingPrecisionTooLarge,
+            ));
+        }
+        Ok(unsafe { self.decode_plaintext_vector_unchecked(encoder, input) })
+    }
+
+    unsafe fn decode_plaintext_vector_unchecked(
+        &mut self,
+        encoder: &FloatEncoderVector,
+        input: &PlaintextVector32,
+    ) -> CleartextVectorF64 {
+        CleartextVectorF64(ImplCleartextList::from_container(
+            encoder
+                .0
+                .iter()
+                .zip(input.0.plaintext_iter())
+                .map(|(encoder, plaintext)| encoder.decode(*plaintext).0)
+                .collect::<Vec<_>>(),
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`PlaintextVectorDecodingEngine`] for [`CoreEngine`] that decodes 64 bits
+/// integers into 64 bits floating point numbers.
+impl PlaintextVectorDecodingEngine<FloatEncoderVector, PlaintextVector64, CleartextVectorF64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::CleartextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let encoder_vector = engine.create_encoder_vector(&vec![
+    ///     FloatEncoderMinMaxConfig {
+    ///         min: 0.,
+    ///         max: 7.,
+    ///         nb_bit_precision: 3,
+    ///         nb_bit_padding: 1,
+    ///     };
+    ///     100
+    /// ])?;
+    /// let cleartext_vector: CleartextVectorF64 = engine.create_cleartext_vector(&vec![3.; 100])?;
+    /// let plaintext_vector: PlaintextVector64 =
+    ///     engine.encode_cleartext_vector(&encoder_vector, &cleartext_vector)?;
+    /// let recovered_cleartext_vector: CleartextVectorF64 =
+    ///     engine.decode_plaintext_vector(&encoder_vector, &plaintext_vector)?;
+    /// #
+    /// assert_eq!(
+    ///     recovered_cleartext_vector.cleartext_count(),
+    ///     CleartextCount(100)
+    /// );
+    /// let recovered: Vec<f64> = engine.retrieve_cleartext_vector(&recovered_cleartext_vector)?;
+    /// assert_eq!(recovered, vec![3.; 100]);
+    /// engine.destroy(encoder_vector)?;
+    /// engine.destroy(cleartext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(recovered_cleartext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decode_plaintext_vector(
+        &mut self,
+        encoder: &FloatEncoderVector,
+        input: &PlaintextVector64,
+    ) -> Result<CleartextVectorF64, PlaintextVectorDecodingError<Self::EngineError>> {
+        PlaintextVectorDecodingError::perform_generic_checks(encoder, input)?;
+        if encoder.0.iter().any(|encoder| !encoder.fits_in::<u64>()) {
+            return Err(PlaintextVectorDecodingError::from(
+                CoreError::EncodingPrecisionTooLarge,
+            ));
+        }
+        Ok(unsafe { self.decode_plaintext_vector_unchecked(encoder, input) })
+    }
+
+    unsafe fn decode_plaintext_vector_unchecked(
+        &mut self,
+        encoder: &FloatEncoderVector,
+        input: &PlaintextVector64,
+    ) -> CleartextVectorF64 {
+        CleartextVectorF64(ImplCleartextList::from_container(
+            encoder
+                .0
+                .iter()
+                .zip(input.0.plaintext_iter())
+                .map(|(encoder, plaintext)| encoder.decode(*plaintext).0)
+                .collect::<Vec<_>>(),
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`PlaintextVectorDecodingEngine`] for [`CoreEngine`] that decodes 32 bits
+/// integers into 32 bits unsigned integers.
+impl PlaintextVectorDecodingEngine<IntegerEncoderVector, PlaintextVector32, CleartextVector32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::CleartextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let encoder_vector = engine.create_encoder_vector(&vec![
+    ///     IntegerEncoderConfig {
+    ///         nb_bit_precision: 4,
+    ///         nb_bit_padding: 1,
+    ///     };
+    ///     100
+    /// ])?;
+    /// let cleartext_vector: CleartextVector32 = engine.create_cleartext_vector(&vec![5_u32; 100])?;
+    /// let plaintext_vector: PlaintextVector32 =
+    ///     engine.encode_cleartext_vector(&encoder_vector, &cleartext_vector)?;
+    /// let recovered_cleartext_vector: CleartextVector32 =
+    ///     engine.decode_plaintext_vector(&encoder_vector, &plaintext_vector)?;
+    /// #
+    /// assert_eq!(
+    ///     recovered_cleartext_vector.cleartext_count(),
+    ///     CleartextCount(100)
+    /// );
+    /// let recovered: Vec<u32> = engine.retrieve_cleartext_vector(&recovered_cleartext_vector)?;
+    /// assert_eq!(recovered, vec![5_u32; 100]);
+    /// engine.destroy(encoder_vector)?;
+    /// engine.destroy(cleartext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(recovered_cleartext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decode_plaintext_vector(
+        &mut self,
+        encoder: &IntegerEncoderVector,
+        input: &PlaintextVector32,
+    ) -> Result<CleartextVector32, PlaintextVectorDecodingError<Self::EngineError>> {
+        PlaintextVectorDecodingError::perform_generic_checks(encoder, input)?;
+        if encoder.0.iter().any(|encoder| !encoder.fits_in::<u32>()) {
+            return Err(PlaintextVectorDecodingError::from(
+                CoreError::EncodingPrecisionTooLarge,
+            ));
+        }
+        Ok(unsafe { self.decode_plaintext_vector_unchecked(encoder, input) })
+    }
+
+    unsafe fn decode_plaintext_vector_unchecked(
+        &mut self,
+        encoder: &IntegerEncoderVector,
+        input: &PlaintextVector32,
+    ) -> CleartextVector32 {
+        CleartextVector32(ImplCleartextList::from_container(
+            encoder
+                .0
+                .iter()
+                .zip(input.0.plaintext_iter())
+                .map(|(encoder, plaintext)| encoder.decode(*plaintext).0)
+                .collect::<Vec<_>>(),
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`PlaintextVectorDecodingEngine`] for [`CoreEngine`] that decodes 64 bits
+/// integers into 64 bits unsigned integers.
+impl PlaintextVectorDecodingEngine<IntegerEncoderVector, PlaintextVector64, CleartextVector64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::CleartextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let encoder_vector = engine.create_encoder_vector(&vec![
+    ///     IntegerEncoderConfig {
+    ///         nb_bit_precision: 4,
+    ///         nb_bit_padding: 1,
+    ///     };
+    ///     100
+    /// ])?;
+    /// let cleartext_vector: CleartextVector64 = engine.create_cleartext_vector(&vec![5_u64; 100])?;
+    /// let plaintext_vector: PlaintextVector64 =
+    ///     engine.encode_cleartext_vector(&encoder_vector, &cleartext_vector)?;
+    /// let recovered_cleartext_vector: CleartextVector64 =
+    ///     engine.decode_plaintext_vector(&encoder_vector, &plaintext_vector)?;
+    /// #
+    /// assert_eq!(
+    ///     recovered_cleartext_vector.cleartext_count(),
+    ///     CleartextCount(100)
+    /// );
+    /// let recovered: Vec<u64> = engine.retrieve_cleartext_vector(&recovered_cleartext_vector)?;
+    /// assert_eq!(recovered, vec![5_u64; 100]);
+    /// engine.destroy(encoder_vector)?;
+    /// engine.destroy(cleartext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(recovered_cleartext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decode_plaintext_vector(
+        &mut self,
+        encoder: &IntegerEncoderVector,
+        input: &PlaintextVector64,
+    ) -> Result<CleartextVector64, PlaintextVectorDecodingError<Self::EngineError>> {
+        PlaintextVectorDecodingError::perform_generic_checks(encoder, input)?;
+        if encoder.0.iter().any(|encoder| !encoder.fits_in::<u64>()) {
+            return Err(PlaintextVectorDecodingError::from(
+                CoreError::EncodingPrecisionTooLarge,
+            ));
+        }
+        Ok(unsafe { self.decode_plaintext_vector_unchecked(encoder, input) })
+    }
+
+    unsafe fn decode_plaintext_vector_unchecked(
+        &mut self,
+        encoder: &IntegerEncoderVector,
+        input: &PlaintextVector64,
+    ) -> CleartextVector64 {
+        CleartextVector64(ImplCleartextList::from_container(
+            encoder
+                .0
+                .iter()
+                .zip(input.0.plaintext_iter())
+                .map(|(encoder, plaintext)| encoder.decode(*plaintext).0)
+                .collect::<Vec<_>>(),
+        ))
+    }
+}
@@ -0,0 +1,109 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{PlaintextVector32, PlaintextVector64};
+use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::backends::core::private::math::torus::CastTorus;
+use crate::specification::engines::{
+    PlaintextVectorDiscardingConversionEngine, PlaintextVectorDiscardingConversionError,
+};
+
+/// # Description:
+/// Implementation of [`PlaintextVectorDiscardingConversionEngine`] for [`CoreEngine`] that converts
+/// a 32 bits plaintext vector into a 64 bits plaintext vector, by rescaling the torus elements.
+impl PlaintextVectorDiscardingConversionEngine<PlaintextVector32, PlaintextVector64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input: PlaintextVector32 = engine.create_plaintext_vector(&vec![3_u32 << 20; 3])?;
+    /// let mut output: PlaintextVector64 =
+    ///     engine.create_plaintext_vector(&vec![0_u64; 3])?;
+    ///
+    /// engine.discard_convert_plaintext_vector(&mut output, &input)?;
+    /// #
+    /// let values: Vec<u64> = engine.retrieve_plaintext_vector(&output)?;
+    /// assert_eq!(values, vec![3_u64 << 52; 3]);
+    ///
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_plaintext_vector(
+        &mut self,
+        output: &mut PlaintextVector64,
+        input: &PlaintextVector32,
+    ) -> Result<(), PlaintextVectorDiscardingConversionError<Self::EngineError>> {
+        PlaintextVectorDiscardingConversionError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_convert_plaintext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_plaintext_vector_unchecked(
+        &mut self,
+        output: &mut PlaintextVector64,
+        input: &PlaintextVector32,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |&x| x.cast_torus());
+    }
+}
+
+/// # Description:
+/// Implementation of [`PlaintextVectorDiscardingConversionEngine`] for [`CoreEngine`] that converts
+/// a 64 bits plaintext vector into a 32 bits plaintext vector, by rescaling the torus elements.
+impl PlaintextVectorDiscardingConversionEngine<PlaintextVector64, PlaintextVector32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input: PlaintextVector64 = engine.create_plaintext_vector(&vec![3_u64 << 50; 3])?;
+    /// let mut output: PlaintextVector32 =
+    ///     engine.create_plaintext_vector(&vec![0_u32; 3])?;
+    ///
+    /// engine.discard_convert_plaintext_vector(&mut output, &input)?;
+    /// #
+    /// let values: Vec<u32> = engine.retrieve_plaintext_vector(&output)?;
+    /// assert_eq!(values, vec![3_u32 << 18; 3]);
+    ///
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_plaintext_vector(
+        &mut self,
+        output: &mut PlaintextVector32,
+        input: &PlaintextVector64,
+    ) -> Result<(), PlaintextVectorDiscardingConversionError<Self::EngineError>> {
+        PlaintextVectorDiscardingConversionError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_convert_plaintext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_plaintext_vector_unchecked(
+        &mut self,
+        output: &mut PlaintextVector32,
+        input: &PlaintextVector64,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |&x| x.cast_torus());
+    }
+}
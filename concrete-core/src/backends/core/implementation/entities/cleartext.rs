@@ -17,3 +17,11 @@ impl AbstractEntity for Cleartext64 {
     type Kind = CleartextKind;
 }
 impl CleartextEntity for Cleartext64 {}
+
+/// A structure representing a floating point cleartext with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextF64(pub(crate) ImplCleartext<f64>);
+impl AbstractEntity for CleartextF64 {
+    type Kind = CleartextKind;
+}
+impl CleartextEntity for CleartextF64 {}
@@ -0,0 +1,86 @@
+use crate::backends::core::private::crypto::encoding::{
+    FloatEncoder as ImplFloatEncoder, IntegerEncoder as ImplIntegerEncoder,
+};
+use crate::specification::entities::markers::EncoderKind;
+use crate::specification::entities::{AbstractEntity, EncoderEntity};
+
+/// A structure representing a floating point encoder.
+///
+/// This encoder maps the real numbers of an interval to plaintexts, with a given number of bits
+/// of precision and padding.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FloatEncoder(pub(crate) ImplFloatEncoder);
+impl AbstractEntity for FloatEncoder {
+    type Kind = EncoderKind;
+}
+impl EncoderEntity for FloatEncoder {}
+
+/// A configuration for a [`FloatEncoder`] built from the bounds of its interval.
+///
+/// The `2^nb_bit_precision` representable values are evenly spread between `min` and `max`, both
+/// included.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FloatEncoderMinMaxConfig {
+    pub min: f64,
+    pub max: f64,
+    pub nb_bit_precision: usize,
+    pub nb_bit_padding: usize,
+}
+
+impl FloatEncoderMinMaxConfig {
+    pub(crate) fn to_float_encoder(self) -> ImplFloatEncoder {
+        ImplFloatEncoder::new_min_max(
+            self.min,
+            self.max,
+            self.nb_bit_precision,
+            self.nb_bit_padding,
+        )
+    }
+}
+
+/// A configuration for a [`FloatEncoder`] built from the center and radius of its interval.
+///
+/// The `2^nb_bit_precision` representable values are evenly spread between `center - radius` and
+/// `center + radius`, both included.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FloatEncoderCenterRadiusConfig {
+    pub center: f64,
+    pub radius: f64,
+    pub nb_bit_precision: usize,
+    pub nb_bit_padding: usize,
+}
+
+impl FloatEncoderCenterRadiusConfig {
+    pub(crate) fn to_float_encoder(self) -> ImplFloatEncoder {
+        ImplFloatEncoder::new_center_radius(
+            self.center,
+            self.radius,
+            self.nb_bit_precision,
+            self.nb_bit_padding,
+        )
+    }
+}
+
+/// A structure representing an integer encoder.
+///
+/// This encoder maps the integers of `[0, 2^nb_bit_precision)` to plaintexts, with a given number
+/// of bits of padding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntegerEncoder(pub(crate) ImplIntegerEncoder);
+impl AbstractEntity for IntegerEncoder {
+    type Kind = EncoderKind;
+}
+impl EncoderEntity for IntegerEncoder {}
+
+/// A configuration for an [`IntegerEncoder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntegerEncoderConfig {
+    pub nb_bit_precision: usize,
+    pub nb_bit_padding: usize,
+}
+
+impl IntegerEncoderConfig {
+    pub(crate) fn to_integer_encoder(self) -> ImplIntegerEncoder {
+        ImplIntegerEncoder::new(self.nb_bit_precision, self.nb_bit_padding)
+    }
+}
@@ -10,7 +10,7 @@ use crate::specification::entities::{AbstractEntity, GswCiphertextEntity};
 /// A structure representing a GSW ciphertext with 32 bits of precision.
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
-pub struct GswCiphertext32(ImplGswCiphertext<Vec<u32>, u32>);
+pub struct GswCiphertext32(pub(crate) ImplGswCiphertext<Vec<u32>, u32>);
 
 impl AbstractEntity for GswCiphertext32 {
     type Kind = GswCiphertextKind;
@@ -35,7 +35,7 @@ impl GswCiphertextEntity for GswCiphertext32 {
 /// A structure representing a GSW ciphertext with 64 bits of precision.
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
-pub struct GswCiphertext64(ImplGswCiphertext<Vec<u64>, u64>);
+pub struct GswCiphertext64(pub(crate) ImplGswCiphertext<Vec<u64>, u64>);
 
 impl AbstractEntity for GswCiphertext64 {
     type Kind = GswCiphertextKind;
@@ -0,0 +1,70 @@
+use crate::backends::core::private::crypto::lwe::LweSeededKeyswitchKey as ImplLweSeededKeyswitchKey;
+use crate::specification::entities::markers::{BinaryKeyDistribution, LweSeededKeyswitchKeyKind};
+use crate::specification::entities::{AbstractEntity, LweSeededKeyswitchKeyEntity};
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a seeded LWE keyswitch key with 32 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LweSeededKeyswitchKey32(pub(crate) ImplLweSeededKeyswitchKey<Vec<u32>>);
+impl AbstractEntity for LweSeededKeyswitchKey32 {
+    type Kind = LweSeededKeyswitchKeyKind;
+}
+impl LweSeededKeyswitchKeyEntity for LweSeededKeyswitchKey32 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.before_key_size()
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.0.after_key_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_levels_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn seed(&self) -> u128 {
+        self.0.seed()
+    }
+}
+
+/// A structure representing a seeded LWE keyswitch key with 64 bits of precision.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LweSeededKeyswitchKey64(pub(crate) ImplLweSeededKeyswitchKey<Vec<u64>>);
+impl AbstractEntity for LweSeededKeyswitchKey64 {
+    type Kind = LweSeededKeyswitchKeyKind;
+}
+impl LweSeededKeyswitchKeyEntity for LweSeededKeyswitchKey64 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.before_key_size()
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.0.after_key_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_levels_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn seed(&self) -> u128 {
+        self.0.seed()
+    }
+}
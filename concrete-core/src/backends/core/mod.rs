@@ -4,6 +4,10 @@
 //! strongly biased towards x86_64 platforms. In particular, it uses fftw to perform polynomials
 //! multiplication, and uses special aesni and rdseed instructions for faster random number
 //! generation.
+//!
+//! When the `multithread` feature is activated, the backend also exposes a
+//! [`CoreParallelEngine`](engines::CoreParallelEngine), which splits the operations on ciphertext
+//! vectors across the threads of a rayon pool.
 
 #[doc(hidden)]
 pub mod private;
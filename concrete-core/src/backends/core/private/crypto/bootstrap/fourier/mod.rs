@@ -876,6 +876,116 @@ where
         let local_accumulator = &mut buffers.lut_buffer;
         constant_sample_extract(lwe_out, &*local_accumulator);
     }
+
+    /// Performs a blind rotation of an accumulator by the phase of an lwe ciphertext, and writes
+    /// the rotated accumulator in the output glwe ciphertext.
+    ///
+    /// This is the first step of the [`bootstrap`](`FourierBootstrapKey::bootstrap`), without the
+    /// final sample extraction.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::bootstrap::{
+    ///     FourierBootstrapKey, FourierBuffers, StandardBootstrapKey,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::encoding::Plaintext;
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweCiphertext;
+    /// use concrete_core::backends::core::private::crypto::lwe::LweCiphertext;
+    /// use concrete_core::backends::core::private::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::{GlweSecretKey, LweSecretKey};
+    /// use concrete_core::backends::core::private::math::fft::Complex64;
+    ///
+    /// let polynomial_size = PolynomialSize(512);
+    /// let rlwe_dimension = GlweDimension(1);
+    /// let lwe_dimension = LweDimension(10);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(7);
+    /// let std = LogStandardDev::from_log_standard_dev(-29.);
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    /// let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    /// let rlwe_sk =
+    ///     GlweSecretKey::generate_binary(rlwe_dimension, polynomial_size, &mut secret_generator);
+    /// let lwe_sk = LweSecretKey::generate_binary(lwe_dimension, &mut secret_generator);
+    ///
+    /// let mut coef_bsk = StandardBootstrapKey::allocate(
+    ///     0 as u32,
+    ///     rlwe_dimension.to_glwe_size(),
+    ///     polynomial_size,
+    ///     level,
+    ///     base_log,
+    ///     lwe_dimension,
+    /// );
+    /// coef_bsk.fill_with_new_key(&lwe_sk, &rlwe_sk, std, &mut encryption_generator);
+    /// let mut fourier_bsk = FourierBootstrapKey::allocate(
+    ///     Complex64::new(0., 0.),
+    ///     rlwe_dimension.to_glwe_size(),
+    ///     polynomial_size,
+    ///     level,
+    ///     base_log,
+    ///     lwe_dimension,
+    /// );
+    /// let mut buffers = FourierBuffers::new(fourier_bsk.polynomial_size(), fourier_bsk.glwe_size());
+    /// fourier_bsk.fill_with_forward_fourier(&coef_bsk, &mut buffers);
+    ///
+    /// let mut lwe_in = LweCiphertext::allocate(0u32, lwe_dimension.to_lwe_size());
+    /// lwe_sk.encrypt_lwe(&mut lwe_in, &Plaintext(2u32.pow(30)), std, &mut encryption_generator);
+    ///
+    /// let accumulator =
+    ///     GlweCiphertext::allocate(0u32, polynomial_size, rlwe_dimension.to_glwe_size());
+    /// let mut rotated =
+    ///     GlweCiphertext::allocate(0u32, polynomial_size, rlwe_dimension.to_glwe_size());
+    ///
+    /// fourier_bsk.blind_rotate_accumulator(&mut rotated, &lwe_in, &accumulator, &mut buffers);
+    /// ```
+    pub fn blind_rotate_accumulator<C1, C2, C3>(
+        &self,
+        glwe_out: &mut GlweCiphertext<C1>,
+        lwe_in: &LweCiphertext<C2>,
+        accumulator: &GlweCiphertext<C3>,
+        buffers: &mut FourierBuffers<Scalar>,
+    ) where
+        GlweCiphertext<C1>: AsMutTensor<Element = Scalar>,
+        LweCiphertext<C2>: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<C3>: AsRefTensor<Element = Scalar>,
+    {
+        ck_dim_eq!(
+            self.poly_size =>
+            glwe_out.polynomial_size(),
+            accumulator.polynomial_size()
+        );
+        ck_dim_eq!(
+            self.glwe_size =>
+            glwe_out.size(),
+            accumulator.size()
+        );
+
+        // We retrieve the accumulator buffer, and fill it with the input accumulator values.
+        {
+            let local_accumulator = &mut buffers.lut_buffer;
+            local_accumulator
+                .as_mut_tensor()
+                .as_mut_slice()
+                .copy_from_slice(accumulator.as_tensor().as_slice());
+        }
+
+        // We perform the blind rotate
+        self.blind_rotate(buffers, lwe_in);
+
+        // We copy the rotated accumulator to the output.
+        glwe_out
+            .as_mut_tensor()
+            .as_mut_slice()
+            .copy_from_slice(buffers.lut_buffer.as_tensor().as_slice());
+    }
 }
 
 impl<Element, Cont, Scalar> AsRefTensor for FourierBootstrapKey<Cont, Scalar>
@@ -124,3 +124,206 @@ where
             .fill_with_one(encoded.as_tensor(), |e| self.decode(Plaintext(*e)).0);
     }
 }
+
+/// An encoder for real cleartexts, keeping track of the precision and padding of the encoding.
+///
+/// The messages which can be encoded are the real numbers of the interval
+/// `[origin, origin + delta)`. This interval is mapped to the torus, and the encoded values are
+/// shifted right by `nb_bit_padding` bits, leaving room for carries to grow during homomorphic
+/// computations. When decoding, the plaintext is rounded to its `nb_bit_precision +
+/// nb_bit_padding` most significant bits.
+///
+/// Integer messages can be encoded exactly by using `0` as the origin and `2^nb_bit_precision` as
+/// the delta.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FloatEncoder {
+    /// The lower bound of the interval of encodable messages.
+    pub origin: f64,
+    /// The size of the interval of encodable messages.
+    pub delta: f64,
+    /// The number of bits of precision of the encoding.
+    pub nb_bit_precision: usize,
+    /// The number of bits of padding on top of the encoded messages.
+    pub nb_bit_padding: usize,
+}
+
+impl FloatEncoder {
+    /// Creates an encoder whose `2^nb_bit_precision` representable values are evenly spread
+    /// between `min` and `max`, both included.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::backends::core::private::crypto::encoding::*;
+    /// let encoder = FloatEncoder::new_min_max(0., 3., 2, 1);
+    /// assert_eq!(encoder.origin, 0.);
+    /// assert_eq!(encoder.delta, 4.);
+    /// ```
+    pub fn new_min_max(min: f64, max: f64, nb_bit_precision: usize, nb_bit_padding: usize) -> Self {
+        let granularity = (max - min) / (f64::powi(2., nb_bit_precision as i32) - 1.);
+        FloatEncoder {
+            origin: min,
+            delta: max - min + granularity,
+            nb_bit_precision,
+            nb_bit_padding,
+        }
+    }
+
+    /// Creates an encoder whose `2^nb_bit_precision` representable values are evenly spread
+    /// between `center - radius` and `center + radius`, both included.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::backends::core::private::crypto::encoding::*;
+    /// let encoder = FloatEncoder::new_center_radius(0., 1.5, 2, 1);
+    /// assert_eq!(encoder.origin, -1.5);
+    /// assert_eq!(encoder.delta, 4.);
+    /// ```
+    pub fn new_center_radius(
+        center: f64,
+        radius: f64,
+        nb_bit_precision: usize,
+        nb_bit_padding: usize,
+    ) -> Self {
+        FloatEncoder::new_min_max(
+            center - radius,
+            center + radius,
+            nb_bit_precision,
+            nb_bit_padding,
+        )
+    }
+
+    /// Returns the distance between two consecutive representable values.
+    pub fn granularity(&self) -> f64 {
+        self.delta / f64::powi(2., self.nb_bit_precision as i32)
+    }
+
+    /// Returns whether the `message` belongs to the interval of encodable messages.
+    pub fn is_message_in_interval(&self, message: f64) -> bool {
+        message >= self.origin && message < self.origin + self.delta
+    }
+
+    /// Returns whether the encoding fits in an unsigned integer of the given precision.
+    pub fn fits_in<T: UnsignedTorus>(&self) -> bool {
+        self.nb_bit_precision + self.nb_bit_padding <= T::BITS
+    }
+
+    /// Encodes a single real cleartext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::backends::core::private::crypto::encoding::*;
+    /// let encoder = FloatEncoder::new_min_max(0., 7., 3, 1);
+    /// let encoded: Plaintext<u32> = encoder.encode(Cleartext(3.));
+    /// assert_eq!(encoded.0, 3 << 28);
+    /// let decoded = encoder.decode(encoded);
+    /// assert_eq!(decoded.0, 3.);
+    /// ```
+    pub fn encode<T: UnsignedTorus>(&self, cleartext: Cleartext<f64>) -> Plaintext<T> {
+        let torus = (cleartext.0 - self.origin) / self.delta;
+        let padded = torus / f64::powi(2., self.nb_bit_padding as i32);
+        Plaintext(<T as FromTorus<f64>>::from_torus(padded))
+    }
+
+    /// Decodes a single plaintext into a real cleartext.
+    ///
+    /// See [`FloatEncoder::encode`] for an example.
+    pub fn decode<T: UnsignedTorus>(&self, plaintext: Plaintext<T>) -> Cleartext<f64> {
+        let nb_bit = self.nb_bit_precision + self.nb_bit_padding;
+        let rounded = if nb_bit < T::BITS {
+            let shift = T::BITS - nb_bit;
+            let half = T::ONE << (shift - 1);
+            (plaintext.0.wrapping_add(half) >> shift) << shift
+        } else {
+            plaintext.0
+        };
+        let unpadded = if self.nb_bit_padding < T::BITS {
+            rounded << self.nb_bit_padding
+        } else {
+            T::ZERO
+        };
+        let torus: f64 = unpadded.into_torus();
+        Cleartext(torus * self.delta + self.origin)
+    }
+}
+
+/// An encoder for integer cleartexts, keeping track of the precision and padding of the encoding.
+///
+/// The messages which can be encoded are the integers of the interval `[0, 2^nb_bit_precision)`.
+/// They are written on the most significant bits of the plaintext, right below `nb_bit_padding`
+/// bits of padding. When decoding, the plaintext is rounded to its `nb_bit_precision +
+/// nb_bit_padding` most significant bits, and the padding is discarded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntegerEncoder {
+    /// The number of bits of precision of the encoding.
+    pub nb_bit_precision: usize,
+    /// The number of bits of padding on top of the encoded messages.
+    pub nb_bit_padding: usize,
+}
+
+impl IntegerEncoder {
+    /// Creates an encoder for the integers of `[0, 2^nb_bit_precision)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::backends::core::private::crypto::encoding::*;
+    /// let encoder = IntegerEncoder::new(4, 1);
+    /// assert_eq!(encoder.nb_bit_precision, 4);
+    /// assert_eq!(encoder.nb_bit_padding, 1);
+    /// ```
+    pub fn new(nb_bit_precision: usize, nb_bit_padding: usize) -> Self {
+        IntegerEncoder {
+            nb_bit_precision,
+            nb_bit_padding,
+        }
+    }
+
+    /// Returns whether the `message` belongs to the interval of encodable messages.
+    pub fn is_message_in_interval<T: UnsignedTorus>(&self, message: T) -> bool {
+        self.nb_bit_precision >= T::BITS || message < (T::ONE << self.nb_bit_precision)
+    }
+
+    /// Returns whether the encoding fits in an unsigned integer of the given precision.
+    pub fn fits_in<T: UnsignedTorus>(&self) -> bool {
+        self.nb_bit_precision + self.nb_bit_padding <= T::BITS
+    }
+
+    /// Encodes a single integer cleartext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::backends::core::private::crypto::encoding::*;
+    /// let encoder = IntegerEncoder::new(3, 1);
+    /// let encoded: Plaintext<u32> = encoder.encode(Cleartext(3_u32));
+    /// assert_eq!(encoded.0, 3 << 28);
+    /// let decoded = encoder.decode(encoded);
+    /// assert_eq!(decoded.0, 3);
+    /// ```
+    pub fn encode<T: UnsignedTorus>(&self, cleartext: Cleartext<T>) -> Plaintext<T> {
+        let shift = T::BITS - self.nb_bit_precision - self.nb_bit_padding;
+        Plaintext(cleartext.0 << shift)
+    }
+
+    /// Decodes a single plaintext into an integer cleartext.
+    ///
+    /// See [`IntegerEncoder::encode`] for an example.
+    pub fn decode<T: UnsignedTorus>(&self, plaintext: Plaintext<T>) -> Cleartext<T> {
+        let shift = T::BITS - self.nb_bit_precision - self.nb_bit_padding;
+        let rounded = if shift > 0 {
+            let half = T::ONE << (shift - 1);
+            plaintext.0.wrapping_add(half) >> shift
+        } else {
+            plaintext.0
+        };
+        let mask = if self.nb_bit_precision < T::BITS {
+            (T::ONE << self.nb_bit_precision) - T::ONE
+        } else {
+            T::MAX
+        };
+        Cleartext(rounded & mask)
+    }
+}
@@ -1,5 +1,5 @@
 use crate::backends::core::private::crypto::encoding::{
-    Cleartext, Encoder, Plaintext, RealEncoder,
+    Cleartext, Encoder, FloatEncoder, IntegerEncoder, Plaintext, RealEncoder,
 };
 use crate::backends::core::private::math::torus::UnsignedTorus;
 use crate::backends::core::private::test_tools::{any_uint, random_uint_between};
@@ -48,3 +48,62 @@ fn test_encoding_decoding_u32() {
 fn test_encoding_decoding_u64() {
     test_encoding_decoding::<u64>()
 }
+
+fn test_float_encoder_round_trip<T: UnsignedTorus>() {
+    //! Encodes and decodes every representable value of random float encoders
+    let n_tests = 100;
+    for _i in 0..n_tests {
+        let nb_bit_precision = random_uint_between(1u8..9) as usize;
+        let nb_bit_padding = random_uint_between(0u8..3) as usize;
+        let min = random_uint_between(0u8..100) as f64;
+        let width = random_uint_between(1u8..100) as f64;
+        let encoder =
+            FloatEncoder::new_min_max(-min, width - min, nb_bit_precision, nb_bit_padding);
+
+        for i in 0..(1 << nb_bit_precision) {
+            let message = encoder.origin + (i as f64) * encoder.granularity();
+            assert!(encoder.is_message_in_interval(message));
+            let encoding: Plaintext<T> = encoder.encode(Cleartext(message));
+            let decoding = encoder.decode(encoding);
+            assert!((decoding.0 - message).abs() < encoder.granularity() / 2.);
+        }
+    }
+}
+
+#[test]
+fn test_float_encoder_round_trip_u32() {
+    test_float_encoder_round_trip::<u32>()
+}
+
+#[test]
+fn test_float_encoder_round_trip_u64() {
+    test_float_encoder_round_trip::<u64>()
+}
+
+fn test_integer_encoder_round_trip<T: UnsignedTorus>() {
+    //! Encodes and decodes random messages of random integer encoders, with some noise
+    let n_tests = 100;
+    for _i in 0..n_tests {
+        let nb_bit_precision = random_uint_between(1u8..9) as usize;
+        let nb_bit_padding = random_uint_between(0u8..3) as usize;
+        let encoder = IntegerEncoder::new(nb_bit_precision, nb_bit_padding);
+        let message = random_uint_between(T::ZERO..(T::ONE << nb_bit_precision));
+        assert!(encoder.is_message_in_interval(message));
+
+        let encoding: Plaintext<T> = encoder.encode(Cleartext(message));
+        let shift = T::BITS - nb_bit_precision - nb_bit_padding;
+        let noise = random_uint_between(T::ZERO..(T::ONE << (shift - 1)));
+        let decoding = encoder.decode(Plaintext(encoding.0.wrapping_add(noise)));
+        assert_eq!(decoding.0, message);
+    }
+}
+
+#[test]
+fn test_integer_encoder_round_trip_u32() {
+    test_integer_encoder_round_trip::<u32>()
+}
+
+#[test]
+fn test_integer_encoder_round_trip_u64() {
+    test_integer_encoder_round_trip::<u64>()
+}
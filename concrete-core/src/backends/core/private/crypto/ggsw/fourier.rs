@@ -692,6 +692,38 @@ impl<Cont, Scalar> FourierGgswCiphertext<Cont, Scalar> {
             }
         }
     }
+
+    /// Computes the CMux between ct0 and ct1 and writes the result in output.
+    ///
+    /// If the GGSW ciphertext encrypts a zero, the output contains the plaintext of `ct0`, and if
+    /// it encrypts a one, the output contains the plaintext of `ct1`.
+    pub fn cmux<C0, C1, COut>(
+        &self,
+        output: &mut GlweCiphertext<COut>,
+        ct0: &GlweCiphertext<C0>,
+        ct1: &GlweCiphertext<C1>,
+        buffers: &mut FourierBuffers<Scalar>,
+    ) where
+        Self: AsRefTensor<Element = Complex64>,
+        GlweCiphertext<C0>: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<C1>: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<COut>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        let mut buffer = GlweCiphertext::allocate(Scalar::ZERO, ct1.polynomial_size(), ct1.size());
+        buffer
+            .as_mut_tensor()
+            .as_mut_slice()
+            .copy_from_slice(ct1.as_tensor().as_slice());
+        output
+            .as_mut_tensor()
+            .as_mut_slice()
+            .copy_from_slice(ct0.as_tensor().as_slice());
+        buffer
+            .as_mut_tensor()
+            .update_with_wrapping_sub(ct0.as_tensor());
+        self.external_product(output, &buffer, buffers);
+    }
 }
 
 impl<Element, Cont, Scalar> AsRefTensor for FourierGgswCiphertext<Cont, Scalar>
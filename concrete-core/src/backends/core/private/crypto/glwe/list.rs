@@ -1,4 +1,6 @@
 use concrete_commons::numeric::Numeric;
+#[cfg(feature = "multithread")]
+use rayon::{iter::IndexedParallelIterator, prelude::*};
 #[cfg(feature = "serde_serialize")]
 use serde::{Deserialize, Serialize};
 
@@ -217,6 +219,46 @@ impl<Cont> GlweList<Cont> {
             .map(move |sub| GlweCiphertext::from_container(sub.into_container(), poly_size))
     }
 
+    /// Returns a parallel iterator over ciphertexts borrowed from the list.
+    ///
+    /// # Notes
+    /// This iterator is hidden behind the "multithread" feature gate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{CiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::{GlweBody, GlweList};
+    /// use concrete_core::backends::core::private::math::tensor::AsRefTensor;
+    /// use rayon::iter::ParallelIterator;
+    /// let list = GlweList::allocate(
+    ///     0 as u8,
+    ///     PolynomialSize(10),
+    ///     GlweDimension(20),
+    ///     CiphertextCount(30),
+    /// );
+    /// list.par_ciphertext_iter().for_each(|ciphertext| {
+    ///     let (body, masks) = ciphertext.get_body_and_mask();
+    ///     assert_eq!(body.as_polynomial().polynomial_size(), PolynomialSize(10));
+    /// });
+    /// assert_eq!(list.par_ciphertext_iter().count(), 30);
+    /// ```
+    #[cfg(feature = "multithread")]
+    pub fn par_ciphertext_iter(
+        &self,
+    ) -> impl IndexedParallelIterator<Item = GlweCiphertext<&[<Self as AsRefTensor>::Element]>>
+    where
+        Self: AsRefTensor,
+        <Self as AsRefTensor>::Element: Sync,
+    {
+        ck_dim_div!(self.as_tensor().len() => self.rlwe_size.0, self.poly_size.0);
+        let poly_size = self.poly_size;
+        let size = self.rlwe_size.0 * self.polynomial_size().0;
+        self.as_tensor()
+            .par_subtensor_iter(size)
+            .map(move |sub| GlweCiphertext::from_container(sub.into_container(), poly_size))
+    }
+
     /// Returns an iterator over ciphertexts borrowed from the list.
     ///
     /// # Example
@@ -5,9 +5,13 @@ pub use ciphertext::*;
 pub use fourier::*;
 pub use list::*;
 pub use mask::*;
+pub use relinearization::*;
+pub use tensor_product::*;
 
 mod body;
 mod ciphertext;
 mod fourier;
 mod list;
 mod mask;
+mod relinearization;
+mod tensor_product;
@@ -0,0 +1,438 @@
+use crate::backends::core::private::crypto::encoding::PlaintextList;
+use crate::backends::core::private::crypto::secret::generators::EncryptionRandomGenerator;
+use crate::backends::core::private::crypto::secret::GlweSecretKey;
+use crate::backends::core::private::math::decomposition::{
+    DecompositionLevel, DecompositionTerm, SignedDecomposer,
+};
+use crate::backends::core::private::math::polynomial::Polynomial;
+use crate::backends::core::private::math::tensor::{
+    ck_dim_div, ck_dim_eq, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+use concrete_commons::dispersion::DispersionParameter;
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, PlaintextCount,
+    PolynomialSize,
+};
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+
+use super::{GlweCiphertext, GlweTensorProductCiphertext};
+
+/// A GLWE relinearization key.
+///
+/// A relinearization key allows to turn the tensor product of two GLWE ciphertexts encrypted
+/// under the key $S = (S_1, \dots, S_k)$ back into a GLWE ciphertext encrypted under $S$. For
+/// every product of key polynomials $S_i S_j$ with $i \leq j$, the key contains the encryptions
+/// under $S$ of the $l$ levels of its decomposition over the basis $B$, i.e. of
+/// $S_i S_j \frac{q}{B^1}, \dots, S_i S_j \frac{q}{B^l}$.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweRelinearizationKey<Cont> {
+    tensor: Tensor<Cont>,
+    poly_size: PolynomialSize,
+    glwe_size: GlweSize,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+}
+
+tensor_traits!(GlweRelinearizationKey);
+
+impl<Scalar> GlweRelinearizationKey<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates a relinearization key whose polynomials coefficients are all `value`.
+    ///
+    /// # Note
+    ///
+    /// This function does *not* generate a relinearization key, but merely allocates a container
+    /// of the right size. See [`GlweRelinearizationKey::fill_with_relinearization_key`] to fill
+    /// the container with a proper relinearization key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweRelinearizationKey;
+    /// let rlk = GlweRelinearizationKey::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(256),
+    ///     GlweDimension(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    /// );
+    /// assert_eq!(rlk.polynomial_size(), PolynomialSize(256));
+    /// assert_eq!(rlk.glwe_dimension(), GlweDimension(2));
+    /// assert_eq!(rlk.decomposition_level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(rlk.decomposition_base_log(), DecompositionBaseLog(7));
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        poly_size: PolynomialSize,
+        glwe_dimension: GlweDimension,
+        decomp_level_count: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+    ) -> Self {
+        let k = glwe_dimension.0;
+        GlweRelinearizationKey {
+            tensor: Tensor::from_container(vec![
+                value;
+                (k * (k + 1) / 2)
+                    * decomp_level_count.0
+                    * (k + 1)
+                    * poly_size.0
+            ]),
+            poly_size,
+            glwe_size: glwe_dimension.to_glwe_size(),
+            decomp_base_log,
+            decomp_level_count,
+        }
+    }
+}
+
+impl<Cont> GlweRelinearizationKey<Cont> {
+    /// Creates a relinearization key from an existing container.
+    ///
+    /// # Notes
+    ///
+    /// This method does not create a relinearization key, but merely wrap the container in the
+    /// proper type. It assumes that either the container already contains a proper
+    /// relinearization key, or that [`GlweRelinearizationKey::fill_with_relinearization_key`] will
+    /// be called right after.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweRelinearizationKey;
+    /// let rlk = GlweRelinearizationKey::from_container(
+    ///     vec![0 as u32; 3 * 3 * 3 * 256],
+    ///     PolynomialSize(256),
+    ///     GlweDimension(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    /// );
+    /// assert_eq!(rlk.polynomial_size(), PolynomialSize(256));
+    /// assert_eq!(rlk.glwe_dimension(), GlweDimension(2));
+    /// assert_eq!(rlk.decomposition_level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(rlk.decomposition_base_log(), DecompositionBaseLog(7));
+    /// ```
+    pub fn from_container(
+        cont: Cont,
+        poly_size: PolynomialSize,
+        glwe_dimension: GlweDimension,
+        decomp_level_count: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+    ) -> Self
+    where
+        Cont: AsRefSlice,
+    {
+        let k = glwe_dimension.0;
+        let tensor = Tensor::from_container(cont);
+        ck_dim_eq!(
+            tensor.len() =>
+            (k * (k + 1) / 2) * decomp_level_count.0 * (k + 1) * poly_size.0
+        );
+        GlweRelinearizationKey {
+            tensor,
+            poly_size,
+            glwe_size: glwe_dimension.to_glwe_size(),
+            decomp_base_log,
+            decomp_level_count,
+        }
+    }
+
+    /// Returns the size of the polynomials used in the key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweRelinearizationKey;
+    /// let rlk = GlweRelinearizationKey::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(256),
+    ///     GlweDimension(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    /// );
+    /// assert_eq!(rlk.polynomial_size(), PolynomialSize(256));
+    /// ```
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the dimension of the GLWE ciphertexts the key can relinearize.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweRelinearizationKey;
+    /// let rlk = GlweRelinearizationKey::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(256),
+    ///     GlweDimension(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    /// );
+    /// assert_eq!(rlk.glwe_dimension(), GlweDimension(2));
+    /// ```
+    pub fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_size.to_glwe_dimension()
+    }
+
+    /// Returns the number of levels used to decompose the products of the key polynomials.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweRelinearizationKey;
+    /// let rlk = GlweRelinearizationKey::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(256),
+    ///     GlweDimension(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    /// );
+    /// assert_eq!(rlk.decomposition_level_count(), DecompositionLevelCount(3));
+    /// ```
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Returns the logarithm of the base used to decompose the products of the key polynomials.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweRelinearizationKey;
+    /// let rlk = GlweRelinearizationKey::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(256),
+    ///     GlweDimension(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    /// );
+    /// assert_eq!(rlk.decomposition_base_log(), DecompositionBaseLog(7));
+    /// ```
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Fills the current relinearization key container with an actual relinearization key
+    /// constructed from a GLWE secret key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweRelinearizationKey;
+    /// use concrete_core::backends::core::private::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::GlweSecretKey;
+    /// use concrete_core::backends::core::private::math::tensor::AsRefTensor;
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    /// let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    /// let key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    /// let mut rlk = GlweRelinearizationKey::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(256),
+    ///     GlweDimension(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    /// );
+    /// rlk.fill_with_relinearization_key(
+    ///     &key,
+    ///     LogStandardDev::from_log_standard_dev(-15.),
+    ///     &mut encryption_generator,
+    /// );
+    /// assert!(!rlk.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    pub fn fill_with_relinearization_key<KeyCont, Scalar>(
+        &mut self,
+        secret_key: &GlweSecretKey<BinaryKeyKind, KeyCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweSecretKey<BinaryKeyKind, KeyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.glwe_dimension().0 => secret_key.key_size().0);
+        ck_dim_eq!(self.poly_size.0 => secret_key.polynomial_size().0);
+
+        let k = self.glwe_dimension().0;
+        let poly_size = self.poly_size;
+        let decomp_base_log = self.decomp_base_log;
+        let decomp_level_count = self.decomp_level_count;
+        let block_size = decomp_level_count.0 * self.glwe_size.0 * poly_size.0;
+        let ciphertext_size = self.glwe_size.0 * poly_size.0;
+
+        let key_polynomials = secret_key.as_polynomial_list();
+        let mut key_product = Polynomial::allocate(Scalar::ZERO, poly_size);
+        let mut messages = PlaintextList::allocate(Scalar::ZERO, PlaintextCount(poly_size.0));
+
+        let pairs = (0..k).flat_map(|i| (i..k).map(move |j| (i, j)));
+        for ((i, j), block) in pairs.zip(self.as_mut_tensor().subtensor_iter_mut(block_size)) {
+            key_product.fill_with_wrapping_mul(
+                &key_polynomials.get_polynomial(i),
+                &key_polynomials.get_polynomial(j),
+            );
+            for (level, ciphertext) in (1..=decomp_level_count.0)
+                .map(DecompositionLevel)
+                .zip(block.into_container().chunks_mut(ciphertext_size))
+            {
+                messages
+                    .as_mut_tensor()
+                    .fill_with_one(key_product.as_tensor(), |coef| {
+                        DecompositionTerm::new(level, decomp_base_log, *coef)
+                            .to_recomposition_summand()
+                    });
+                secret_key.encrypt_glwe(
+                    &mut GlweCiphertext::from_container(ciphertext, poly_size),
+                    &messages,
+                    noise_parameters,
+                    generator,
+                );
+            }
+        }
+    }
+
+    /// Relinearizes a tensor product ciphertext into a GLWE ciphertext.
+    ///
+    /// Each quadratic mask of the input is decomposed over the basis of the key, and the
+    /// products of the decomposition terms with the matching encryptions of $S_i S_j$ are
+    /// subtracted from the linear part of the input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, DeltaLog, GlweDimension, PlaintextCount,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::encoding::PlaintextList;
+    /// use concrete_core::backends::core::private::crypto::glwe::{
+    ///     GlweCiphertext, GlweRelinearizationKey, GlweTensorProductCiphertext,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::GlweSecretKey;
+    /// use concrete_core::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+    ///
+    /// let poly_size = PolynomialSize(256);
+    /// let glwe_dimension = GlweDimension(1);
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    /// let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    /// let key = GlweSecretKey::generate_binary(glwe_dimension, poly_size, &mut secret_generator);
+    /// let noise = LogStandardDev::from_log_standard_dev(-50.);
+    ///
+    /// let mut rlk = GlweRelinearizationKey::allocate(
+    ///     0 as u64,
+    ///     poly_size,
+    ///     glwe_dimension,
+    ///     DecompositionLevelCount(4),
+    ///     DecompositionBaseLog(10),
+    /// );
+    /// rlk.fill_with_relinearization_key(&key, noise, &mut encryption_generator);
+    ///
+    /// // We encrypt 2 and 3 with a scaling factor of 2^60.
+    /// let mut lhs_plaintexts = PlaintextList::allocate(0_u64, PlaintextCount(poly_size.0));
+    /// let mut rhs_plaintexts = PlaintextList::allocate(0_u64, PlaintextCount(poly_size.0));
+    /// *lhs_plaintexts.as_mut_tensor().get_element_mut(0) = 2 << 60;
+    /// *rhs_plaintexts.as_mut_tensor().get_element_mut(0) = 3 << 60;
+    /// let mut lhs = GlweCiphertext::allocate(0 as u64, poly_size, glwe_dimension.to_glwe_size());
+    /// let mut rhs = GlweCiphertext::allocate(0 as u64, poly_size, glwe_dimension.to_glwe_size());
+    /// key.encrypt_glwe(&mut lhs, &lhs_plaintexts, noise, &mut encryption_generator);
+    /// key.encrypt_glwe(&mut rhs, &rhs_plaintexts, noise, &mut encryption_generator);
+    ///
+    /// let mut tensor_product =
+    ///     GlweTensorProductCiphertext::allocate(0 as u64, poly_size, glwe_dimension);
+    /// tensor_product.fill_with_tensor_product(&lhs, &rhs, DeltaLog(60));
+    /// let mut product =
+    ///     GlweCiphertext::allocate(0 as u64, poly_size, glwe_dimension.to_glwe_size());
+    /// rlk.relinearize(&mut product, &tensor_product);
+    ///
+    /// let mut decrypted = PlaintextList::allocate(0_u64, PlaintextCount(poly_size.0));
+    /// key.decrypt_glwe(&mut decrypted, &product);
+    /// let rounded = (decrypted.as_tensor().get_element(0).wrapping_add(1 << 59)) >> 60;
+    /// assert_eq!(rounded, 6);
+    /// ```
+    pub fn relinearize<OutputCont, InputCont, Scalar>(
+        &self,
+        output: &mut GlweCiphertext<OutputCont>,
+        input: &GlweTensorProductCiphertext<InputCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<OutputCont>: AsMutTensor<Element = Scalar>,
+        GlweTensorProductCiphertext<InputCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.glwe_dimension().0 => input.glwe_dimension().0, output.mask_size().0);
+        ck_dim_eq!(self.poly_size.0 => input.polynomial_size().0, output.polynomial_size().0);
+
+        let poly_size = self.poly_size;
+        let block_size = self.decomp_level_count.0 * self.glwe_size.0 * poly_size.0;
+        let ciphertext_size = self.glwe_size.0 * poly_size.0;
+
+        // We start from the linear part of the tensor product.
+        output
+            .as_mut_tensor()
+            .fill_with_copy(input.get_linear_masks_and_body().as_tensor());
+
+        let decomposer = SignedDecomposer::new(self.decomp_base_log, self.decomp_level_count);
+        let mut product = Polynomial::allocate(Scalar::ZERO, poly_size);
+        for (quadratic_mask, block) in input
+            .get_quadratic_masks()
+            .polynomial_iter()
+            .zip(self.as_tensor().subtensor_iter(block_size))
+        {
+            ck_dim_div!(block.len() => ciphertext_size);
+            let mut decomposition = decomposer.decompose_tensor(&quadratic_mask);
+            while let Some(term) = decomposition.next_term() {
+                let term_polynomial = Polynomial::from_container(term.as_tensor().as_slice());
+                let offset = (term.level().0 - 1) * ciphertext_size;
+                let level_ciphertext = GlweCiphertext::from_container(
+                    &block.as_slice()[offset..offset + ciphertext_size],
+                    poly_size,
+                );
+                for (mut output_polynomial, key_polynomial) in output
+                    .as_mut_polynomial_list()
+                    .polynomial_iter_mut()
+                    .zip(level_ciphertext.as_polynomial_list().polynomial_iter())
+                {
+                    product.fill_with_karatsuba_mul(&term_polynomial, &key_polynomial);
+                    output_polynomial.update_with_wrapping_sub(&product);
+                }
+            }
+        }
+    }
+}
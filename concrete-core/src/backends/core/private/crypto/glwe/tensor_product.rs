@@ -0,0 +1,423 @@
+use crate::backends::core::private::math::polynomial::{Polynomial, PolynomialList};
+use crate::backends::core::private::math::tensor::{
+    ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+use concrete_commons::numeric::{CastFrom, CastInto, Numeric};
+use concrete_commons::parameters::{DeltaLog, GlweDimension, PolynomialCount, PolynomialSize};
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+
+use super::GlweCiphertext;
+
+/// The tensor product of two GLWE ciphertexts.
+///
+/// For two GLWE ciphertexts of dimension $k$ encrypted under the key $S = (S_1, \dots, S_k)$, the
+/// tensor product is a GLWE ciphertext of dimension $k(k+1)/2 + k$, encrypted under the
+/// extended key $(S_1 S_1, S_1 S_2, \dots, S_k S_k, S_1, \dots, S_k)$. The ciphertext is
+/// stored as:
+///
+/// + the $k(k+1)/2$ quadratic masks, associated with the products $S_i S_j$ for $i \leq j$, in
+/// lexicographic order,
+/// + the $k$ linear masks, associated with the key polynomials $S_i$,
+/// + the body.
+///
+/// A tensor product ciphertext is turned back into a regular GLWE ciphertext with a
+/// [`GlweRelinearizationKey`](`super::GlweRelinearizationKey`).
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlweTensorProductCiphertext<Cont> {
+    pub(crate) tensor: Tensor<Cont>,
+    pub(crate) poly_size: PolynomialSize,
+    pub(crate) glwe_dimension: GlweDimension,
+}
+
+tensor_traits!(GlweTensorProductCiphertext);
+
+impl<Scalar> GlweTensorProductCiphertext<Vec<Scalar>> {
+    /// Allocates a new tensor product ciphertext, whose coefficients are all `value`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialCount, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweTensorProductCiphertext;
+    /// let tensor_product =
+    ///     GlweTensorProductCiphertext::allocate(0 as u8, PolynomialSize(10), GlweDimension(2));
+    /// assert_eq!(tensor_product.polynomial_size(), PolynomialSize(10));
+    /// assert_eq!(tensor_product.glwe_dimension(), GlweDimension(2));
+    /// assert_eq!(
+    ///     tensor_product.as_polynomial_list().polynomial_count(),
+    ///     PolynomialCount(6)
+    /// );
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        poly_size: PolynomialSize,
+        glwe_dimension: GlweDimension,
+    ) -> GlweTensorProductCiphertext<Vec<Scalar>>
+    where
+        Scalar: Copy,
+    {
+        let count = tensor_product_polynomial_count(glwe_dimension);
+        GlweTensorProductCiphertext::from_container(
+            vec![value; count.0 * poly_size.0],
+            poly_size,
+            glwe_dimension,
+        )
+    }
+}
+
+impl<Cont> GlweTensorProductCiphertext<Cont> {
+    /// Creates a tensor product ciphertext from an existing container.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweTensorProductCiphertext;
+    /// let tensor_product = GlweTensorProductCiphertext::from_container(
+    ///     vec![0 as u8; 60],
+    ///     PolynomialSize(10),
+    ///     GlweDimension(2),
+    /// );
+    /// assert_eq!(tensor_product.polynomial_size(), PolynomialSize(10));
+    /// assert_eq!(tensor_product.glwe_dimension(), GlweDimension(2));
+    /// ```
+    pub fn from_container(
+        cont: Cont,
+        poly_size: PolynomialSize,
+        glwe_dimension: GlweDimension,
+    ) -> GlweTensorProductCiphertext<Cont>
+    where
+        Cont: AsRefSlice,
+    {
+        let tensor = Tensor::from_container(cont);
+        ck_dim_eq!(tensor.len() => tensor_product_polynomial_count(glwe_dimension).0 * poly_size.0);
+        GlweTensorProductCiphertext {
+            tensor,
+            poly_size,
+            glwe_dimension,
+        }
+    }
+
+    /// Returns the number of coefficients of the polynomials of the ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweTensorProductCiphertext;
+    /// let tensor_product =
+    ///     GlweTensorProductCiphertext::allocate(0 as u8, PolynomialSize(10), GlweDimension(2));
+    /// assert_eq!(tensor_product.polynomial_size(), PolynomialSize(10));
+    /// ```
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the dimension of the GLWE ciphertexts the tensor product was computed from.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweTensorProductCiphertext;
+    /// let tensor_product =
+    ///     GlweTensorProductCiphertext::allocate(0 as u8, PolynomialSize(10), GlweDimension(2));
+    /// assert_eq!(tensor_product.glwe_dimension(), GlweDimension(2));
+    /// ```
+    pub fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    /// Returns a borrowed polynomial list containing the quadratic masks of the ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialCount, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweTensorProductCiphertext;
+    /// let tensor_product =
+    ///     GlweTensorProductCiphertext::allocate(0 as u8, PolynomialSize(10), GlweDimension(2));
+    /// assert_eq!(
+    ///     tensor_product.get_quadratic_masks().polynomial_count(),
+    ///     PolynomialCount(3)
+    /// );
+    /// ```
+    pub fn get_quadratic_masks(&self) -> PolynomialList<&[<Self as AsRefTensor>::Element]>
+    where
+        Self: AsRefTensor,
+    {
+        let k = self.glwe_dimension.0;
+        PolynomialList::from_container(
+            self.as_tensor()
+                .get_sub(..(k * (k + 1) / 2) * self.poly_size.0)
+                .into_container(),
+            self.poly_size,
+        )
+    }
+
+    /// Returns a borrowed polynomial list containing the linear masks and the body of the
+    /// ciphertext, i.e. the polynomials laid out as a GLWE ciphertext under the original key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialCount, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweTensorProductCiphertext;
+    /// let tensor_product =
+    ///     GlweTensorProductCiphertext::allocate(0 as u8, PolynomialSize(10), GlweDimension(2));
+    /// assert_eq!(
+    ///     tensor_product.get_linear_masks_and_body().polynomial_count(),
+    ///     PolynomialCount(3)
+    /// );
+    /// ```
+    pub fn get_linear_masks_and_body(&self) -> PolynomialList<&[<Self as AsRefTensor>::Element]>
+    where
+        Self: AsRefTensor,
+    {
+        let k = self.glwe_dimension.0;
+        PolynomialList::from_container(
+            self.as_tensor()
+                .get_sub((k * (k + 1) / 2) * self.poly_size.0..)
+                .into_container(),
+            self.poly_size,
+        )
+    }
+
+    /// Returns a borrowed polynomial list containing all the polynomials of the ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialCount, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweTensorProductCiphertext;
+    /// let tensor_product =
+    ///     GlweTensorProductCiphertext::allocate(0 as u8, PolynomialSize(10), GlweDimension(1));
+    /// assert_eq!(
+    ///     tensor_product.as_polynomial_list().polynomial_count(),
+    ///     PolynomialCount(3)
+    /// );
+    /// ```
+    pub fn as_polynomial_list(&self) -> PolynomialList<&[<Self as AsRefTensor>::Element]>
+    where
+        Self: AsRefTensor,
+    {
+        PolynomialList::from_container(self.as_tensor().as_slice(), self.poly_size)
+    }
+
+    /// Returns a mutably borrowed polynomial list containing all the polynomials of the
+    /// ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweTensorProductCiphertext;
+    /// use concrete_core::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+    /// let mut tensor_product =
+    ///     GlweTensorProductCiphertext::allocate(0 as u8, PolynomialSize(10), GlweDimension(1));
+    /// for mut poly in tensor_product.as_mut_polynomial_list().polynomial_iter_mut() {
+    ///     poly.as_mut_tensor().fill_with_element(9);
+    /// }
+    /// assert!(tensor_product.as_tensor().iter().all(|a| *a == 9));
+    /// ```
+    pub fn as_mut_polynomial_list(
+        &mut self,
+    ) -> PolynomialList<&mut [<Self as AsMutTensor>::Element]>
+    where
+        Self: AsMutTensor,
+    {
+        let poly_size = self.poly_size;
+        PolynomialList::from_container(self.as_mut_tensor().as_mut_slice(), poly_size)
+    }
+
+    /// Fills the current ciphertext with the tensor product of two GLWE ciphertexts.
+    ///
+    /// The products of the polynomials are computed exactly, using centered representatives of
+    /// the input coefficients, then divided by $\Delta = 2^D$ and rounded back to the torus. If
+    /// the two inputs encrypt $\Delta_1 M_1$ and $\Delta_2 M_2$ with $\Delta = \min(\Delta_1,
+    /// \Delta_2)$ dividing both of them, the output encrypts $\max(\Delta_1, \Delta_2) M_1 M_2$.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DeltaLog, GlweDimension, GlweSize, PlaintextCount, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::encoding::PlaintextList;
+    /// use concrete_core::backends::core::private::crypto::glwe::{
+    ///     GlweCiphertext, GlweTensorProductCiphertext,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::GlweSecretKey;
+    /// use concrete_core::backends::core::private::math::tensor::AsRefTensor;
+    ///
+    /// let poly_size = PolynomialSize(4);
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    /// let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    /// let key = GlweSecretKey::generate_binary(GlweDimension(1), poly_size, &mut secret_generator);
+    /// let noise = LogStandardDev::from_log_standard_dev(-50.);
+    ///
+    /// let delta_log = DeltaLog(60);
+    /// let lhs_plaintexts = PlaintextList::from_container(vec![2_u64 << 60, 1 << 60, 0, 0]);
+    /// let rhs_plaintexts = PlaintextList::from_container(vec![3_u64 << 60, 0, 0, 0]);
+    /// let mut lhs = GlweCiphertext::allocate(0 as u64, poly_size, GlweSize(2));
+    /// let mut rhs = GlweCiphertext::allocate(0 as u64, poly_size, GlweSize(2));
+    /// key.encrypt_glwe(&mut lhs, &lhs_plaintexts, noise, &mut encryption_generator);
+    /// key.encrypt_glwe(&mut rhs, &rhs_plaintexts, noise, &mut encryption_generator);
+    ///
+    /// let mut tensor_product =
+    ///     GlweTensorProductCiphertext::allocate(0 as u64, poly_size, GlweDimension(1));
+    /// tensor_product.fill_with_tensor_product(&lhs, &rhs, delta_log);
+    /// assert!(!tensor_product.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    pub fn fill_with_tensor_product<LhsCont, RhsCont, Scalar>(
+        &mut self,
+        lhs: &GlweCiphertext<LhsCont>,
+        rhs: &GlweCiphertext<RhsCont>,
+        delta_log: DeltaLog,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<LhsCont>: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<RhsCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus + CastFrom<u128>,
+        Scalar::Signed: CastInto<i128>,
+    {
+        ck_dim_eq!(self.poly_size.0 => lhs.polynomial_size().0, rhs.polynomial_size().0);
+        ck_dim_eq!(self.glwe_dimension.0 => lhs.mask_size().0, rhs.mask_size().0);
+        debug_assert!(
+            delta_log.0 <= <Scalar as Numeric>::BITS,
+            "The scaling factor can not be larger than the modulus."
+        );
+
+        let k = self.glwe_dimension.0;
+        let poly_size = self.poly_size;
+        let lhs_polynomials = lhs.as_polynomial_list();
+        let rhs_polynomials = rhs.as_polynomial_list();
+        let mut accumulator = vec![0u128; poly_size.0];
+        let mut output = self.as_mut_polynomial_list();
+        let mut output_polynomials = output.polynomial_iter_mut();
+
+        // The quadratic masks: -(A1_i A2_j + A1_j A2_i) for i < j, and -A1_i A2_i on the diagonal.
+        for i in 0..k {
+            for j in i..k {
+                accumulator.iter_mut().for_each(|a| *a = 0);
+                update_with_wide_mul(
+                    &mut accumulator,
+                    &lhs_polynomials.get_polynomial(i),
+                    &rhs_polynomials.get_polynomial(j),
+                );
+                if i != j {
+                    update_with_wide_mul(
+                        &mut accumulator,
+                        &lhs_polynomials.get_polynomial(j),
+                        &rhs_polynomials.get_polynomial(i),
+                    );
+                }
+                accumulator.iter_mut().for_each(|a| *a = a.wrapping_neg());
+                fill_with_rounded_division(
+                    &mut output_polynomials.next().unwrap(),
+                    &accumulator,
+                    delta_log,
+                );
+            }
+        }
+
+        // The linear masks: B1 A2_i + B2 A1_i.
+        for i in 0..k {
+            accumulator.iter_mut().for_each(|a| *a = 0);
+            update_with_wide_mul(
+                &mut accumulator,
+                &lhs_polynomials.get_polynomial(k),
+                &rhs_polynomials.get_polynomial(i),
+            );
+            update_with_wide_mul(
+                &mut accumulator,
+                &rhs_polynomials.get_polynomial(k),
+                &lhs_polynomials.get_polynomial(i),
+            );
+            fill_with_rounded_division(
+                &mut output_polynomials.next().unwrap(),
+                &accumulator,
+                delta_log,
+            );
+        }
+
+        // The body: B1 B2.
+        accumulator.iter_mut().for_each(|a| *a = 0);
+        update_with_wide_mul(
+            &mut accumulator,
+            &lhs_polynomials.get_polynomial(k),
+            &rhs_polynomials.get_polynomial(k),
+        );
+        fill_with_rounded_division(
+            &mut output_polynomials.next().unwrap(),
+            &accumulator,
+            delta_log,
+        );
+    }
+}
+
+/// Returns the number of polynomials in the tensor product of two GLWE ciphertexts of dimension
+/// `glwe_dimension`.
+fn tensor_product_polynomial_count(glwe_dimension: GlweDimension) -> PolynomialCount {
+    let k = glwe_dimension.0;
+    PolynomialCount(k * (k + 1) / 2 + k + 1)
+}
+
+/// Adds the product of two polynomials, reduced modulo $(X^N + 1)$, to the accumulator.
+///
+/// The coefficients are lifted to their centered representatives before being multiplied, and the
+/// accumulation is performed modulo $2^{128}$, which is enough to compute the product exactly
+/// modulo $q \Delta$.
+fn update_with_wide_mul<Scalar>(
+    accumulator: &mut [u128],
+    lhs: &Polynomial<&[Scalar]>,
+    rhs: &Polynomial<&[Scalar]>,
+) where
+    Scalar: UnsignedTorus,
+    Scalar::Signed: CastInto<i128>,
+{
+    let degree = lhs.polynomial_size().0;
+    let lift = |coef: &Scalar| -> u128 {
+        let signed: i128 = coef.into_signed().cast_into();
+        signed as u128
+    };
+    let rhs_lifted: Vec<u128> = rhs.as_tensor().iter().map(lift).collect();
+    for (lhs_index, lhs_coef) in lhs.as_tensor().iter().enumerate() {
+        let lhs_coef = lift(lhs_coef);
+        for (rhs_index, rhs_coef) in rhs_lifted.iter().enumerate() {
+            let product = lhs_coef.wrapping_mul(*rhs_coef);
+            let target = lhs_index + rhs_index;
+            if target < degree {
+                accumulator[target] = accumulator[target].wrapping_add(product);
+            } else {
+                accumulator[target - degree] = accumulator[target - degree].wrapping_sub(product);
+            }
+        }
+    }
+}
+
+/// Fills the output polynomial with the accumulator divided by $2^D$, rounded to the closest
+/// integer and reduced modulo $q$.
+fn fill_with_rounded_division<Scalar>(
+    output: &mut Polynomial<&mut [Scalar]>,
+    accumulator: &[u128],
+    delta_log: DeltaLog,
+) where
+    Scalar: UnsignedTorus + CastFrom<u128>,
+{
+    let rounding = if delta_log.0 == 0 {
+        0
+    } else {
+        1u128 << (delta_log.0 - 1)
+    };
+    for (coef, acc) in output.as_mut_tensor().iter_mut().zip(accumulator.iter()) {
+        *coef = Scalar::cast_from(acc.wrapping_add(rounding) >> delta_log.0);
+    }
+}
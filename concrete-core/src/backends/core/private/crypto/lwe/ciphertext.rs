@@ -1,3 +1,5 @@
+#[cfg(feature = "multithread")]
+use rayon::prelude::*;
 #[cfg(feature = "serde_serialize")]
 use serde::{Deserialize, Serialize};
 
@@ -387,6 +389,104 @@ impl<Cont> LweCiphertext<Cont> {
         *self.get_mut_body() = LweBody(new_body);
     }
 
+    /// Fills the current ciphertext with the result of a multisum between the `input_list` and
+    /// the `weights` values, plus the `bias`, using as many threads as possible.
+    ///
+    /// # Notes
+    /// This method is hidden behind the "multithread" feature gate.
+    ///
+    /// The partial multisums computed by the different threads are accumulated in separate
+    /// ciphertexts, which are eventually added to the current one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{LweDimension, LweSize};
+    /// use concrete_core::backends::core::private::crypto::encoding::*;
+    /// use concrete_core::backends::core::private::crypto::lwe::*;
+    /// use concrete_core::backends::core::private::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::LweSecretKey;
+    /// use concrete_core::backends::core::private::crypto::*;
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    /// let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    ///
+    /// let secret_key = LweSecretKey::generate_binary(LweDimension(4), &mut secret_generator);
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    /// let encoder = RealEncoder {
+    ///     offset: 0. as f32,
+    ///     delta: 100.,
+    /// };
+    ///
+    /// let clear_values = CleartextList::from_container(vec![1. as f32, 2., 3.]);
+    /// let mut plain_values = PlaintextList::from_container(vec![0 as u32; 3]);
+    /// encoder.encode_list(&mut plain_values, &clear_values);
+    /// let mut ciphertext_values = LweList::from_container(vec![0. as u32; 5 * 3], LweSize(5));
+    /// secret_key.encrypt_lwe_list(
+    ///     &mut ciphertext_values,
+    ///     &plain_values,
+    ///     noise,
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// let mut output = LweCiphertext::from_container(vec![0. as u32; 5]);
+    /// let weights = CleartextList::from_container(vec![7, 8, 9]);
+    /// let bias = encoder.encode(Cleartext(13.));
+    ///
+    /// output.par_fill_with_multisum_with_bias(&ciphertext_values, &weights, &bias);
+    ///
+    /// let mut decrypted = Plaintext(0 as u32);
+    /// secret_key.decrypt_lwe(&mut decrypted, &output);
+    /// let decoded = encoder.decode(decrypted);
+    /// assert!((decoded.0 - 63.).abs() < 0.1);
+    /// ```
+    #[cfg(feature = "multithread")]
+    pub fn par_fill_with_multisum_with_bias<Scalar, InputCont, WeightCont>(
+        &mut self,
+        input_list: &LweList<InputCont>,
+        weights: &CleartextList<WeightCont>,
+        bias: &Plaintext<Scalar>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweList<InputCont>: AsRefTensor<Element = Scalar>,
+        CleartextList<WeightCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus + Send + Sync,
+    {
+        let lwe_size = self.lwe_size();
+
+        // compute the partial multisums in parallel, and reduce them in a single ciphertext
+        let multisum = input_list
+            .par_ciphertext_iter()
+            .zip(weights.as_tensor().par_iter())
+            .fold(
+                || LweCiphertext::allocate(Scalar::ZERO, lwe_size),
+                |mut acc, (input_cipher, weight)| {
+                    acc.as_mut_tensor()
+                        .update_with_one(input_cipher.as_tensor(), |o, c| {
+                            *o = o.wrapping_add(c.wrapping_mul(*weight))
+                        });
+                    acc
+                },
+            )
+            .reduce(
+                || LweCiphertext::allocate(Scalar::ZERO, lwe_size),
+                |mut acc, partial| {
+                    acc.as_mut_tensor()
+                        .update_with_wrapping_add(partial.as_tensor());
+                    acc
+                },
+            );
+        self.as_mut_tensor()
+            .update_with_wrapping_add(multisum.as_tensor());
+
+        // add the bias
+        let new_body = (self.get_body().0).wrapping_add(bias.0);
+        *self.get_mut_body() = LweBody(new_body);
+    }
+
     /// Adds the `other` ciphertext to the current one.
     ///
     /// # Example
@@ -1,3 +1,5 @@
+#[cfg(feature = "multithread")]
+use rayon::prelude::*;
 #[cfg(feature = "serde_serialize")]
 use serde::{Deserialize, Serialize};
 
@@ -576,6 +578,31 @@ impl<Cont> LweKeyswitchKey<Cont> {
             self.keyswitch_ciphertext(&mut output_cipher, &input_cipher);
         }
     }
+
+    /// Switches the key of a list of ciphertexts, using as many threads as possible.
+    ///
+    /// # Notes
+    /// This method is hidden behind the "multithread" feature gate.
+    #[cfg(feature = "multithread")]
+    pub fn par_keyswitch_list<InCont, OutCont, Scalar>(
+        &self,
+        output: &mut LweList<OutCont>,
+        input: &LweList<InCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LweList<InCont>: AsRefTensor<Element = Scalar>,
+        LweList<OutCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus + Send + Sync,
+        Cont: Sync,
+    {
+        ck_dim_eq!(input.count().0 => output.count().0);
+        input
+            .par_ciphertext_iter()
+            .zip(output.par_ciphertext_iter_mut())
+            .for_each(|(input_cipher, mut output_cipher)| {
+                self.keyswitch_ciphertext(&mut output_cipher, &input_cipher);
+            });
+    }
 }
 
 /// The encryption of a single bit of the output key.
@@ -1,3 +1,5 @@
+#[cfg(feature = "multithread")]
+use rayon::{iter::IndexedParallelIterator, prelude::*};
 #[cfg(feature = "serde_serialize")]
 use serde::{Deserialize, Serialize};
 
@@ -255,6 +257,76 @@ impl<Cont> LweList<Cont> {
             .map(|sub| LweCiphertext::from_container(sub.into_container()))
     }
 
+    /// Returns a parallel iterator over ciphers borrowed from the list.
+    ///
+    /// # Notes
+    /// This iterator is hidden behind the "multithread" feature gate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::backends::core::private::crypto::lwe::*;
+    /// use concrete_core::backends::core::private::crypto::*;
+    /// use rayon::iter::ParallelIterator;
+    /// let list = LweList::from_container(vec![0 as u8; 200], LweSize(10));
+    /// list.par_ciphertext_iter().for_each(|ciphertext| {
+    ///     let body = ciphertext.get_body();
+    ///     assert_eq!(body, &LweBody(0));
+    /// });
+    /// assert_eq!(list.par_ciphertext_iter().count(), 20);
+    /// ```
+    #[cfg(feature = "multithread")]
+    pub fn par_ciphertext_iter(
+        &self,
+    ) -> impl IndexedParallelIterator<Item = LweCiphertext<&[<Self as AsRefTensor>::Element]>>
+    where
+        Self: AsRefTensor,
+        <Self as AsRefTensor>::Element: Sync,
+    {
+        ck_dim_div!(self.as_tensor().len() => self.lwe_size.0);
+        self.as_tensor()
+            .par_subtensor_iter(self.lwe_size.0)
+            .map(|sub| LweCiphertext::from_container(sub.into_container()))
+    }
+
+    /// Returns a parallel iterator over ciphers mutably borrowed from the list.
+    ///
+    /// # Notes
+    /// This iterator is hidden behind the "multithread" feature gate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::backends::core::private::crypto::lwe::*;
+    /// use concrete_core::backends::core::private::crypto::*;
+    /// use rayon::iter::ParallelIterator;
+    /// let mut list = LweList::from_container(vec![0 as u8; 200], LweSize(10));
+    /// list.par_ciphertext_iter_mut().for_each(|mut ciphertext| {
+    ///     let body = ciphertext.get_mut_body();
+    ///     *body = LweBody(2);
+    /// });
+    /// for ciphertext in list.ciphertext_iter() {
+    ///     let body = ciphertext.get_body();
+    ///     assert_eq!(body, &LweBody(2));
+    /// }
+    /// ```
+    #[cfg(feature = "multithread")]
+    pub fn par_ciphertext_iter_mut(
+        &mut self,
+    ) -> impl IndexedParallelIterator<Item = LweCiphertext<&mut [<Self as AsMutTensor>::Element]>>
+    where
+        Self: AsMutTensor,
+        <Self as AsMutTensor>::Element: Sync + Send,
+    {
+        ck_dim_div!(self.as_tensor().len() => self.lwe_size.0);
+        let lwe_size = self.lwe_size.0;
+        self.as_mut_tensor()
+            .par_subtensor_iter_mut(lwe_size)
+            .map(|sub| LweCiphertext::from_container(sub.into_container()))
+    }
+
     /// Returns an iterator over sub lists borrowed from the list.
     ///
     /// # Example
@@ -2,7 +2,9 @@
 pub use ciphertext::*;
 pub use keyswitch::*;
 pub use list::*;
+pub use seeded::*;
 
 mod ciphertext;
 mod keyswitch;
 mod list;
+mod seeded;
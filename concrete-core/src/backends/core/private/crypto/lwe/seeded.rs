@@ -0,0 +1,428 @@
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+
+use concrete_commons::dispersion::DispersionParameter;
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweSize,
+};
+
+use crate::backends::core::private::crypto::encoding::Plaintext;
+use crate::backends::core::private::crypto::secret::generators::EncryptionRandomGenerator;
+use crate::backends::core::private::crypto::secret::LweSecretKey;
+use crate::backends::core::private::math::decomposition::{DecompositionLevel, DecompositionTerm};
+use crate::backends::core::private::math::random::RandomGenerator;
+use crate::backends::core::private::math::tensor::{
+    ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefTensor, Tensor,
+};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+
+use super::{LweBody, LweCiphertext, LweKeyswitchKey, LweList, LweMask};
+
+/// An LWE ciphertext whose mask is replaced by the seed of the generator it was sampled from.
+///
+/// The mask of an LWE ciphertext is uniformly random, and is most of its size. A seeded
+/// ciphertext only stores the body and the seed of the csprng used to sample the mask, which is
+/// enough to expand it back to an [`LweCiphertext`].
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweSeededCiphertext<Scalar> {
+    body: Scalar,
+    lwe_dimension: LweDimension,
+    seed: u128,
+}
+
+impl<Scalar: Copy> LweSeededCiphertext<Scalar> {
+    /// Allocates a new seeded ciphertext, whose body is `value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::backends::core::private::crypto::lwe::LweSeededCiphertext;
+    /// let ct = LweSeededCiphertext::allocate(0 as u8, LweDimension(3), 42);
+    /// assert_eq!(ct.lwe_dimension(), LweDimension(3));
+    /// assert_eq!(ct.seed(), 42);
+    /// ```
+    pub fn allocate(value: Scalar, lwe_dimension: LweDimension, seed: u128) -> Self {
+        LweSeededCiphertext {
+            body: value,
+            lwe_dimension,
+            seed,
+        }
+    }
+
+    /// Returns the dimension of the mask represented by the seed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::backends::core::private::crypto::lwe::LweSeededCiphertext;
+    /// let ct = LweSeededCiphertext::allocate(0 as u8, LweDimension(3), 42);
+    /// assert_eq!(ct.lwe_dimension(), LweDimension(3));
+    /// ```
+    pub fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+
+    /// Returns the seed of the generator the mask is sampled from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::backends::core::private::crypto::lwe::LweSeededCiphertext;
+    /// let ct = LweSeededCiphertext::allocate(0 as u8, LweDimension(3), 42);
+    /// assert_eq!(ct.seed(), 42);
+    /// ```
+    pub fn seed(&self) -> u128 {
+        self.seed
+    }
+
+    /// Returns the body of the ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::backends::core::private::crypto::lwe::*;
+    /// let ct = LweSeededCiphertext::allocate(7 as u8, LweDimension(3), 42);
+    /// assert_eq!(ct.get_body(), LweBody(7));
+    /// ```
+    pub fn get_body(&self) -> LweBody<Scalar> {
+        LweBody(self.body)
+    }
+
+    /// Encrypts a plaintext in the seeded ciphertext.
+    ///
+    /// The mask is sampled from a generator seeded with the seed of the ciphertext, while the
+    /// noise is sampled from the noise generator of `generator`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{LweDimension, LweSize};
+    /// use concrete_core::backends::core::private::crypto::encoding::Plaintext;
+    /// use concrete_core::backends::core::private::crypto::lwe::*;
+    /// use concrete_core::backends::core::private::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::LweSecretKey;
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    /// let secret_key = LweSecretKey::generate_binary(LweDimension(256), &mut secret_generator);
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    /// let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    ///
+    /// let mut seeded = LweSeededCiphertext::allocate(0u32, LweDimension(256), 42);
+    /// seeded.fill_with_encryption(
+    ///     &secret_key,
+    ///     &Plaintext(3u32 << 20),
+    ///     noise,
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// let mut ciphertext = LweCiphertext::allocate(0u32, LweSize(257));
+    /// seeded.expand_into(&mut ciphertext);
+    /// let mut decrypted = Plaintext(0u32);
+    /// secret_key.decrypt_lwe(&mut decrypted, &ciphertext);
+    /// let error = decrypted.0.wrapping_sub(3u32 << 20) as i32;
+    /// assert!(error.abs() < 1 << 20);
+    /// ```
+    pub fn fill_with_encryption<KeyCont>(
+        &mut self,
+        key: &LweSecretKey<BinaryKeyKind, KeyCont>,
+        encoded: &Plaintext<Scalar>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator,
+    ) where
+        LweSecretKey<BinaryKeyKind, KeyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(key.key_size() => self.lwe_dimension);
+        let mut mask = LweMask::from_container(vec![Scalar::ZERO; self.lwe_dimension.0]);
+        let mut mask_generator = RandomGenerator::new(Some(self.seed));
+        self.body = encrypt_with_mask(
+            key,
+            &mut mask,
+            encoded,
+            noise_parameters,
+            &mut mask_generator,
+            generator,
+        );
+    }
+
+    /// Expands the seeded ciphertext into a regular LWE ciphertext, by sampling its mask again.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{LweDimension, LweSize};
+    /// use concrete_core::backends::core::private::crypto::lwe::*;
+    /// let seeded = LweSeededCiphertext::allocate(7 as u32, LweDimension(3), 42);
+    /// let mut ciphertext = LweCiphertext::allocate(0 as u32, LweSize(4));
+    /// seeded.expand_into(&mut ciphertext);
+    /// assert_eq!(ciphertext.get_body(), &LweBody(7));
+    /// ```
+    pub fn expand_into<Cont>(&self, output: &mut LweCiphertext<Cont>)
+    where
+        LweCiphertext<Cont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(output.lwe_size().0 => self.lwe_dimension.to_lwe_size().0);
+        let mut mask_generator = RandomGenerator::new(Some(self.seed));
+        let (body, mut mask) = output.get_mut_body_and_mask();
+        mask_generator.fill_tensor_with_random_uniform(&mut mask);
+        body.0 = self.body;
+    }
+}
+
+/// An LWE keyswitch key whose masks are replaced by the seed of the generator they were sampled
+/// from.
+///
+/// The key only stores the body of each ciphertext of an [`LweKeyswitchKey`], in the same order,
+/// and the seed of the csprng used to sample all the masks one after the other.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweSeededKeyswitchKey<Cont> {
+    tensor: Tensor<Cont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    output_lwe_dimension: LweDimension,
+    seed: u128,
+}
+
+tensor_traits!(LweSeededKeyswitchKey);
+
+impl<Scalar> LweSeededKeyswitchKey<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates a seeded keyswitching key whose bodies are all `value`.
+    ///
+    /// # Note
+    ///
+    /// This function does *not* generate a keyswitch key, but merely allocates a container of the
+    /// right size. See [`LweSeededKeyswitchKey::fill_with_seeded_keyswitch_key`] to fill the
+    /// container with a proper keyswitching key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::lwe::LweSeededKeyswitchKey;
+    /// let ksk = LweSeededKeyswitchKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     LweDimension(10),
+    ///     LweDimension(20),
+    ///     42,
+    /// );
+    /// assert_eq!(
+    ///     ksk.decomposition_levels_count(),
+    ///     DecompositionLevelCount(10)
+    /// );
+    /// assert_eq!(ksk.decomposition_base_log(), DecompositionBaseLog(16));
+    /// assert_eq!(ksk.before_key_size(), LweDimension(10));
+    /// assert_eq!(ksk.after_key_size(), LweDimension(20));
+    /// assert_eq!(ksk.seed(), 42);
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        decomp_size: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        input_size: LweDimension,
+        output_size: LweDimension,
+        seed: u128,
+    ) -> Self {
+        LweSeededKeyswitchKey {
+            tensor: Tensor::from_container(vec![value; decomp_size.0 * input_size.0]),
+            decomp_base_log,
+            decomp_level_count: decomp_size,
+            output_lwe_dimension: output_size,
+            seed,
+        }
+    }
+}
+
+impl<Cont> LweSeededKeyswitchKey<Cont> {
+    /// Returns the size of the output key.
+    pub fn after_key_size(&self) -> LweDimension {
+        self.output_lwe_dimension
+    }
+
+    /// Returns the size of the input key.
+    pub fn before_key_size(&self) -> LweDimension
+    where
+        Self: AsRefTensor,
+    {
+        LweDimension(self.as_tensor().len() / self.decomp_level_count.0)
+    }
+
+    /// Returns the number of levels used for the decomposition of the input key bits.
+    pub fn decomposition_levels_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Returns the logarithm of the base used for the decomposition of the input key bits.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns the seed of the generator the masks are sampled from.
+    pub fn seed(&self) -> u128 {
+        self.seed
+    }
+
+    /// Fills the current seeded keyswitch key container with an actual keyswitching key
+    /// constructed from an input and an output key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::lwe::*;
+    /// use concrete_core::backends::core::private::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::LweSecretKey;
+    ///
+    /// let input_size = LweDimension(10);
+    /// let output_size = LweDimension(20);
+    /// let decomp_log_base = DecompositionBaseLog(3);
+    /// let decomp_level_count = DecompositionLevelCount(5);
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    /// let input_key = LweSecretKey::generate_binary(input_size, &mut secret_generator);
+    /// let output_key = LweSecretKey::generate_binary(output_size, &mut secret_generator);
+    /// let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    ///
+    /// let mut seeded_ksk = LweSeededKeyswitchKey::allocate(
+    ///     0 as u32,
+    ///     decomp_level_count,
+    ///     decomp_log_base,
+    ///     input_size,
+    ///     output_size,
+    ///     42,
+    /// );
+    /// seeded_ksk.fill_with_seeded_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     noise,
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// let mut ksk = LweKeyswitchKey::allocate(
+    ///     0 as u32,
+    ///     decomp_level_count,
+    ///     decomp_log_base,
+    ///     input_size,
+    ///     output_size,
+    /// );
+    /// seeded_ksk.expand_into(&mut ksk);
+    /// assert_eq!(ksk.before_key_size(), input_size);
+    /// ```
+    pub fn fill_with_seeded_keyswitch_key<InKeyCont, OutKeyCont, Scalar>(
+        &mut self,
+        before_key: &LweSecretKey<BinaryKeyKind, InKeyCont>,
+        after_key: &LweSecretKey<BinaryKeyKind, OutKeyCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweSecretKey<BinaryKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        LweSecretKey<BinaryKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.before_key_size() => before_key.key_size());
+        ck_dim_eq!(self.output_lwe_dimension => after_key.key_size());
+
+        // We retrieve decomposition arguments
+        let decomp_level_count = self.decomp_level_count;
+        let decomp_base_log = self.decomp_base_log;
+
+        // The masks of all the ciphertexts are sampled one after the other from the same
+        // generator, in the order of the bodies.
+        let mut mask_generator = RandomGenerator::new(Some(self.seed));
+        let mut mask = LweMask::from_container(vec![Scalar::ZERO; self.output_lwe_dimension.0]);
+
+        // loop over the before key blocks
+        for (input_key_bit, block) in before_key.as_tensor().iter().zip(
+            self.as_mut_tensor()
+                .subtensor_iter_mut(decomp_level_count.0),
+        ) {
+            // We encrypt the powers of the key bit
+            for (level, body) in (1..=decomp_level_count.0)
+                .map(DecompositionLevel)
+                .zip(block.into_container().iter_mut())
+            {
+                let message = Plaintext(
+                    DecompositionTerm::new(level, decomp_base_log, *input_key_bit)
+                        .to_recomposition_summand(),
+                );
+                *body = encrypt_with_mask(
+                    after_key,
+                    &mut mask,
+                    &message,
+                    noise_parameters,
+                    &mut mask_generator,
+                    generator,
+                );
+            }
+        }
+    }
+
+    /// Expands the seeded keyswitch key into a regular keyswitch key, by sampling its masks again.
+    pub fn expand_into<OutCont, Scalar>(&self, output: &mut LweKeyswitchKey<OutCont>)
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        LweKeyswitchKey<OutCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(output.lwe_size() => self.output_lwe_dimension.to_lwe_size());
+        ck_dim_eq!(output.decomposition_levels_count() => self.decomp_level_count);
+        let lwe_size = LweSize(self.output_lwe_dimension.0 + 1);
+        let mut mask_generator = RandomGenerator::new(Some(self.seed));
+        let mut ciphertexts =
+            LweList::from_container(output.as_mut_tensor().as_mut_slice(), lwe_size);
+        for (body, mut ciphertext) in self
+            .as_tensor()
+            .iter()
+            .zip(ciphertexts.ciphertext_iter_mut())
+        {
+            let (output_body, mut output_mask) = ciphertext.get_mut_body_and_mask();
+            mask_generator.fill_tensor_with_random_uniform(&mut output_mask);
+            output_body.0 = *body;
+        }
+    }
+}
+
+// Encrypts a plaintext with a mask sampled from `mask_generator`, and returns the body of the
+// ciphertext. The noise is sampled from the noise generator of `generator`.
+fn encrypt_with_mask<KeyCont, Scalar>(
+    key: &LweSecretKey<BinaryKeyKind, KeyCont>,
+    mask: &mut LweMask<Vec<Scalar>>,
+    encoded: &Plaintext<Scalar>,
+    noise_parameters: impl DispersionParameter,
+    mask_generator: &mut RandomGenerator,
+    generator: &mut EncryptionRandomGenerator,
+) -> Scalar
+where
+    LweSecretKey<BinaryKeyKind, KeyCont>: AsRefTensor<Element = Scalar>,
+    Scalar: UnsignedTorus,
+{
+    mask_generator.fill_tensor_with_random_uniform(mask);
+    let noise: Scalar = generator.random_noise(noise_parameters);
+    noise
+        .wrapping_add(mask.compute_multisum(key))
+        .wrapping_add(encoded.0)
+}
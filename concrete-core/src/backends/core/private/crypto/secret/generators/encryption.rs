@@ -5,6 +5,8 @@ use crate::backends::core::private::math::tensor::AsMutTensor;
 
 use concrete_commons::dispersion::DispersionParameter;
 use concrete_commons::numeric::UnsignedInteger;
+#[cfg(feature = "multithread")]
+use concrete_commons::parameters::CiphertextCount;
 use concrete_commons::parameters::{
     DecompositionLevelCount, GlweDimension, GlweSize, LweDimension, LweSize, PolynomialSize,
 };
@@ -28,6 +30,17 @@ impl EncryptionRandomGenerator {
         }
     }
 
+    /// Creates a new encryption generator, seeding both the mask and the noise generators.
+    ///
+    /// Since the noise generator is seeded as well, the produced encryptions are entirely
+    /// reproducible. This should only be used to replay computations, e.g. in tests.
+    pub fn new_deterministic(mask_seed: u128, noise_seed: u128) -> EncryptionRandomGenerator {
+        EncryptionRandomGenerator {
+            mask: RandomGenerator::new(Some(mask_seed)),
+            noise: RandomGenerator::new(Some(noise_seed)),
+        }
+    }
+
     // Allows to seed the noise generator. For testing purpose only.
     #[allow(dead_code)]
     pub(crate) fn seed_noise_generator(&mut self, seed: u128) {
@@ -165,6 +178,19 @@ impl EncryptionRandomGenerator {
         self.par_try_fork(lwe_size.0, mask_bytes, noise_bytes)
     }
 
+    // Forks the generator into a parallel iterator, when splitting a lwe list into lwe
+    // ciphertexts.
+    #[cfg(feature = "multithread")]
+    pub(crate) fn par_fork_lwe_list_to_lwe<T: UnsignedInteger>(
+        &mut self,
+        lwe_count: CiphertextCount,
+        lwe_size: LweSize,
+    ) -> Option<impl IndexedParallelIterator<Item = EncryptionRandomGenerator>> {
+        let mask_bytes = mask_bytes_per_lwe::<T>(lwe_size.to_lwe_dimension());
+        let noise_bytes = noise_bytes_per_lwe();
+        self.par_try_fork(lwe_count.0, mask_bytes, noise_bytes)
+    }
+
     // Forks both generators into an iterator
     fn try_fork(
         &mut self,
@@ -215,6 +241,11 @@ impl EncryptionRandomGenerator {
         self.mask.fill_tensor_with_random_uniform(output)
     }
 
+    // Samples the seed of a generator of masks, using the mask generator.
+    pub(crate) fn random_mask_seed(&mut self) -> u128 {
+        self.mask.random_uniform()
+    }
+
     // Sample a noise value, using the noise generator.
     pub(crate) fn random_noise<Scalar>(&mut self, std: impl DispersionParameter) -> Scalar
     where
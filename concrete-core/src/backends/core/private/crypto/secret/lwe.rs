@@ -414,6 +414,82 @@ where
         }
     }
 
+    /// Encrypts a list of ciphertexts, using as many threads as possible.
+    ///
+    /// # Notes
+    /// This method is hidden behind the "multithread" feature gate.
+    ///
+    /// The generator is forked into one generator per ciphertext, which makes the result
+    /// independent of the way the work is split between threads.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{CiphertextCount, LweDimension, LweSize, PlaintextCount};
+    /// use concrete_core::backends::core::private::crypto::encoding::*;
+    /// use concrete_core::backends::core::private::crypto::lwe::*;
+    /// use concrete_core::backends::core::private::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::*;
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    /// let secret_key = LweSecretKey::generate_binary(LweDimension(256), &mut secret_generator);
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    ///
+    /// let plain_values = PlaintextList::allocate(3u32 << 20, PlaintextCount(100));
+    /// let mut encrypted_values = LweList::allocate(0u32, LweSize(257), CiphertextCount(100));
+    /// let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    /// secret_key.par_encrypt_lwe_list(
+    ///     &mut encrypted_values,
+    ///     &plain_values,
+    ///     noise,
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// let mut decrypted_values = PlaintextList::allocate(0u32, PlaintextCount(100));
+    /// secret_key.decrypt_lwe_list(&mut decrypted_values, &encrypted_values);
+    /// for decrypted in decrypted_values.plaintext_iter() {
+    ///     let error = decrypted.0.wrapping_sub(3u32 << 20) as i32;
+    ///     assert!(error.abs() < 1 << 20);
+    /// }
+    /// ```
+    #[cfg(feature = "multithread")]
+    pub fn par_encrypt_lwe_list<OutputCont, InputCont, Scalar>(
+        &self,
+        output: &mut LweList<OutputCont>,
+        encoded: &PlaintextList<InputCont>,
+        noise_parameters: impl DispersionParameter + Send + Sync,
+        generator: &mut EncryptionRandomGenerator,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LweList<OutputCont>: AsMutTensor<Element = Scalar>,
+        PlaintextList<InputCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus + Send + Sync,
+        Cont: Sync,
+    {
+        debug_assert!(
+            output.count().0 == encoded.count().0,
+            "Lwe cipher list size and encoded list size are not compatible"
+        );
+        let generators = generator
+            .par_fork_lwe_list_to_lwe::<Scalar>(output.count(), output.lwe_size())
+            .expect("Failed to split generator into lwe");
+        output
+            .par_ciphertext_iter_mut()
+            .zip(encoded.as_tensor().par_iter())
+            .zip(generators)
+            .for_each(|((mut cipher, message), mut generator)| {
+                self.encrypt_lwe(
+                    &mut cipher,
+                    &Plaintext(*message),
+                    noise_parameters,
+                    &mut generator,
+                );
+            });
+    }
+
     /// Decrypts a single ciphertext.
     ///
     /// See ['encrypt_lwe'] for an example.
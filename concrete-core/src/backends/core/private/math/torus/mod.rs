@@ -10,7 +10,8 @@
 //! Still, in some cases, we may need to represent an unsigned integer as a torus value in
 //! floating point representation. For this reason we provide the [`IntoTorus`] and [`FromTorus`]
 //! traits which allow to go back and forth between an unsigned integer representation and a
-//! floating point representation.
+//! floating point representation. The [`CastTorus`] trait allows to move torus elements between
+//! unsigned integer representations of different precisions.
 
 use crate::backends::core::private::math::random::{
     Gaussian, RandomGenerable, Uniform, UniformBinary, UniformTernary,
@@ -78,6 +79,45 @@ implement!(u32);
 implement!(u64);
 implement!(u128);
 
+/// A trait that converts a torus element in unsigned integer representation to the closest torus
+/// element in another unsigned integer representation.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::backends::core::private::math::torus::CastTorus;
+/// let a: u64 = 3_u32.cast_torus();
+/// assert_eq!(a, 3 << 32);
+/// let b: u32 = ((3_u64 << 32) + (1 << 31)).cast_torus();
+/// assert_eq!(b, 4);
+/// ```
+pub trait CastTorus<Output>: UnsignedInteger
+where
+    Output: UnsignedInteger,
+{
+    /// Consumes `self` and returns the closest torus element in the `Output` representation.
+    fn cast_torus(self) -> Output;
+}
+
+macro_rules! implement_cast {
+    ($Small: tt, $Large: tt) => {
+        impl CastTorus<$Large> for $Small {
+            fn cast_torus(self) -> $Large {
+                ($Large::from(self)) << (<$Large as Numeric>::BITS - <$Small as Numeric>::BITS)
+            }
+        }
+        impl CastTorus<$Small> for $Large {
+            fn cast_torus(self) -> $Small {
+                let shift = <$Large as Numeric>::BITS - <$Small as Numeric>::BITS;
+                // The wrapping of the rounding carry is the expected torus behavior.
+                (self.wrapping_add(1 << (shift - 1)) >> shift) as $Small
+            }
+        }
+    };
+}
+
+implement_cast!(u32, u64);
+
 /// A marker trait for unsigned integer types that can be used in ciphertexts, keys etc.
 pub trait UnsignedTorus:
     UnsignedInteger
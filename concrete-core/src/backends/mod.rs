@@ -3,7 +3,8 @@
 //! This module contains all the backends implementing the concrete specification. As of now we
 //! support the following backend:
 //!
-//! + `core` : A single threaded CPU backend geared towards x86_64 architectures.
+//! + `core` : A CPU backend geared towards x86_64 architectures, with an optional multithreaded
+//! engine.
 
 #[cfg(feature = "backend_core")]
 pub mod core;
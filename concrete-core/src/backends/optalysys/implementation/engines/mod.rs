@@ -2,6 +2,7 @@ use std::collections::BTreeMap;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 
+use crate::backends::core::implementation::engines::derive_engine_seeds;
 use crate::backends::core::private::crypto::secret::generators::{
     EncryptionRandomGenerator as ImplEncryptionRandomGenerator,
     SecretRandomGenerator as ImplSecretRandomGenerator,
@@ -40,6 +41,28 @@ pub struct OptalysysEngine {
 }
 
 impl OptalysysEngine {
+    /// Creates a new engine whose random generators are all derived from the given seed.
+    ///
+    /// The generators are seeded as the ones of a
+    /// [`CoreEngine`](crate::backends::core::engines::CoreEngine) created with the same seed.
+    ///
+    /// # Note:
+    ///
+    /// The noise of the encryptions is seeded as well, which is not secure. This constructor is
+    /// meant for testing and debugging purposes only, and [`AbstractEngine::new`] should be used
+    /// otherwise.
+    pub fn new_with_seed(seed: u128) -> Result<Self, OptalysysError> {
+        let (secret_seed, mask_seed, noise_seed) = derive_engine_seeds(seed);
+        Ok(OptalysysEngine {
+            secret_generator: ImplSecretRandomGenerator::new(Some(secret_seed)),
+            encryption_generator: ImplEncryptionRandomGenerator::new_deterministic(
+                mask_seed, noise_seed,
+            ),
+            fourier_bsk_buffers_u32: Default::default(),
+            fourier_bsk_buffers_u64: Default::default(),
+        })
+    }
+
     pub(crate) fn get_fourier_bootstrap_u32_buffer(
         &mut self,
         fourier_bsk: &OptalysysFourierLweBootstrapKey32,
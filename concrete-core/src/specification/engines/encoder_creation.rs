@@ -0,0 +1,35 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::EncoderEntity;
+
+engine_error! {
+    EncoderCreationError for EncoderCreationEngine @
+}
+
+/// A trait for engines creating encoders from configurations.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an encoder from the `config`
+/// configuration. By configuration here, we mean that `Config` can be any type that suits the
+/// backend implementor (a struct wrapping the bounds of an interval and a precision for instance).
+///
+/// # Formal Definition
+pub trait EncoderCreationEngine<Config, Encoder>: AbstractEngine
+where
+    Encoder: EncoderEntity,
+{
+    /// Creates an encoder from a config.
+    fn create_encoder(
+        &mut self,
+        config: &Config,
+    ) -> Result<Encoder, EncoderCreationError<Self::EngineError>>;
+
+    /// Unsafely creates an encoder from a config.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`EncoderCreationError`]. For safety concerns _specific_ to an engine, refer to the
+    /// implementer safety section.
+    unsafe fn create_encoder_unchecked(&mut self, config: &Config) -> Encoder;
+}
@@ -0,0 +1,42 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{CleartextEntity, GlweCiphertextEntity};
+
+engine_error! {
+    GlweCiphertextCleartextFusingMultiplicationError for
+    GlweCiphertextCleartextFusingMultiplicationEngine @
+}
+
+/// A trait for engines multiplying (fusing) GLWE ciphertexts by cleartexts.
+///
+/// # Semantics
+///
+/// This [fusing](super#operation-semantics) operation multiply the `output` GLWE ciphertext with
+/// the `input` cleartext.
+///
+/// # Formal Definition
+pub trait GlweCiphertextCleartextFusingMultiplicationEngine<Ciphertext, Cleartext>:
+    AbstractEngine
+where
+    Cleartext: CleartextEntity,
+    Ciphertext: GlweCiphertextEntity,
+{
+    /// Multiply a GLWE ciphertext with a cleartext.
+    fn fuse_mul_glwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut Ciphertext,
+        input: &Cleartext,
+    ) -> Result<(), GlweCiphertextCleartextFusingMultiplicationError<Self::EngineError>>;
+
+    /// Unsafely multiply a GLWE ciphertext with a cleartext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextCleartextFusingMultiplicationError`]. For safety concerns _specific_ to
+    /// an engine, refer to the implementer safety section.
+    unsafe fn fuse_mul_glwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut Ciphertext,
+        input: &Cleartext,
+    );
+}
@@ -0,0 +1,98 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweCiphertextEntity, GlweRelinearizationKeyEntity};
+use concrete_commons::parameters::DeltaLog;
+
+engine_error! {
+    GlweCiphertextDiscardingTensorProductError for GlweCiphertextDiscardingTensorProductEngine @
+    GlweDimensionMismatch => "The input, output and key GLWE dimensions must be the same.",
+    PolynomialSizeMismatch => "The input, output and key polynomial sizes must be the same.",
+    DeltaLogTooLarge => "The delta log must be smaller than the precision of the ciphertext."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextDiscardingTensorProductError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCiphertext1, InputCiphertext2, RelinearizationKey, Output>(
+        output: &Output,
+        input_1: &InputCiphertext1,
+        input_2: &InputCiphertext2,
+        relinearization_key: &RelinearizationKey,
+        delta_log: DeltaLog,
+        integer_precision: usize,
+    ) -> Result<(), Self>
+    where
+        InputCiphertext1: GlweCiphertextEntity,
+        InputCiphertext2: GlweCiphertextEntity,
+        RelinearizationKey: GlweRelinearizationKeyEntity,
+        Output: GlweCiphertextEntity,
+    {
+        if output.glwe_dimension() != input_1.glwe_dimension()
+            || output.glwe_dimension() != input_2.glwe_dimension()
+            || output.glwe_dimension() != relinearization_key.glwe_dimension()
+        {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if output.polynomial_size() != input_1.polynomial_size()
+            || output.polynomial_size() != input_2.polynomial_size()
+            || output.polynomial_size() != relinearization_key.polynomial_size()
+        {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        if delta_log.0 >= integer_precision {
+            return Err(Self::DeltaLogTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines multiplying (discarding) GLWE ciphertexts with a tensor product.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext with
+/// the tensor product of the `input_1` and `input_2` GLWE ciphertexts, relinearized with the
+/// `relinearization_key` GLWE relinearization key.
+///
+/// Both inputs are expected to encrypt messages scaled by a multiple of $\Delta = 2^D$, where $D$
+/// is the `delta_log` parameter. The product of the two scaled messages is divided by $\Delta$
+/// before relinearization, such that encrypting $\Delta \cdot m_1$ and $\Delta \cdot m_2$ results
+/// in an encryption of $\Delta \cdot m_1 \cdot m_2$.
+///
+/// # Formal Definition
+pub trait GlweCiphertextDiscardingTensorProductEngine<
+    InputCiphertext1,
+    InputCiphertext2,
+    RelinearizationKey,
+    OutputCiphertext,
+>: AbstractEngine where
+    InputCiphertext1: GlweCiphertextEntity,
+    InputCiphertext2: GlweCiphertextEntity<KeyDistribution = InputCiphertext1::KeyDistribution>,
+    RelinearizationKey:
+        GlweRelinearizationKeyEntity<KeyDistribution = InputCiphertext1::KeyDistribution>,
+    OutputCiphertext: GlweCiphertextEntity<KeyDistribution = InputCiphertext1::KeyDistribution>,
+{
+    /// Computes the relinearized tensor product of two GLWE ciphertexts.
+    fn discard_tensor_product_glwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext1,
+        input_2: &InputCiphertext2,
+        relinearization_key: &RelinearizationKey,
+        delta_log: DeltaLog,
+    ) -> Result<(), GlweCiphertextDiscardingTensorProductError<Self::EngineError>>;
+
+    /// Unsafely computes the relinearized tensor product of two GLWE ciphertexts.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextDiscardingTensorProductError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_tensor_product_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext1,
+        input_2: &InputCiphertext2,
+        relinearization_key: &RelinearizationKey,
+        delta_log: DeltaLog,
+    );
+}
@@ -0,0 +1,83 @@
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GgswCiphertextEntity, GlweCiphertextEntity};
+
+use super::engine_error;
+
+engine_error! {
+    GlweCiphertextGgswCiphertextDiscardingCmuxError for
+    GlweCiphertextGgswCiphertextDiscardingCmuxEngine @
+    PolynomialSizeMismatch => "All the GGSW and GLWE ciphertexts polynomial sizes must be the same.",
+    GlweDimensionMismatch => "All the GGSW and GLWE ciphertexts GLWE dimension must be the same."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextGgswCiphertextDiscardingCmuxError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<GlweInput, GgswInput, GlweOutput>(
+        output: &GlweOutput,
+        glwe_input_0: &GlweInput,
+        glwe_input_1: &GlweInput,
+        ggsw_input: &GgswInput,
+    ) -> Result<(), Self>
+    where
+        GlweInput: GlweCiphertextEntity,
+        GgswInput: GgswCiphertextEntity,
+        GlweOutput: GlweCiphertextEntity,
+    {
+        if output.polynomial_size().0 != glwe_input_0.polynomial_size().0
+            || output.polynomial_size().0 != glwe_input_1.polynomial_size().0
+            || output.polynomial_size().0 != ggsw_input.polynomial_size().0
+        {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        if output.glwe_dimension().0 != glwe_input_0.glwe_dimension().0
+            || output.glwe_dimension().0 != glwe_input_1.glwe_dimension().0
+            || output.glwe_dimension().0 != ggsw_input.glwe_dimension().0
+        {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines computing the controlled multiplexer (CMUX) of two GLWE ciphertexts, under
+/// the control of a GGSW ciphertext.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext with
+/// the result of the CMUX between the `glwe_input_0` and `glwe_input_1` GLWE ciphertexts, using
+/// the `ggsw_input` GGSW ciphertext as the selector. If the GGSW ciphertext encrypts a zero,
+/// `output` encrypts the plaintexts of `glwe_input_0`, and if it encrypts a one, `output`
+/// encrypts the plaintexts of `glwe_input_1`.
+///
+/// # Formal Definition
+pub trait GlweCiphertextGgswCiphertextDiscardingCmuxEngine<GlweInput, GgswInput, GlweOutput>:
+    AbstractEngine
+where
+    GlweInput: GlweCiphertextEntity,
+    GgswInput: GgswCiphertextEntity<KeyDistribution = GlweInput::KeyDistribution>,
+    GlweOutput: GlweCiphertextEntity<KeyDistribution = GlweInput::KeyDistribution>,
+{
+    /// Computes the CMUX of two GLWE ciphertexts.
+    fn discard_cmux_glwe_ciphertext_ggsw_ciphertext(
+        &mut self,
+        output: &mut GlweOutput,
+        glwe_input_0: &GlweInput,
+        glwe_input_1: &GlweInput,
+        ggsw_input: &GgswInput,
+    ) -> Result<(), GlweCiphertextGgswCiphertextDiscardingCmuxError<Self::EngineError>>;
+
+    /// Unsafely computes the CMUX of two GLWE ciphertexts.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextGgswCiphertextDiscardingCmuxError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_cmux_glwe_ciphertext_ggsw_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweOutput,
+        glwe_input_0: &GlweInput,
+        glwe_input_1: &GlweInput,
+        ggsw_input: &GgswInput,
+    );
+}
@@ -0,0 +1,62 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweCiphertextEntity, PlaintextVectorEntity};
+
+engine_error! {
+    GlweCiphertextPlaintextVectorFusingAdditionError for
+    GlweCiphertextPlaintextVectorFusingAdditionEngine @
+    PlaintextCountMismatch => "The plaintext count of the input vector and the output polynomial \
+                               size must be the same."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextPlaintextVectorFusingAdditionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<Ciphertext, PlaintextVector>(
+        output: &Ciphertext,
+        input: &PlaintextVector,
+    ) -> Result<(), Self>
+    where
+        Ciphertext: GlweCiphertextEntity,
+        PlaintextVector: PlaintextVectorEntity,
+    {
+        if output.polynomial_size().0 != input.plaintext_count().0 {
+            return Err(Self::PlaintextCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines adding (fusing) plaintext vectors to GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [fusing](super#operation-semantics) operation adds the `input` plaintext vector to the
+/// `output` GLWE ciphertext, the i-th plaintext being added to the i-th coefficient of the body
+/// polynomial.
+///
+/// # Formal Definition
+pub trait GlweCiphertextPlaintextVectorFusingAdditionEngine<Ciphertext, PlaintextVector>:
+    AbstractEngine
+where
+    PlaintextVector: PlaintextVectorEntity,
+    Ciphertext: GlweCiphertextEntity,
+{
+    /// Adds a plaintext vector to a GLWE ciphertext.
+    fn fuse_add_glwe_ciphertext_plaintext_vector(
+        &mut self,
+        output: &mut Ciphertext,
+        input: &PlaintextVector,
+    ) -> Result<(), GlweCiphertextPlaintextVectorFusingAdditionError<Self::EngineError>>;
+
+    /// Unsafely adds a plaintext vector to a GLWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextPlaintextVectorFusingAdditionError`]. For safety concerns _specific_ to
+    /// an engine, refer to the implementer safety section.
+    unsafe fn fuse_add_glwe_ciphertext_plaintext_vector_unchecked(
+        &mut self,
+        output: &mut Ciphertext,
+        input: &PlaintextVector,
+    );
+}
@@ -0,0 +1,74 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GswCiphertextEntity, LweSecretKeyEntity, PlaintextEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+engine_error! {
+    GswCiphertextScalarEncryptionError for GswCiphertextScalarEncryptionEngine @
+    NullDecompositionBaseLog => "The ciphertext decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The ciphertext decomposition level count must be greater than \
+                                    zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must not exceed \
+                              the precision of the ciphertext."
+}
+
+impl<EngineError: std::error::Error> GswCiphertextScalarEncryptionError<EngineError> {
+    pub fn perform_generic_checks(
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        integer_precision: usize,
+    ) -> Result<(), Self> {
+        if decomposition_base_log.0 == 0 {
+            return Err(Self::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(Self::NullDecompositionLevelCount);
+        }
+        if decomposition_level_count.0 * decomposition_base_log.0 > integer_precision {
+            return Err(Self::DecompositionTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines encrypting GSW ciphertexts containing a single plaintext.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GSW ciphertext containing the
+/// encryption of the `input` plaintext, under the `key` secret key.
+///
+/// # Formal Definition
+pub trait GswCiphertextScalarEncryptionEngine<SecretKey, Plaintext, Ciphertext>:
+    AbstractEngine
+where
+    SecretKey: LweSecretKeyEntity,
+    Plaintext: PlaintextEntity,
+    Ciphertext: GswCiphertextEntity<KeyDistribution = SecretKey::KeyDistribution>,
+{
+    /// Encrypts a plaintext into a GSW ciphertext.
+    fn encrypt_scalar_gsw_ciphertext(
+        &mut self,
+        key: &SecretKey,
+        input: &Plaintext,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<Ciphertext, GswCiphertextScalarEncryptionError<Self::EngineError>>;
+
+    /// Unsafely encrypts a plaintext into a GSW ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GswCiphertextScalarEncryptionError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn encrypt_scalar_gsw_ciphertext_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &Plaintext,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Ciphertext;
+}
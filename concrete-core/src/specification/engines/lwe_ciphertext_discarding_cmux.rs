@@ -0,0 +1,73 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GswCiphertextEntity, LweCiphertextEntity};
+
+engine_error! {
+    LweCiphertextDiscardingCmuxError for LweCiphertextDiscardingCmuxEngine @
+    LweDimensionMismatch => "All the LWE and GSW ciphertexts LWE dimensions must be the same."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextDiscardingCmuxError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<LweInput, GswInput, LweOutput>(
+        output: &LweOutput,
+        input_0: &LweInput,
+        input_1: &LweInput,
+        gsw_input: &GswInput,
+    ) -> Result<(), Self>
+    where
+        LweInput: LweCiphertextEntity,
+        GswInput: GswCiphertextEntity,
+        LweOutput: LweCiphertextEntity,
+    {
+        if output.lwe_dimension().0 != input_0.lwe_dimension().0
+            || output.lwe_dimension().0 != input_1.lwe_dimension().0
+            || output.lwe_dimension().0 != gsw_input.lwe_dimension().0
+        {
+            return Err(Self::LweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines computing the controlled multiplexer (CMUX) of two LWE ciphertexts, under
+/// the control of a GSW ciphertext.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext with
+/// the result of the CMUX between the `input_0` and `input_1` LWE ciphertexts, using the
+/// `gsw_input` GSW ciphertext as the selector. If the GSW ciphertext encrypts a zero, `output`
+/// encrypts the plaintext of `input_0`, and if it encrypts a one, `output` encrypts the plaintext
+/// of `input_1`.
+///
+/// # Formal Definition
+pub trait LweCiphertextDiscardingCmuxEngine<LweInput, GswInput, LweOutput>: AbstractEngine
+where
+    LweInput: LweCiphertextEntity,
+    GswInput: GswCiphertextEntity<KeyDistribution = LweInput::KeyDistribution>,
+    LweOutput: LweCiphertextEntity<KeyDistribution = LweInput::KeyDistribution>,
+{
+    /// Computes the CMUX of two LWE ciphertexts.
+    fn discard_cmux_lwe_ciphertext(
+        &mut self,
+        output: &mut LweOutput,
+        input_0: &LweInput,
+        input_1: &LweInput,
+        gsw_input: &GswInput,
+    ) -> Result<(), LweCiphertextDiscardingCmuxError<Self::EngineError>>;
+
+    /// Unsafely computes the CMUX of two LWE ciphertexts.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDiscardingCmuxError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn discard_cmux_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweOutput,
+        input_0: &LweInput,
+        input_1: &LweInput,
+        gsw_input: &GswInput,
+    );
+}
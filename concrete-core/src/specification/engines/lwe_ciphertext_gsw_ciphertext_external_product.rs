@@ -0,0 +1,64 @@
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GswCiphertextEntity, LweCiphertextEntity};
+
+use super::engine_error;
+
+engine_error! {
+    LweCiphertextGswCiphertextExternalProductError for
+    LweCiphertextGswCiphertextExternalProductEngine @
+    LweDimensionMismatch => "The GSW ciphertext and LWE ciphertext LWE dimension must be the same."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextGswCiphertextExternalProductError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<LweCiphertext, GswCiphertext>(
+        lwe_input: &LweCiphertext,
+        gsw_input: &GswCiphertext,
+    ) -> Result<(), Self>
+    where
+        LweCiphertext: LweCiphertextEntity,
+        GswCiphertext: GswCiphertextEntity,
+    {
+        if lwe_input.lwe_dimension().0 != gsw_input.lwe_dimension().0 {
+            return Err(Self::LweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines computing the external product between an LWE ciphertext and a GSW
+/// ciphertext.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an LWE ciphertext containing the
+/// result of the external product between a `lwe_input` LWE ciphertext and a `gsw_input` GSW
+/// ciphertext.
+///
+/// # Formal Definition
+pub trait LweCiphertextGswCiphertextExternalProductEngine<LweInput, GswInput, Output>:
+    AbstractEngine
+where
+    LweInput: LweCiphertextEntity,
+    GswInput: GswCiphertextEntity<KeyDistribution = LweInput::KeyDistribution>,
+    Output: LweCiphertextEntity<KeyDistribution = LweInput::KeyDistribution>,
+{
+    /// Computes the external product between an LWE and a GSW ciphertext.
+    fn compute_external_product_lwe_ciphertext_gsw_ciphertext(
+        &mut self,
+        lwe_input: &LweInput,
+        gsw_input: &GswInput,
+    ) -> Result<Output, LweCiphertextGswCiphertextExternalProductError<Self::EngineError>>;
+
+    /// Unsafely computes the external product between an LWE and a GSW ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextGswCiphertextExternalProductError`]. For safety concerns _specific_ to
+    /// an engine, refer to the implementer safety section.
+    unsafe fn compute_external_product_lwe_ciphertext_gsw_ciphertext_unchecked(
+        &mut self,
+        lwe_input: &LweInput,
+        gsw_input: &GswInput,
+    ) -> Output;
+}
@@ -0,0 +1,73 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweCiphertextEntity, LweCiphertextVectorEntity};
+use concrete_commons::parameters::LweDimension;
+
+engine_error! {
+    LweCiphertextVectorDiscardingExtractionError for LweCiphertextVectorDiscardingExtractionEngine @
+    SizeMismatch => "The sizes of the output LWE (LWE dimension) and the input GLWE (GLWE \
+                     dimension * poly size) must be compatible.",
+    CiphertextCountMismatch => "The output vector LWE ciphertext count and the input GLWE \
+                                polynomial size must be the same."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextVectorDiscardingExtractionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<GlweCiphertext, LweCiphertextVector>(
+        output: &LweCiphertextVector,
+        input: &GlweCiphertext,
+    ) -> Result<(), Self>
+    where
+        GlweCiphertext: GlweCiphertextEntity,
+        LweCiphertextVector:
+            LweCiphertextVectorEntity<KeyDistribution = GlweCiphertext::KeyDistribution>,
+    {
+        if output.lwe_dimension()
+            != LweDimension(input.polynomial_size().0 * input.glwe_dimension().0)
+        {
+            return Err(Self::SizeMismatch);
+        }
+        if output.lwe_ciphertext_count().0 != input.polynomial_size().0 {
+            return Err(Self::CiphertextCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines extracting (discarding) LWE ciphertext vectors from GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext vector
+/// with the extraction of every coefficient of the `input` GLWE ciphertext: the `n`-th LWE
+/// ciphertext of `output` is the extraction of the `n`-th coefficient of `input`.
+///
+/// # Formal definition
+///
+/// This operation is usually referred to as a _sample extract_ in the literature.
+pub trait LweCiphertextVectorDiscardingExtractionEngine<GlweCiphertext, LweCiphertextVector>:
+    AbstractEngine
+where
+    GlweCiphertext: GlweCiphertextEntity,
+    LweCiphertextVector:
+        LweCiphertextVectorEntity<KeyDistribution = GlweCiphertext::KeyDistribution>,
+{
+    /// Extracts an LWE ciphertext vector from a GLWE ciphertext.
+    fn discard_extract_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector,
+        input: &GlweCiphertext,
+    ) -> Result<(), LweCiphertextVectorDiscardingExtractionError<Self::EngineError>>;
+
+    /// Unsafely extracts an LWE ciphertext vector from a GLWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorDiscardingExtractionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_extract_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector,
+        input: &GlweCiphertext,
+    );
+}
@@ -0,0 +1,49 @@
+use super::engine_error;
+
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    LweSecretKeyEntity, LweSeededCiphertextEntity, PlaintextEntity,
+};
+use concrete_commons::dispersion::Variance;
+
+engine_error! {
+    LweSeededCiphertextEncryptionError for LweSeededCiphertextEncryptionEngine @
+}
+
+/// A trait for engines encrypting seeded LWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a seeded LWE ciphertext containing
+/// the encryption of the `input` plaintext under the `key` secret key. The mask of the ciphertext
+/// is sampled from a generator seeded with a fresh seed, which is stored in place of the mask.
+///
+/// # Formal Definition
+pub trait LweSeededCiphertextEncryptionEngine<SecretKey, Plaintext, Ciphertext>:
+    AbstractEngine
+where
+    SecretKey: LweSecretKeyEntity,
+    Plaintext: PlaintextEntity,
+    Ciphertext: LweSeededCiphertextEntity<KeyDistribution = SecretKey::KeyDistribution>,
+{
+    /// Encrypts a seeded LWE ciphertext.
+    fn encrypt_lwe_seeded_ciphertext(
+        &mut self,
+        key: &SecretKey,
+        input: &Plaintext,
+        noise: Variance,
+    ) -> Result<Ciphertext, LweSeededCiphertextEncryptionError<Self::EngineError>>;
+
+    /// Unsafely encrypts a seeded LWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweSeededCiphertextEncryptionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn encrypt_lwe_seeded_ciphertext_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &Plaintext,
+        noise: Variance,
+    ) -> Ciphertext;
+}
@@ -0,0 +1,35 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweCiphertextEntity, LweSeededCiphertextEntity};
+
+engine_error! {
+    LweSeededCiphertextExpansionError for LweSeededCiphertextExpansionEngine @
+}
+
+/// A trait for engines expanding seeded LWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an LWE ciphertext containing the
+/// expansion of the `input` seeded LWE ciphertext, whose mask is sampled again from its seed.
+///
+/// # Formal Definition
+pub trait LweSeededCiphertextExpansionEngine<Input, Output>: AbstractEngine
+where
+    Input: LweSeededCiphertextEntity,
+    Output: LweCiphertextEntity<KeyDistribution = Input::KeyDistribution>,
+{
+    /// Expands a seeded LWE ciphertext.
+    fn expand_lwe_seeded_ciphertext(
+        &mut self,
+        input: &Input,
+    ) -> Result<Output, LweSeededCiphertextExpansionError<Self::EngineError>>;
+
+    /// Unsafely expands a seeded LWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweSeededCiphertextExpansionError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn expand_lwe_seeded_ciphertext_unchecked(&mut self, input: &Input) -> Output;
+}
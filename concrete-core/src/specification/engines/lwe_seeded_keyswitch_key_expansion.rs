@@ -0,0 +1,38 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweKeyswitchKeyEntity, LweSeededKeyswitchKeyEntity};
+
+engine_error! {
+    LweSeededKeyswitchKeyExpansionError for LweSeededKeyswitchKeyExpansionEngine @
+}
+
+/// A trait for engines expanding seeded LWE keyswitch keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an LWE keyswitch key containing the
+/// expansion of the `input` seeded LWE keyswitch key, whose masks are sampled again from its seed.
+///
+/// # Formal Definition
+pub trait LweSeededKeyswitchKeyExpansionEngine<Input, Output>: AbstractEngine
+where
+    Input: LweSeededKeyswitchKeyEntity,
+    Output: LweKeyswitchKeyEntity<
+        InputKeyDistribution = Input::InputKeyDistribution,
+        OutputKeyDistribution = Input::OutputKeyDistribution,
+    >,
+{
+    /// Expands a seeded LWE keyswitch key.
+    fn expand_lwe_seeded_keyswitch_key(
+        &mut self,
+        input: &Input,
+    ) -> Result<Output, LweSeededKeyswitchKeyExpansionError<Self::EngineError>>;
+
+    /// Unsafely expands a seeded LWE keyswitch key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweSeededKeyswitchKeyExpansionError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn expand_lwe_seeded_keyswitch_key_unchecked(&mut self, input: &Input) -> Output;
+}
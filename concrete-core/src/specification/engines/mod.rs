@@ -149,19 +149,27 @@ mod cleartext_vector_discarding_retrieval;
 mod cleartext_vector_encoding;
 mod cleartext_vector_retrieval;
 mod destruction;
+mod encoder_creation;
+mod encoder_vector_creation;
 mod ggsw_ciphertext_conversion;
 mod ggsw_ciphertext_discarding_conversion;
 mod ggsw_ciphertext_scalar_discarding_encryption;
 mod ggsw_ciphertext_scalar_encryption;
 mod ggsw_ciphertext_scalar_trivial_encryption;
+mod glwe_ciphertext_cleartext_fusing_multiplication;
 mod glwe_ciphertext_conversion;
 mod glwe_ciphertext_decryption;
 mod glwe_ciphertext_discarding_conversion;
 mod glwe_ciphertext_discarding_decryption;
 mod glwe_ciphertext_discarding_encryption;
+mod glwe_ciphertext_discarding_tensor_product;
 mod glwe_ciphertext_encryption;
+mod glwe_ciphertext_fusing_addition;
+mod glwe_ciphertext_fusing_subtraction;
+mod glwe_ciphertext_ggsw_ciphertext_discarding_cmux;
 mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product;
 mod glwe_ciphertext_ggsw_ciphertext_external_product;
+mod glwe_ciphertext_plaintext_vector_fusing_addition;
 mod glwe_ciphertext_trivial_decryption;
 mod glwe_ciphertext_trivial_encryption;
 mod glwe_ciphertext_vector_conversion;
@@ -174,19 +182,23 @@ mod glwe_ciphertext_vector_trivial_decryption;
 mod glwe_ciphertext_vector_trivial_encryption;
 mod glwe_ciphertext_vector_zero_encryption;
 mod glwe_ciphertext_zero_encryption;
+mod glwe_relinearization_key_creation;
 mod glwe_secret_key_conversion;
 mod glwe_secret_key_creation;
 mod glwe_secret_key_discarding_conversion;
 mod glwe_secret_key_to_lwe_secret_key_transmutation;
+mod gsw_ciphertext_scalar_encryption;
 mod lwe_bootstrap_key_conversion;
 mod lwe_bootstrap_key_creation;
 mod lwe_bootstrap_key_discarding_conversion;
+mod lwe_ciphertext_blind_rotation;
 mod lwe_ciphertext_cleartext_discarding_multiplication;
 mod lwe_ciphertext_cleartext_fusing_multiplication;
 mod lwe_ciphertext_conversion;
 mod lwe_ciphertext_decryption;
 mod lwe_ciphertext_discarding_addition;
 mod lwe_ciphertext_discarding_bootstrap;
+mod lwe_ciphertext_discarding_cmux;
 mod lwe_ciphertext_discarding_conversion;
 mod lwe_ciphertext_discarding_decryption;
 mod lwe_ciphertext_discarding_encryption;
@@ -200,6 +212,7 @@ mod lwe_ciphertext_encryption;
 mod lwe_ciphertext_fusing_addition;
 mod lwe_ciphertext_fusing_negation;
 mod lwe_ciphertext_fusing_subtraction;
+mod lwe_ciphertext_gsw_ciphertext_external_product;
 mod lwe_ciphertext_loading;
 mod lwe_ciphertext_plaintext_discarding_addition;
 mod lwe_ciphertext_plaintext_discarding_subtraction;
@@ -215,6 +228,7 @@ mod lwe_ciphertext_vector_discarding_bootstrap;
 mod lwe_ciphertext_vector_discarding_conversion;
 mod lwe_ciphertext_vector_discarding_decryption;
 mod lwe_ciphertext_vector_discarding_encryption;
+mod lwe_ciphertext_vector_discarding_extraction;
 mod lwe_ciphertext_vector_discarding_keyswitch;
 mod lwe_ciphertext_vector_discarding_loading;
 mod lwe_ciphertext_vector_discarding_negation;
@@ -234,6 +248,11 @@ mod lwe_keyswitch_key_discarding_conversion;
 mod lwe_secret_key_conversion;
 mod lwe_secret_key_creation;
 mod lwe_secret_key_discarding_conversion;
+mod lwe_seeded_ciphertext_encryption;
+mod lwe_seeded_ciphertext_expansion;
+mod lwe_seeded_keyswitch_key_creation;
+mod lwe_seeded_keyswitch_key_expansion;
+mod lwe_to_glwe_secret_key_transmutation;
 mod plaintext_conversion;
 mod plaintext_creation;
 mod plaintext_decoding;
@@ -260,19 +279,27 @@ pub use cleartext_vector_discarding_retrieval::*;
 pub use cleartext_vector_encoding::*;
 pub use cleartext_vector_retrieval::*;
 pub use destruction::*;
+pub use encoder_creation::*;
+pub use encoder_vector_creation::*;
 pub use ggsw_ciphertext_conversion::*;
 pub use ggsw_ciphertext_discarding_conversion::*;
 pub use ggsw_ciphertext_scalar_discarding_encryption::*;
 pub use ggsw_ciphertext_scalar_encryption::*;
 pub use ggsw_ciphertext_scalar_trivial_encryption::*;
+pub use glwe_ciphertext_cleartext_fusing_multiplication::*;
 pub use glwe_ciphertext_conversion::*;
 pub use glwe_ciphertext_decryption::*;
 pub use glwe_ciphertext_discarding_conversion::*;
 pub use glwe_ciphertext_discarding_decryption::*;
 pub use glwe_ciphertext_discarding_encryption::*;
+pub use glwe_ciphertext_discarding_tensor_product::*;
 pub use glwe_ciphertext_encryption::*;
+pub use glwe_ciphertext_fusing_addition::*;
+pub use glwe_ciphertext_fusing_subtraction::*;
+pub use glwe_ciphertext_ggsw_ciphertext_discarding_cmux::*;
 pub use glwe_ciphertext_ggsw_ciphertext_discarding_external_product::*;
 pub use glwe_ciphertext_ggsw_ciphertext_external_product::*;
+pub use glwe_ciphertext_plaintext_vector_fusing_addition::*;
 pub use glwe_ciphertext_trivial_decryption::*;
 pub use glwe_ciphertext_trivial_encryption::*;
 pub use glwe_ciphertext_vector_conversion::*;
@@ -285,19 +312,23 @@ pub use glwe_ciphertext_vector_trivial_decryption::*;
 pub use glwe_ciphertext_vector_trivial_encryption::*;
 pub use glwe_ciphertext_vector_zero_encryption::*;
 pub use glwe_ciphertext_zero_encryption::*;
+pub use glwe_relinearization_key_creation::*;
 pub use glwe_secret_key_conversion::*;
 pub use glwe_secret_key_creation::*;
 pub use glwe_secret_key_discarding_conversion::*;
 pub use glwe_secret_key_to_lwe_secret_key_transmutation::*;
+pub use gsw_ciphertext_scalar_encryption::*;
 pub use lwe_bootstrap_key_conversion::*;
 pub use lwe_bootstrap_key_creation::*;
 pub use lwe_bootstrap_key_discarding_conversion::*;
+pub use lwe_ciphertext_blind_rotation::*;
 pub use lwe_ciphertext_cleartext_discarding_multiplication::*;
 pub use lwe_ciphertext_cleartext_fusing_multiplication::*;
 pub use lwe_ciphertext_conversion::*;
 pub use lwe_ciphertext_decryption::*;
 pub use lwe_ciphertext_discarding_addition::*;
 pub use lwe_ciphertext_discarding_bootstrap::*;
+pub use lwe_ciphertext_discarding_cmux::*;
 pub use lwe_ciphertext_discarding_conversion::*;
 pub use lwe_ciphertext_discarding_decryption::*;
 pub use lwe_ciphertext_discarding_encryption::*;
@@ -311,6 +342,7 @@ pub use lwe_ciphertext_encryption::*;
 pub use lwe_ciphertext_fusing_addition::*;
 pub use lwe_ciphertext_fusing_negation::*;
 pub use lwe_ciphertext_fusing_subtraction::*;
+pub use lwe_ciphertext_gsw_ciphertext_external_product::*;
 pub use lwe_ciphertext_loading::*;
 pub use lwe_ciphertext_plaintext_discarding_addition::*;
 pub use lwe_ciphertext_plaintext_discarding_subtraction::*;
@@ -326,6 +358,7 @@ pub use lwe_ciphertext_vector_discarding_bootstrap::*;
 pub use lwe_ciphertext_vector_discarding_conversion::*;
 pub use lwe_ciphertext_vector_discarding_decryption::*;
 pub use lwe_ciphertext_vector_discarding_encryption::*;
+pub use lwe_ciphertext_vector_discarding_extraction::*;
 pub use lwe_ciphertext_vector_discarding_keyswitch::*;
 pub use lwe_ciphertext_vector_discarding_loading::*;
 pub use lwe_ciphertext_vector_discarding_negation::*;
@@ -345,6 +378,11 @@ pub use lwe_keyswitch_key_discarding_conversion::*;
 pub use lwe_secret_key_conversion::*;
 pub use lwe_secret_key_creation::*;
 pub use lwe_secret_key_discarding_conversion::*;
+pub use lwe_seeded_ciphertext_encryption::*;
+pub use lwe_seeded_ciphertext_expansion::*;
+pub use lwe_seeded_keyswitch_key_creation::*;
+pub use lwe_seeded_keyswitch_key_expansion::*;
+pub use lwe_to_glwe_secret_key_transmutation::*;
 pub use plaintext_conversion::*;
 pub use plaintext_creation::*;
 pub use plaintext_decoding::*;
@@ -0,0 +1,29 @@
+use crate::specification::entities::markers::{GlweRelinearizationKeyKind, KeyDistributionMarker};
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+
+/// A trait implemented by types embodying a GLWE relinearization key.
+///
+/// A GLWE relinearization key is associated with a
+/// [`KeyDistribution`](`GlweRelinearizationKeyEntity::KeyDistribution`) type, which conveys the
+/// distribution of the secret key it was generated from.
+///
+/// # Formal Definition
+pub trait GlweRelinearizationKeyEntity: AbstractEntity<Kind = GlweRelinearizationKeyKind> {
+    /// The distribution of the key the relinearized ciphertexts are encrypted with.
+    type KeyDistribution: KeyDistributionMarker;
+
+    /// Returns the GLWE dimension of the key.
+    fn glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the polynomial size of the key.
+    fn polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the number of decomposition levels of the key.
+    fn decomposition_level_count(&self) -> DecompositionLevelCount;
+
+    /// Returns the logarithm of the base used in the key.
+    fn decomposition_base_log(&self) -> DecompositionBaseLog;
+}
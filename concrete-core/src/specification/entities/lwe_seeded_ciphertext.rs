@@ -0,0 +1,22 @@
+use crate::specification::entities::markers::{KeyDistributionMarker, LweSeededCiphertextKind};
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::LweDimension;
+
+/// A trait implemented by types embodying a seeded LWE ciphertext.
+///
+/// A seeded LWE ciphertext only stores the body of an LWE ciphertext, together with the seed of
+/// the generator its mask was sampled from. It is associated with a
+/// [`KeyDistribution`](`LweSeededCiphertextEntity::KeyDistribution`) type, which conveys the
+/// distribution of the secret key it was encrypted with.
+///
+/// # Formal Definition
+pub trait LweSeededCiphertextEntity: AbstractEntity<Kind = LweSeededCiphertextKind> {
+    /// The distribution of the key the ciphertext was encrypted with.
+    type KeyDistribution: KeyDistributionMarker;
+
+    /// Returns the LWE dimension of the ciphertext.
+    fn lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the seed of the generator the mask is sampled from.
+    fn seed(&self) -> u128;
+}
@@ -0,0 +1,38 @@
+use crate::specification::entities::markers::{KeyDistributionMarker, LweSeededKeyswitchKeyKind};
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+
+/// A trait implemented by types embodying a seeded LWE keyswitch key.
+///
+/// A seeded LWE keyswitch key only stores the bodies of the ciphertexts of an LWE keyswitch key,
+/// together with the seed of the generator their masks were sampled from. It is associated with
+/// two [`KeyDistributionMarker`] types:
+///
+/// + The [`InputKeyDistribution`](`LweSeededKeyswitchKeyEntity::InputKeyDistribution`) type
+/// conveys the distribution of the input secret key.
+/// + The [`OutputKeyDistribution`](`LweSeededKeyswitchKeyEntity::OutputKeyDistribution`) type
+/// conveys the distribution of the output secret key.
+///
+/// # Formal Definition
+pub trait LweSeededKeyswitchKeyEntity: AbstractEntity<Kind = LweSeededKeyswitchKeyKind> {
+    /// The distribution of the key the input ciphertext is encrypted with.
+    type InputKeyDistribution: KeyDistributionMarker;
+
+    /// The distribution of the key the output ciphertext is encrypted with.
+    type OutputKeyDistribution: KeyDistributionMarker;
+
+    /// Returns the input LWE dimension of the key.
+    fn input_lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the output LWE dimension of the key.
+    fn output_lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the number of decomposition levels of the key.
+    fn decomposition_level_count(&self) -> DecompositionLevelCount;
+
+    /// Returns the logarithm of the base used in the key.
+    fn decomposition_base_log(&self) -> DecompositionBaseLog;
+
+    /// Returns the seed of the generator the masks are sampled from.
+    fn seed(&self) -> u128;
+}
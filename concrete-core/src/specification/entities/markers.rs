@@ -58,6 +58,12 @@ entity_kind_marker! {
             => "An empty type representing the LWE keyswitch key kind in the type system.",
         LweBootstrapKeyKind
             => "An empty type representing the LWE bootstrap key kind in the type system.",
+        GlweRelinearizationKeyKind
+            => "An empty type representing the GLWE relinearization key kind in the type system.",
+        LweSeededCiphertextKind
+            => "An empty type representing the seeded LWE ciphertext kind in the type system.",
+        LweSeededKeyswitchKeyKind
+            => "An empty type representing the seeded LWE keyswitch key kind in the type system.",
         EncoderKind
             => "An empty type representing the encoder kind in the type system.",
         EncoderVectorKind
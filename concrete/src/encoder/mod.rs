@@ -8,14 +8,12 @@ use serde::{Deserialize, Serialize};
 
 use crate::error::CryptoAPIError;
 use crate::Torus;
-use concrete_core::crypto;
-use concrete_core::math::decomposition::SignedDecomposer;
-use concrete_npe as npe;
+use concrete_npe::estimate_number_of_noise_bits;
 
 use super::{read_from_file, write_to_file};
 use crate::plaintext::Plaintext;
+use concrete_commons::dispersion::Variance;
 use concrete_commons::numeric::Numeric;
-use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
 
 /// Structure describing one particular Encoding
 /// # Attributes
@@ -153,8 +151,7 @@ impl Encoder {
         variance: f64,
     ) -> Result<usize, CryptoAPIError> {
         // check output noise
-        let nb_noise_bit: usize = npe::nb_bit_from_variance_99(variance, <Torus as Numeric>::BITS
-                                                               as usize);
+        let nb_noise_bit: usize = estimate_number_of_noise_bits::<Torus, _>(Variance(variance));
 
         // check if there actually some noise in the ciphertext
         if nb_noise_bit == 0 {
@@ -491,27 +488,17 @@ impl Encoder {
     /// let plaintext = encoder.encode_outside_interval_operators(m).unwrap();
     /// ```
     pub fn encode_outside_interval_operators(&self, m: f64) -> Result<Torus, CryptoAPIError> {
-        use concrete_core::crypto::encoding::Encoder as CoreEncoder;
-
         // check if the encoder is valid
         if !self.is_valid() {
             return Err(InvalidEncoderError!(self.nb_bit_precision, self.delta));
         }
 
-        // call core_api module to encode
-        let encoder = crypto::encoding::RealEncoder {
-            offset: self.o,
-            delta: self.delta,
-        };
-        let mut res: Torus = encoder.encode(crypto::encoding::Cleartext(m)).0;
+        // encode
+        let mut res: Torus = encode_real(m, self.o, self.delta);
 
         // round if in rounding context
         if self.round {
-            let decomposer = SignedDecomposer::<Torus>::new(
-                DecompositionBaseLog(self.nb_bit_precision),
-                DecompositionLevelCount(1),
-            );
-            res = decomposer.closest_representable(res);
+            res = closest_representable(res, self.nb_bit_precision);
         }
 
         // shift if there is some padding
@@ -544,8 +531,6 @@ impl Encoder {
     /// let new_message = encoder.decode_core(plaintext).unwrap();
     /// ```
     pub fn decode_core(&self, pt: Torus) -> Result<f64, CryptoAPIError> {
-        use concrete_core::crypto::encoding::Encoder as CoreEncoder;
-
         // check valid encoder
         if !self.is_valid() {
             return Err(InvalidEncoderError!(self.nb_bit_precision, self.delta));
@@ -553,11 +538,7 @@ impl Encoder {
 
         // round if asked
         let mut tmp: Torus = if self.round {
-            let decomposer = SignedDecomposer::<Torus>::new(
-                DecompositionBaseLog(self.nb_bit_precision + self.nb_bit_padding),
-                DecompositionLevelCount(1),
-            );
-            decomposer.closest_representable(pt)
+            closest_representable(pt, self.nb_bit_precision + self.nb_bit_padding)
         } else {
             pt
         };
@@ -570,22 +551,13 @@ impl Encoder {
         // round if round is set to false and if in the security margin
         let starting_value_security_margin: Torus = ((1 << (self.nb_bit_precision + 1)) - 1)
             << (<Torus as Numeric>::BITS - self.nb_bit_precision);
-        let decomposer = SignedDecomposer::<Torus>::new(
-            DecompositionBaseLog(self.nb_bit_precision),
-            DecompositionLevelCount(1),
-        );
         tmp = if tmp > starting_value_security_margin {
-            decomposer.closest_representable(tmp)
+            closest_representable(tmp, self.nb_bit_precision)
         } else {
             tmp
         };
 
-        let encoder = crypto::encoding::RealEncoder {
-            offset: self.o,
-            delta: self.delta,
-        };
-
-        Ok(encoder.decode(crypto::encoding::Plaintext(tmp)).0)
+        Ok(decode_real(tmp, self.o, self.delta))
     }
 
     /// Check if the Encoder looks valid or not
@@ -655,5 +627,29 @@ impl fmt::Display for Encoder {
     }
 }
 
+/// Map a real number to the torus with an affine function sending `offset` to 0 and
+/// `offset + delta` to 1, and round the result to the closest torus element
+fn encode_real(m: f64, offset: f64, delta: f64) -> Torus {
+    let scaled = (m - offset) / delta;
+    let mut fract = (scaled - scaled.floor()) * f64::powi(2., <Torus as Numeric>::BITS as i32);
+    if fract - fract.floor() >= 0.5 {
+        fract += 1.;
+    }
+    fract as Torus
+}
+
+/// Map a torus element back to a real number with the inverse of the affine function of
+/// `encode_real`
+fn decode_real(pt: Torus, offset: f64, delta: f64) -> f64 {
+    (pt as f64) * f64::powi(2., -(<Torus as Numeric>::BITS as i32)) * delta + offset
+}
+
+/// Round a torus element to its `nb_bit` most significant bits
+fn closest_representable(input: Torus, nb_bit: usize) -> Torus {
+    let non_rep_bit_count: usize = <Torus as Numeric>::BITS - nb_bit;
+    let non_rep_msb = (input >> (non_rep_bit_count - 1)) & 1;
+    ((input >> non_rep_bit_count).wrapping_add(non_rep_msb)) << non_rep_bit_count
+}
+
 #[cfg(test)]
 mod tests;
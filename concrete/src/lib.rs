@@ -5,22 +5,43 @@
 //! Welcome to the concrete documentation. If you are new to FHE, you may have a look at the
 //! [concrete book](https://concrete.zama.ai/).
 
+use std::cell::RefCell;
 use std::error::Error;
 use std::fs::File;
 use std::io::{BufReader, BufWriter};
 use std::path::Path;
 
+use concrete_core::prelude::{AbstractEngine, CoreEngine};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
 pub use error::*;
 
-pub use crate::traits::GenericAdd;
+pub use crate::operators::HomomorphicResult;
+pub use crate::traits::{GenericAdd, HomomorphicAdd, HomomorphicMul, HomomorphicSub};
 
 pub mod traits;
 
+mod raw;
+
 pub type Torus = u64;
 
+thread_local! {
+    static CORE_ENGINE: RefCell<CoreEngine> = RefCell::new(CoreEngine::new().unwrap());
+}
+
+/// Executes `f` with the `concrete-core` engine owned by the current thread.
+///
+/// # Panics
+///
+/// This function panics if it is called from within `f`.
+fn with_engine<R, F>(f: F) -> R
+where
+    F: FnOnce(&mut CoreEngine) -> R,
+{
+    CORE_ENGINE.with(|engine| f(&mut engine.borrow_mut()))
+}
+
 #[allow(unused_macros)]
 macro_rules! assert_eq_granularity {
     ($A:expr, $B:expr, $ENC:expr) => {
@@ -39,9 +60,10 @@ macro_rules! assert_eq_granularity {
 #[allow(unused_macros)]
 macro_rules! generate_random_interval {
     () => {{
-        let coins: Vec<u32> = concrete_core::math::random::RandomGenerator::new(None)
-            .random_uniform_tensor(3)
-            .into_container();
+        let coins: Vec<u32> =
+            concrete_core::backends::core::private::math::random::RandomGenerator::new(None)
+                .random_uniform_tensor(3)
+                .into_container();
 
         let interval_type: usize = (coins[0] % 3) as usize;
         let interval_size = ((coins[1] % (1000 * 1000)) as f64) / 1000.;
@@ -68,9 +90,10 @@ macro_rules! generate_random_interval {
 #[allow(unused_macros)]
 macro_rules! generate_random_centered_interval {
     () => {{
-        let coins: Vec<u32> = concrete_core::math::random::RandomGenerator::new(None)
-            .random_uniform_tensor(2)
-            .into_container();
+        let coins: Vec<u32> =
+            concrete_core::backends::core::private::math::random::RandomGenerator::new(None)
+                .random_uniform_tensor(2)
+                .into_container();
 
         let interval_size = ((coins[0] % (1000 * 1000)) as f64) / 1000.;
 
@@ -83,9 +106,10 @@ macro_rules! generate_random_centered_interval {
 #[allow(unused_macros)]
 macro_rules! generate_precision_padding {
     ($max_precision: expr, $max_padding: expr) => {{
-        let rs: Vec<u32> = concrete_core::math::random::RandomGenerator::new(None)
-            .random_uniform_tensor(2)
-            .into_container();
+        let rs: Vec<u32> =
+            concrete_core::backends::core::private::math::random::RandomGenerator::new(None)
+                .random_uniform_tensor(2)
+                .into_container();
         (
             ((rs[0] % $max_precision) as usize) + 1,
             (rs[1] % $max_padding) as usize,
@@ -99,9 +123,10 @@ macro_rules! random_index {
         if $max == 0 {
             (0 as usize)
         } else {
-            let rs: Vec<u32> = concrete_core::math::random::RandomGenerator::new(None)
-                .random_uniform_tensor(1)
-                .into_container();
+            let rs: Vec<u32> =
+                concrete_core::backends::core::private::math::random::RandomGenerator::new(None)
+                    .random_uniform_tensor(1)
+                    .into_container();
             (rs[0] % ($max as u32)) as usize
         }
     }};
@@ -110,9 +135,10 @@ macro_rules! random_index {
 #[allow(unused_macros)]
 macro_rules! random_message {
     ($min: expr, $max: expr) => {{
-        let rs: Vec<u64> = concrete_core::math::random::RandomGenerator::new(None)
-            .random_uniform_tensor(1)
-            .into_container();
+        let rs: Vec<u64> =
+            concrete_core::backends::core::private::math::random::RandomGenerator::new(None)
+                .random_uniform_tensor(1)
+                .into_container();
         (rs[0] as f64) / f64::powi(2., 64) * ($max - $min) + $min
     }};
 }
@@ -151,6 +177,28 @@ fn read_from_file<P: AsRef<Path>, U: DeserializeOwned>(path: P) -> Result<U, Box
     Ok(u)
 }
 
+fn write_to_binary_file<P: AsRef<Path>, U: Serialize>(
+    path: P,
+    u: &U,
+) -> Result<(), Box<dyn Error>> {
+    // Create the file
+    let file = File::create(path)?;
+    let writer = BufWriter::new(file);
+    bincode::serialize_into(writer, u)?;
+    Ok(())
+}
+
+fn read_from_binary_file<P: AsRef<Path>, U: DeserializeOwned>(
+    path: P,
+) -> Result<U, Box<dyn Error>> {
+    // Open the file in read-only mode with buffer.
+    let file = File::open(path)?;
+    let reader = BufReader::new(file);
+    // Read the binary contents of the file
+    let u = bincode::deserialize_from(reader)?;
+    Ok(u)
+}
+
 // #[derive(Serialize, Deserialize)]
 // #[serde(remote = "Complex64")]
 // struct SerdeCtorus {
@@ -201,6 +249,8 @@ macro_rules! pub_mod_use {
 }
 #[macro_use]
 pub mod error;
+#[macro_use]
+pub mod operators;
 pub_mod_use!(lwe_params);
 pub_mod_use!(encoder);
 pub_mod_use!(lwe);
@@ -212,6 +262,7 @@ pub_mod_use!(lwe_bsk);
 pub_mod_use!(lwe_secret_key);
 pub_mod_use!(rlwe_params);
 pub_mod_use!(rlwe_secret_key);
+pub_mod_use!(rlwe_rlk);
 
 #[cfg(test)]
 mod tests_serde;
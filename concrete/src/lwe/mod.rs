@@ -4,26 +4,33 @@
 mod tests;
 
 use crate::error::CryptoAPIError;
-use crate::traits::GenericAdd;
-use crate::{read_from_file, write_to_file, Torus};
+use crate::operators::Operand;
+use crate::traits::{GenericAdd, HomomorphicAdd, HomomorphicMul, HomomorphicSub};
+use crate::{read_from_file, with_engine, write_to_file, Torus};
 use backtrace::Backtrace;
 use colored::Colorize;
-use concrete_commons::dispersion::StandardDev;
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+use concrete_commons::key_kinds::BinaryKeyKind;
 use concrete_commons::numeric::Numeric;
-use concrete_commons::parameters::{GlweSize, LweSize, PolynomialSize};
-use concrete_core::crypto::bootstrap::Bootstrap;
-use concrete_core::crypto::secret::generators::EncryptionRandomGenerator;
-use concrete_core::{
-    crypto::{
-        self,
-        encoding::{Cleartext, Plaintext},
-        glwe::GlweCiphertext,
-        lwe::LweCiphertext,
-    },
-    math::tensor::{AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor},
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, LweDimension, LweSize,
+    PolynomialSize,
+};
+use concrete_core::prelude::{
+    CleartextCreationEngine, GlweCiphertext64, GlweCiphertextTrivialEncryptionEngine,
+    LweCiphertext64, LweCiphertextCleartextFusingMultiplicationEngine,
+    LweCiphertextDecryptionEngine, LweCiphertextDiscardingBootstrapEngine,
+    LweCiphertextDiscardingKeyswitchEngine, LweCiphertextEncryptionEngine,
+    LweCiphertextFusingAdditionEngine, LweCiphertextFusingNegationEngine,
+    LweCiphertextFusingSubtractionEngine, LweCiphertextPlaintextFusingAdditionEngine,
+    LweCiphertextTrivialEncryptionEngine, PlaintextCreationEngine, PlaintextRetrievalEngine,
+    PlaintextVectorCreationEngine,
+};
+use concrete_npe::{
+    estimate_addition_noise, estimate_integer_plaintext_multiplication_noise,
+    estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms,
+    estimate_modulus_switching_noise_with_binary_key, estimate_pbs_noise,
 };
-use concrete_npe as npe;
-use concrete_npe::{LWE as NPELWE};
 use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::fmt;
@@ -37,7 +44,7 @@ use std::fmt;
 /// * `encoder` - the encoder of the LWE ciphertext
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct LWE {
-    pub ciphertext: crypto::lwe::LweCiphertext<Vec<Torus>>,
+    pub ciphertext: LweCiphertext64,
     pub variance: f64,
     pub dimension: usize,
     pub encoder: crate::Encoder,
@@ -61,6 +68,63 @@ impl GenericAdd<&LWE, CryptoAPIError> for LWE {
     }
 }
 
+impl HomomorphicAdd<&LWE, CryptoAPIError> for LWE {
+    fn homomorphic_add(&self, right: &LWE) -> Result<LWE, CryptoAPIError> {
+        self.add_with_padding(right)
+    }
+    fn homomorphic_add_inplace(&mut self, right: &LWE) -> Result<(), CryptoAPIError> {
+        self.add_with_padding_inplace(right)
+    }
+}
+
+impl HomomorphicSub<&LWE, CryptoAPIError> for LWE {
+    fn homomorphic_sub(&self, right: &LWE) -> Result<LWE, CryptoAPIError> {
+        self.sub_with_padding(right)
+    }
+    fn homomorphic_sub_inplace(&mut self, right: &LWE) -> Result<(), CryptoAPIError> {
+        self.sub_with_padding_inplace(right)
+    }
+}
+
+impl HomomorphicMul<i32, CryptoAPIError> for LWE {
+    fn homomorphic_mul(&self, right: i32) -> Result<LWE, CryptoAPIError> {
+        self.mul_constant_static_encoder(right)
+    }
+    fn homomorphic_mul_inplace(&mut self, right: i32) -> Result<(), CryptoAPIError> {
+        self.mul_constant_static_encoder_inplace(right)
+    }
+}
+
+impl HomomorphicMul<(&LWE, &crate::LWEBSK), CryptoAPIError> for LWE {
+    fn homomorphic_mul(&self, right: (&LWE, &crate::LWEBSK)) -> Result<LWE, CryptoAPIError> {
+        self.mul_from_bootstrap(right.0, right.1)
+    }
+    fn homomorphic_mul_inplace(
+        &mut self,
+        right: (&LWE, &crate::LWEBSK),
+    ) -> Result<(), CryptoAPIError> {
+        *self = self.mul_from_bootstrap(right.0, right.1)?;
+        Ok(())
+    }
+}
+
+impl Operand for LWE {
+    fn add_operand(&mut self, right: &LWE) -> Result<(), CryptoAPIError> {
+        self.add_with_padding_inplace(right)
+    }
+    fn sub_operand(&mut self, right: &LWE) -> Result<(), CryptoAPIError> {
+        self.sub_with_padding_inplace(right)
+    }
+    fn neg_operand(&mut self) -> Result<(), CryptoAPIError> {
+        self.opposite_inplace()
+    }
+    fn mul_operand(&mut self, right: i32) -> Result<(), CryptoAPIError> {
+        self.mul_constant_static_encoder_inplace(right)
+    }
+}
+
+impl_operators!(LWE);
+
 impl LWE {
     /// Instantiate a new LWE filled with zeros from a dimension
     ///
@@ -79,8 +143,14 @@ impl LWE {
     /// let empty_ciphertexts = LWE::zero(630).unwrap();
     /// ```
     pub fn zero(dimension: usize) -> Result<crate::LWE, CryptoAPIError> {
+        let ciphertext = with_engine(|engine| {
+            let plaintext = engine.create_plaintext(&(0 as Torus)).unwrap();
+            engine
+                .trivially_encrypt_lwe_ciphertext(LweSize(dimension + 1), &plaintext)
+                .unwrap()
+        });
         Ok(LWE {
-            ciphertext: crypto::lwe::LweCiphertext::allocate(0, LweSize(dimension + 1)),
+            ciphertext,
             variance: 0.,
             dimension,
             encoder: crate::Encoder::zero(),
@@ -131,12 +201,8 @@ impl LWE {
             );
         }
 
-        let mut res = LWE {
-            ciphertext: crypto::lwe::LweCiphertext::allocate(0, LweSize(sk.dimension + 1)),
-            variance: 0.,
-            dimension: sk.dimension,
-            encoder: result_encoder,
-        };
+        let mut res = LWE::zero(sk.dimension)?;
+        res.encoder = result_encoder;
         res.encrypt_raw(sk, plaintext).unwrap();
 
         Ok(res)
@@ -182,12 +248,12 @@ impl LWE {
         self.variance = var;
 
         // encrypt
-        sk.val.encrypt_lwe(
-            &mut self.ciphertext,
-            &Plaintext(plaintext),
-            StandardDev::from_standard_dev(sk.std_dev),
-            &mut EncryptionRandomGenerator::new(None),
-        );
+        self.ciphertext = with_engine(|engine| {
+            let plaintext = engine.create_plaintext(&plaintext).unwrap();
+            engine
+                .encrypt_lwe_ciphertext(&sk.val, &plaintext, Variance(var))
+                .unwrap()
+        });
 
         Ok(())
     }
@@ -223,14 +289,11 @@ impl LWE {
             return Err(DimensionError!(self.dimension, sk.dimension));
         }
 
-        // create a temporary variable to store the result of the phase computation
-        let mut output = Plaintext(0);
-
         // compute the phase
-        sk.val.decrypt_lwe(&mut output, &self.ciphertext);
+        let output: Torus = self.phase(sk);
 
         // decode
-        let result: f64 = self.encoder.decode_single(output.0)?;
+        let result: f64 = self.encoder.decode_single(output)?;
 
         Ok(result)
     }
@@ -266,18 +329,15 @@ impl LWE {
             return Err(DimensionError!(self.dimension, sk.dimension));
         }
 
-        // create a temporary variable to store the result of the phase computation
-        let mut output = Plaintext(0);
-
         // compute the phase
-        sk.val.decrypt_lwe(&mut output, &self.ciphertext);
+        let output: Torus = self.phase(sk);
 
         // round context
         let mut enc_round = self.encoder.clone();
         enc_round.round = true;
 
         // decode
-        let result: f64 = enc_round.decode_single(output.0)?;
+        let result: f64 = enc_round.decode_single(output)?;
 
         Ok(result)
     }
@@ -351,15 +411,7 @@ impl LWE {
         }
         let mut ec_tmp = self.encoder.clone();
         ec_tmp.o = 0.;
-        let update = self
-            .ciphertext
-            .as_tensor()
-            .get_element(self.dimension)
-            .wrapping_add(ec_tmp.encode_outside_interval_operators(message)?);
-        *self
-            .ciphertext
-            .as_mut_tensor()
-            .get_element_mut(self.dimension) = update;
+        self.add_to_body(ec_tmp.encode_outside_interval_operators(message)?);
 
         Ok(())
     }
@@ -546,7 +598,7 @@ impl LWE {
         }
 
         // add the two ciphertexts together
-        self.ciphertext.update_with_add(&ct.ciphertext);
+        self.add_ciphertext(ct);
 
         let enc1 = self.encoder.clone();
         let enc2 = ct.encoder.clone();
@@ -563,22 +615,22 @@ impl LWE {
         tmp_ec.o = new_min;
         let tmp_sum = enc1.o + enc2.o;
 
-        let lwe_body = self.ciphertext.get_body().0;
-        let updated_body_value = if tmp_sum <= new_min {
+        let correction: Torus = if tmp_sum <= new_min {
             let tmp_shift = new_min + (new_min - tmp_sum);
             let plaintext = tmp_ec.encode_outside_interval_operators(tmp_shift)?;
-            lwe_body.wrapping_sub(plaintext)
+            plaintext.wrapping_neg()
         } else {
-            let plaintext = tmp_ec.encode_outside_interval_operators(tmp_sum)?;
-            lwe_body.wrapping_add(plaintext)
+            tmp_ec.encode_outside_interval_operators(tmp_sum)?
         };
-        self.ciphertext.get_mut_body().0 = updated_body_value;
+        self.add_to_body(correction);
 
         // update the Encoder
         self.encoder.o = new_min;
 
         // update the noise with the NPE
-        self.variance = npe::add_ciphertexts(self.variance, ct.variance);
+        self.variance =
+            estimate_addition_noise::<Torus, _, _>(Variance(self.variance), Variance(ct.variance))
+                .get_variance();
         self.encoder.update_precision_from_variance(self.variance)?;
 
         Ok(())
@@ -683,22 +735,22 @@ impl LWE {
         }
 
         // add ciphertexts together
-        self.ciphertext.update_with_add(&ct.ciphertext);
+        self.add_ciphertext(ct);
 
         // correction related to the addition
         let mut tmp_enc = self.encoder.clone();
         tmp_enc.o = 0.;
         let correction: Torus = tmp_enc.encode_core(self.encoder.delta / 2.)?;
-        let update =
-            self.ciphertext.as_tensor().as_slice()[self.dimension].wrapping_sub(correction);
-        self.ciphertext.as_mut_tensor().as_mut_slice()[self.dimension] = update;
+        self.add_to_body(correction.wrapping_neg());
 
         // update the Encoder and the variance
         // compute the new encoder
         self.encoder.o += ct.encoder.o + self.encoder.delta / 2.;
 
         // compute the new variance
-        self.variance = npe::add_ciphertexts(self.variance, ct.variance);
+        self.variance =
+            estimate_addition_noise::<Torus, _, _>(Variance(self.variance), Variance(ct.variance))
+                .get_variance();
 
         // update the encoder precision based on the variance
         self.encoder.update_precision_from_variance(self.variance)?;
@@ -801,12 +853,14 @@ impl LWE {
         }
 
         // add ciphertexts together
-        self.ciphertext.update_with_add(&ct.ciphertext);
+        self.add_ciphertext(ct);
 
         // update the Encoder list and variances
 
         // compute the new variance
-        self.variance = npe::add_ciphertexts(self.variance, ct.variance);
+        self.variance =
+            estimate_addition_noise::<Torus, _, _>(Variance(self.variance), Variance(ct.variance))
+                .get_variance();
 
         // compute the new encoder
         self.encoder.o += ct.encoder.o;
@@ -920,12 +974,14 @@ impl LWE {
         }
 
         // add ciphertexts together
-        self.ciphertext.update_with_add(&ct.ciphertext);
+        self.add_ciphertext(ct);
 
         // update the Encoder list and variances
 
         // compute the new variance
-        self.variance = npe::add_ciphertexts(self.variance, ct.variance);
+        self.variance =
+            estimate_addition_noise::<Torus, _, _>(Variance(self.variance), Variance(ct.variance))
+                .get_variance();
 
         // compute the new encoder
         self.encoder.o += ct.encoder.o;
@@ -1036,13 +1092,11 @@ impl LWE {
         }
 
         // subtract ciphertexts together
-        self.ciphertext.update_with_sub(&ct.ciphertext);
+        self.sub_ciphertext(ct);
 
         // correction related to the subtraction
         let correction: Torus = 1 << (<Torus as Numeric>::BITS - self.encoder.nb_bit_padding);
-        let update =
-            self.ciphertext.as_tensor().as_slice()[self.dimension].wrapping_add(correction);
-        self.ciphertext.as_mut_tensor().as_mut_slice()[self.dimension] = update;
+        self.add_to_body(correction);
 
         // update the Encoder
         self.encoder.o -= ct.encoder.o + ct.encoder.delta;
@@ -1052,7 +1106,9 @@ impl LWE {
             usize::min(self.encoder.nb_bit_precision, ct.encoder.nb_bit_precision);
 
         // update the noise with the NPE
-        self.variance = npe::add_ciphertexts(self.variance, ct.variance);
+        self.variance =
+            estimate_addition_noise::<Torus, _, _>(Variance(self.variance), Variance(ct.variance))
+                .get_variance();
         self.encoder.update_precision_from_variance(self.variance)?;
 
         Ok(())
@@ -1157,13 +1213,11 @@ impl LWE {
         }
 
         // subtract ciphertexts together
-        self.ciphertext.update_with_sub(&ct.ciphertext);
+        self.sub_ciphertext(ct);
 
         // correction related to the subtraction
         let correction: Torus = 1 << (<Torus as Numeric>::BITS - self.encoder.nb_bit_padding);
-        let update =
-            self.ciphertext.as_tensor().as_slice()[self.dimension].wrapping_add(correction);
-        self.ciphertext.as_mut_tensor().as_mut_slice()[self.dimension] = update;
+        self.add_to_body(correction);
 
         // update the Encoder
         self.encoder.o -= ct.encoder.o + ct.encoder.delta;
@@ -1173,7 +1227,9 @@ impl LWE {
             usize::max(self.encoder.nb_bit_precision, ct.encoder.nb_bit_precision) + 1;
 
         // update the noise with the NPE
-        self.variance = npe::add_ciphertexts(self.variance, ct.variance);
+        self.variance =
+            estimate_addition_noise::<Torus, _, _>(Variance(self.variance), Variance(ct.variance))
+                .get_variance();
         self.encoder.update_precision_from_variance(self.variance)?;
 
         Ok(())
@@ -1257,18 +1313,20 @@ impl LWE {
         let cor = cor0.wrapping_mul((message - 1) as Torus);
 
         // multiplication
-        self.ciphertext
-            .update_with_scalar_mul(Cleartext(message as Torus));
+        self.mul_by_constant(message as Torus);
 
         // apply correction
-        let update = self.ciphertext.as_tensor().as_slice()[self.dimension].wrapping_sub(cor);
-        self.ciphertext.as_mut_tensor().as_mut_slice()[self.dimension] = update;
+        self.add_to_body(cor.wrapping_neg());
 
         // compute the absolute value
         let m_abs = message.abs();
 
         // call to the NPE to estimate the new variance
-        self.variance = npe::LWE::single_scalar_mul(self.variance, m_abs as Torus);
+        self.variance = estimate_integer_plaintext_multiplication_noise::<Torus, _>(
+            Variance(self.variance),
+            m_abs as Torus,
+        )
+        .get_variance();
 
         if m_abs != 0 {
             // update the encoder precision based on the variance
@@ -1403,11 +1461,10 @@ impl LWE {
 
         // encode 0 and subtract it
         let tmp_sub = self.encoder.encode_core(0.)?;
-        let update = self.ciphertext.as_tensor().as_slice()[self.dimension].wrapping_sub(tmp_sub);
-        self.ciphertext.as_mut_tensor().as_mut_slice()[self.dimension] = update;
+        self.add_to_body(tmp_sub.wrapping_neg());
 
         // scalar multiplication
-        self.ciphertext.update_with_scalar_mul(Cleartext(scal));
+        self.mul_by_constant(scal);
 
         // new encoder
         let new_o = self.encoder.o * max_constant;
@@ -1453,7 +1510,11 @@ impl LWE {
         self.encoder.copy(&tmp_encoder);
         self.encoder.nb_bit_precision = usize::min(self.encoder.nb_bit_precision, new_precision);
         // call to the NPE to estimate the new variance
-        self.variance = npe::LWE::single_scalar_mul(self.variance, scal);
+        self.variance = estimate_integer_plaintext_multiplication_noise::<Torus, _>(
+            Variance(self.variance),
+            scal,
+        )
+        .get_variance();
 
         if scal != 0 {
             // update the encoder precision based on the variance
@@ -1462,12 +1523,11 @@ impl LWE {
 
         // encode 0 with the new encoder
         let tmp_add = self.encoder.encode_core(0.)?;
-        let update = self.ciphertext.as_tensor().as_slice()[self.dimension].wrapping_add(tmp_add);
-        self.ciphertext.as_mut_tensor().as_mut_slice()[self.dimension] = update;
+        self.add_to_body(tmp_add);
 
         if negative {
             // compute the opposite
-            self.ciphertext.update_with_neg();
+            self.negate();
 
             // add correction if there is some padding
             let mut cor: Torus = 0;
@@ -1483,15 +1543,7 @@ impl LWE {
                         - self.encoder.nb_bit_precision),
                 );
             }
-            let update = self
-                .ciphertext
-                .as_tensor()
-                .get_element(self.dimension)
-                .wrapping_add(cor);
-            *self
-                .ciphertext
-                .as_mut_tensor()
-                .get_element_mut(self.dimension) = update;
+            self.add_to_body(cor);
 
             // change the encoder
             self.encoder.opposite_inplace()?;
@@ -1572,7 +1624,7 @@ impl LWE {
         }
 
         // compute the opposite
-        self.ciphertext.update_with_neg();
+        self.negate();
 
         // add correction if there is some padding
         let mut cor: Torus = 0;
@@ -1588,8 +1640,7 @@ impl LWE {
                     - self.encoder.nb_bit_precision),
             );
         }
-        let update = self.ciphertext.as_tensor().as_slice()[self.dimension].wrapping_add(cor);
-        self.ciphertext.as_mut_tensor().as_mut_slice()[self.dimension] = update;
+        self.add_to_body(cor);
 
         // change the encoder
         self.encoder.opposite_inplace()?;
@@ -1645,18 +1696,27 @@ impl LWE {
         let mut res: crate::LWE = crate::LWE::zero(ksk.dimension_after)?;
 
         // key switch
-        ksk.ciphertexts
-            .keyswitch_ciphertext(&mut res.ciphertext, &self.ciphertext);
+        with_engine(|engine| {
+            engine
+                .discard_keyswitch_lwe_ciphertext(
+                    &mut res.ciphertext,
+                    &self.ciphertext,
+                    &ksk.ciphertexts,
+                )
+                .unwrap()
+        });
 
         // deal with encoders, noise and new precision
         // calls the NPE to find out the amount of noise after KS
-        res.variance = <Torus as NPELWE>::key_switch(
-            self.dimension,
-            ksk.level,
-            ksk.base_log,
-            ksk.variance,
-            self.variance,
-        );
+        res.variance =
+            estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<Torus, _, _, BinaryKeyKind>(
+                LweDimension(self.dimension),
+                Variance(self.variance),
+                Variance(ksk.variance),
+                DecompositionBaseLog(ksk.base_log),
+                DecompositionLevelCount(ksk.level),
+            )
+            .get_variance();
 
         // copy the encoders
         res.encoder.copy(&self.encoder);
@@ -1792,48 +1852,41 @@ impl LWE {
         // generate the look up table (throw error if a bit of padding is missing)
         let lut = bsk.generate_functional_look_up_table(&self.encoder, encoder_output, f)?;
 
-        // build the trivial accumulator
-        let mut accumulator = GlweCiphertext::allocate(
-            0_u64,
-            PolynomialSize(bsk.polynomial_size),
-            GlweSize(bsk.dimension + 1),
-        );
-        accumulator
-            .as_mut_tensor()
-            .as_mut_slice()
-            .get_mut(
-                (bsk.dimension * bsk.polynomial_size)..((bsk.dimension + 1) * bsk.polynomial_size),
-            )
-            .unwrap()
-            .copy_from_slice(&lut);
-
         // allocate the result
-        let mut result =
-            LweCiphertext::allocate(0, LweSize(bsk.dimension * bsk.polynomial_size + 1));
+        let mut result = crate::LWE::zero(bsk.dimension * bsk.polynomial_size)?;
 
+        // remove the padding but one bit
+        let mut input = self.clone();
         if self.encoder.nb_bit_padding > 1 {
-            // remove the padding but one bit
-            let mut self_clone = self.clone();
-            self_clone.remove_padding_inplace(self.encoder.nb_bit_padding - 1)?;
-
-            // compute the bootstrap
-            bsk.ciphertexts
-                .bootstrap(&mut result, &self_clone.ciphertext, & accumulator);
-        } else {
-            // compute the bootstrap
-            bsk.ciphertexts
-                .bootstrap(&mut result, &self.ciphertext, & accumulator);
+            input.remove_padding_inplace(self.encoder.nb_bit_padding - 1)?;
         }
 
+        // build the trivial accumulator and compute the bootstrap
+        with_engine(|engine| {
+            let lut = engine.create_plaintext_vector(&lut).unwrap();
+            let accumulator: GlweCiphertext64 = engine
+                .trivially_encrypt_glwe_ciphertext(GlweSize(bsk.dimension + 1), &lut)
+                .unwrap();
+            engine
+                .discard_bootstrap_lwe_ciphertext(
+                    &mut result.ciphertext,
+                    &input.ciphertext,
+                    &accumulator,
+                    &bsk.ciphertexts,
+                )
+                .unwrap();
+        });
+
         // compute the new variance (without the drift)
-        let new_var: f64 = <Torus as npe::cross::Cross>::bootstrap(
-            self.dimension,
-            bsk.dimension,
-            bsk.level,
-            bsk.base_log,
-            bsk.polynomial_size,
-            bsk.variance,
-        );
+        let new_var: f64 = estimate_pbs_noise::<Torus, _, BinaryKeyKind>(
+            LweDimension(self.dimension),
+            PolynomialSize(bsk.polynomial_size),
+            GlweDimension(bsk.dimension),
+            DecompositionBaseLog(bsk.base_log),
+            DecompositionLevelCount(bsk.level),
+            Variance(bsk.variance),
+        )
+        .get_variance();
 
         // create the output encoder
         let mut new_encoder_output: crate::Encoder = encoder_output.clone();
@@ -1852,7 +1905,7 @@ impl LWE {
 
         // calls the NPE to find out the amount of noise after rounding the input ciphertext (drift)
         let nb_rounding_noise_bit: usize =
-            (npe::lwe::log2_rounding_noise(self.dimension)).ceil() as usize + 1;
+            log2_rounding_noise(self.dimension, bsk.get_polynomial_size_log()).ceil() as usize + 1;
 
         // deals with the drift error
         if nb_rounding_noise_bit + 1 + new_encoder_output.nb_bit_precision
@@ -1875,14 +1928,10 @@ impl LWE {
         }
 
         // construct the output
-        let lwe = crate::LWE {
-            variance: new_var,
-            ciphertext: result,
-            dimension: bsk.polynomial_size * bsk.dimension,
-            encoder: new_encoder_output,
-        };
+        result.variance = new_var;
+        result.encoder = new_encoder_output;
 
-        Ok(lwe)
+        Ok(result)
     }
 
     /// Multiply two LWE ciphertexts thanks to two bootstrapping procedures
@@ -2031,46 +2080,115 @@ impl LWE {
         }
 
         // shift of nb bits to the left
-        self.ciphertext.as_mut_tensor().update_with_scalar_shl(&nb);
+        let coeff: Torus = 1 << nb;
+        self.mul_by_constant(coeff);
 
         // correction of the encoder
         self.encoder.nb_bit_padding -= nb;
 
         // call to the NPE to estimate the new variance
-        let coeff: Torus = 1 << nb;
-        self.variance = npe::LWE::single_scalar_mul(self.variance, coeff);
+        self.variance = estimate_integer_plaintext_multiplication_noise::<Torus, _>(
+            Variance(self.variance),
+            coeff,
+        )
+        .get_variance();
 
         // update the encoder precision based on the variance
         self.encoder.update_precision_from_variance(self.variance)?;
 
         Ok(())
     }
+
+    /// Decrypt the ciphertext without decoding it
+    fn phase(&self, sk: &crate::LWESecretKey) -> Torus {
+        with_engine(|engine| {
+            let plaintext = engine
+                .decrypt_lwe_ciphertext(&sk.val, &self.ciphertext)
+                .unwrap();
+            engine.retrieve_plaintext(&plaintext).unwrap()
+        })
+    }
+
+    /// Add the ciphertext of `ct` to the ciphertext of `self`
+    fn add_ciphertext(&mut self, ct: &crate::LWE) {
+        with_engine(|engine| {
+            engine
+                .fuse_add_lwe_ciphertext(&mut self.ciphertext, &ct.ciphertext)
+                .unwrap()
+        });
+    }
+
+    /// Subtract the ciphertext of `ct` from the ciphertext of `self`
+    fn sub_ciphertext(&mut self, ct: &crate::LWE) {
+        with_engine(|engine| {
+            engine
+                .fuse_sub_lwe_ciphertext(&mut self.ciphertext, &ct.ciphertext)
+                .unwrap()
+        });
+    }
+
+    /// Add `value` to the body of the ciphertext
+    fn add_to_body(&mut self, value: Torus) {
+        with_engine(|engine| {
+            let plaintext = engine.create_plaintext(&value).unwrap();
+            engine
+                .fuse_add_lwe_ciphertext_plaintext(&mut self.ciphertext, &plaintext)
+                .unwrap()
+        });
+    }
+
+    /// Multiply the whole ciphertext by `constant`
+    fn mul_by_constant(&mut self, constant: Torus) {
+        with_engine(|engine| {
+            let cleartext = engine.create_cleartext(&constant).unwrap();
+            engine
+                .fuse_mul_lwe_ciphertext_cleartext(&mut self.ciphertext, &cleartext)
+                .unwrap()
+        });
+    }
+
+    /// Negate the whole ciphertext
+    fn negate(&mut self) {
+        with_engine(|engine| {
+            engine
+                .fuse_neg_lwe_ciphertext(&mut self.ciphertext)
+                .unwrap()
+        });
+    }
+}
+
+/// Return the log2 of the standard deviation of the noise added when the mask of an LWE ciphertext
+/// of dimension `dimension` is rounded to fit a polynomial of size 2^`polynomial_size_log`
+pub(crate) fn log2_rounding_noise(dimension: usize, polynomial_size_log: usize) -> f64 {
+    let nb_msb = polynomial_size_log + 1;
+    let variance = estimate_modulus_switching_noise_with_binary_key::<Torus, _>(
+        LweDimension(dimension),
+        nb_msb,
+        Variance(0.),
+    );
+    f64::log2(variance.get_variance()) / 2. + nb_msb as f64
 }
 
 /// Print needed pieces of information about an LWE
 impl fmt::Display for LWE {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let n = 2;
+        let samples: Vec<Torus> = crate::raw::coefficients(&self.ciphertext);
         let mut to_be_print: String = "".to_string();
 
         to_be_print += " LWE {\n         -> samples = [";
 
-        if self.ciphertext.as_tensor().len() <= 2 * n {
-            for elt in self.ciphertext.as_tensor().iter() {
+        if samples.len() <= 2 * n {
+            for elt in samples.iter() {
                 to_be_print = to_be_print + &format!("{}, ", *elt);
             }
         } else {
-            for elt in self.ciphertext.as_tensor().get_sub(0..n).iter() {
+            for elt in samples[0..n].iter() {
                 to_be_print = to_be_print + &format!("{}, ", *elt);
             }
             to_be_print += "...";
 
-            for elt in self
-                .ciphertext
-                .as_tensor()
-                .get_sub(self.ciphertext.as_tensor().len() - n..)
-                .iter()
-            {
+            for elt in samples[samples.len() - n..].iter() {
                 to_be_print = to_be_print + &format!("{}, ", *elt);
             }
         }
@@ -1,6 +1,6 @@
 #![allow(clippy::modulo_one)]
 
-use crate::traits::GenericAdd;
+use crate::traits::{GenericAdd, HomomorphicMul, HomomorphicSub};
 
 #[test]
 fn test_encode_encrypt_x_decrypt() {
@@ -769,3 +769,253 @@ fn test_valid_lookup_table() {
         assert_eq_granularity!(cst, decryption_zero, ciphertext_output_zero.encoder);
     }
 }
+
+#[test]
+fn test_encode_encrypt_x_add_sub_operators_x_decrypt() {
+    // random settings
+    let (min1, max1) = generate_random_interval!();
+    let (min2, _max2) = generate_random_interval!();
+    let max2 = min2 + max1 - min1; // same interval size
+    let (precision, mut padding) = generate_precision_padding!(8, 3);
+    padding += 1; // at least one bit
+
+    // encoders
+    let encoder1 = crate::Encoder::new(min1, max1, precision, padding).unwrap();
+    let encoder2 = crate::Encoder::new(min2, max2, precision, padding).unwrap();
+
+    // generate a secret key
+    let secret_key = crate::LWESecretKey::new(&crate::LWE128_1024);
+
+    for _ in 0..100 {
+        // two messages
+        let message1: f64 = random_message!(min1, max1);
+        let message2: f64 = random_message!(min2, max2);
+
+        // encode and encrypt
+        let ciphertext1 = crate::LWE::encode_encrypt(&secret_key, message1, &encoder1).unwrap();
+        let ciphertext2 = crate::LWE::encode_encrypt(&secret_key, message2, &encoder2).unwrap();
+
+        // addition and subtraction with the operators
+        let sum = (&ciphertext1 + &ciphertext2).unwrap();
+        let difference = (&ciphertext1 - &ciphertext2).unwrap();
+
+        // decryption
+        let decryption_sum: f64 = sum.decrypt_decode_round(&secret_key).unwrap();
+        let decryption_difference: f64 = difference.decrypt_decode_round(&secret_key).unwrap();
+
+        // check the precision loss related to the encryption
+        assert_eq_granularity!(message1 + message2, decryption_sum, sum.encoder);
+        assert_eq_granularity!(
+            message1 - message2,
+            decryption_difference,
+            difference.encoder
+        );
+    }
+}
+
+#[test]
+fn test_encode_encrypt_x_neg_mul_operators_x_decrypt() {
+    // random settings
+    let (min, max) = generate_random_centered_interval!();
+    let (precision, padding) = generate_precision_padding!(6, 2);
+    let b = min.abs().min(max.abs()) / 20.;
+
+    // encoders
+    let encoder = crate::Encoder::new(min, max, precision, padding).unwrap();
+
+    // generate a secret key
+    let secret_key = crate::LWESecretKey::new(&crate::LWE128_1024);
+
+    for _ in 0..100 {
+        // two messages
+        let message1: f64 = random_message!(-b, b);
+        let message2_float: f64 = random_message!(-b, b);
+        let message2: i32 = message2_float as i32;
+
+        // encode and encrypt
+        let ciphertext = crate::LWE::encode_encrypt(&secret_key, message1, &encoder).unwrap();
+
+        // opposite and multiplication with the operators
+        let opposite = (-&ciphertext).unwrap();
+        let product = (&ciphertext * message2).unwrap();
+
+        // decryption
+        let decryption_opposite: f64 = opposite.decrypt_decode_round(&secret_key).unwrap();
+        let decryption_product: f64 = product.decrypt_decode_round(&secret_key).unwrap();
+
+        // check the precision loss related to the encryption
+        assert_eq_granularity!(-message1, decryption_opposite, opposite.encoder);
+        assert_eq_granularity!(
+            message1 * (message2 as f64),
+            decryption_product,
+            product.encoder
+        );
+    }
+}
+
+#[test]
+fn test_encode_encrypt_x_homomorphic_sub_mul_inplace_x_decrypt() {
+    // random settings
+    let (min, max) = generate_random_centered_interval!();
+    let (precision, mut padding) = generate_precision_padding!(6, 2);
+    padding += 1; // at least one bit
+    let b = min.abs().min(max.abs()) / 20.;
+
+    // encoders
+    let encoder = crate::Encoder::new(min, max, precision, padding).unwrap();
+
+    // generate a secret key
+    let secret_key = crate::LWESecretKey::new(&crate::LWE128_1024);
+
+    for _ in 0..100 {
+        // three messages
+        let message1: f64 = random_message!(-b, b);
+        let message2: f64 = random_message!(-b, b);
+        let message3_float: f64 = random_message!(-b, b);
+        let message3: i32 = message3_float as i32;
+
+        // encode and encrypt
+        let mut ciphertext1 = crate::LWE::encode_encrypt(&secret_key, message1, &encoder).unwrap();
+        let ciphertext2 = crate::LWE::encode_encrypt(&secret_key, message2, &encoder).unwrap();
+
+        // subtraction and multiplication by a constant with the traits
+        let difference = ciphertext1.homomorphic_sub(&ciphertext2).unwrap();
+        ciphertext1.homomorphic_mul_inplace(message3).unwrap();
+
+        // decryption
+        let decryption_difference: f64 = difference.decrypt_decode_round(&secret_key).unwrap();
+        let decryption_product: f64 = ciphertext1.decrypt_decode_round(&secret_key).unwrap();
+
+        // check the precision loss related to the encryption
+        assert_eq_granularity!(
+            message1 - message2,
+            decryption_difference,
+            difference.encoder
+        );
+        assert_eq_granularity!(
+            message1 * (message3 as f64),
+            decryption_product,
+            ciphertext1.encoder
+        );
+    }
+}
+
+#[test]
+fn test_encode_encrypt_x_chained_operators_x_decrypt() {
+    // random settings
+    let (min1, max1) = generate_random_interval!();
+    let (min2, _max2) = generate_random_interval!();
+    let max2 = min2 + max1 - min1; // same interval size
+    let (precision, mut padding) = generate_precision_padding!(8, 3);
+    padding += 2; // at least two bits
+
+    // encoders
+    let encoder1 = crate::Encoder::new(min1, max1, precision, padding).unwrap();
+    let encoder2 = crate::Encoder::new(min2, max2, precision, padding).unwrap();
+
+    // generate a secret key
+    let secret_key = crate::LWESecretKey::new(&crate::LWE128_1024);
+
+    for _ in 0..100 {
+        // two messages
+        let message1: f64 = random_message!(min1, max1);
+        let message2: f64 = random_message!(min2, max2);
+
+        // encode and encrypt
+        let ciphertext1 = crate::LWE::encode_encrypt(&secret_key, message1, &encoder1).unwrap();
+        let ciphertext2 = crate::LWE::encode_encrypt(&secret_key, message2, &encoder2).unwrap();
+
+        // a third message encoded like the sum of the two first ones
+        let encoder3 = (&ciphertext1 + &ciphertext2).unwrap().encoder;
+        let message3: f64 = random_message!(encoder3.o, encoder3.o + encoder3.get_size());
+        let ciphertext3 = crate::LWE::encode_encrypt(&secret_key, message3, &encoder3).unwrap();
+
+        // chained addition and subtraction with the operators
+        let result = (&ciphertext1 + &ciphertext2 - &ciphertext3).unwrap();
+        let opposite_result = (&ciphertext3 - (&ciphertext1 + &ciphertext2)).unwrap();
+
+        // decryption
+        let decryption: f64 = result.decrypt_decode_round(&secret_key).unwrap();
+        let opposite_decryption: f64 = opposite_result.decrypt_decode_round(&secret_key).unwrap();
+
+        // check the precision loss related to the encryption
+        assert_eq_granularity!(message1 + message2 - message3, decryption, result.encoder);
+        assert_eq_granularity!(
+            message3 - message1 - message2,
+            opposite_decryption,
+            opposite_result.encoder
+        );
+    }
+}
+
+#[test]
+fn test_encode_encrypt_x_chained_results_operators_x_decrypt() {
+    // random settings
+    let (min, max) = generate_random_centered_interval!();
+    let (precision, mut padding) = generate_precision_padding!(6, 2);
+    padding += 2; // at least two bits
+    let bound = min.abs().min(max.abs()) / 20.;
+
+    // encoder
+    let encoder = crate::Encoder::new(min, max, precision, padding).unwrap();
+
+    // generate a secret key
+    let secret_key = crate::LWESecretKey::new(&crate::LWE128_1024);
+
+    for _ in 0..100 {
+        // four messages and a constant
+        let messages: Vec<f64> = random_messages!(-bound, bound, 4);
+        let constant: i32 = random_index!(4) as i32;
+
+        // encode and encrypt
+        let ciphertexts: Vec<crate::LWE> = messages
+            .iter()
+            .map(|m| crate::LWE::encode_encrypt(&secret_key, *m, &encoder).unwrap())
+            .collect();
+        let (a, b, c, d) = (
+            &ciphertexts[0],
+            &ciphertexts[1],
+            &ciphertexts[2],
+            &ciphertexts[3],
+        );
+
+        // operators applied to the results of other operators
+        let sum = ((a + b) + (c + d)).unwrap();
+        let opposite = (-(a + b)).unwrap();
+        let product = ((a + b) * constant).unwrap();
+
+        // decryption
+        let decryption_sum: f64 = sum.decrypt_decode_round(&secret_key).unwrap();
+        let decryption_opposite: f64 = opposite.decrypt_decode_round(&secret_key).unwrap();
+        let decryption_product: f64 = product.decrypt_decode_round(&secret_key).unwrap();
+
+        // check the precision loss related to the encryption
+        assert_eq_granularity!(messages.iter().sum::<f64>(), decryption_sum, sum.encoder);
+        assert_eq_granularity!(
+            -messages[0] - messages[1],
+            decryption_opposite,
+            opposite.encoder
+        );
+        assert_eq_granularity!(
+            (messages[0] + messages[1]) * (constant as f64),
+            decryption_product,
+            product.encoder
+        );
+    }
+}
+
+#[test]
+fn test_operators_x_carry_the_first_error() {
+    // generate a secret key
+    let secret_key = crate::LWESecretKey::new(&crate::LWE128_1024);
+
+    // encoders with different interval sizes cannot be added
+    let encoder1 = crate::Encoder::new(0., 10., 4, 2).unwrap();
+    let encoder2 = crate::Encoder::new(0., 20., 4, 2).unwrap();
+    let ciphertext1 = crate::LWE::encode_encrypt(&secret_key, 1., &encoder1).unwrap();
+    let ciphertext2 = crate::LWE::encode_encrypt(&secret_key, 1., &encoder2).unwrap();
+
+    // the error goes through the rest of the expression
+    let result = -((&ciphertext1 + &ciphertext2) * 2 + &ciphertext1);
+    assert!(result.is_err());
+}
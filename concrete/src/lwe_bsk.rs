@@ -1,24 +1,20 @@
-use std::fmt;
+use super::{read_from_binary_file, with_engine, write_to_binary_file};
+use crate::error::CryptoAPIError;
+use crate::{raw, Torus};
 use backtrace::Backtrace;
 use colored::Colorize;
-use concrete_commons::dispersion::StandardDev;
+use concrete_commons::dispersion::Variance;
 use concrete_commons::numeric::Numeric;
-use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, PolynomialSize};
-use concrete_core::{
-    math::tensor::{AsMutTensor, AsRefTensor},
-    math::{fft::Complex64, tensor::Tensor},
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+use concrete_core::prelude::{
+    FourierLweBootstrapKey64, LweBootstrapKeyCreationEngine, LweBootstrapKeyEntity,
 };
-use concrete_core::crypto::bootstrap::{FourierBootstrapKey,StandardBootstrapKey};
-use concrete_core::crypto::secret::generators::EncryptionRandomGenerator;
-use concrete_core::math::fft::AlignedVec;
-use crate::error::CryptoAPIError;
-use crate::Torus;
-
-use serde::{Serialize, Deserialize};
+use serde::{Deserialize, Serialize};
+use std::fmt;
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct LWEBSK {
-    pub ciphertexts: FourierBootstrapKey<AlignedVec<Complex64>,u64>,
+    pub ciphertexts: FourierLweBootstrapKey64,
     pub variance: f64,
     pub dimension: usize,
     pub polynomial_size: usize,
@@ -29,8 +25,7 @@ pub struct LWEBSK {
 impl LWEBSK {
     /// Return the dimension of an LWE we can bootstrap with this key
     pub fn get_lwe_dimension(&self) -> usize {
-        self.ciphertexts.as_tensor().len()
-            / (usize::pow(self.dimension + 1, 2) * self.level * self.polynomial_size)
+        self.ciphertexts.input_lwe_dimension().0
     }
 
     /// Return the log2 of the polynomial size of the RLWE involved in the bootstrap
@@ -130,31 +125,17 @@ impl LWEBSK {
         base_log: usize,
         level: usize,
     ) -> LWEBSK {
-        // allocation for the bootstrapping key
-        let mut coef_bsk  = StandardBootstrapKey::allocate(
-            0_u64,
-            GlweSize(sk_output.val.key_size().0 + 1),
-            sk_output.val.polynomial_size(),
-            DecompositionLevelCount(level),
-            DecompositionBaseLog(base_log),
-            sk_input.val.key_size(),
-        );
-        coef_bsk.fill_with_new_key(
-            &sk_input.val,
-            &sk_output.val,
-            StandardDev::from_standard_dev(sk_output.std_dev),
-            &mut EncryptionRandomGenerator::new(None),
-        );
-        let mut fourier_bsk : FourierBootstrapKey<AlignedVec<Complex64>,u64>=
-            FourierBootstrapKey::allocate(
-            Complex64::new(0., 0.),
-            GlweSize(sk_output.val.key_size().0 + 1),
-            sk_output.val.polynomial_size(),
-            DecompositionLevelCount(level),
-            DecompositionBaseLog(base_log),
-            sk_input.val.key_size(),
-        );
-        fourier_bsk.fill_with_forward_fourier(&coef_bsk);
+        let fourier_bsk: FourierLweBootstrapKey64 = with_engine(|engine| {
+            engine
+                .create_lwe_bootstrap_key(
+                    &sk_input.val,
+                    &sk_output.val,
+                    DecompositionBaseLog(base_log),
+                    DecompositionLevelCount(level),
+                    Variance(f64::powi(sk_output.std_dev, 2)),
+                )
+                .unwrap()
+        });
 
         LWEBSK {
             ciphertexts: fourier_bsk,
@@ -183,13 +164,14 @@ impl LWEBSK {
         level: usize,
     ) -> LWEBSK {
         // allocation for the bootstrapping key
-        let fourier_bsk = FourierBootstrapKey::allocate(
-            Complex64::new(0., 0.),
-            GlweSize(sk_output.val.key_size().0 + 1),
-            sk_output.val.polynomial_size(),
-            DecompositionLevelCount(level),
-            DecompositionBaseLog(base_log),
-            sk_input.val.key_size(),
+        let glwe_size = sk_output.dimension + 1;
+        let len = sk_input.dimension * level * glwe_size * glwe_size * sk_output.polynomial_size;
+        let fourier_bsk = raw::fourier_lwe_bootstrap_key(
+            vec![(0., 0.); len],
+            sk_output.dimension,
+            sk_output.polynomial_size,
+            base_log,
+            level,
         );
 
         LWEBSK {
@@ -203,57 +185,48 @@ impl LWEBSK {
     }
 
     pub fn save(&self, path: &str) {
-        let mut tensor = Tensor::allocate(0, self.ciphertexts.as_tensor().len() * 2 + 6);
-
-        *tensor.get_element_mut(0) = self.variance.to_bits();
-        *tensor.get_element_mut(1) = self.dimension as u64;
-        *tensor.get_element_mut(2) = self.polynomial_size as u64;
-        *tensor.get_element_mut(3) = self.base_log as u64;
-        *tensor.get_element_mut(4) = self.level as u64;
-        *tensor.get_element_mut(5) = self.ciphertexts.key_size().0 as u64;
+        let ciphertexts: Vec<(f64, f64)> = raw::coefficients(&self.ciphertexts);
+        let mut tensor: Vec<u64> = vec![0; 6];
 
-        for (mut couple, c) in tensor
-            .get_sub_mut(6..(self.ciphertexts.as_tensor().len() * 2 + 6))
-            .subtensor_iter_mut(2)
-            .zip(self.ciphertexts.as_tensor().iter())
-        {
-            *couple.get_element_mut(0) = c.re.to_bits();
-            *couple.get_element_mut(1) = c.im.to_bits();
+        tensor[0] = self.variance.to_bits();
+        tensor[1] = self.dimension as u64;
+        tensor[2] = self.polynomial_size as u64;
+        tensor[3] = self.base_log as u64;
+        tensor[4] = self.level as u64;
+        tensor[5] = self.get_lwe_dimension() as u64;
+        for (re, im) in ciphertexts.iter() {
+            tensor.push(re.to_bits());
+            tensor.push(im.to_bits());
         }
 
-        tensor.save_to_file(path).unwrap();
+        write_to_binary_file(path, &tensor).unwrap();
     }
 
     pub fn load(path: &str) -> crate::LWEBSK {
-        let tensor = Tensor::load_from_file(path).expect("Failed to load file");
+        let tensor: Vec<u64> = read_from_binary_file(path).expect("Failed to load file");
 
-        let mut res = crate::LWEBSK {
-            variance: f64::from_bits(*tensor.get_element(0)),
-            dimension: *tensor.get_element(1) as usize,
-            polynomial_size: *tensor.get_element(2) as usize,
-            base_log: *tensor.get_element(3) as usize,
-            level: *tensor.get_element(4) as usize,
-            ciphertexts: FourierBootstrapKey::allocate(
-                Complex64::new(0., 0.),
-                GlweSize(*tensor.get_element(1) as usize + 1),
-                PolynomialSize(*tensor.get_element(2) as usize),
-                DecompositionLevelCount(*tensor.get_element(4) as usize),
-                DecompositionBaseLog(*tensor.get_element(3) as usize),
-                LweDimension(*tensor.get_element(5) as usize),
+        let dimension = tensor[1] as usize;
+        let polynomial_size = tensor[2] as usize;
+        let base_log = tensor[3] as usize;
+        let level = tensor[4] as usize;
+        let ciphertexts: Vec<(f64, f64)> = tensor[6..]
+            .chunks(2)
+            .map(|couple| (f64::from_bits(couple[0]), f64::from_bits(couple[1])))
+            .collect();
+        crate::LWEBSK {
+            variance: f64::from_bits(tensor[0]),
+            dimension,
+            polynomial_size,
+            base_log,
+            level,
+            ciphertexts: raw::fourier_lwe_bootstrap_key(
+                ciphertexts,
+                dimension,
+                polynomial_size,
+                base_log,
+                level,
             ),
-        };
-
-        for (couple, c) in tensor
-            .get_sub(6..)
-            .subtensor_iter(2)
-            .zip(res.ciphertexts.as_mut_tensor().iter_mut())
-        {
-            *c = Complex64::new(
-                f64::from_bits(*couple.get_element(0)),
-                f64::from_bits(*couple.get_element(1)),
-            );
         }
-        res
     }
 }
 
@@ -261,27 +234,23 @@ impl LWEBSK {
 impl fmt::Display for LWEBSK {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let n = 2;
+        let samples: Vec<(f64, f64)> = raw::coefficients(&self.ciphertexts);
         let mut to_be_print: String = "".to_string();
 
         to_be_print += " LWEBSK {\n         -> samples = [";
 
-        if self.ciphertexts.as_tensor().len() <= 2 * n {
-            for elt in self.ciphertexts.as_tensor().iter() {
-                to_be_print += &format!("{}, ", *elt);
+        if samples.len() <= 2 * n {
+            for (re, im) in samples.iter() {
+                to_be_print = to_be_print + &format!("{}, ", format_complex(*re, *im));
             }
         } else {
-            for elt in self.ciphertexts.as_tensor().get_sub(0..n).iter() {
-                to_be_print += &format!("{}, ", *elt);
+            for (re, im) in samples[0..n].iter() {
+                to_be_print = to_be_print + &format!("{}, ", format_complex(*re, *im));
             }
             to_be_print += "...";
 
-            for elt in self
-                .ciphertexts
-                .as_tensor()
-                .get_sub(self.ciphertexts.as_tensor().len() - n..)
-                .iter()
-            {
-                to_be_print += &format!("{}, ", *elt);
+            for (re, im) in samples[samples.len() - n..].iter() {
+                to_be_print = to_be_print + &format!("{}, ", format_complex(*re, *im));
             }
         }
         to_be_print += "]\n";
@@ -296,3 +265,12 @@ impl fmt::Display for LWEBSK {
         writeln!(f, "{}", to_be_print)
     }
 }
+
+/// Format a complex number like `num_complex` does
+fn format_complex(re: f64, im: f64) -> String {
+    if im < 0. {
+        format!("{}-{}i", re, -im)
+    } else {
+        format!("{}+{}i", re, im)
+    }
+}
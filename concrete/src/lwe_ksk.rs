@@ -1,18 +1,14 @@
-use crate::Torus;
-use concrete_core::{
-    crypto,
-    math::tensor::Tensor,
-    math::tensor::{AsMutTensor, AsRefTensor},
-};
+use super::{read_from_binary_file, with_engine, write_to_binary_file};
+use crate::{raw, Torus};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+use concrete_core::prelude::{LweKeyswitchKey64, LweKeyswitchKeyCreationEngine};
 use serde::{Deserialize, Serialize};
 use std::fmt;
-use concrete_commons::dispersion::StandardDev;
-use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
-use concrete_core::crypto::secret::generators::EncryptionRandomGenerator;
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct LWEKSK {
-    pub ciphertexts: crypto::lwe::LweKeyswitchKey<Vec<Torus>>,
+    pub ciphertexts: LweKeyswitchKey64,
     pub base_log: usize,
     pub level: usize,
     pub dimension_before: usize,
@@ -37,12 +33,11 @@ impl LWEKSK {
         base_log: usize,
         level: usize,
     ) -> LWEKSK {
-        let ksk_ciphertexts = crypto::lwe::LweKeyswitchKey::allocate(
-            0,
-            DecompositionLevelCount(level),
-            DecompositionBaseLog(base_log),
-            LweDimension(sk_before.dimension),
-            LweDimension(sk_after.dimension),
+        let ksk_ciphertexts = raw::lwe_keyswitch_key(
+            vec![0; sk_before.dimension * level * (sk_after.dimension + 1)],
+            base_log,
+            level,
+            sk_after.dimension,
         );
 
         LWEKSK {
@@ -70,20 +65,17 @@ impl LWEKSK {
         base_log: usize,
         level: usize,
     ) -> LWEKSK {
-        let mut ksk_ciphertexts = crypto::lwe::LweKeyswitchKey::allocate(
-            0_u64,
-            DecompositionLevelCount(level),
-            DecompositionBaseLog(base_log),
-            LweDimension(sk_before.dimension),
-            LweDimension(sk_after.dimension),
-        );
-
-        ksk_ciphertexts.fill_with_keyswitch_key(
-            &sk_before.val,
-            &sk_after.val,
-            StandardDev::from_standard_dev(sk_after.std_dev),
-            &mut EncryptionRandomGenerator::new(None),
-        );
+        let ksk_ciphertexts: LweKeyswitchKey64 = with_engine(|engine| {
+            engine
+                .create_lwe_keyswitch_key(
+                    &sk_before.val,
+                    &sk_after.val,
+                    DecompositionLevelCount(level),
+                    DecompositionBaseLog(base_log),
+                    Variance(f64::powi(sk_after.std_dev, 2)),
+                )
+                .unwrap()
+        });
 
         LWEKSK {
             ciphertexts: ksk_ciphertexts,
@@ -96,48 +88,39 @@ impl LWEKSK {
     }
 
     pub fn save(&self, path: &str) {
-        let mut tensor = Tensor::allocate(0, self.ciphertexts.as_tensor().len() + 6);
-
-        *tensor.get_element_mut(0) = self.variance.to_bits();
-        *tensor.get_element_mut(1) = self.dimension_before as u64;
-        *tensor.get_element_mut(2) = self.dimension_after as u64;
-        *tensor.get_element_mut(3) = self.base_log as u64;
-        *tensor.get_element_mut(4) = self.level as u64;
-        *tensor.get_element_mut(5) = self.ciphertexts.as_tensor().len() as u64;
-
-        for (dst, src) in tensor
-            .get_sub_mut(6..(self.ciphertexts.as_tensor().len() + 6))
-            .iter_mut()
-            .zip(self.ciphertexts.as_tensor().iter())
-        {
-            *dst = *src;
-        }
-        tensor.save_to_file(path).unwrap();
+        let ciphertexts: Vec<Torus> = raw::coefficients(&self.ciphertexts);
+        let mut tensor: Vec<u64> = vec![0; 6];
+
+        tensor[0] = self.variance.to_bits();
+        tensor[1] = self.dimension_before as u64;
+        tensor[2] = self.dimension_after as u64;
+        tensor[3] = self.base_log as u64;
+        tensor[4] = self.level as u64;
+        tensor[5] = ciphertexts.len() as u64;
+        tensor.extend_from_slice(&ciphertexts);
+
+        write_to_binary_file(path, &tensor).unwrap();
     }
 
     pub fn load(path: &str) -> crate::LWEKSK {
-        let tensor: Tensor<Vec<u64>> = Tensor::load_from_file(path).unwrap();
-
-        let mut res = crate::LWEKSK {
-            variance: f64::from_bits(*tensor.get_element(0)),
-            dimension_before: *tensor.get_element(1) as usize,
-            dimension_after: *tensor.get_element(2) as usize,
-            base_log: *tensor.get_element(3) as usize,
-            level: *tensor.get_element(4) as usize,
-            ciphertexts: crypto::lwe::LweKeyswitchKey::allocate(
-                0,
-                DecompositionLevelCount(*tensor.get_element(4) as usize),
-                DecompositionBaseLog(*tensor.get_element(3) as usize),
-                LweDimension(*tensor.get_element(1) as usize),
-                LweDimension(*tensor.get_element(2) as usize),
+        let tensor: Vec<u64> = read_from_binary_file(path).unwrap();
+
+        let dimension_after = tensor[2] as usize;
+        let base_log = tensor[3] as usize;
+        let level = tensor[4] as usize;
+        crate::LWEKSK {
+            variance: f64::from_bits(tensor[0]),
+            dimension_before: tensor[1] as usize,
+            dimension_after,
+            base_log,
+            level,
+            ciphertexts: raw::lwe_keyswitch_key(
+                tensor[6..].to_vec(),
+                base_log,
+                level,
+                dimension_after,
             ),
-        };
-
-        res.ciphertexts
-            .as_mut_tensor()
-            .fill_with_one(&tensor.get_sub(6..), |v| *v);
-
-        res
+        }
     }
 }
 
@@ -145,26 +128,22 @@ impl LWEKSK {
 impl fmt::Display for LWEKSK {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let n = 2;
+        let samples: Vec<Torus> = raw::coefficients(&self.ciphertexts);
         let mut to_be_print: String = "".to_string();
 
         to_be_print += " LWEBSK {\n         -> samples = [";
 
-        if self.ciphertexts.as_tensor().len() <= 2 * n {
-            for elt in self.ciphertexts.as_tensor().iter() {
+        if samples.len() <= 2 * n {
+            for elt in samples.iter() {
                 to_be_print = to_be_print + &format!("{}, ", *elt);
             }
         } else {
-            for elt in self.ciphertexts.as_tensor().get_sub(0..n).iter() {
+            for elt in samples[0..n].iter() {
                 to_be_print = to_be_print + &format!("{}, ", *elt);
             }
             to_be_print += "...";
 
-            for elt in self
-                .ciphertexts
-                .as_tensor()
-                .get_sub(self.ciphertexts.as_tensor().len() - n..)
-                .iter()
-            {
+            for elt in samples[samples.len() - n..].iter() {
                 to_be_print = to_be_print + &format!("{}, ", *elt);
             }
         }
@@ -1,21 +1,18 @@
-use super::{read_from_file, write_to_file};
+use super::{read_from_file, with_engine, write_to_file};
 use crate::error::CryptoAPIError;
 use backtrace::Backtrace;
 use colored::Colorize;
-use concrete_core::{
-    crypto::secret::{GlweSecretKey, LweSecretKey},
-    math::tensor::IntoTensor,
+use concrete_commons::parameters::{LweDimension, PolynomialSize};
+use concrete_core::prelude::{
+    LweSecretKey64, LweSecretKeyCreationEngine, LweToGlweSecretKeyTransmutationEngine,
 };
 use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::fmt;
-use concrete_commons::key_kinds::BinaryKeyKind;
-use concrete_commons::parameters::{LweDimension, PolynomialSize};
-use concrete_core::crypto::secret::generators::SecretRandomGenerator;
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct LWESecretKey {
-    pub val: LweSecretKey<BinaryKeyKind, Vec<u64>>,
+    pub val: LweSecretKey64,
     pub dimension: usize,
     pub std_dev: f64,
 }
@@ -27,8 +24,11 @@ impl LWESecretKey {
     /// # Output
     /// * a new LWESecretKey
     pub fn new(params: &crate::LWEParams) -> LWESecretKey {
-        let val =  LweSecretKey::generate_binary(LweDimension(params.dimension), &mut SecretRandomGenerator::new
-            (None));
+        let val = with_engine(|engine| {
+            engine
+                .create_lwe_secret_key(LweDimension(params.dimension))
+                .unwrap()
+        });
         LWESecretKey {
             val,
             dimension: params.dimension,
@@ -43,8 +43,11 @@ impl LWESecretKey {
     /// # Output
     /// * a new LWESecretKey
     pub fn new_raw(dimension: usize, std_dev: f64) -> LWESecretKey {
-        let val = LweSecretKey::generate_binary(LweDimension(dimension), &mut SecretRandomGenerator::new
-            (None));
+        let val = with_engine(|engine| {
+            engine
+                .create_lwe_secret_key(LweDimension(dimension))
+                .unwrap()
+        });
         LWESecretKey {
             val,
             dimension,
@@ -64,11 +67,16 @@ impl LWESecretKey {
         if self.dimension % polynomial_size != 0 {
             return Err(LweToRlweError!(self.dimension, polynomial_size));
         }
+        let val = with_engine(|engine| {
+            engine
+                .transmute_lwe_secret_key_to_glwe_secret_key(
+                    self.val.clone(),
+                    PolynomialSize(polynomial_size),
+                )
+                .unwrap()
+        });
         Ok(crate::RLWESecretKey {
-            val: GlweSecretKey::binary_from_container(
-                self.val.clone().into_tensor().into_container(),
-                PolynomialSize(polynomial_size),
-            ),
+            val,
             dimension: self.dimension / polynomial_size,
             polynomial_size,
             std_dev: self.std_dev,
@@ -0,0 +1,162 @@
+//! Arithmetic operators on ciphertexts
+//!
+//! The `Add`, `Sub`, `Neg` and `Mul<i32>` operators are implemented for references to `LWE`,
+//! `VectorLWE` and `VectorRLWE`, and return a [`HomomorphicResult`]. A `HomomorphicResult` is
+//! itself an operand of these operators, so expressions such as `(&a + &b) * 3 - &c` can be
+//! written directly: the first error is carried until the end of the expression.
+
+use crate::error::CryptoAPIError;
+
+/// The result of an arithmetic operator applied to ciphertexts.
+///
+/// # Example
+/// ```rust
+/// use concrete::*;
+///
+/// // encoder
+/// let encoder = Encoder::new(-10., 10., 6, 4).unwrap();
+///
+/// // generate a secret key
+/// let secret_key = LWESecretKey::new(&LWE128_1024);
+///
+/// // encode and encrypt
+/// let ciphertext_1 = LWE::encode_encrypt(&secret_key, 2.5, &encoder).unwrap();
+/// let ciphertext_2 = LWE::encode_encrypt(&secret_key, -1.2, &encoder).unwrap();
+///
+/// // chain the operators
+/// let result: LWE = (-(&ciphertext_1 + &ciphertext_2) * 2).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct HomomorphicResult<T>(pub Result<T, CryptoAPIError>);
+
+impl<T> HomomorphicResult<T> {
+    /// Return the result of the operators as a standard `Result`
+    pub fn into_result(self) -> Result<T, CryptoAPIError> {
+        self.0
+    }
+
+    /// Return the output ciphertext
+    ///
+    /// # Panics
+    /// This function panics if one of the operators failed.
+    pub fn unwrap(self) -> T {
+        self.0.unwrap()
+    }
+
+    /// Return true if none of the operators failed
+    pub fn is_ok(&self) -> bool {
+        self.0.is_ok()
+    }
+
+    /// Return true if one of the operators failed
+    pub fn is_err(&self) -> bool {
+        self.0.is_err()
+    }
+}
+
+impl<T> From<Result<T, CryptoAPIError>> for HomomorphicResult<T> {
+    fn from(result: Result<T, CryptoAPIError>) -> Self {
+        HomomorphicResult(result)
+    }
+}
+
+impl<T> From<HomomorphicResult<T>> for Result<T, CryptoAPIError> {
+    fn from(result: HomomorphicResult<T>) -> Self {
+        result.0
+    }
+}
+
+/// The in-place operations the arithmetic operators are built upon
+pub(crate) trait Operand: Clone {
+    fn add_operand(&mut self, right: &Self) -> Result<(), CryptoAPIError>;
+    fn sub_operand(&mut self, right: &Self) -> Result<(), CryptoAPIError>;
+    fn neg_operand(&mut self) -> Result<(), CryptoAPIError>;
+    fn mul_operand(&mut self, right: i32) -> Result<(), CryptoAPIError>;
+}
+
+/// Implement the arithmetic operators for a type implementing `Operand`
+macro_rules! impl_operators {
+    ($T: ty) => {
+        impl_operators!(@binary $T, Add, add, add_operand);
+        impl_operators!(@binary $T, Sub, sub, sub_operand);
+
+        impl std::ops::Neg for &$T {
+            type Output = $crate::operators::HomomorphicResult<$T>;
+
+            fn neg(self) -> Self::Output {
+                -$crate::operators::HomomorphicResult(Ok(self.clone()))
+            }
+        }
+
+        impl std::ops::Neg for $crate::operators::HomomorphicResult<$T> {
+            type Output = $crate::operators::HomomorphicResult<$T>;
+
+            fn neg(self) -> Self::Output {
+                $crate::operators::HomomorphicResult(self.0.and_then(|mut res| {
+                    $crate::operators::Operand::neg_operand(&mut res)?;
+                    Ok(res)
+                }))
+            }
+        }
+
+        impl std::ops::Mul<i32> for &$T {
+            type Output = $crate::operators::HomomorphicResult<$T>;
+
+            fn mul(self, right: i32) -> Self::Output {
+                $crate::operators::HomomorphicResult(Ok(self.clone())) * right
+            }
+        }
+
+        impl std::ops::Mul<i32> for $crate::operators::HomomorphicResult<$T> {
+            type Output = $crate::operators::HomomorphicResult<$T>;
+
+            fn mul(self, right: i32) -> Self::Output {
+                $crate::operators::HomomorphicResult(self.0.and_then(|mut res| {
+                    $crate::operators::Operand::mul_operand(&mut res, right)?;
+                    Ok(res)
+                }))
+            }
+        }
+    };
+    (@binary $T: ty, $Op: ident, $op: ident, $operand: ident) => {
+        impl std::ops::$Op<&$T> for &$T {
+            type Output = $crate::operators::HomomorphicResult<$T>;
+
+            fn $op(self, right: &$T) -> Self::Output {
+                std::ops::$Op::$op($crate::operators::HomomorphicResult(Ok(self.clone())), right)
+            }
+        }
+
+        impl std::ops::$Op<$crate::operators::HomomorphicResult<$T>> for &$T {
+            type Output = $crate::operators::HomomorphicResult<$T>;
+
+            fn $op(self, right: $crate::operators::HomomorphicResult<$T>) -> Self::Output {
+                std::ops::$Op::$op($crate::operators::HomomorphicResult(Ok(self.clone())), right)
+            }
+        }
+
+        impl std::ops::$Op<&$T> for $crate::operators::HomomorphicResult<$T> {
+            type Output = $crate::operators::HomomorphicResult<$T>;
+
+            fn $op(self, right: &$T) -> Self::Output {
+                $crate::operators::HomomorphicResult(self.0.and_then(|mut res| {
+                    $crate::operators::Operand::$operand(&mut res, right)?;
+                    Ok(res)
+                }))
+            }
+        }
+
+        impl std::ops::$Op<$crate::operators::HomomorphicResult<$T>>
+            for $crate::operators::HomomorphicResult<$T>
+        {
+            type Output = $crate::operators::HomomorphicResult<$T>;
+
+            fn $op(self, right: $crate::operators::HomomorphicResult<$T>) -> Self::Output {
+                match right.0 {
+                    Ok(right) => std::ops::$Op::$op(self, &right),
+                    Err(err) => $crate::operators::HomomorphicResult(self.0.and(Err(err))),
+                }
+            }
+        }
+    };
+}
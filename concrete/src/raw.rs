@@ -0,0 +1,79 @@
+//! Raw coefficients of the `concrete-core` entities
+//!
+//! The entities of `concrete-core` do not expose their coefficients, but they can be serialized.
+//! Their serialized form always starts with the list of their coefficients, followed by their
+//! parameters. The functions of this module go through this form to read the coefficients of an
+//! entity, or to build an entity from a list of coefficients.
+
+use crate::Torus;
+use concrete_core::prelude::{FourierLweBootstrapKey64, LweKeyswitchKey64};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// The leading list of coefficients of a serialized entity
+#[derive(Deserialize)]
+struct Coefficients<T> {
+    tensor: Vec<T>,
+}
+
+/// The serialized form of an `LweKeyswitchKey64`
+#[derive(Serialize)]
+struct RawLweKeyswitchKey {
+    tensor: Vec<Torus>,
+    decomp_base_log: usize,
+    decomp_level_count: usize,
+    lwe_size: usize,
+}
+
+/// The serialized form of a `FourierLweBootstrapKey64`
+#[derive(Serialize)]
+struct RawFourierLweBootstrapKey {
+    tensor: Vec<(f64, f64)>,
+    poly_size: usize,
+    glwe_size: usize,
+    decomp_level: usize,
+    decomp_base_log: usize,
+}
+
+/// Return the coefficients of an entity whose coefficients are of type `T`
+pub(crate) fn coefficients<E: Serialize, T: DeserializeOwned>(entity: &E) -> Vec<T> {
+    let bytes = bincode::serialize(entity).unwrap();
+    bincode::deserialize::<Coefficients<T>>(&bytes)
+        .unwrap()
+        .tensor
+}
+
+/// Build a key switching key from its coefficients
+pub(crate) fn lwe_keyswitch_key(
+    coefficients: Vec<Torus>,
+    base_log: usize,
+    level: usize,
+    dimension_after: usize,
+) -> LweKeyswitchKey64 {
+    let raw = RawLweKeyswitchKey {
+        tensor: coefficients,
+        decomp_base_log: base_log,
+        decomp_level_count: level,
+        lwe_size: dimension_after + 1,
+    };
+    bincode::deserialize(&bincode::serialize(&raw).unwrap()).unwrap()
+}
+
+/// Build a bootstrapping key from its coefficients in the Fourier domain, as (real, imaginary)
+/// couples
+pub(crate) fn fourier_lwe_bootstrap_key(
+    coefficients: Vec<(f64, f64)>,
+    dimension: usize,
+    polynomial_size: usize,
+    base_log: usize,
+    level: usize,
+) -> FourierLweBootstrapKey64 {
+    let raw = RawFourierLweBootstrapKey {
+        tensor: coefficients,
+        poly_size: polynomial_size,
+        glwe_size: dimension + 1,
+        decomp_level: level,
+        decomp_base_log: base_log,
+    };
+    bincode::deserialize(&bincode::serialize(&raw).unwrap()).unwrap()
+}
@@ -0,0 +1,73 @@
+use super::{read_from_file, with_engine, write_to_file};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+use concrete_core::prelude::{GlweRelinearizationKey64, GlweRelinearizationKeyCreationEngine};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct RLWERLK {
+    pub val: GlweRelinearizationKey64,
+    pub polynomial_size: usize,
+    pub dimension: usize,
+    pub base_log: usize,
+    pub level: usize,
+    pub variance: f64,
+}
+
+impl RLWERLK {
+    /// Generate a valid RLWE relinearization key
+    /// # Argument
+    /// * `sk` - an RLWE secret key (used to encrypt the input and the output of the relinearization)
+    /// * `base_log` - the log2 of the decomposition base
+    /// * `level` - the number of levels of the decomposition
+    ///
+    /// # Output
+    /// * an RLWERLK
+    pub fn new(sk: &crate::RLWESecretKey, base_log: usize, level: usize) -> RLWERLK {
+        let val: GlweRelinearizationKey64 = with_engine(|engine| {
+            engine
+                .create_glwe_relinearization_key(
+                    &sk.val,
+                    DecompositionLevelCount(level),
+                    DecompositionBaseLog(base_log),
+                    Variance(sk.get_variance()),
+                )
+                .unwrap()
+        });
+
+        RLWERLK {
+            val,
+            polynomial_size: sk.polynomial_size,
+            dimension: sk.dimension,
+            base_log,
+            level,
+            variance: sk.get_variance(),
+        }
+    }
+
+    pub fn save(&self, path: &str) {
+        write_to_file(path, self).unwrap();
+    }
+
+    pub fn load(path: &str) -> crate::RLWERLK {
+        read_from_file(path).unwrap()
+    }
+}
+
+/// Print needed pieces of information about an RLWERLK
+impl fmt::Display for RLWERLK {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut to_be_print: String = "".to_string();
+
+        to_be_print += " RLWERLK {\n";
+        to_be_print = to_be_print + &format!("         -> variance = {}\n", self.variance);
+        to_be_print = to_be_print + &format!("         -> dimension = {}\n", self.dimension);
+        to_be_print =
+            to_be_print + &format!("         -> polynomial_size = {}\n", self.polynomial_size);
+        to_be_print = to_be_print + &format!("         -> base_log = {}\n", self.base_log);
+        to_be_print = to_be_print + &format!("         -> level = {}\n", self.level);
+        to_be_print += "       }";
+        writeln!(f, "{}", to_be_print)
+    }
+}
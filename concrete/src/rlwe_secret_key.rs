@@ -1,18 +1,15 @@
-use super::{read_from_file, write_to_file};
-use concrete_core::{
-    crypto::secret::{GlweSecretKey, LweSecretKey},
-    math::tensor::IntoTensor,
+use super::{read_from_file, with_engine, write_to_file};
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+use concrete_core::prelude::{
+    GlweSecretKey64, GlweSecretKeyCreationEngine, GlweToLweSecretKeyTransmutationEngine,
 };
 use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::fmt;
-use concrete_commons::key_kinds::BinaryKeyKind;
-use concrete_commons::parameters::{GlweDimension, PolynomialSize};
-use concrete_core::crypto::secret::generators::SecretRandomGenerator;
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct RLWESecretKey {
-    pub val: GlweSecretKey<BinaryKeyKind, Vec<u64>> ,
+    pub val: GlweSecretKey64,
     pub polynomial_size: usize,
     pub dimension: usize,
     pub std_dev: f64,
@@ -25,11 +22,14 @@ impl RLWESecretKey {
     /// # Output
     /// * a new RLWESecretKey
     pub fn new(params: &crate::RLWEParams) -> RLWESecretKey {
-        let val = GlweSecretKey::generate_binary(
-            GlweDimension(params.dimension),
-            PolynomialSize(params.polynomial_size),
-            &mut SecretRandomGenerator::new(None),
-        );
+        let val = with_engine(|engine| {
+            engine
+                .create_glwe_secret_key(
+                    GlweDimension(params.dimension),
+                    PolynomialSize(params.polynomial_size),
+                )
+                .unwrap()
+        });
         RLWESecretKey {
             val,
             polynomial_size: params.polynomial_size,
@@ -45,11 +45,11 @@ impl RLWESecretKey {
     /// # Output
     /// * a new RLWESecretKey
     pub fn new_raw(polynomial_size: usize, dimension: usize, std_dev: f64) -> RLWESecretKey {
-        let val = GlweSecretKey::generate_binary(
-            GlweDimension(dimension),
-            PolynomialSize(polynomial_size),
-            &mut SecretRandomGenerator::new(None),
-        );
+        let val = with_engine(|engine| {
+            engine
+                .create_glwe_secret_key(GlweDimension(dimension), PolynomialSize(polynomial_size))
+                .unwrap()
+        });
         RLWESecretKey {
             val,
             polynomial_size,
@@ -62,8 +62,13 @@ impl RLWESecretKey {
     /// # Output
     /// * an LWE secret key
     pub fn to_lwe_secret_key(&self) -> crate::LWESecretKey {
+        let val = with_engine(|engine| {
+            engine
+                .transmute_glwe_secret_key_to_lwe_secret_key(self.val.clone())
+                .unwrap()
+        });
         crate::LWESecretKey {
-            val: LweSecretKey::binary_from_container(self.val.clone().into_tensor().into_container()),
+            val,
             dimension: self.dimension * self.polynomial_size,
             std_dev: self.std_dev,
         }
@@ -1,15 +1,9 @@
 use std::fs::remove_file;
 use std::path::Path;
 
-use concrete_commons::parameters::{
-    DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, PolynomialSize,
-};
-use concrete_core::crypto::bootstrap::FourierBootstrapKey;
-use concrete_core::crypto::lwe::LweKeyswitchKey;
-use concrete_core::math::fft::Complex64;
-
 use crate::{
-    Encoder, LWEParams, LWESecretKey, RLWEParams, RLWESecretKey, VectorLWE, LWEBSK, LWEKSK,
+    raw, write_to_binary_file, Encoder, LWEParams, LWESecretKey, RLWEParams, RLWESecretKey,
+    VectorLWE, LWEBSK, LWEKSK,
 };
 
 fn delete_file<P: AsRef<Path>>(path: P) -> std::io::Result<()> {
@@ -47,13 +41,12 @@ fn test_lwebsk_save() {
     // sk_input.key_size: 20
 
     let a = LWEBSK {
-        ciphertexts: FourierBootstrapKey::allocate(
-            Complex64::new(2., 0.),
-            GlweSize(2),
-            PolynomialSize(512),
-            DecompositionLevelCount(4),
-            DecompositionBaseLog(5),
-            LweDimension(20),
+        ciphertexts: raw::fourier_lwe_bootstrap_key(
+            vec![(2., 0.); 20 * 4 * 2 * 2 * 512],
+            1,
+            512,
+            5,
+            4,
         ),
         variance: 0.5,
         dimension: 1,
@@ -72,13 +65,7 @@ fn test_lwebsk_save() {
 fn test_lweksk_save() {
     let filename: &str = "lweksk.json";
     let ksk1 = LWEKSK {
-        ciphertexts: LweKeyswitchKey::allocate(
-            0,
-            DecompositionLevelCount(7),
-            DecompositionBaseLog(2),
-            LweDimension(1024),
-            LweDimension(512),
-        ),
+        ciphertexts: raw::lwe_keyswitch_key(vec![0; 1024 * 7 * 513], 2, 7, 512),
         variance: 0.5,
         dimension_before: 1024,
         dimension_after: 512,
@@ -159,6 +146,46 @@ fn test_rlwesecretkey_save() {
     assert!(sk1 == sk2);
 }
 
+#[test]
+fn test_lweksk_load_baseline_layout() {
+    let filename: &str = "lweksk_baseline.bin";
+
+    // a key switching key with a header and its coefficients, as written by the previous versions
+    let coefficients: Vec<u64> = (0..(4 * 2 * 3)).collect();
+    let mut tensor: Vec<u64> = vec![0.25_f64.to_bits(), 4, 2, 3, 2, coefficients.len() as u64];
+    tensor.extend_from_slice(&coefficients);
+    write_to_binary_file(filename, &tensor).unwrap();
+
+    let ksk = LWEKSK::load(filename);
+    delete_file(filename).unwrap();
+    assert_eq!(ksk.variance, 0.25);
+    assert_eq!(ksk.dimension_before, 4);
+    assert_eq!(ksk.dimension_after, 2);
+    assert_eq!(ksk.base_log, 3);
+    assert_eq!(ksk.level, 2);
+    assert_eq!(raw::coefficients::<_, u64>(&ksk.ciphertexts), coefficients);
+}
+
+#[test]
+fn test_lwebsk_lweksk_save_generated_keys() {
+    let bsk_filename: &str = "lwebsk_generated.bin";
+    let ksk_filename: &str = "lweksk_generated.bin";
+
+    let lwe_sk = LWESecretKey::new(&crate::LWE128_630);
+    let rlwe_sk = RLWESecretKey::new(&crate::RLWE128_1024_1);
+    let bsk1 = LWEBSK::new(&lwe_sk, &rlwe_sk, 6, 4);
+    let ksk1 = LWEKSK::new(&rlwe_sk.to_lwe_secret_key(), &lwe_sk, 2, 7);
+
+    bsk1.save(bsk_filename);
+    ksk1.save(ksk_filename);
+    let bsk2 = LWEBSK::load(bsk_filename);
+    let ksk2 = LWEKSK::load(ksk_filename);
+    delete_file(bsk_filename).unwrap();
+    delete_file(ksk_filename).unwrap();
+    assert!(bsk1 == bsk2, "bsk1 != bsk2");
+    assert!(ksk1 == ksk2, "ksk1 != ksk2");
+}
+
 // use crate::crypto_api::glwe::VectorRLWE;
 // use crate::crypto_api::Plaintext;
 // use crate::crypto_api::{LWEParams, LWESecretKey, RLWEParams, RLWESecretKey, LWEBSK, LWEKSK};
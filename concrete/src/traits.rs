@@ -1,16 +1,16 @@
-pub trait HomomorphicAdd<T, U> {
-    fn add(&self, left: &U, right: T) -> U;
-    fn add_inplace(&self, left: &mut U, right: T);
+pub trait HomomorphicAdd<T, E>: Sized {
+    fn homomorphic_add(&self, right: T) -> Result<Self, E>;
+    fn homomorphic_add_inplace(&mut self, right: T) -> Result<(), E>;
 }
 
-pub trait HomomorphicSub<T, U> {
-    fn sub(&self, left: &U, right: T) -> U;
-    fn sub_inplace(&self, left: &mut U, right: T);
+pub trait HomomorphicSub<T, E>: Sized {
+    fn homomorphic_sub(&self, right: T) -> Result<Self, E>;
+    fn homomorphic_sub_inplace(&mut self, right: T) -> Result<(), E>;
 }
 
-pub trait HomomorphicMul<T, U> {
-    fn mul(&self, left: &U, right: T) -> U;
-    fn mul_inplace(&self, left: &mut U, right: T);
+pub trait HomomorphicMul<T, E>: Sized {
+    fn homomorphic_mul(&self, right: T) -> Result<Self, E>;
+    fn homomorphic_mul_inplace(&mut self, right: T) -> Result<(), E>;
 }
 
 pub trait GenericAdd<T, E>: Sized {
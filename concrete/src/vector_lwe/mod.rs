@@ -4,27 +4,34 @@ use std::error::Error;
 use std::fmt;
 
 use crate::error::CryptoAPIError;
-use crate::{read_from_file, write_to_file, Torus};
+use crate::operators::Operand;
+use crate::traits::{HomomorphicAdd, HomomorphicMul, HomomorphicSub};
+use crate::{read_from_file, with_engine, write_to_file, Torus};
 use backtrace::Backtrace;
 use colored::Colorize;
-use concrete_commons::dispersion::StandardDev;
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+use concrete_commons::key_kinds::BinaryKeyKind;
 use concrete_commons::numeric::Numeric;
-use concrete_commons::parameters::{CiphertextCount, GlweSize, LweSize, PolynomialSize};
-use concrete_core::crypto::secret::generators::EncryptionRandomGenerator;
-use concrete_core::{
-    crypto::{
-        encoding::PlaintextList,
-        glwe::GlweCiphertext,
-        lwe::{LweCiphertext, LweList},
-    },
-    math::tensor::Tensor,
-    math::tensor::{AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, IntoTensor},
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, LweCiphertextIndex,
+    LweDimension, LweSize, PolynomialSize,
+};
+use concrete_core::prelude::{
+    CleartextCreationEngine, GlweCiphertext64, GlweCiphertextTrivialEncryptionEngine,
+    LweCiphertext64, LweCiphertextCleartextFusingMultiplicationEngine,
+    LweCiphertextDiscardingBootstrapEngine, LweCiphertextDiscardingStoringEngine,
+    LweCiphertextFusingAdditionEngine, LweCiphertextFusingNegationEngine,
+    LweCiphertextLoadingEngine, LweCiphertextPlaintextFusingAdditionEngine, LweCiphertextVector64,
+    LweCiphertextVectorDecryptionEngine, LweCiphertextVectorDiscardingKeyswitchEngine,
+    LweCiphertextVectorEncryptionEngine, LweCiphertextVectorFusingAdditionEngine,
+    LweCiphertextVectorFusingSubtractionEngine, LweCiphertextVectorTrivialEncryptionEngine,
+    PlaintextCreationEngine, PlaintextVectorCreationEngine, PlaintextVectorRetrievalEngine,
+};
+use concrete_npe::{
+    estimate_addition_noise, estimate_integer_plaintext_multiplication_noise,
+    estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms, estimate_number_of_noise_bits,
+    estimate_pbs_noise, estimate_several_additions_noise,
 };
-
-use concrete_core::crypto::bootstrap::Bootstrap;
-
-use concrete_npe as npe;
-use concrete_npe::{ LWE};
 use itertools::izip;
 use serde::{Deserialize, Serialize};
 
@@ -42,13 +49,89 @@ mod tests;
 /// * `encoders` - the encoders of each LWE ciphertext of the list
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct VectorLWE {
-    pub ciphertexts: LweList<Vec<Torus>>,
+    pub ciphertexts: LweCiphertextVector64,
     pub variances: Vec<f64>,
     pub dimension: usize,
     pub nb_ciphertexts: usize,
     pub encoders: Vec<crate::Encoder>,
 }
 
+impl HomomorphicAdd<&VectorLWE, CryptoAPIError> for VectorLWE {
+    fn homomorphic_add(&self, right: &VectorLWE) -> Result<VectorLWE, CryptoAPIError> {
+        self.add_with_padding(right)
+    }
+    fn homomorphic_add_inplace(&mut self, right: &VectorLWE) -> Result<(), CryptoAPIError> {
+        self.add_with_padding_inplace(right)
+    }
+}
+
+impl HomomorphicSub<&VectorLWE, CryptoAPIError> for VectorLWE {
+    fn homomorphic_sub(&self, right: &VectorLWE) -> Result<VectorLWE, CryptoAPIError> {
+        self.sub_with_padding(right)
+    }
+    fn homomorphic_sub_inplace(&mut self, right: &VectorLWE) -> Result<(), CryptoAPIError> {
+        self.sub_with_padding_inplace(right)
+    }
+}
+
+impl HomomorphicMul<&[i32], CryptoAPIError> for VectorLWE {
+    fn homomorphic_mul(&self, right: &[i32]) -> Result<VectorLWE, CryptoAPIError> {
+        self.mul_constant_static_encoder(right)
+    }
+    fn homomorphic_mul_inplace(&mut self, right: &[i32]) -> Result<(), CryptoAPIError> {
+        self.mul_constant_static_encoder_inplace(right)
+    }
+}
+
+impl HomomorphicMul<(&VectorLWE, &crate::LWEBSK), CryptoAPIError> for VectorLWE {
+    fn homomorphic_mul(
+        &self,
+        right: (&VectorLWE, &crate::LWEBSK),
+    ) -> Result<VectorLWE, CryptoAPIError> {
+        let (ct, bsk) = right;
+
+        // check if we have the same number of ciphertexts
+        if ct.nb_ciphertexts != self.nb_ciphertexts {
+            return Err(NbCTError!(ct.nb_ciphertexts, self.nb_ciphertexts));
+        }
+
+        // multiply the ciphertexts one by one
+        let mut res = VectorLWE::zero(bsk.dimension * bsk.polynomial_size, self.nb_ciphertexts)?;
+        for n in 0..self.nb_ciphertexts {
+            let product = self.mul_from_bootstrap_nth(ct, bsk, n, n)?;
+            res.copy_in_nth_nth_inplace(n, &product, 0)?;
+        }
+        Ok(res)
+    }
+    fn homomorphic_mul_inplace(
+        &mut self,
+        right: (&VectorLWE, &crate::LWEBSK),
+    ) -> Result<(), CryptoAPIError> {
+        *self = self.homomorphic_mul(right)?;
+        Ok(())
+    }
+}
+
+impl Operand for VectorLWE {
+    fn add_operand(&mut self, right: &VectorLWE) -> Result<(), CryptoAPIError> {
+        self.add_with_padding_inplace(right)
+    }
+    fn sub_operand(&mut self, right: &VectorLWE) -> Result<(), CryptoAPIError> {
+        self.sub_with_padding_inplace(right)
+    }
+    fn neg_operand(&mut self) -> Result<(), CryptoAPIError> {
+        for n in 0..self.nb_ciphertexts {
+            self.opposite_nth_inplace(n)?;
+        }
+        Ok(())
+    }
+    fn mul_operand(&mut self, right: i32) -> Result<(), CryptoAPIError> {
+        self.mul_constant_static_encoder_inplace(&vec![right; self.nb_ciphertexts])
+    }
+}
+
+impl_operators!(VectorLWE);
+
 impl VectorLWE {
     /// Instantiate a new VectorLWE filled with zeros from a dimension and a number of ciphertexts
     /// `nb_ciphertexts` has to be at least 1.
@@ -75,12 +158,16 @@ impl VectorLWE {
         if nb_ciphertexts == 0 {
             return Err(ZeroCiphertextsInStructureError!(nb_ciphertexts));
         }
+        let ciphertexts = with_engine(|engine| {
+            let plaintexts = engine
+                .create_plaintext_vector(&vec![0 as Torus; nb_ciphertexts])
+                .unwrap();
+            engine
+                .trivially_encrypt_lwe_ciphertext_vector(LweSize(dimension + 1), &plaintexts)
+                .unwrap()
+        });
         Ok(VectorLWE {
-            ciphertexts: LweList::allocate(
-                0,
-                LweSize(dimension + 1),
-                CiphertextCount(nb_ciphertexts),
-            ),
+            ciphertexts,
             variances: vec![0.; nb_ciphertexts],
             dimension,
             nb_ciphertexts,
@@ -133,22 +220,9 @@ impl VectorLWE {
             return Err(IndexError!(ct.nb_ciphertexts, ct_index));
         }
 
-        // get the size of one lwe ciphertext
-        let ct_size = self.get_ciphertext_size();
-
         // copy the content
-        for (output, input) in izip!(
-            self.ciphertexts
-                .as_mut_tensor()
-                .get_sub_mut((self_index * (ct_size))..((self_index + 1) * (ct_size)))
-                .iter_mut(),
-            ct.ciphertexts
-                .as_tensor()
-                .get_sub((ct_index * (ct_size))..((ct_index + 1) * (ct_size)))
-                .iter()
-        ) {
-            *output = *input;
-        }
+        let input = load_nth(&ct.ciphertexts, ct_index);
+        store_nth(&mut self.ciphertexts, self_index, &input);
 
         // copy the variance
         self.variances[self_index] = ct.variances[ct_index];
@@ -182,19 +256,10 @@ impl VectorLWE {
             return Err(IndexError!(self.nb_ciphertexts, n));
         }
 
-        let ct = self
-            .ciphertexts
-            .as_tensor()
-            .get_sub((n * (self.get_ciphertext_size()))..((n + 1) * (self.get_ciphertext_size())))
-            .as_slice()
-            .to_owned();
-        let result = VectorLWE {
-            ciphertexts: LweList::from_container(ct.to_vec(), LweSize(self.dimension + 1)),
-            variances: vec![self.variances[n]; 1],
-            dimension: self.dimension,
-            nb_ciphertexts: 1,
-            encoders: vec![self.encoders[n].clone(); 1],
-        };
+        let mut result = VectorLWE::zero(self.dimension, 1)?;
+        store_nth(&mut result.ciphertexts, 0, &load_nth(&self.ciphertexts, n));
+        result.variances[0] = self.variances[n];
+        result.encoders[0] = self.encoders[n].clone();
         Ok(result)
     }
 
@@ -283,17 +348,8 @@ impl VectorLWE {
             );
         }
 
-        let mut res = VectorLWE {
-            ciphertexts: LweList::allocate(
-                0,
-                LweSize(sk.dimension + 1),
-                CiphertextCount(messages.len()),
-            ),
-            variances: vec![0.; messages.len()],
-            dimension: sk.dimension,
-            nb_ciphertexts: messages.len(),
-            encoders: vec![result_encoder; messages.len()],
-        };
+        let mut res = VectorLWE::zero(sk.dimension, messages.len())?;
+        res.encoders = vec![result_encoder; messages.len()];
         res.encrypt_raw(sk, &plaintexts).unwrap();
 
         Ok(res)
@@ -357,17 +413,8 @@ impl VectorLWE {
             }
         }
 
-        let mut res = VectorLWE {
-            ciphertexts: LweList::allocate(
-                0,
-                LweSize(sk.dimension + 1),
-                CiphertextCount(messages.len()),
-            ),
-            variances: vec![0.; messages.len()],
-            dimension: sk.dimension,
-            nb_ciphertexts: messages.len(),
-            encoders: result_encoders,
-        };
+        let mut res = VectorLWE::zero(sk.dimension, messages.len())?;
+        res.encoders = result_encoders;
         res.encrypt_raw(sk, &plaintexts).unwrap();
 
         Ok(res)
@@ -470,12 +517,12 @@ impl VectorLWE {
         }
 
         // encrypt
-        sk.val.encrypt_lwe_list(
-            &mut self.ciphertexts,
-            &PlaintextList::from_container(plaintexts),
-            StandardDev::from_standard_dev(sk.std_dev),
-            &mut EncryptionRandomGenerator::new(None),
-        );
+        self.ciphertexts = with_engine(|engine| {
+            let plaintexts = engine.create_plaintext_vector(plaintexts).unwrap();
+            engine
+                .encrypt_lwe_ciphertext_vector(&sk.val, &plaintexts, Variance(var))
+                .unwrap()
+        });
 
         Ok(())
     }
@@ -517,14 +564,8 @@ impl VectorLWE {
         // allocate the result
         let mut result: Vec<f64> = vec![0.; self.nb_ciphertexts];
 
-        // create a temporary variable to store the result of the phase computation
-        let mut tmp: Vec<Torus> = vec![0; self.nb_ciphertexts];
-
         // compute the phase
-        sk.val.decrypt_lwe_list(
-            &mut PlaintextList::from_container(tmp.as_mut_slice()),
-            &self.ciphertexts,
-        );
+        let tmp: Vec<Torus> = self.phase(sk);
 
         // decode
         for (r, pt, enc) in izip!(result.iter_mut(), tmp.iter(), self.encoders.iter()) {
@@ -568,16 +609,8 @@ impl VectorLWE {
             return Err(DimensionError!(self.dimension, sk.dimension));
         }
 
-        // create a temporary variable to store the result of the phase computation
-        let mut tmp: Vec<u64> = vec![0; self.nb_ciphertexts];
-
         // compute the phase
-        sk.val.decrypt_lwe_list(
-            &mut PlaintextList::from_container(tmp.as_mut_slice()),
-            &self.ciphertexts,
-        );
-
-        Ok(tmp)
+        Ok(self.phase(sk))
     }
 
     /// Decrypt the list of ciphertexts, meaning compute the phase and directly decode the output as if the encoder was set in round mode
@@ -620,14 +653,8 @@ impl VectorLWE {
         // allocate the result
         let mut result: Vec<f64> = vec![0.; self.nb_ciphertexts];
 
-        // create a temporary variable to store the result of the phase computation
-        let mut tmp: Vec<Torus> = vec![0; self.nb_ciphertexts];
-
         // compute the phase
-        sk.val.decrypt_lwe_list(
-            &mut PlaintextList::from_container(tmp.as_mut_slice()),
-            &self.ciphertexts,
-        );
+        let tmp: Vec<Torus> = self.phase(sk);
 
         // decode
         for (r, pt, enc) in izip!(result.iter_mut(), tmp.iter(), self.encoders.iter()) {
@@ -709,24 +736,15 @@ impl VectorLWE {
         &mut self,
         messages: &[f64],
     ) -> Result<(), CryptoAPIError> {
-        // get the size of one lwe ciphertext
-        let ct_size = self.get_ciphertext_size();
-
-        for (mut ciphertext, lwe_encoder, m) in izip!(
-            self.ciphertexts.as_mut_tensor().subtensor_iter_mut(ct_size),
-            self.encoders.iter(),
-            messages.iter()
-        ) {
+        for (n, (lwe_encoder, m)) in izip!(self.encoders.iter(), messages.iter()).enumerate() {
             // error if one message is not in [-delta,delta]
             if m.abs() > lwe_encoder.delta {
                 return Err(MessageTooBigError!(*m, lwe_encoder.delta));
             }
             let mut ec_tmp = lwe_encoder.clone();
             ec_tmp.o = 0.;
-            let update = ciphertext
-                .get_element(self.dimension)
-                .wrapping_add(ec_tmp.encode_outside_interval_operators(*m)?);
-            *ciphertext.get_element_mut(self.dimension) = update;
+            let update = ec_tmp.encode_outside_interval_operators(*m)?;
+            add_to_body_nth(&mut self.ciphertexts, n, update);
         }
         Ok(())
     }
@@ -919,20 +937,12 @@ impl VectorLWE {
         }
 
         // add the two ciphertexts together
-        self.ciphertexts
-            .as_mut_tensor()
-            .update_with_wrapping_add(ct.ciphertexts.as_tensor());
-
-        // get the size of one lwe ciphertext
-        let ct_size = self.get_ciphertext_size();
+        self.add_ciphertexts(ct);
 
         // correction related to the addition
-        for (mut ciphertext, enc1, enc2, new) in izip!(
-            self.ciphertexts.as_mut_tensor().subtensor_iter_mut(ct_size),
-            self.encoders.iter(),
-            ct.encoders.iter(),
-            new_min.iter()
-        ) {
+        for (n, (enc1, enc2, new)) in
+            izip!(self.encoders.iter(), ct.encoders.iter(), new_min.iter()).enumerate()
+        {
             // error if the deltas are not identical as well as the paddings
             if !deltas_eq!(enc1.delta, enc2.delta) {
                 return Err(DeltaError!(enc1.delta, enc2.delta));
@@ -941,10 +951,8 @@ impl VectorLWE {
             }
             let mut tmp_ec = enc1.clone();
             tmp_ec.o = *new;
-            let update = ciphertext
-                .get_element(self.dimension)
-                .wrapping_add(tmp_ec.encode_outside_interval_operators(enc1.o + enc2.o)?);
-            *ciphertext.get_element_mut(self.dimension) = update;
+            let update = tmp_ec.encode_outside_interval_operators(enc1.o + enc2.o)?;
+            add_to_body_nth(&mut self.ciphertexts, n, update);
         }
 
         // update the Encoder list
@@ -958,7 +966,8 @@ impl VectorLWE {
             ct.variances.iter(),
             self.encoders.iter_mut()
         ) {
-            *var1 = npe::add_ciphertexts(*var1, *var2);
+            *var1 = estimate_addition_noise::<Torus, _, _>(Variance(*var1), Variance(*var2))
+                .get_variance();
             enc.update_precision_from_variance(*var1)?;
         }
         Ok(())
@@ -1063,26 +1072,15 @@ impl VectorLWE {
             }
         }
 
-        // get the size of one lwe ciphertext
-        let ct_size = self.get_ciphertext_size();
-
         // add ciphertexts together
-        self.ciphertexts
-            .as_mut_tensor()
-            .update_with_wrapping_add(ct.ciphertexts.as_tensor());
+        self.add_ciphertexts(ct);
 
         // correction related to the addition
-        for (mut ciphertext, enc1) in izip!(
-            self.ciphertexts.as_mut_tensor().subtensor_iter_mut(ct_size),
-            self.encoders.iter(),
-        ) {
+        for (n, enc1) in self.encoders.iter().enumerate() {
             let mut tmp_enc = enc1.clone();
             tmp_enc.o = 0.;
             let correction: Torus = tmp_enc.encode_core(enc1.delta / 2.)?;
-            let update = ciphertext
-                .get_element(self.dimension)
-                .wrapping_sub(correction);
-            *ciphertext.get_element_mut(self.dimension) = update;
+            add_to_body_nth(&mut self.ciphertexts, n, correction.wrapping_neg());
         }
 
         // update the Encoder list and variances
@@ -1096,7 +1094,9 @@ impl VectorLWE {
             self_enc.o += ct_enc.o + self_enc.delta / 2.;
 
             // compute the new variance
-            *self_var = npe::add_ciphertexts(*self_var, *ct_var);
+            *self_var =
+                estimate_addition_noise::<Torus, _, _>(Variance(*self_var), Variance(*ct_var))
+                    .get_variance();
 
             // update the encoder precision based on the variance
             self_enc.update_precision_from_variance(*self_var)?;
@@ -1207,9 +1207,7 @@ impl VectorLWE {
         }
 
         // add ciphertexts together
-        self.ciphertexts
-            .as_mut_tensor()
-            .update_with_wrapping_add(ct.ciphertexts.as_tensor());
+        self.add_ciphertexts(ct);
 
         // update the Encoder list and variances
         for (self_enc, ct_enc, self_var, ct_var) in izip!(
@@ -1219,7 +1217,9 @@ impl VectorLWE {
             ct.variances.iter()
         ) {
             // compute the new variance
-            *self_var = npe::add_ciphertexts(*self_var, *ct_var);
+            *self_var =
+                estimate_addition_noise::<Torus, _, _>(Variance(*self_var), Variance(*ct_var))
+                    .get_variance();
 
             // compute the new encoder
             self_enc.o += ct_enc.o;
@@ -1335,23 +1335,12 @@ impl VectorLWE {
         }
 
         // subtract ciphertexts together
-        self.ciphertexts
-            .as_mut_tensor()
-            .update_with_wrapping_sub(ct.ciphertexts.as_tensor());
-
-        // get the size of one lwe ciphertext
-        let ct_size = self.get_ciphertext_size();
+        self.sub_ciphertexts(ct);
 
         // correction related to the subtraction
-        for (mut ciphertext, enc1) in izip!(
-            self.ciphertexts.as_mut_tensor().subtensor_iter_mut(ct_size),
-            self.encoders.iter(),
-        ) {
+        for (n, enc1) in self.encoders.iter().enumerate() {
             let correction: Torus = 1 << (<Torus as Numeric>::BITS - enc1.nb_bit_padding);
-            let update = ciphertext
-                .get_element(self.dimension)
-                .wrapping_add(correction);
-            *ciphertext.get_element_mut(self.dimension) = update;
+            add_to_body_nth(&mut self.ciphertexts, n, correction);
         }
 
         // update the Encoder list
@@ -1367,7 +1356,8 @@ impl VectorLWE {
             ct.variances.iter(),
             self.encoders.iter_mut()
         ) {
-            *var1 = npe::add_ciphertexts(*var1, *var2);
+            *var1 = estimate_addition_noise::<Torus, _, _>(Variance(*var1), Variance(*var2))
+                .get_variance();
             enc.update_precision_from_variance(*var1)?;
         }
         Ok(())
@@ -1449,30 +1439,31 @@ impl VectorLWE {
         &mut self,
         messages: &[i32],
     ) -> Result<(), CryptoAPIError> {
-        // get the size of one lwe ciphertext
-        let ct_size = self.get_ciphertext_size();
-
-        for (mut ciphertext, m, encoder, var) in izip!(
-            self.ciphertexts.as_mut_tensor().subtensor_iter_mut(ct_size),
+        for (n, (m, encoder, var)) in izip!(
             messages.iter(),
             self.encoders.iter_mut(),
             self.variances.iter_mut(),
-        ) {
+        )
+        .enumerate()
+        {
             // compute correction
             let cor0: Torus = encoder.encode_outside_interval_operators(0.)?;
             let cor = cor0.wrapping_mul((*m - 1) as Torus);
 
             // multiplication
-            ciphertext.update_with_wrapping_scalar_mul(&(*m as Torus));
+            mul_by_constant_nth(&mut self.ciphertexts, n, *m as Torus);
 
             // apply correction
-            let update = ciphertext.get_element(self.dimension).wrapping_sub(cor);
-            *ciphertext.get_element_mut(self.dimension) = update;
+            add_to_body_nth(&mut self.ciphertexts, n, cor.wrapping_neg());
 
             // compute the absolute value
             let m_abs = m.abs();
             // call to the NPE to estimate the new variance
-            *var = npe::LWE::single_scalar_mul(*var, m_abs as Torus);
+            *var = estimate_integer_plaintext_multiplication_noise::<Torus, _>(
+                Variance(*var),
+                m_abs as Torus,
+            )
+            .get_variance();
 
             if m_abs != 0 {
                 // update the encoder precision based on the variance
@@ -1603,15 +1594,13 @@ impl VectorLWE {
             }
         }
 
-        // get the size of one lwe ciphertext
-        let ct_size = self.get_ciphertext_size();
-
-        for (mut ciphertext, c, encoder, var) in izip!(
-            self.ciphertexts.as_mut_tensor().subtensor_iter_mut(ct_size),
+        for (n, (c, encoder, var)) in izip!(
             constants.iter(),
             self.encoders.iter_mut(),
             self.variances.iter_mut(),
-        ) {
+        )
+        .enumerate()
+        {
             // test if negative
             let negative: bool = *c < 0.;
 
@@ -1624,11 +1613,10 @@ impl VectorLWE {
 
             // encode 0 and subtract it
             let tmp_sub = encoder.encode_core(0.)?;
-            let update = ciphertext.get_element(self.dimension).wrapping_sub(tmp_sub);
-            *ciphertext.get_element_mut(self.dimension) = update;
+            add_to_body_nth(&mut self.ciphertexts, n, tmp_sub.wrapping_neg());
 
             // scalar multiplication
-            ciphertext.update_with_wrapping_scalar_mul(&scal);
+            mul_by_constant_nth(&mut self.ciphertexts, n, scal);
 
             // new encoder
             let new_o = encoder.o * max_constant;
@@ -1674,7 +1662,9 @@ impl VectorLWE {
             encoder.copy(&tmp_encoder);
             encoder.nb_bit_precision = usize::min(encoder.nb_bit_precision, new_precision);
             // call to the NPE to estimate the new variance
-            *var = npe::LWE::single_scalar_mul(*var, scal);
+            *var =
+                estimate_integer_plaintext_multiplication_noise::<Torus, _>(Variance(*var), scal)
+                    .get_variance();
 
             if scal != 0 {
                 // update the encoder precision based on the variance
@@ -1683,12 +1673,11 @@ impl VectorLWE {
 
             // encode 0 with the new encoder
             let tmp_add = encoder.encode_core(0.)?;
-            let update = ciphertext.get_element(self.dimension).wrapping_add(tmp_add);
-            *ciphertext.get_element_mut(self.dimension) = update;
+            add_to_body_nth(&mut self.ciphertexts, n, tmp_add);
 
             if negative {
                 // compute the opposite
-                ciphertext.update_with(|a| *a = a.wrapping_neg());
+                negate_nth(&mut self.ciphertexts, n);
 
                 // add correction if there is some padding
                 let mut cor: Torus = 0;
@@ -1704,8 +1693,7 @@ impl VectorLWE {
                             - encoder.nb_bit_precision),
                     );
                 }
-                let update = ciphertext.get_element(self.dimension).wrapping_add(cor);
-                *ciphertext.get_element_mut(self.dimension) = update;
+                add_to_body_nth(&mut self.ciphertexts, n, cor);
 
                 // change the encoder
                 encoder.opposite_inplace()?;
@@ -1795,17 +1783,8 @@ impl VectorLWE {
             ));
         }
 
-        // get the size of one lwe ciphertext
-        let ct_size = self.get_ciphertext_size();
-
-        // select the n-th ciphertext
-        let mut ct = self
-            .ciphertexts
-            .as_mut_tensor()
-            .get_sub_mut((n * (ct_size))..((n + 1) * (ct_size)));
-
-        // compute the opposite
-        ct.update_with(|a| *a = a.wrapping_neg());
+        // compute the opposite of the n-th ciphertext
+        negate_nth(&mut self.ciphertexts, n);
 
         // add correction if there is some padding
         let mut cor: Torus = 0;
@@ -1821,8 +1800,7 @@ impl VectorLWE {
                     - self.encoders[n].nb_bit_precision),
             );
         }
-        let update = ct.get_element(self.dimension).wrapping_add(cor);
-        *ct.get_element_mut(self.dimension) = update;
+        add_to_body_nth(&mut self.ciphertexts, n, cor);
 
         // change the encoder
         self.encoders[n].opposite_inplace()?;
@@ -1879,8 +1857,15 @@ impl VectorLWE {
             crate::VectorLWE::zero(ksk.dimension_after, self.nb_ciphertexts)?;
 
         // key switch
-        ksk.ciphertexts
-            .keyswitch_list(&mut res.ciphertexts, &self.ciphertexts);
+        with_engine(|engine| {
+            engine
+                .discard_keyswitch_lwe_ciphertext_vector(
+                    &mut res.ciphertexts,
+                    &self.ciphertexts,
+                    &ksk.ciphertexts,
+                )
+                .unwrap()
+        });
 
         // deal with encoders, noise and new precision
         for (output_enc, input_enc, vout, vin) in izip!(
@@ -1890,13 +1875,19 @@ impl VectorLWE {
             self.variances.iter()
         ) {
             // calls the NPE to find out the amount of noise after KS
-            *vout = <Torus as LWE>::key_switch(
-                self.dimension,
-                ksk.level,
-                ksk.base_log,
-                ksk.variance,
-                *vin,
-            );
+            *vout = estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<
+                Torus,
+                _,
+                _,
+                BinaryKeyKind,
+            >(
+                LweDimension(self.dimension),
+                Variance(*vin),
+                Variance(ksk.variance),
+                DecompositionBaseLog(ksk.base_log),
+                DecompositionLevelCount(ksk.level),
+            )
+            .get_variance();
 
             // copy the encoders
             output_enc.copy(input_enc);
@@ -2044,67 +2035,45 @@ impl VectorLWE {
         // generate the look up table
         let lut = bsk.generate_functional_look_up_table(&self.encoders[n], encoder_output, f)?;
 
-        // build the trivial accumulator
-        let mut accumulator = GlweCiphertext::allocate(
-            0,
-            PolynomialSize(bsk.polynomial_size),
-            GlweSize(bsk.dimension + 1),
-        );
-
-        accumulator
-            .as_mut_tensor()
-            .as_mut_slice()
-            .get_mut(
-                (bsk.dimension * bsk.polynomial_size)..((bsk.dimension + 1) * bsk.polynomial_size),
-            )
-            .unwrap()
-            .copy_from_slice(&lut);
-
         // allocate the result
-        let mut result =
-            LweCiphertext::allocate(0, LweSize(bsk.dimension * bsk.polynomial_size + 1));
+        let mut result = crate::VectorLWE::zero(bsk.dimension * bsk.polynomial_size, 1)?;
+
+        // select the n-th ciphertext
+        let mut input = load_nth(&self.ciphertexts, n);
 
+        // shift of some bits to the left to remove the padding but one bit
         if self.encoders[n].nb_bit_padding > 1 {
-            // copy the ciphertext to bootstrap
-            let mut ct_clone = self
-                .ciphertexts
-                .as_tensor()
-                .get_sub(n * (self.get_ciphertext_size())..((n + 1) * (self.get_ciphertext_size())))
-                .iter()
-                .copied()
-                .collect::<Vec<Torus>>();
-
-            // shift of some bits to the left
-            Tensor::from_container(ct_clone.as_mut_slice())
-                .update_with_scalar_shl(&(self.encoders[n].nb_bit_padding - 1));
-
-            // compute the bootstrap
-            bsk.ciphertexts.bootstrap(
-                &mut result,
-                &LweCiphertext::from_container(ct_clone),
-                & accumulator,
-            );
-        } else {
-            // compute the bootstrap
-            let ct_view = self
-                .ciphertexts
-                .as_tensor()
-                .get_sub(n * (self.get_ciphertext_size())..((n + 1) * (self.get_ciphertext_size())))
-                .into_container();
-            let ct = LweCiphertext::from_container(ct_view);
-            bsk.ciphertexts
-                .bootstrap(&mut result, &ct, & accumulator);
+            mul_by_constant(&mut input, 1 << (self.encoders[n].nb_bit_padding - 1));
         }
 
+        // build the trivial accumulator and compute the bootstrap
+        let mut output = load_nth(&result.ciphertexts, 0);
+        with_engine(|engine| {
+            let lut = engine.create_plaintext_vector(&lut).unwrap();
+            let accumulator: GlweCiphertext64 = engine
+                .trivially_encrypt_glwe_ciphertext(GlweSize(bsk.dimension + 1), &lut)
+                .unwrap();
+            engine
+                .discard_bootstrap_lwe_ciphertext(
+                    &mut output,
+                    &input,
+                    &accumulator,
+                    &bsk.ciphertexts,
+                )
+                .unwrap();
+        });
+        store_nth(&mut result.ciphertexts, 0, &output);
+
         // compute the new variance (without the drift)
-        let new_var = <Torus as npe::Cross>::bootstrap(
-            self.dimension,
-            bsk.dimension,
-            bsk.level,
-            bsk.base_log,
-            bsk.polynomial_size,
-            bsk.variance,
-        );
+        let new_var: f64 = estimate_pbs_noise::<Torus, _, BinaryKeyKind>(
+            LweDimension(self.dimension),
+            PolynomialSize(bsk.polynomial_size),
+            GlweDimension(bsk.dimension),
+            DecompositionBaseLog(bsk.base_log),
+            DecompositionLevelCount(bsk.level),
+            Variance(bsk.variance),
+        )
+        .get_variance();
 
         // create the output encoder
         let mut new_encoder_output: crate::Encoder = encoder_output.clone();
@@ -2123,7 +2092,9 @@ impl VectorLWE {
 
         // calls the NPE to find out the amount of noise after rounding the input ciphertext (drift)
         let nb_rounding_noise_bit: usize =
-            (npe::lwe::log2_rounding_noise(self.dimension)).ceil() as usize + 1;
+            crate::lwe::log2_rounding_noise(self.dimension, bsk.get_polynomial_size_log()).ceil()
+                as usize
+                + 1;
 
         // deals with the drift error
         if nb_rounding_noise_bit
@@ -2150,18 +2121,10 @@ impl VectorLWE {
         }
 
         // construct the output
-        let lwe = crate::VectorLWE {
-            variances: vec![new_var; 1],
-            ciphertexts: LweList::from_container(
-                result.into_tensor().into_container(),
-                LweSize(bsk.polynomial_size * bsk.dimension + 1),
-            ),
-            dimension: bsk.polynomial_size * bsk.dimension,
-            nb_ciphertexts: 1,
-            encoders: vec![new_encoder_output; 1],
-        };
+        result.variances[0] = new_var;
+        result.encoders[0] = new_encoder_output;
 
-        Ok(lwe)
+        Ok(result)
     }
 
     /// Multiply two LWE ciphertexts thanks to two bootstrapping procedures
@@ -2297,7 +2260,7 @@ impl VectorLWE {
             for _i in 0..encoder.nb_bit_precision {
                 message_res.push('o');
             }
-            let noise = npe::nb_bit_from_variance_99(*variance, <Torus as Numeric>::BITS as usize);
+            let noise = estimate_number_of_noise_bits::<Torus, _>(Variance(*variance));
             // <Torus as Types>::TORUS_BIT  + (f64::log2(3. * f64::sqrt(*variance))).floor() as usize;
             let mut noise_res: String = "".to_string();
             // nose part
@@ -2370,19 +2333,14 @@ impl VectorLWE {
     pub fn sum_with_padding(&self) -> Result<crate::VectorLWE, CryptoAPIError> {
         let nb_bit_padding_consumed: usize =
             f64::ceil(f64::log2(self.nb_ciphertexts as f64)) as usize;
-        let mut ct = Tensor::from_container(vec![0; self.dimension + 1]);
+        let mut ct = load_nth(&self.ciphertexts, 0);
         let mut new_var: f64 = 0.;
         let mut new_o: f64 = 0.;
         let new_delta: f64 = self.encoders[0].delta * f64::powi(2., nb_bit_padding_consumed as i32);
         let mut new_precision: usize = self.encoders[0].nb_bit_precision;
 
-        for (ct_in, var_in, enc_in) in izip!(
-            self.ciphertexts
-                .as_tensor()
-                .subtensor_iter(self.dimension + 1),
-            self.variances.iter(),
-            self.encoders.iter()
-        ) {
+        for (n, (var_in, enc_in)) in izip!(self.variances.iter(), self.encoders.iter()).enumerate()
+        {
             //Find the minimum precision among all the ciphertexts
             new_precision = usize::min(new_precision, enc_in.nb_bit_precision);
             //Check same deltas and paddings among all the ciphertexts
@@ -2394,8 +2352,11 @@ impl VectorLWE {
                     enc_in.nb_bit_padding
                 ));
             }
-            ct.update_with_wrapping_add(&ct_in);
-            new_var = npe::add_ciphertexts(new_var, *var_in);
+            if n > 0 {
+                add_ciphertext(&mut ct, &load_nth(&self.ciphertexts, n));
+            }
+            new_var = estimate_addition_noise::<Torus, _, _>(Variance(new_var), Variance(*var_in))
+                .get_variance();
             new_o += enc_in.o;
         }
         let mut new_encoder: crate::Encoder = crate::Encoder {
@@ -2406,13 +2367,11 @@ impl VectorLWE {
             round: self.encoders[0].round,
         };
         new_encoder.update_precision_from_variance(new_var)?;
-        Ok(VectorLWE {
-            ciphertexts: LweList::from_container(ct.into_container(), LweSize(self.dimension + 1)),
-            variances: vec![new_var],
-            dimension: self.dimension,
-            nb_ciphertexts: 1,
-            encoders: vec![new_encoder],
-        })
+        let mut result = VectorLWE::zero(self.dimension, 1)?;
+        store_nth(&mut result.ciphertexts, 0, &ct);
+        result.variances[0] = new_var;
+        result.encoders[0] = new_encoder;
+        Ok(result)
     }
 
     /// Sum all the LWE ciphertexts contained in self into one single ciphertext and output it as a
@@ -2455,15 +2414,11 @@ impl VectorLWE {
     /// let ciphertext_sum = ciphertext.sum_with_new_min(-50.).unwrap();
     /// ```
     pub fn sum_with_new_min(&self, new_min: f64) -> Result<crate::VectorLWE, CryptoAPIError> {
-        let mut ct = LweList::allocate(0, LweSize(self.dimension + 1), CiphertextCount(1));
+        let mut ct = load_nth(&self.ciphertexts, 0);
 
         // add the ciphertexts together
-        for ct_in in izip!(self
-            .ciphertexts
-            .as_tensor()
-            .subtensor_iter(self.dimension + 1))
-        {
-            ct.as_mut_tensor().update_with_wrapping_add(&ct_in);
+        for n in 1..self.nb_ciphertexts {
+            add_ciphertext(&mut ct, &load_nth(&self.ciphertexts, n));
         }
 
         // deal with the resulting encoding and correcting terms
@@ -2474,51 +2429,141 @@ impl VectorLWE {
             sum_min += enc_in.o;
         }
         let correction = new_encoder.encode_outside_interval_operators(sum_min)?;
-        let update = ct
-            .as_tensor()
-            .get_element(self.dimension)
-            .wrapping_add(correction);
-        *ct.as_mut_tensor().get_element_mut(self.dimension) = update;
+        add_to_body(&mut ct, correction);
 
         // deal with the new variance
-        let new_var = npe::add_several_ciphertexts(&self.variances);
+        let variances: Vec<Variance> = self.variances.iter().map(|var| Variance(*var)).collect();
+        let new_var = estimate_several_additions_noise::<Torus, _>(&variances).get_variance();
         new_encoder.update_precision_from_variance(new_var)?;
 
         // build the output
-        Ok(VectorLWE {
-            ciphertexts: ct,
-            variances: vec![new_var],
-            dimension: self.dimension,
-            nb_ciphertexts: 1,
-            encoders: vec![new_encoder],
+        let mut result = VectorLWE::zero(self.dimension, 1)?;
+        store_nth(&mut result.ciphertexts, 0, &ct);
+        result.variances[0] = new_var;
+        result.encoders[0] = new_encoder;
+        Ok(result)
+    }
+
+    /// Decrypt the ciphertexts without decoding them
+    fn phase(&self, sk: &crate::LWESecretKey) -> Vec<Torus> {
+        with_engine(|engine| {
+            let plaintexts = engine
+                .decrypt_lwe_ciphertext_vector(&sk.val, &self.ciphertexts)
+                .unwrap();
+            engine.retrieve_plaintext_vector(&plaintexts).unwrap()
         })
     }
+
+    /// Add the ciphertexts of `ct` to the ciphertexts of `self`
+    fn add_ciphertexts(&mut self, ct: &crate::VectorLWE) {
+        with_engine(|engine| {
+            engine
+                .fuse_add_lwe_ciphertext_vector(&mut self.ciphertexts, &ct.ciphertexts)
+                .unwrap()
+        });
+    }
+
+    /// Subtract the ciphertexts of `ct` from the ciphertexts of `self`
+    fn sub_ciphertexts(&mut self, ct: &crate::VectorLWE) {
+        with_engine(|engine| {
+            engine
+                .fuse_sub_lwe_ciphertext_vector(&mut self.ciphertexts, &ct.ciphertexts)
+                .unwrap()
+        });
+    }
+}
+
+/// Copy the `n`-th ciphertext out of `ciphertexts`
+fn load_nth(ciphertexts: &LweCiphertextVector64, n: usize) -> LweCiphertext64 {
+    with_engine(|engine| {
+        engine
+            .load_lwe_ciphertext(ciphertexts, LweCiphertextIndex(n))
+            .unwrap()
+    })
+}
+
+/// Overwrite the `n`-th ciphertext of `ciphertexts` with `ct`
+fn store_nth(ciphertexts: &mut LweCiphertextVector64, n: usize, ct: &LweCiphertext64) {
+    with_engine(|engine| {
+        engine
+            .discard_store_lwe_ciphertext(ciphertexts, ct, LweCiphertextIndex(n))
+            .unwrap()
+    });
+}
+
+/// Apply `f` to the `n`-th ciphertext of `ciphertexts`
+fn update_nth<F: FnOnce(&mut LweCiphertext64)>(
+    ciphertexts: &mut LweCiphertextVector64,
+    n: usize,
+    f: F,
+) {
+    let mut ct = load_nth(ciphertexts, n);
+    f(&mut ct);
+    store_nth(ciphertexts, n, &ct);
+}
+
+/// Add the ciphertext `input` to the ciphertext `output`
+fn add_ciphertext(output: &mut LweCiphertext64, input: &LweCiphertext64) {
+    with_engine(|engine| engine.fuse_add_lwe_ciphertext(output, input).unwrap());
+}
+
+/// Add `value` to the body of the ciphertext
+fn add_to_body(ct: &mut LweCiphertext64, value: Torus) {
+    with_engine(|engine| {
+        let plaintext = engine.create_plaintext(&value).unwrap();
+        engine
+            .fuse_add_lwe_ciphertext_plaintext(ct, &plaintext)
+            .unwrap()
+    });
+}
+
+/// Multiply the whole ciphertext by `constant`
+fn mul_by_constant(ct: &mut LweCiphertext64, constant: Torus) {
+    with_engine(|engine| {
+        let cleartext = engine.create_cleartext(&constant).unwrap();
+        engine
+            .fuse_mul_lwe_ciphertext_cleartext(ct, &cleartext)
+            .unwrap()
+    });
+}
+
+/// Add `value` to the body of the `n`-th ciphertext of `ciphertexts`
+fn add_to_body_nth(ciphertexts: &mut LweCiphertextVector64, n: usize, value: Torus) {
+    update_nth(ciphertexts, n, |ct| add_to_body(ct, value));
+}
+
+/// Multiply the `n`-th ciphertext of `ciphertexts` by `constant`
+fn mul_by_constant_nth(ciphertexts: &mut LweCiphertextVector64, n: usize, constant: Torus) {
+    update_nth(ciphertexts, n, |ct| mul_by_constant(ct, constant));
+}
+
+/// Negate the `n`-th ciphertext of `ciphertexts`
+fn negate_nth(ciphertexts: &mut LweCiphertextVector64, n: usize) {
+    update_nth(ciphertexts, n, |ct| {
+        with_engine(|engine| engine.fuse_neg_lwe_ciphertext(ct).unwrap())
+    });
 }
 
 /// Print needed pieces of information about an VectorLWE
 impl fmt::Display for VectorLWE {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let n = 2;
+        let samples: Vec<Torus> = crate::raw::coefficients(&self.ciphertexts);
         let mut to_be_print: String = "".to_string();
 
         to_be_print += " VectorLWE {\n         -> samples = [";
 
-        if self.ciphertexts.as_tensor().len() <= 2 * n {
-            for elt in self.ciphertexts.as_tensor().iter() {
+        if samples.len() <= 2 * n {
+            for elt in samples.iter() {
                 to_be_print = to_be_print + &format!("{}, ", *elt);
             }
         } else {
-            for elt in self.ciphertexts.as_tensor().get_sub(0..n).iter() {
+            for elt in samples[0..n].iter() {
                 to_be_print = to_be_print + &format!("{}, ", *elt);
             }
             to_be_print += "...";
 
-            for elt in self
-                .ciphertexts
-                .as_tensor()
-                .get_sub(self.ciphertexts.as_tensor().len() - n..)
-                .iter()
-            {
+            for elt in samples[samples.len() - n..].iter() {
                 to_be_print = to_be_print + &format!("{}, ", *elt);
             }
         }
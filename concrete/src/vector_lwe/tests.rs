@@ -815,3 +815,53 @@ fn test_encode_encrypt_several_encoders_x_sum_with_new_min_x_decrypt_decode_roun
     assert_eq_granularity!(clear_sum, d, ct_sum.encoders[0]);
     assert_eq!(precision, ct_sum.encoders[0].nb_bit_precision);
 }
+
+#[test]
+fn test_encode_encrypt_x_add_sub_operators_x_decrypt() {
+    // random settings
+    let (min1, max1) = generate_random_interval!();
+    let (min2, _max2) = generate_random_interval!();
+    let max2 = min2 + max1 - min1; // same interval size
+    let (precision, mut padding) = generate_precision_padding!(8, 3);
+    padding += 1; // at least one bit
+    let nb_messages: usize = random_index!(30) + 10;
+
+    // encoders
+    let encoder1 = crate::Encoder::new(min1, max1, precision, padding).unwrap();
+    let encoder2 = crate::Encoder::new(min2, max2, precision, padding).unwrap();
+
+    // generate a secret key
+    let secret_key = crate::LWESecretKey::new(&crate::LWE128_1024);
+
+    // two lists of messages
+    let messages1: Vec<f64> = random_messages!(min1, max1, nb_messages);
+    let messages2: Vec<f64> = random_messages!(min2, max2, nb_messages);
+
+    // encode and encrypt
+    let ciphertext1 = crate::VectorLWE::encode_encrypt(&secret_key, &messages1, &encoder1).unwrap();
+    let ciphertext2 = crate::VectorLWE::encode_encrypt(&secret_key, &messages2, &encoder2).unwrap();
+
+    // addition and subtraction with the operators
+    let sum = (&ciphertext1 + &ciphertext2).unwrap();
+    let difference = (&ciphertext1 - &ciphertext2).unwrap();
+
+    // decryption
+    let decryptions_sum: Vec<f64> = sum.decrypt_decode_round(&secret_key).unwrap();
+    let decryptions_difference: Vec<f64> = difference.decrypt_decode_round(&secret_key).unwrap();
+
+    // check the precision loss related to the encryption
+    let mut cpt: usize = 0;
+    for (m1, m2, d_sum, d_diff, e_sum, e_diff) in izip!(
+        messages1.iter(),
+        messages2.iter(),
+        decryptions_sum.iter(),
+        decryptions_difference.iter(),
+        sum.encoders.iter(),
+        difference.encoders.iter()
+    ) {
+        assert_eq_granularity!(m1 + m2, d_sum, e_sum);
+        assert_eq_granularity!(m1 - m2, d_diff, e_diff);
+        cpt += 1;
+    }
+    assert_eq!(cpt, nb_messages);
+}
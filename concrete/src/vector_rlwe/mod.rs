@@ -3,21 +3,34 @@
 use std::error::Error;
 use std::fmt;
 
+use crate::error::CryptoAPIError;
+use crate::operators::Operand;
+use crate::traits::{HomomorphicAdd, HomomorphicMul, HomomorphicSub};
+use crate::{read_from_file, with_engine, write_to_file, Torus};
 use backtrace::Backtrace;
 use colored::Colorize;
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::numeric::Numeric;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, DeltaLog, GlweDimension, GlweSize,
+    LweCiphertextIndex, MonomialIndex, PolynomialSize,
+};
+use concrete_core::prelude::{
+    CleartextCreationEngine, GlweCiphertext64, GlweCiphertextCleartextFusingMultiplicationEngine,
+    GlweCiphertextDecryptionEngine, GlweCiphertextDiscardingTensorProductEngine,
+    GlweCiphertextEncryptionEngine, GlweCiphertextFusingAdditionEngine,
+    GlweCiphertextFusingSubtractionEngine, GlweCiphertextPlaintextVectorFusingAdditionEngine,
+    GlweCiphertextTrivialEncryptionEngine,
+    LweCiphertextDiscardingExtractionEngine, LweCiphertextDiscardingStoringEngine,
+    LweCiphertextLoadingEngine, PlaintextVectorCreationEngine, PlaintextVectorRetrievalEngine,
+};
+use concrete_npe::{
+    estimate_addition_noise, estimate_integer_plaintext_multiplication_noise,
+    estimate_multiplication_noise,
+};
 use itertools::izip;
 use serde::{Deserialize, Serialize};
-use concrete_core::{
-    crypto::{encoding::PlaintextList, glwe::GlweList},
-    math::tensor::{AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor},
-};
-use concrete_npe as npe;
-use crate::error::CryptoAPIError;
-use crate::{read_from_file, write_to_file, Torus};
-use concrete_commons::dispersion::StandardDev;
-use concrete_commons::numeric::Numeric;
-use concrete_commons::parameters::{CiphertextCount, GlweDimension, PolynomialSize};
-use concrete_core::crypto::secret::generators::EncryptionRandomGenerator;
 
 #[cfg(test)]
 mod tests;
@@ -37,7 +50,7 @@ mod tests;
 /// * `encoders` - the encoders of each RLWE ciphertext of the list
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct VectorRLWE {
-    pub ciphertexts: GlweList<Vec<Torus>>,
+    pub ciphertexts: Vec<GlweCiphertext64>,
     pub variances: Vec<f64>,
     pub dimension: usize,
     pub polynomial_size: usize,
@@ -45,6 +58,69 @@ pub struct VectorRLWE {
     pub encoders: Vec<crate::Encoder>,
 }
 
+impl HomomorphicAdd<&VectorRLWE, CryptoAPIError> for VectorRLWE {
+    fn homomorphic_add(&self, right: &VectorRLWE) -> Result<VectorRLWE, CryptoAPIError> {
+        self.add_with_padding(right)
+    }
+    fn homomorphic_add_inplace(&mut self, right: &VectorRLWE) -> Result<(), CryptoAPIError> {
+        self.add_with_padding_inplace(right)
+    }
+}
+
+impl HomomorphicSub<&VectorRLWE, CryptoAPIError> for VectorRLWE {
+    fn homomorphic_sub(&self, right: &VectorRLWE) -> Result<VectorRLWE, CryptoAPIError> {
+        self.sub_with_padding(right)
+    }
+    fn homomorphic_sub_inplace(&mut self, right: &VectorRLWE) -> Result<(), CryptoAPIError> {
+        self.sub_with_padding_inplace(right)
+    }
+}
+
+impl HomomorphicMul<&[i32], CryptoAPIError> for VectorRLWE {
+    fn homomorphic_mul(&self, right: &[i32]) -> Result<VectorRLWE, CryptoAPIError> {
+        let mut res = self.clone();
+        res.mul_constant_static_encoder_inplace(right)?;
+        Ok(res)
+    }
+    fn homomorphic_mul_inplace(&mut self, right: &[i32]) -> Result<(), CryptoAPIError> {
+        self.mul_constant_static_encoder_inplace(right)
+    }
+}
+
+impl HomomorphicMul<(&VectorRLWE, &crate::RLWERLK), CryptoAPIError> for VectorRLWE {
+    fn homomorphic_mul(
+        &self,
+        right: (&VectorRLWE, &crate::RLWERLK),
+    ) -> Result<VectorRLWE, CryptoAPIError> {
+        let (ct, rlk) = right;
+        self.mul_with_relinearization_key(ct, rlk)
+    }
+    fn homomorphic_mul_inplace(
+        &mut self,
+        right: (&VectorRLWE, &crate::RLWERLK),
+    ) -> Result<(), CryptoAPIError> {
+        let (ct, rlk) = right;
+        self.mul_with_relinearization_key_inplace(ct, rlk)
+    }
+}
+
+impl Operand for VectorRLWE {
+    fn add_operand(&mut self, right: &VectorRLWE) -> Result<(), CryptoAPIError> {
+        self.add_with_padding_inplace(right)
+    }
+    fn sub_operand(&mut self, right: &VectorRLWE) -> Result<(), CryptoAPIError> {
+        self.sub_with_padding_inplace(right)
+    }
+    fn neg_operand(&mut self) -> Result<(), CryptoAPIError> {
+        self.opposite_inplace()
+    }
+    fn mul_operand(&mut self, right: i32) -> Result<(), CryptoAPIError> {
+        self.mul_constant_static_encoder_inplace(&vec![right; self.nb_ciphertexts])
+    }
+}
+
+impl_operators!(VectorRLWE);
+
 impl VectorRLWE {
     /// Instantiate a new VectorRLWE filled with zeros from a polynomial size, a dimension and a number of ciphertexts
     ///
@@ -80,13 +156,20 @@ impl VectorRLWE {
             return Err(NotPowerOfTwoError!(polynomial_size));
         }
 
+        let ciphertexts = with_engine(|engine| {
+            let zeros = engine
+                .create_plaintext_vector(&vec![0 as Torus; polynomial_size])
+                .unwrap();
+            (0..nb_ciphertexts)
+                .map(|_| {
+                    engine
+                        .trivially_encrypt_glwe_ciphertext(GlweSize(dimension + 1), &zeros)
+                        .unwrap()
+                })
+                .collect()
+        });
         Ok(VectorRLWE {
-            ciphertexts: GlweList::allocate(
-                0,
-                PolynomialSize(polynomial_size),
-                GlweDimension(dimension),
-                CiphertextCount(nb_ciphertexts),
-            ),
+            ciphertexts,
             variances: vec![0.; polynomial_size * nb_ciphertexts],
             dimension,
             polynomial_size,
@@ -437,12 +520,18 @@ impl VectorRLWE {
         // set the variances
         self.variances = vec![sk.get_variance(); self.nb_ciphertexts * self.polynomial_size];
 
-        sk.val.encrypt_glwe_list(
-            &mut self.ciphertexts,
-            &PlaintextList::from_container(plaintexts),
-            StandardDev::from_standard_dev(sk.std_dev),
-            &mut EncryptionRandomGenerator::new(None),
-        );
+        // encrypt one RLWE ciphertext per chunk of polynomial size plaintexts
+        self.ciphertexts = with_engine(|engine| {
+            plaintexts
+                .chunks(self.polynomial_size)
+                .map(|chunk| {
+                    let plaintexts = engine.create_plaintext_vector(chunk).unwrap();
+                    engine
+                        .encrypt_glwe_ciphertext(&sk.val, &plaintexts, Variance(sk.get_variance()))
+                        .unwrap()
+                })
+                .collect()
+        });
 
         Ok(())
     }
@@ -491,14 +580,8 @@ impl VectorRLWE {
 
         let mut result: Vec<f64> = vec![0.; self.nb_valid()];
 
-        // create a vec with the good size for the plaintexts
-        let mut tmp_pt: Vec<Torus> = vec![0; self.polynomial_size * self.nb_ciphertexts];
-
         // compute the phase for all ciphertext
-        sk.val.decrypt_glwe_list(
-            &mut PlaintextList::from_container(tmp_pt.as_mut_slice()),
-            &self.ciphertexts,
-        );
+        let tmp_pt: Vec<Torus> = self.phase(sk);
 
         // decode as soon as the encoding is valid
         let mut i: usize = 0;
@@ -558,14 +641,8 @@ impl VectorRLWE {
 
         let mut result: Vec<f64> = vec![0.; self.nb_valid()];
 
-        // create a vec with the good size for the plaintexts
-        let mut tmp_pt: Vec<Torus> = vec![0; self.polynomial_size * self.nb_ciphertexts];
-
         // compute the phase for all ciphertext
-        sk.val.decrypt_glwe_list(
-            &mut PlaintextList::from_container(tmp_pt.as_mut_slice()),
-            &self.ciphertexts,
-        );
+        let tmp_pt: Vec<Torus> = self.phase(sk);
 
         // decode as soon as the encoding is valid
         let mut i: usize = 0;
@@ -695,56 +772,22 @@ impl VectorRLWE {
         res.variances[0] = self.variances[index];
         res.encoders[0].copy(&self.encoders[index]);
 
-        // compute the index for the body
-        let index_body: usize = n_ciphertext * self.polynomial_size * (self.dimension + 1)
-            + self.dimension * self.polynomial_size
-            + n_coeff;
-
-        // fill the body
-        *res.ciphertexts
-            .as_mut_tensor()
-            .get_element_mut(lwe_dimension) = *self.ciphertexts.as_tensor().get_element(index_body);
-
-        // fill the mask
-        res.ciphertexts
-            .as_mut_tensor()
-            .as_mut_slice()
-            .get_mut(0..(self.dimension * self.polynomial_size))
-            .unwrap()
-            .copy_from_slice(
-                self.ciphertexts
-                    .as_tensor()
-                    .as_slice()
-                    .get(
-                        (n_ciphertext * self.polynomial_size * (self.dimension + 1))
-                            ..(n_ciphertext * self.polynomial_size * (self.dimension + 1)
-                                + self.dimension * self.polynomial_size),
-                    )
-                    .unwrap(),
-            );
-
-        // deal with the rotation and the signs
-        let rot: usize = self.polynomial_size - n_coeff - 1;
-
-        for mut polynomial in res
-            .ciphertexts
-            .as_mut_tensor()
-            .get_sub_mut(0..(self.dimension * self.polynomial_size))
-            .subtensor_iter_mut(self.polynomial_size)
-        {
-            if polynomial.len() == self.polynomial_size {
-                polynomial.as_mut_slice().reverse();
-                for elt in polynomial
-                    .as_mut_slice()
-                    .get_mut(0..rot)
-                    .unwrap()
-                    .iter_mut()
-                {
-                    *elt = elt.wrapping_neg();
-                }
-                polynomial.as_mut_slice().rotate_left(rot);
-            }
-        }
+        // extract the LWE ciphertext encrypting the n_coeff-th coefficient
+        with_engine(|engine| {
+            let mut lwe = engine
+                .load_lwe_ciphertext(&res.ciphertexts, LweCiphertextIndex(0))
+                .unwrap();
+            engine
+                .discard_extract_lwe_ciphertext(
+                    &mut lwe,
+                    &self.ciphertexts[n_ciphertext],
+                    MonomialIndex(n_coeff),
+                )
+                .unwrap();
+            engine
+                .discard_store_lwe_ciphertext(&mut res.ciphertexts, &lwe, LweCiphertextIndex(0))
+                .unwrap();
+        });
 
         Ok(res)
     }
@@ -792,23 +835,12 @@ impl VectorRLWE {
         let mut cpt: usize = 0;
 
         // loop over RLWE ciphertexts
-        for (mut ciphertext, encoders) in izip!(
-            self.ciphertexts
-                .as_mut_tensor()
-                .subtensor_iter_mut((self.dimension + 1) * self.polynomial_size),
+        for (ciphertext, encoders) in izip!(
+            self.ciphertexts.iter_mut(),
             self.encoders.chunks_mut(self.polynomial_size)
         ) {
-            for (monomial_coeff, encoder) in izip!(
-                ciphertext
-                    .as_mut_slice()
-                    .get_mut(
-                        (self.polynomial_size * self.dimension)
-                            ..(self.polynomial_size * (self.dimension + 1))
-                    )
-                    .unwrap()
-                    .iter_mut(),
-                encoders.iter_mut()
-            ) {
+            let mut corrections: Vec<Torus> = vec![0; self.polynomial_size];
+            for (correction, encoder) in izip!(corrections.iter_mut(), encoders.iter_mut()) {
                 if encoder.is_valid() {
                     // select the next message
                     let m = messages[cpt];
@@ -820,12 +852,12 @@ impl VectorRLWE {
 
                     let mut ec_tmp = encoder.clone();
                     ec_tmp.o = 0.;
-                    *monomial_coeff =
-                        monomial_coeff.wrapping_add(ec_tmp.encode_outside_interval_operators(m)?);
+                    *correction = ec_tmp.encode_outside_interval_operators(m)?;
 
                     cpt += 1;
                 }
             }
+            add_to_body(ciphertext, &corrections);
         }
 
         Ok(())
@@ -932,43 +964,35 @@ impl VectorRLWE {
         }
 
         // add ciphertexts together
-        self.ciphertexts
-            .as_mut_tensor()
-            .update_with_wrapping_add(ct.ciphertexts.as_tensor());
+        self.add_ciphertexts(ct);
 
         // correction related to the addition
-        for (mut ciphertext, encoders, encoders_ct, self_variances, ct_variances) in izip!(
-            self.ciphertexts
-                .as_mut_tensor()
-                .subtensor_iter_mut(self.polynomial_size * (self.dimension + 1)),
+        for (ciphertext, encoders, encoders_ct, self_variances, ct_variances) in izip!(
+            self.ciphertexts.iter_mut(),
             self.encoders.chunks_mut(self.polynomial_size),
             ct.encoders.chunks(self.polynomial_size),
             self.variances.chunks_mut(self.polynomial_size),
             ct.variances.chunks(self.polynomial_size),
         ) {
-            for (monomial_coeff, encoder, encoder_ct, self_var, ct_var) in izip!(
-                ciphertext
-                    .as_mut_slice()
-                    .get_mut(
-                        (self.polynomial_size * self.dimension)
-                            ..(self.polynomial_size * (self.dimension + 1))
-                    )
-                    .unwrap()
-                    .iter_mut(),
+            let mut corrections: Vec<Torus> = vec![0; self.polynomial_size];
+            for (correction, encoder, encoder_ct, self_var, ct_var) in izip!(
+                corrections.iter_mut(),
                 encoders.iter_mut(),
                 encoders_ct.iter(),
                 self_variances.iter_mut(),
                 ct_variances.iter()
             ) {
                 // compute the new variance
-                *self_var = npe::add_ciphertexts(*self_var, *ct_var);
+                *self_var =
+                    estimate_addition_noise::<Torus, _, _>(Variance(*self_var), Variance(*ct_var))
+                        .get_variance();
 
                 // both coefficients contained a message
                 if encoder.is_valid() && encoder_ct.is_valid() {
                     let mut tmp_enc = encoder.clone();
                     tmp_enc.o = 0.;
-                    let correction: Torus = tmp_enc.encode_core(encoder.delta / 2.)?;
-                    *monomial_coeff = monomial_coeff.wrapping_sub(correction);
+                    let correction_value: Torus = tmp_enc.encode_core(encoder.delta / 2.)?;
+                    *correction = correction_value.wrapping_neg();
                     encoder.o += encoder_ct.o + encoder.delta / 2.;
 
                     // update the encoder precision based on the variance
@@ -982,6 +1006,7 @@ impl VectorRLWE {
                     encoder.update_precision_from_variance(*self_var)?;
                 }
             }
+            add_to_body(ciphertext, &corrections);
         }
         Ok(())
     }
@@ -1056,9 +1081,7 @@ impl VectorRLWE {
         }
 
         // add the ciphertexts together
-        self.ciphertexts
-            .as_mut_tensor()
-            .update_with_wrapping_add(ct.ciphertexts.as_tensor());
+        self.add_ciphertexts(ct);
 
         // update the Encoder list
         for (self_enc, ct_enc, self_var, ct_var) in izip!(
@@ -1068,7 +1091,9 @@ impl VectorRLWE {
             ct.variances.iter()
         ) {
             // compute the new variance
-            *self_var = npe::add_ciphertexts(*self_var, *ct_var);
+            *self_var =
+                estimate_addition_noise::<Torus, _, _>(Variance(*self_var), Variance(*ct_var))
+                    .get_variance();
 
             // compute the new encoder
             if self_enc.is_valid() && ct_enc.is_valid() {
@@ -1158,35 +1183,23 @@ impl VectorRLWE {
         }
 
         // subtract ciphertexts together
-        self.ciphertexts
-            .as_mut_tensor()
-            .update_with_wrapping_sub(ct.ciphertexts.as_tensor());
+        self.sub_ciphertexts(ct);
 
         // correction related to the subtraction
-        for (mut ciphertext, enc1_list, enc2_list) in izip!(
-            self.ciphertexts
-                .as_mut_tensor()
-                .subtensor_iter_mut((self.dimension + 1) * self.polynomial_size),
+        for (ciphertext, enc1_list, enc2_list) in izip!(
+            self.ciphertexts.iter_mut(),
             self.encoders.chunks(self.polynomial_size),
             ct.encoders.chunks(self.polynomial_size),
         ) {
-            for (coeff, enc1, enc2) in izip!(
-                ciphertext
-                    .as_mut_slice()
-                    .get_mut(
-                        (self.dimension * self.polynomial_size)
-                            ..((self.dimension + 1) * self.polynomial_size),
-                    )
-                    .unwrap()
-                    .iter_mut(),
-                enc1_list.iter(),
-                enc2_list.iter()
-            ) {
+            let mut corrections: Vec<Torus> = vec![0; self.polynomial_size];
+            for (correction, enc1, enc2) in
+                izip!(corrections.iter_mut(), enc1_list.iter(), enc2_list.iter())
+            {
                 if enc1.is_valid() && enc2.is_valid() {
-                    let correction: Torus = 1 << (<Torus as Numeric>::BITS - enc1.nb_bit_padding);
-                    *coeff = coeff.wrapping_add(correction);
+                    *correction = 1 << (<Torus as Numeric>::BITS - enc1.nb_bit_padding);
                 }
             }
+            add_to_body(ciphertext, &corrections);
         }
 
         // update the Encoder list
@@ -1197,7 +1210,9 @@ impl VectorRLWE {
             ct.variances.iter()
         ) {
             // compute the new variance
-            *self_var = npe::add_ciphertexts(*self_var, *ct_var);
+            *self_var =
+                estimate_addition_noise::<Torus, _, _>(Variance(*self_var), Variance(*ct_var))
+                    .get_variance();
 
             // compute the new encoder
             if self_enc.is_valid() && ct_enc.is_valid() {
@@ -1224,29 +1239,21 @@ impl VectorRLWE {
         &mut self,
         messages: &[i32],
     ) -> Result<(), CryptoAPIError> {
-        for (mut ciphertext, m, encoder_list, variance_list) in izip!(
-            self.ciphertexts
-                .as_mut_tensor()
-                .subtensor_iter_mut((self.dimension + 1) * self.polynomial_size),
+        for (ciphertext, m, encoder_list, variance_list) in izip!(
+            self.ciphertexts.iter_mut(),
             messages.iter(),
             self.encoders.chunks_mut(self.polynomial_size),
             self.variances.chunks_mut(self.polynomial_size),
         ) {
             // compute the multiplication
-            ciphertext.update_with_wrapping_scalar_mul(&(*m as Torus));
+            mul_by_constant(ciphertext, *m as Torus);
 
             // compute the absolute value
             let m_abs = m.abs();
 
-            for (coeff, enc, var) in izip!(
-                ciphertext
-                    .as_mut_slice()
-                    .get_mut(
-                        ((self.dimension) * self.polynomial_size)
-                            ..((self.dimension + 1) * self.polynomial_size)
-                    )
-                    .unwrap()
-                    .iter_mut(),
+            let mut corrections: Vec<Torus> = vec![0; self.polynomial_size];
+            for (correction, enc, var) in izip!(
+                corrections.iter_mut(),
                 encoder_list.iter_mut(),
                 variance_list.iter_mut(),
             ) {
@@ -1256,16 +1263,62 @@ impl VectorRLWE {
                     let cor = cor0.wrapping_mul((*m - 1) as Torus);
 
                     // apply correction
-                    *coeff = coeff.wrapping_sub(cor);
+                    *correction = cor.wrapping_neg();
                 }
                 // call to the NPE to estimate the new variance
-                *var = npe::LWE::single_scalar_mul(*var, m_abs as Torus);
+                *var = estimate_integer_plaintext_multiplication_noise::<Torus, _>(
+                    Variance(*var),
+                    m_abs as Torus,
+                )
+                .get_variance();
 
                 if m_abs != 0 {
                     // update the encoder precision based on the variance
                     enc.update_precision_from_variance(*var)?;
                 }
             }
+            add_to_body(ciphertext, &corrections);
+        }
+        Ok(())
+    }
+
+    /// Compute the opposite of every RLWE ciphertext in the structure
+    ///
+    /// # Output
+    /// * a new VectorRLWE whose messages are the opposite of the messages of `self`
+    pub fn opposite(&self) -> Result<crate::VectorRLWE, CryptoAPIError> {
+        let mut res = self.clone();
+        res.opposite_inplace()?;
+        Ok(res)
+    }
+
+    /// Compute the opposite of every RLWE ciphertext in the structure
+    /// The coefficients without a valid encoder are left unchanged
+    pub fn opposite_inplace(&mut self) -> Result<(), CryptoAPIError> {
+        for (ciphertext, encoder_list) in izip!(
+            self.ciphertexts.iter_mut(),
+            self.encoders.chunks_mut(self.polynomial_size),
+        ) {
+            // compute the opposite of the ciphertext
+            negate(ciphertext);
+
+            // add correction if there is some padding
+            let mut corrections: Vec<Torus> = vec![0; self.polynomial_size];
+            for (correction, enc) in izip!(corrections.iter_mut(), encoder_list.iter_mut()) {
+                if enc.is_valid() {
+                    let cor: Torus =
+                        1 << (<Torus as Numeric>::BITS - enc.nb_bit_padding - enc.nb_bit_precision);
+                    if enc.nb_bit_padding > 0 {
+                        *correction = (1 << (<Torus as Numeric>::BITS - enc.nb_bit_padding)) - cor;
+                    } else {
+                        *correction = cor.wrapping_neg();
+                    }
+
+                    // change the encoder
+                    enc.opposite_inplace()?;
+                }
+            }
+            add_to_body(ciphertext, &corrections);
         }
         Ok(())
     }
@@ -1342,11 +1395,8 @@ impl VectorRLWE {
             }
         }
 
-        // store the size of one RLWE
-        let ct_size: usize = self.get_ciphertext_size();
-
-        for (mut ciphertext, c, encoders, variances) in izip!(
-            self.ciphertexts.as_mut_tensor().subtensor_iter_mut(ct_size),
+        for (ciphertext, c, encoders, variances) in izip!(
+            self.ciphertexts.iter_mut(),
             constants.iter(),
             self.encoders.chunks_mut(self.polynomial_size),
             self.variances.chunks_mut(self.polynomial_size),
@@ -1362,21 +1412,16 @@ impl VectorRLWE {
                 (c_abs / max_constant * f64::powi(2., nb_bit_padding as i32)).round() as Torus;
 
             // subtract the encoded zeros (pre mul correction)
-            for (b, encoder) in izip!(
-                ciphertext
-                    .as_mut_slice()
-                    .get_mut((self.polynomial_size * self.dimension)..(ct_size))
-                    .unwrap()
-                    .iter_mut(),
-                encoders.iter()
-            ) {
+            let mut corrections: Vec<Torus> = vec![0; self.polynomial_size];
+            for (correction, encoder) in izip!(corrections.iter_mut(), encoders.iter()) {
                 if encoder.is_valid() {
-                    *b = b.wrapping_sub(encoder.encode_core(0.)?);
+                    *correction = encoder.encode_core(0.)?.wrapping_neg();
                 }
             }
+            add_to_body(ciphertext, &corrections);
 
             // scalar multiplication
-            ciphertext.update_with_wrapping_scalar_mul(&scal);
+            mul_by_constant(ciphertext, scal);
 
             // compute the discretization of c_abs
             let discret_c_abs =
@@ -1386,17 +1431,18 @@ impl VectorRLWE {
             let rounding_error = (discret_c_abs - c_abs).abs();
 
             // post mul correction and new encoders
-            for (b, encoder, var) in izip!(
-                ciphertext
-                    .as_mut_slice()
-                    .get_mut((self.polynomial_size * self.dimension)..(ct_size))
-                    .unwrap()
-                    .iter_mut(),
+            let mut corrections: Vec<Torus> = vec![0; self.polynomial_size];
+            for (correction, encoder, var) in izip!(
+                corrections.iter_mut(),
                 encoders.iter_mut(),
                 variances.iter_mut()
             ) {
                 // call to the NPE to estimate the new variance
-                *var = npe::LWE::single_scalar_mul(*var, scal);
+                *var = estimate_integer_plaintext_multiplication_noise::<Torus, _>(
+                    Variance(*var),
+                    scal,
+                )
+                .get_variance();
                 if scal != 0 {
                     // update the encoder precision based on the variance
                     encoder.update_precision_from_variance(*var)?;
@@ -1441,23 +1487,17 @@ impl VectorRLWE {
                     encoder.nb_bit_precision = usize::min(encoder.nb_bit_precision, new_precision);
 
                     // encode 0 with the new encoder
-                    let tmp_add = encoder.encode_core(0.)?;
-                    *b = b.wrapping_add(tmp_add);
+                    *correction = encoder.encode_core(0.)?;
                 }
             }
+            add_to_body(ciphertext, &corrections);
 
             if negative {
                 // compute the opposite
-                ciphertext.update_with_wrapping_neg();
+                negate(ciphertext);
 
-                for (b, encoder) in izip!(
-                    ciphertext
-                        .as_mut_slice()
-                        .get_mut((self.polynomial_size * self.dimension)..(ct_size))
-                        .unwrap()
-                        .iter_mut(),
-                    encoders.iter_mut()
-                ) {
+                let mut corrections: Vec<Torus> = vec![0; self.polynomial_size];
+                for (correction, encoder) in izip!(corrections.iter_mut(), encoders.iter_mut()) {
                     if encoder.is_valid() {
                         // add correction if there is some padding
                         let mut cor: Torus = 0;
@@ -1473,13 +1513,242 @@ impl VectorRLWE {
                                     - encoder.nb_bit_precision),
                             );
                         }
-                        *b = b.wrapping_add(cor);
+                        *correction = cor;
 
                         // change the encoder
                         encoder.opposite_inplace()?;
                     }
                 }
+                add_to_body(ciphertext, &corrections);
+            }
+        }
+        Ok(())
+    }
+
+    /// Multiply each VectorRLWE ciphertext with the polynomial of the matching ciphertext of `ct`
+    /// by computing a tensor product followed by a relinearization, and consume some bits of padding
+    /// it needs to have the same number of ciphertexts in both VectorRLWE
+    /// it also needs that the input encoders all contain zero in their intervals
+    /// the output encoders are centered on zero and their precision is updated from the estimated variance
+    /// the sum of the paddings of the inputs must be at least the biggest padding plus precision of an input,
+    /// plus log2 of the number of products summed in a coefficient (rounded up), plus one
+    ///
+    /// # Argument
+    /// * `ct` - a VectorRLWE struct
+    /// * `rlk` - a relinearization key generated from the secret key of the ciphertexts
+    ///
+    /// # Output
+    /// * a new VectorRLWE
+    /// * DimensionError - if the ciphertexts have incompatible dimensions
+    /// * PolynomialSizeError - if the ciphertexts have incompatible polynomial size
+    /// * NbCTError - if the ciphertexts have not the same number of RLWE ciphertexts
+    /// * ZeroInIntervalError - if 0 is not in the encoder interval
+    /// * PaddingError - if the encoders of a ciphertext have incompatible paddings
+    /// * DeltaError - if the encoders of a ciphertext have incompatible deltas
+    /// * NotEnoughPaddingError - if there is not enough padding for the operation
+    ///
+    /// # Example
+    /// ```rust
+    /// use concrete::*;
+    ///
+    /// // generate a secret key and a relinearization key
+    /// let sk = RLWESecretKey::new(&RLWE128_1024_1);
+    /// let rlk = RLWERLK::new(&sk, 5, 6);
+    ///
+    /// // settings for the encoder and some messages
+    /// let (min, max) = (-2., 1.);
+    /// let (precision, padding) = (2, 5);
+    /// let encoder = Encoder::new_rounding_context(min, max, precision, padding).unwrap();
+    /// let messages_1: Vec<f64> = vec![-2., 1., -1.];
+    /// let messages_2: Vec<f64> = vec![1., -2., 0.];
+    ///
+    /// // encode and encrypt
+    /// let ct_1 = VectorRLWE::encode_encrypt(&sk, &messages_1, &encoder).unwrap();
+    /// let ct_2 = VectorRLWE::encode_encrypt(&sk, &messages_2, &encoder).unwrap();
+    ///
+    /// // multiply and decrypt
+    /// let ct_3 = ct_1.mul_with_relinearization_key(&ct_2, &rlk).unwrap();
+    /// let res = ct_3.decrypt_decode(&sk).unwrap();
+    /// ```
+    pub fn mul_with_relinearization_key(
+        &self,
+        ct: &crate::VectorRLWE,
+        rlk: &crate::RLWERLK,
+    ) -> Result<crate::VectorRLWE, CryptoAPIError> {
+        let mut res = self.clone();
+        res.mul_with_relinearization_key_inplace(ct, rlk)?;
+        Ok(res)
+    }
+
+    /// Multiply each VectorRLWE ciphertext with the polynomial of the matching ciphertext of `ct`
+    /// by computing a tensor product followed by a relinearization, and consume some bits of padding
+    /// it needs to have the same number of ciphertexts in both VectorRLWE
+    /// it also needs that the input encoders all contain zero in their intervals
+    /// the output encoders are centered on zero and their precision is updated from the estimated variance
+    /// the sum of the paddings of the inputs must be at least the biggest padding plus precision of an input,
+    /// plus log2 of the number of products summed in a coefficient (rounded up), plus one
+    ///
+    /// # Argument
+    /// * `ct` - a VectorRLWE struct
+    /// * `rlk` - a relinearization key generated from the secret key of the ciphertexts
+    ///
+    /// # Output
+    /// * DimensionError - if the ciphertexts have incompatible dimensions
+    /// * PolynomialSizeError - if the ciphertexts have incompatible polynomial size
+    /// * NbCTError - if the ciphertexts have not the same number of RLWE ciphertexts
+    /// * ZeroInIntervalError - if 0 is not in the encoder interval
+    /// * PaddingError - if the encoders of a ciphertext have incompatible paddings
+    /// * DeltaError - if the encoders of a ciphertext have incompatible deltas
+    /// * NotEnoughPaddingError - if there is not enough padding for the operation
+    pub fn mul_with_relinearization_key_inplace(
+        &mut self,
+        ct: &crate::VectorRLWE,
+        rlk: &crate::RLWERLK,
+    ) -> Result<(), CryptoAPIError> {
+        // check the dimensions
+        if self.dimension != ct.dimension {
+            return Err(DimensionError!(self.dimension, ct.dimension));
+        } else if self.dimension != rlk.dimension {
+            return Err(DimensionError!(self.dimension, rlk.dimension));
+        }
+        // check the polynomial sizes
+        else if self.polynomial_size != ct.polynomial_size {
+            return Err(PolynomialSizeError!(
+                self.polynomial_size,
+                ct.polynomial_size
+            ));
+        } else if self.polynomial_size != rlk.polynomial_size {
+            return Err(PolynomialSizeError!(
+                self.polynomial_size,
+                rlk.polynomial_size
+            ));
+        }
+        // check if we have the same number of ciphertexts
+        else if self.nb_ciphertexts != ct.nb_ciphertexts {
+            return Err(NbCTError!(self.nb_ciphertexts, ct.nb_ciphertexts));
+        }
+
+        let polynomial_size = self.polynomial_size;
+        for (ciphertext, ct_ciphertext, encoders, ct_encoders, variances, ct_variances) in izip!(
+            self.ciphertexts.iter_mut(),
+            ct.ciphertexts.iter(),
+            self.encoders.chunks_mut(polynomial_size),
+            ct.encoders.chunks(polynomial_size),
+            self.variances.chunks_mut(polynomial_size),
+            ct.variances.chunks(polynomial_size),
+        ) {
+            // get the encoding shared by the valid coefficients of each polynomial
+            let (encoder_1, encoder_2) = match (
+                get_multiplication_encoder(encoders)?,
+                get_multiplication_encoder(ct_encoders)?,
+            ) {
+                (Some(encoder_1), Some(encoder_2)) => (encoder_1, encoder_2),
+                _ => {
+                    // there is no message to multiply
+                    mul_by_constant(ciphertext, 0);
+                    for (encoder, var) in izip!(encoders.iter_mut(), variances.iter_mut()) {
+                        encoder.copy(&crate::Encoder::zero());
+                        *var = 0.;
+                    }
+                    continue;
+                }
+            };
+
+            // count the number of products summed in each coefficient of the output
+            let mut nb_terms: Vec<usize> = vec![0; polynomial_size];
+            for (i, _) in encoders.iter().enumerate().filter(|(_, e)| e.is_valid()) {
+                for (j, _) in ct_encoders.iter().enumerate().filter(|(_, e)| e.is_valid()) {
+                    nb_terms[(i + j) % polynomial_size] += 1;
+                }
+            }
+
+            // compute the scaling factor removed from the tensor product
+            let width_1 = encoder_1.nb_bit_padding + encoder_1.nb_bit_precision;
+            let width_2 = encoder_2.nb_bit_padding + encoder_2.nb_bit_precision;
+            let width = usize::max(width_1, width_2);
+            let padding = encoder_1.nb_bit_padding + encoder_2.nb_bit_padding;
+
+            // check the paddings
+            let max_nb_terms = nb_terms.iter().copied().max().unwrap_or(0);
+            let required_padding = width + (max_nb_terms as f64).log2().ceil() as usize + 1;
+            if padding < required_padding {
+                return Err(NotEnoughPaddingError!(padding, required_padding));
+            }
+
+            // subtract the encoded zeros (pre mul correction)
+            let mut input_1 = ciphertext.clone();
+            add_to_body(&mut input_1, &encoded_zeros(encoders)?);
+            let mut input_2 = ct_ciphertext.clone();
+            add_to_body(&mut input_2, &encoded_zeros(ct_encoders)?);
+
+            // tensor product and relinearization
+            with_engine(|engine| {
+                engine
+                    .discard_tensor_product_glwe_ciphertext(
+                        ciphertext,
+                        &input_1,
+                        &input_2,
+                        &rlk.val,
+                        DeltaLog(<Torus as Numeric>::BITS - width),
+                    )
+                    .unwrap()
+            });
+
+            // call to the NPE to estimate the new variance
+            let var_1 = get_max_variance(encoders, variances);
+            let var_2 = get_max_variance(ct_encoders, ct_variances);
+            let new_var = estimate_multiplication_noise::<Torus, _, _, _, BinaryKeyKind>(
+                PolynomialSize(polynomial_size),
+                GlweDimension(self.dimension),
+                Variance(var_1),
+                Variance(var_2),
+                f64::powi(2., (<Torus as Numeric>::BITS - width_1) as i32),
+                f64::powi(2., (<Torus as Numeric>::BITS - width_2) as i32),
+                f64::powi(2., encoder_1.nb_bit_precision as i32),
+                f64::powi(2., encoder_2.nb_bit_precision as i32),
+                Variance(rlk.variance),
+                DecompositionBaseLog(rlk.base_log),
+                DecompositionLevelCount(rlk.level),
+            )
+            .get_variance();
+
+            // post mul correction and new encoders
+            let mut corrections: Vec<Torus> = vec![0; polynomial_size];
+            for (correction, encoder, var, nb) in izip!(
+                corrections.iter_mut(),
+                encoders.iter_mut(),
+                variances.iter_mut(),
+                nb_terms.iter()
+            ) {
+                *var = new_var;
+                if *nb == 0 {
+                    encoder.copy(&crate::Encoder::zero());
+                    continue;
+                }
+
+                // new encoder centered on zero
+                let log_nb_terms = (*nb as f64).log2().ceil() as usize;
+                let new_delta =
+                    encoder_1.delta * encoder_2.delta * f64::powi(2., log_nb_terms as i32 + 1);
+                let tmp_encoder = crate::Encoder {
+                    o: -new_delta / 2.,
+                    delta: new_delta,
+                    nb_bit_precision: encoder_1.nb_bit_precision
+                        + encoder_2.nb_bit_precision
+                        + log_nb_terms
+                        + 1,
+                    nb_bit_padding: padding - width - log_nb_terms - 1,
+                    round: true,
+                };
+                encoder.copy(&tmp_encoder);
+
+                // update the encoder precision based on the variance
+                encoder.update_precision_from_variance(*var)?;
+
+                // encode 0 with the new encoder
+                *correction = encoder.encode_core(0.)?;
             }
+            add_to_body(ciphertext, &corrections);
         }
         Ok(())
     }
@@ -1506,6 +1775,113 @@ impl VectorRLWE {
     pub fn load(path: &str) -> Result<VectorRLWE, Box<dyn Error>> {
         read_from_file(path)
     }
+
+    /// Decrypt the ciphertexts without decoding them
+    fn phase(&self, sk: &crate::RLWESecretKey) -> Vec<Torus> {
+        with_engine(|engine| {
+            let mut result: Vec<Torus> =
+                Vec::with_capacity(self.nb_ciphertexts * self.polynomial_size);
+            for ciphertext in self.ciphertexts.iter() {
+                let plaintexts = engine.decrypt_glwe_ciphertext(&sk.val, ciphertext).unwrap();
+                result.extend(engine.retrieve_plaintext_vector(&plaintexts).unwrap());
+            }
+            result
+        })
+    }
+
+    /// Add the ciphertexts of `ct` to the ciphertexts of `self`
+    fn add_ciphertexts(&mut self, ct: &crate::VectorRLWE) {
+        with_engine(|engine| {
+            for (output, input) in izip!(self.ciphertexts.iter_mut(), ct.ciphertexts.iter()) {
+                engine.fuse_add_glwe_ciphertext(output, input).unwrap();
+            }
+        });
+    }
+
+    /// Subtract the ciphertexts of `ct` from the ciphertexts of `self`
+    fn sub_ciphertexts(&mut self, ct: &crate::VectorRLWE) {
+        with_engine(|engine| {
+            for (output, input) in izip!(self.ciphertexts.iter_mut(), ct.ciphertexts.iter()) {
+                engine.fuse_sub_glwe_ciphertext(output, input).unwrap();
+            }
+        });
+    }
+}
+
+/// Add `values` to the coefficients of the body of the ciphertext
+fn add_to_body(ct: &mut GlweCiphertext64, values: &[Torus]) {
+    with_engine(|engine| {
+        let plaintexts = engine.create_plaintext_vector(values).unwrap();
+        engine
+            .fuse_add_glwe_ciphertext_plaintext_vector(ct, &plaintexts)
+            .unwrap()
+    });
+}
+
+/// Multiply the whole ciphertext by `constant`
+fn mul_by_constant(ct: &mut GlweCiphertext64, constant: Torus) {
+    with_engine(|engine| {
+        let cleartext = engine.create_cleartext(&constant).unwrap();
+        engine
+            .fuse_mul_glwe_ciphertext_cleartext(ct, &cleartext)
+            .unwrap()
+    });
+}
+
+/// Negate the whole ciphertext
+fn negate(ct: &mut GlweCiphertext64) {
+    mul_by_constant(ct, (1 as Torus).wrapping_neg());
+}
+
+/// Return the opposite of the encoding of zero for each valid encoder of `encoders`
+fn encoded_zeros(encoders: &[crate::Encoder]) -> Result<Vec<Torus>, CryptoAPIError> {
+    let mut res: Vec<Torus> = vec![0; encoders.len()];
+    for (r, encoder) in izip!(res.iter_mut(), encoders.iter()) {
+        if encoder.is_valid() {
+            *r = encoder.encode_core(0.)?.wrapping_neg();
+        }
+    }
+    Ok(res)
+}
+
+/// Check that the valid encoders of a polynomial can be used in a polynomial multiplication
+/// and return an encoder with their delta, their padding and their biggest precision
+fn get_multiplication_encoder(
+    encoders: &[crate::Encoder],
+) -> Result<Option<crate::Encoder>, CryptoAPIError> {
+    let mut res: Option<crate::Encoder> = None;
+    for encoder in encoders.iter().filter(|e| e.is_valid()) {
+        // check that zero is in the interval
+        if encoder.o > 0. || encoder.o + encoder.delta < 0. {
+            return Err(ZeroInIntervalError!(encoder.o, encoder.delta));
+        }
+        match res.as_mut() {
+            None => res = Some(encoder.clone()),
+            Some(r) => {
+                // check nb bit padding
+                if r.nb_bit_padding != encoder.nb_bit_padding {
+                    return Err(PaddingError!(r.nb_bit_padding, encoder.nb_bit_padding));
+                }
+                // check the deltas
+                else if !deltas_eq!(r.delta, encoder.delta) {
+                    return Err(DeltaError!(r.delta, encoder.delta));
+                }
+                r.nb_bit_precision = usize::max(r.nb_bit_precision, encoder.nb_bit_precision);
+            }
+        }
+    }
+    Ok(res)
+}
+
+/// Return the biggest variance among the coefficients with a valid encoder
+fn get_max_variance(encoders: &[crate::Encoder], variances: &[f64]) -> f64 {
+    let mut res: f64 = 0.;
+    for (encoder, var) in izip!(encoders.iter(), variances.iter()) {
+        if encoder.is_valid() {
+            res = f64::max(res, *var);
+        }
+    }
+    res
 }
 
 // pub ciphertexts: Vec<Torus>,
@@ -1518,24 +1894,25 @@ impl VectorRLWE {
 impl fmt::Display for VectorRLWE {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let n = 2;
+        let samples: Vec<Torus> = self
+            .ciphertexts
+            .iter()
+            .flat_map(|ciphertext| crate::raw::coefficients::<_, Torus>(ciphertext))
+            .collect();
         let mut to_be_print: String = "".to_string();
         to_be_print += " VectorRLWE {\n         -> samples = [";
 
-        if self.ciphertexts.as_tensor().len() <= 2 * n {
-            for elt in self.ciphertexts.as_tensor().iter() {
+        if samples.len() <= 2 * n {
+            for elt in samples.iter() {
                 to_be_print = to_be_print + &format!("{}, ", *elt);
             }
         } else {
-            for elt in self.ciphertexts.as_tensor().get_sub(0..n).iter() {
+            for elt in samples[0..n].iter() {
                 to_be_print = to_be_print + &format!("{}, ", *elt);
             }
             to_be_print += "...";
-            for elt in self
-                .ciphertexts
-                .as_tensor()
-                .get_sub(self.ciphertexts.as_tensor().len() - n..)
-                .iter()
-            {
+
+            for elt in samples[samples.len() - n..].iter() {
                 to_be_print = to_be_print + &format!("{}, ", *elt);
             }
         }
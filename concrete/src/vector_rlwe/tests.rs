@@ -578,3 +578,166 @@ fn test_encode_encrypt_on_cst_x_mul_constant_with_padding_inplace_x_decrypt_with
     }
     assert_eq!(cpt, nb_messages);
 }
+
+#[test]
+fn test_encode_encrypt_packed_x_mul_with_relinearization_key_inplace_x_decrypt_with_encoders() {
+    // generate a secret key and a relinearization key
+    let secret_key = crate::RLWESecretKey::new(&crate::RLWE128_1024_1);
+    let rlk = crate::RLWERLK::new(&secret_key, 5, 6);
+
+    // random settings
+    let precision: usize = random_index!(2) + 1;
+    let nb_messages: usize = random_index!(30) + 10;
+    let log_nb_terms = (nb_messages as f64).log2().ceil() as usize;
+    let padding: usize = precision + log_nb_terms + 1;
+    let max = f64::powi(2., precision as i32 - 1) - 1.;
+    let min = -max - 1.;
+
+    // encoders
+    let encoder = crate::Encoder::new_rounding_context(min, max, precision, padding).unwrap();
+
+    // two lists of integer messages
+    let messages1: Vec<f64> = random_messages!(min, max, nb_messages)
+        .iter()
+        .map(|m| m.round())
+        .collect();
+    let messages2: Vec<f64> = random_messages!(min, max, nb_messages)
+        .iter()
+        .map(|m| m.round())
+        .collect();
+
+    // compute the negacyclic product of the two polynomials in clear
+    let mut expected: Vec<f64> = vec![0.; 2 * nb_messages - 1];
+    for (i, m1) in messages1.iter().enumerate() {
+        for (j, m2) in messages2.iter().enumerate() {
+            expected[i + j] += m1 * m2;
+        }
+    }
+
+    // encode and encrypt
+    let mut ciphertext =
+        crate::VectorRLWE::encode_encrypt_packed(&secret_key, &messages1, &encoder).unwrap();
+    let ciphertext2 =
+        crate::VectorRLWE::encode_encrypt_packed(&secret_key, &messages2, &encoder).unwrap();
+
+    // multiplication between the two ciphertexts
+    ciphertext
+        .mul_with_relinearization_key_inplace(&ciphertext2, &rlk)
+        .unwrap();
+
+    // decryption
+    let (decryptions, dec_encoders) = ciphertext.decrypt_with_encoders(&secret_key).unwrap();
+    assert_eq!(decryptions.len(), expected.len());
+
+    // check the precision loss related to the multiplication
+    for (e, d, enc) in izip!(expected.iter(), decryptions.iter(), dec_encoders.iter()) {
+        assert_eq_granularity!(e, d, enc);
+    }
+}
+
+#[test]
+fn test_encode_encrypt_packed_x_homomorphic_add_inplace_x_decrypt() {
+    use crate::traits::HomomorphicAdd;
+
+    // generate a secret key
+    let dimension: usize = random_index!(4) + 1;
+    let polynomial_size: usize = 1024;
+    let log_std_dev: i32 = -(random_index!(40) as i32 + 20);
+    let params = crate::RLWEParams::new(polynomial_size, dimension, log_std_dev).unwrap();
+    let sk = crate::RLWESecretKey::new(&params);
+
+    // random settings
+    let (min1, max1) = generate_random_interval!();
+    let (min2, _max2) = generate_random_interval!();
+    let max2 = min2 + max1 - min1; // same interval size
+    let (precision, mut padding) = generate_precision_padding!(8, 3);
+    padding += 1; // at least one bit
+    let nb_messages: usize = random_index!(polynomial_size - 1) + 1;
+
+    // encoders
+    let encoder1 = crate::Encoder::new(min1, max1, precision, padding).unwrap();
+    let encoder2 = crate::Encoder::new(min2, max2, precision, padding).unwrap();
+
+    // two lists of messages
+    let messages1: Vec<f64> = random_messages!(min1, max1, nb_messages);
+    let messages2: Vec<f64> = random_messages!(min2, max2, nb_messages);
+
+    // encode and encrypt
+    let mut ciphertext1 =
+        crate::VectorRLWE::encode_encrypt_packed(&sk, &messages1, &encoder1).unwrap();
+    let ciphertext2 = crate::VectorRLWE::encode_encrypt_packed(&sk, &messages2, &encoder2).unwrap();
+
+    // addition with the trait
+    ciphertext1.homomorphic_add_inplace(&ciphertext2).unwrap();
+
+    // decryption
+    let decryptions: Vec<f64> = ciphertext1.decrypt_decode_round(&sk).unwrap();
+
+    // check the precision loss related to the encryption
+    let mut cpt: usize = 0;
+    for (m1, m2, d, e) in izip!(
+        messages1.iter(),
+        messages2.iter(),
+        decryptions.iter(),
+        ciphertext1.encoders.iter()
+    ) {
+        assert_eq_granularity!(m1 + m2, d, e);
+        assert_eq!(precision, e.nb_bit_precision);
+        cpt += 1;
+    }
+    assert_eq!(cpt, nb_messages);
+}
+
+#[test]
+fn test_encode_encrypt_packed_x_neg_chained_operators_x_decrypt() {
+    // generate a secret key
+    let dimension: usize = random_index!(4) + 1;
+    let polynomial_size: usize = 1024;
+    let log_std_dev: i32 = -(random_index!(40) as i32 + 20);
+    let params = crate::RLWEParams::new(polynomial_size, dimension, log_std_dev).unwrap();
+    let sk = crate::RLWESecretKey::new(&params);
+
+    // random settings
+    let (min1, max1) = generate_random_interval!();
+    let (min2, _max2) = generate_random_interval!();
+    let max2 = min2 + max1 - min1; // same interval size
+    let (precision, mut padding) = generate_precision_padding!(8, 3);
+    padding += 1; // at least one bit
+    let nb_messages: usize = random_index!(polynomial_size - 1) + 1;
+
+    // encoders
+    let encoder1 = crate::Encoder::new(min1, max1, precision, padding).unwrap();
+    let encoder2 = crate::Encoder::new(min2, max2, precision, padding).unwrap();
+
+    // two lists of messages
+    let messages1: Vec<f64> = random_messages!(min1, max1, nb_messages);
+    let messages2: Vec<f64> = random_messages!(min2, max2, nb_messages);
+
+    // encode and encrypt
+    let ciphertext1 = crate::VectorRLWE::encode_encrypt_packed(&sk, &messages1, &encoder1).unwrap();
+    let ciphertext2 = crate::VectorRLWE::encode_encrypt_packed(&sk, &messages2, &encoder2).unwrap();
+
+    // opposite and opposite of a sum with the operators
+    let opposite = (-&ciphertext1).unwrap();
+    let opposite_sum = (-(&ciphertext1 + &ciphertext2)).unwrap();
+
+    // decryption
+    let decryptions: Vec<f64> = opposite.decrypt_decode_round(&sk).unwrap();
+    let decryptions_sum: Vec<f64> = opposite_sum.decrypt_decode_round(&sk).unwrap();
+
+    // check the precision loss related to the encryption
+    let mut cpt: usize = 0;
+    for (m1, m2, d, d_sum, e, e_sum) in izip!(
+        messages1.iter(),
+        messages2.iter(),
+        decryptions.iter(),
+        decryptions_sum.iter(),
+        opposite.encoders.iter(),
+        opposite_sum.encoders.iter()
+    ) {
+        assert_eq_granularity!(-m1, d, e);
+        assert_eq_granularity!(-m1 - m2, d_sum, e_sum);
+        cpt += 1;
+    }
+    assert_eq!(cpt, nb_messages);
+}